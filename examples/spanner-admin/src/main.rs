@@ -1,6 +1,18 @@
+use std::sync::Arc;
+
 use googapis::{
-    google::spanner::admin::database::v1::{
-        database_admin_client::DatabaseAdminClient, ListDatabasesRequest,
+    google::{
+        longrunning::operations_client::OperationsClient,
+        spanner::{
+            admin::database::v1::{
+                database_admin_client::DatabaseAdminClient, ListDatabasesRequest,
+            },
+            v1::spanner_client::SpannerClient,
+        },
+    },
+    spanner::{
+        migration::{Migration, Migrator},
+        session::{PoolSettings, SessionPool},
     },
     CERTIFICATES,
 };
@@ -8,16 +20,29 @@ use gouth::Token;
 use tonic::{
     metadata::MetadataValue,
     transport::{Certificate, Channel, ClientTlsConfig},
-    Request,
+    Request, Status,
 };
 
 // PROJECT=your-project-id DATABASE=your-spanner-instance cargo run
+//
+// The migrations are applied to a database of the instance when DATABASE_ID is set.
+
+fn authorization(
+    token: Arc<Token>,
+) -> impl Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static {
+    move |mut req: Request<()>| {
+        let token = &*token.header_value().unwrap();
+        let meta = MetadataValue::from_str(token).unwrap();
+        req.metadata_mut().insert("authorization", meta);
+        Ok(req)
+    }
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let project = std::env::var("PROJECT")?;
     let database = std::env::var("DATABASE")?;
-    let token = Token::new()?;
+    let token = Arc::new(Token::new()?);
 
     let tls_config = ClientTlsConfig::new()
         .ca_certificate(Certificate::from_pem(CERTIFICATES))
@@ -28,12 +53,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .connect()
         .await?;
 
-    let mut service = DatabaseAdminClient::with_interceptor(channel, move |mut req: Request<()>| {
-        let token = &*token.header_value().unwrap();
-        let meta = MetadataValue::from_str(token).unwrap();
-        req.metadata_mut().insert("authorization", meta);
-        Ok(req)
-    });
+    let mut service =
+        DatabaseAdminClient::with_interceptor(channel.clone(), authorization(token.clone()));
 
     let response = service
         .list_databases(Request::new(ListDatabasesRequest {
@@ -45,5 +66,48 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("RESPONSE={:?}", response);
 
+    let database_id = match std::env::var("DATABASE_ID") {
+        Ok(id) => id,
+        Err(_) => return Ok(()),
+    };
+    let pool = SessionPool::new(
+        SpannerClient::with_interceptor(channel.clone(), authorization(token.clone())),
+        format!(
+            "projects/{}/instances/{}/databases/{}",
+            project, database, database_id
+        ),
+        PoolSettings::default(),
+    )
+    .await?;
+    let operations = OperationsClient::with_interceptor(channel, authorization(token));
+    let mut migrator = Migrator::new(service, operations, pool)
+        .migration(Migration::new(1, "create singers").statement(
+            "CREATE TABLE Singers (SingerId INT64 NOT NULL, Name STRING(MAX)) PRIMARY KEY (SingerId)",
+        ))
+        .migration(
+            Migration::new(2, "index names")
+                .statement("CREATE INDEX SingersByName ON Singers(Name)"),
+        );
+
+    for migration in migrator.pending().await? {
+        println!("PENDING={} {:?}", migration.version(), migration.statements());
+    }
+    let versions = migrator
+        .migrate_with_progress(|migration, metadata| {
+            println!(
+                "MIGRATION={} APPLIED={}/{}",
+                migration.version(),
+                metadata.commit_timestamps.len(),
+                metadata.statements.len()
+            )
+        })
+        .await?;
+    println!("APPLIED={:?}", versions);
+
+    let drift = migrator.drift().await?;
+    if !drift.is_empty() {
+        println!("DRIFT={:?}", drift);
+    }
+
     Ok(())
 }
@@ -1110,3 +1110,51 @@ pub mod ad_mob_api_server {
         const NAME: &'static str = "google.ads.admob.v1.AdMobApi";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListPublisherAccountsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListPublisherAccountsResponse {
+    type Item = PublisherAccount;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.account
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> ad_mob_api_client::AdMobApiClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_publisher_accounts`, following `next_page_token`."]
+    pub fn list_publisher_accounts_pages(
+        &self,
+        request: ListPublisherAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<ListPublisherAccountsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_publisher_accounts(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_publisher_accounts`."]
+    pub fn list_publisher_accounts_stream(
+        &self,
+        request: ListPublisherAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<PublisherAccount, tonic::Status>> {
+        crate::paging::items(self.list_publisher_accounts_pages(request))
+    }
+}
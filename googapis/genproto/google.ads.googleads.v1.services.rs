@@ -24492,3 +24492,242 @@ pub mod video_service_server {
         const NAME: &'static str = "google.ads.googleads.v1.services.VideoService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCampaignDraftAsyncErrorsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCampaignDraftAsyncErrorsResponse {
+    type Item = super::super::super::super::rpc::Status;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.errors
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> campaign_draft_service_client::CampaignDraftServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_campaign_draft_async_errors`, following `next_page_token`."]
+    pub fn list_campaign_draft_async_errors_pages(
+        &self,
+        request: ListCampaignDraftAsyncErrorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCampaignDraftAsyncErrorsResponse, tonic::Status>>
+    {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_campaign_draft_async_errors(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_campaign_draft_async_errors`."]
+    pub fn list_campaign_draft_async_errors_stream(
+        &self,
+        request: ListCampaignDraftAsyncErrorsRequest,
+    ) -> impl futures::Stream<Item = Result<super::super::super::super::rpc::Status, tonic::Status>>
+    {
+        crate::paging::items(self.list_campaign_draft_async_errors_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCampaignExperimentAsyncErrorsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCampaignExperimentAsyncErrorsResponse {
+    type Item = super::super::super::super::rpc::Status;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.errors
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> campaign_experiment_service_client::CampaignExperimentServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_campaign_experiment_async_errors`, following `next_page_token`."]
+    pub fn list_campaign_experiment_async_errors_pages(
+        &self,
+        request: ListCampaignExperimentAsyncErrorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCampaignExperimentAsyncErrorsResponse, tonic::Status>>
+    {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_campaign_experiment_async_errors(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_campaign_experiment_async_errors`."]
+    pub fn list_campaign_experiment_async_errors_stream(
+        &self,
+        request: ListCampaignExperimentAsyncErrorsRequest,
+    ) -> impl futures::Stream<Item = Result<super::super::super::super::rpc::Status, tonic::Status>>
+    {
+        crate::paging::items(self.list_campaign_experiment_async_errors_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchGoogleAdsFieldsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchGoogleAdsFieldsResponse {
+    type Item = super::resources::GoogleAdsField;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.results
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> google_ads_field_service_client::GoogleAdsFieldServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search_google_ads_fields`, following `next_page_token`."]
+    pub fn search_google_ads_fields_pages(
+        &self,
+        request: SearchGoogleAdsFieldsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchGoogleAdsFieldsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_google_ads_fields(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_google_ads_fields`."]
+    pub fn search_google_ads_fields_stream(
+        &self,
+        request: SearchGoogleAdsFieldsRequest,
+    ) -> impl futures::Stream<Item = Result<super::resources::GoogleAdsField, tonic::Status>> {
+        crate::paging::items(self.search_google_ads_fields_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchGoogleAdsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchGoogleAdsResponse {
+    type Item = GoogleAdsRow;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.results
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> google_ads_service_client::GoogleAdsServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search`, following `next_page_token`."]
+    pub fn search_pages(
+        &self,
+        request: SearchGoogleAdsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchGoogleAdsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.search(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search`."]
+    pub fn search_stream(
+        &self,
+        request: SearchGoogleAdsRequest,
+    ) -> impl futures::Stream<Item = Result<GoogleAdsRow, tonic::Status>> {
+        crate::paging::items(self.search_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListMutateJobResultsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListMutateJobResultsResponse {
+    type Item = MutateJobResult;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.results
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> mutate_job_service_client::MutateJobServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_mutate_job_results`, following `next_page_token`."]
+    pub fn list_mutate_job_results_pages(
+        &self,
+        request: ListMutateJobResultsRequest,
+    ) -> impl futures::Stream<Item = Result<ListMutateJobResultsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_mutate_job_results(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_mutate_job_results`."]
+    pub fn list_mutate_job_results_stream(
+        &self,
+        request: ListMutateJobResultsRequest,
+    ) -> impl futures::Stream<Item = Result<MutateJobResult, tonic::Status>> {
+        crate::paging::items(self.list_mutate_job_results_pages(request))
+    }
+}
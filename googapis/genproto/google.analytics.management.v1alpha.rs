@@ -2390,3 +2390,324 @@ pub mod management_server {
         const NAME: &'static str = "google.analytics.management.v1alpha.Management";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAccountsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAccountsResponse {
+    type Item = Account;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.accounts
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListPropertiesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListPropertiesResponse {
+    type Item = Property;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.properties
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListUserLinksRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListUserLinksResponse {
+    type Item = UserLink;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.user_links
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for AuditUserLinksRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for AuditUserLinksResponse {
+    type Item = AuditUserLink;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.user_links
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListWebDataStreamsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListWebDataStreamsResponse {
+    type Item = WebDataStream;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.web_data_streams
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListIosAppDataStreamsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListIosAppDataStreamsResponse {
+    type Item = IosAppDataStream;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.ios_app_data_streams
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAndroidAppDataStreamsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAndroidAppDataStreamsResponse {
+    type Item = AndroidAppDataStream;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.android_app_data_streams
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListGoogleAdsLinksRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListGoogleAdsLinksResponse {
+    type Item = GoogleAdsLink;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.google_ads_links
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> management_client::ManagementClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_accounts`, following `next_page_token`."]
+    pub fn list_accounts_pages(
+        &self,
+        request: ListAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAccountsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_accounts(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_accounts`."]
+    pub fn list_accounts_stream(
+        &self,
+        request: ListAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<Account, tonic::Status>> {
+        crate::paging::items(self.list_accounts_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_properties`, following `next_page_token`."]
+    pub fn list_properties_pages(
+        &self,
+        request: ListPropertiesRequest,
+    ) -> impl futures::Stream<Item = Result<ListPropertiesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_properties(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_properties`."]
+    pub fn list_properties_stream(
+        &self,
+        request: ListPropertiesRequest,
+    ) -> impl futures::Stream<Item = Result<Property, tonic::Status>> {
+        crate::paging::items(self.list_properties_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_user_links`, following `next_page_token`."]
+    pub fn list_user_links_pages(
+        &self,
+        request: ListUserLinksRequest,
+    ) -> impl futures::Stream<Item = Result<ListUserLinksResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_user_links(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_user_links`."]
+    pub fn list_user_links_stream(
+        &self,
+        request: ListUserLinksRequest,
+    ) -> impl futures::Stream<Item = Result<UserLink, tonic::Status>> {
+        crate::paging::items(self.list_user_links_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `audit_user_links`, following `next_page_token`."]
+    pub fn audit_user_links_pages(
+        &self,
+        request: AuditUserLinksRequest,
+    ) -> impl futures::Stream<Item = Result<AuditUserLinksResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .audit_user_links(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `audit_user_links`."]
+    pub fn audit_user_links_stream(
+        &self,
+        request: AuditUserLinksRequest,
+    ) -> impl futures::Stream<Item = Result<AuditUserLink, tonic::Status>> {
+        crate::paging::items(self.audit_user_links_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_web_data_streams`, following `next_page_token`."]
+    pub fn list_web_data_streams_pages(
+        &self,
+        request: ListWebDataStreamsRequest,
+    ) -> impl futures::Stream<Item = Result<ListWebDataStreamsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_web_data_streams(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_web_data_streams`."]
+    pub fn list_web_data_streams_stream(
+        &self,
+        request: ListWebDataStreamsRequest,
+    ) -> impl futures::Stream<Item = Result<WebDataStream, tonic::Status>> {
+        crate::paging::items(self.list_web_data_streams_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_ios_app_data_streams`, following `next_page_token`."]
+    pub fn list_ios_app_data_streams_pages(
+        &self,
+        request: ListIosAppDataStreamsRequest,
+    ) -> impl futures::Stream<Item = Result<ListIosAppDataStreamsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_ios_app_data_streams(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_ios_app_data_streams`."]
+    pub fn list_ios_app_data_streams_stream(
+        &self,
+        request: ListIosAppDataStreamsRequest,
+    ) -> impl futures::Stream<Item = Result<IosAppDataStream, tonic::Status>> {
+        crate::paging::items(self.list_ios_app_data_streams_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_android_app_data_streams`, following `next_page_token`."]
+    pub fn list_android_app_data_streams_pages(
+        &self,
+        request: ListAndroidAppDataStreamsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAndroidAppDataStreamsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_android_app_data_streams(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_android_app_data_streams`."]
+    pub fn list_android_app_data_streams_stream(
+        &self,
+        request: ListAndroidAppDataStreamsRequest,
+    ) -> impl futures::Stream<Item = Result<AndroidAppDataStream, tonic::Status>> {
+        crate::paging::items(self.list_android_app_data_streams_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_google_ads_links`, following `next_page_token`."]
+    pub fn list_google_ads_links_pages(
+        &self,
+        request: ListGoogleAdsLinksRequest,
+    ) -> impl futures::Stream<Item = Result<ListGoogleAdsLinksResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_google_ads_links(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_google_ads_links`."]
+    pub fn list_google_ads_links_stream(
+        &self,
+        request: ListGoogleAdsLinksRequest,
+    ) -> impl futures::Stream<Item = Result<GoogleAdsLink, tonic::Status>> {
+        crate::paging::items(self.list_google_ads_links_pages(request))
+    }
+}
@@ -1715,3 +1715,129 @@ pub mod service_manager_server {
         const NAME: &'static str = "google.api.servicemanagement.v1.ServiceManager";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListServicesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListServicesResponse {
+    type Item = ManagedService;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.services
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListServiceConfigsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListServiceConfigsResponse {
+    type Item = super::super::Service;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.service_configs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListServiceRolloutsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListServiceRolloutsResponse {
+    type Item = Rollout;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.rollouts
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> service_manager_client::ServiceManagerClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_services`, following `next_page_token`."]
+    pub fn list_services_pages(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListServicesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_services(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_services`."]
+    pub fn list_services_stream(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ManagedService, tonic::Status>> {
+        crate::paging::items(self.list_services_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_service_configs`, following `next_page_token`."]
+    pub fn list_service_configs_pages(
+        &self,
+        request: ListServiceConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ListServiceConfigsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_service_configs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_service_configs`."]
+    pub fn list_service_configs_stream(
+        &self,
+        request: ListServiceConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<super::super::Service, tonic::Status>> {
+        crate::paging::items(self.list_service_configs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_service_rollouts`, following `next_page_token`."]
+    pub fn list_service_rollouts_pages(
+        &self,
+        request: ListServiceRolloutsRequest,
+    ) -> impl futures::Stream<Item = Result<ListServiceRolloutsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_service_rollouts(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_service_rollouts`."]
+    pub fn list_service_rollouts_stream(
+        &self,
+        request: ListServiceRolloutsRequest,
+    ) -> impl futures::Stream<Item = Result<Rollout, tonic::Status>> {
+        crate::paging::items(self.list_service_rollouts_pages(request))
+    }
+}
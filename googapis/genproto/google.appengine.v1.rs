@@ -2555,3 +2555,147 @@ pub struct OperationMetadataV1 {
     #[prost(string, tag = "5")]
     pub target: std::string::String,
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListInstancesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListInstancesResponse {
+    type Item = Instance;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.instances
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> instances_client::InstancesClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_instances`, following `next_page_token`."]
+    pub fn list_instances_pages(
+        &self,
+        request: ListInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<ListInstancesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_instances(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_instances`."]
+    pub fn list_instances_stream(
+        &self,
+        request: ListInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<Instance, tonic::Status>> {
+        crate::paging::items(self.list_instances_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListVersionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListVersionsResponse {
+    type Item = Version;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.versions
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> versions_client::VersionsClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_versions`, following `next_page_token`."]
+    pub fn list_versions_pages(
+        &self,
+        request: ListVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListVersionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_versions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_versions`."]
+    pub fn list_versions_stream(
+        &self,
+        request: ListVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<Version, tonic::Status>> {
+        crate::paging::items(self.list_versions_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListServicesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListServicesResponse {
+    type Item = Service;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.services
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> services_client::ServicesClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_services`, following `next_page_token`."]
+    pub fn list_services_pages(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListServicesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_services(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_services`."]
+    pub fn list_services_stream(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<Service, tonic::Status>> {
+        crate::paging::items(self.list_services_pages(request))
+    }
+}
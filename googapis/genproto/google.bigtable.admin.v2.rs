@@ -4223,3 +4223,307 @@ pub mod bigtable_table_admin_server {
         const NAME: &'static str = "google.bigtable.admin.v2.BigtableTableAdmin";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAppProfilesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAppProfilesResponse {
+    type Item = AppProfile;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.app_profiles
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> bigtable_instance_admin_client::BigtableInstanceAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_app_profiles`, following `next_page_token`."]
+    pub fn list_app_profiles_pages(
+        &self,
+        request: ListAppProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<ListAppProfilesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_app_profiles(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_app_profiles`."]
+    pub fn list_app_profiles_stream(
+        &self,
+        request: ListAppProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<AppProfile, tonic::Status>> {
+        crate::paging::items(self.list_app_profiles_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTablesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTablesResponse {
+    type Item = Table;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.tables
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSnapshotsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSnapshotsResponse {
+    type Item = Snapshot;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.snapshots
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListBackupsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListBackupsResponse {
+    type Item = Backup;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.backups
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> bigtable_table_admin_client::BigtableTableAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_tables`, following `next_page_token`."]
+    pub fn list_tables_pages(
+        &self,
+        request: ListTablesRequest,
+    ) -> impl futures::Stream<Item = Result<ListTablesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_tables(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_tables`."]
+    pub fn list_tables_stream(
+        &self,
+        request: ListTablesRequest,
+    ) -> impl futures::Stream<Item = Result<Table, tonic::Status>> {
+        crate::paging::items(self.list_tables_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_snapshots`, following `next_page_token`."]
+    pub fn list_snapshots_pages(
+        &self,
+        request: ListSnapshotsRequest,
+    ) -> impl futures::Stream<Item = Result<ListSnapshotsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_snapshots(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_snapshots`."]
+    pub fn list_snapshots_stream(
+        &self,
+        request: ListSnapshotsRequest,
+    ) -> impl futures::Stream<Item = Result<Snapshot, tonic::Status>> {
+        crate::paging::items(self.list_snapshots_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_backups`, following `next_page_token`."]
+    pub fn list_backups_pages(
+        &self,
+        request: ListBackupsRequest,
+    ) -> impl futures::Stream<Item = Result<ListBackupsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_backups(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_backups`."]
+    pub fn list_backups_stream(
+        &self,
+        request: ListBackupsRequest,
+    ) -> impl futures::Stream<Item = Result<Backup, tonic::Status>> {
+        crate::paging::items(self.list_backups_pages(request))
+    }
+}
+#[cfg(feature = "longrunning")]
+impl<T> bigtable_instance_admin_client::BigtableInstanceAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody>,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Calls `create_instance` and returns its operation typed by `operation_info`."]
+    pub async fn create_instance_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<CreateInstanceRequest>,
+    ) -> Result<crate::longrunning::Operation<Instance, CreateInstanceMetadata>, tonic::Status>
+    {
+        let response = self.create_instance(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.bigtable.admin.v2.Instance",
+            "type.googleapis.com/google.bigtable.admin.v2.CreateInstanceMetadata",
+        ))
+    }
+    #[doc = r" Calls `partial_update_instance` and returns its operation typed by `operation_info`."]
+    pub async fn partial_update_instance_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<PartialUpdateInstanceRequest>,
+    ) -> Result<crate::longrunning::Operation<Instance, UpdateInstanceMetadata>, tonic::Status>
+    {
+        let response = self.partial_update_instance(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.bigtable.admin.v2.Instance",
+            "type.googleapis.com/google.bigtable.admin.v2.UpdateInstanceMetadata",
+        ))
+    }
+    #[doc = r" Calls `create_cluster` and returns its operation typed by `operation_info`."]
+    pub async fn create_cluster_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<CreateClusterRequest>,
+    ) -> Result<crate::longrunning::Operation<Cluster, CreateClusterMetadata>, tonic::Status> {
+        let response = self.create_cluster(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.bigtable.admin.v2.Cluster",
+            "type.googleapis.com/google.bigtable.admin.v2.CreateClusterMetadata",
+        ))
+    }
+    #[doc = r" Calls `update_cluster` and returns its operation typed by `operation_info`."]
+    pub async fn update_cluster_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<Cluster>,
+    ) -> Result<crate::longrunning::Operation<Cluster, UpdateClusterMetadata>, tonic::Status> {
+        let response = self.update_cluster(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.bigtable.admin.v2.Cluster",
+            "type.googleapis.com/google.bigtable.admin.v2.UpdateClusterMetadata",
+        ))
+    }
+    #[doc = r" Calls `update_app_profile` and returns its operation typed by `operation_info`."]
+    pub async fn update_app_profile_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<UpdateAppProfileRequest>,
+    ) -> Result<crate::longrunning::Operation<AppProfile, UpdateAppProfileMetadata>, tonic::Status>
+    {
+        let response = self.update_app_profile(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.bigtable.admin.v2.AppProfile",
+            "type.googleapis.com/google.bigtable.admin.v2.UpdateAppProfileMetadata",
+        ))
+    }
+}
+#[cfg(feature = "longrunning")]
+impl<T> bigtable_table_admin_client::BigtableTableAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody>,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Calls `create_table_from_snapshot` and returns its operation typed by `operation_info`."]
+    pub async fn create_table_from_snapshot_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<CreateTableFromSnapshotRequest>,
+    ) -> Result<crate::longrunning::Operation<Table, CreateTableFromSnapshotMetadata>, tonic::Status>
+    {
+        let response = self.create_table_from_snapshot(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.bigtable.admin.v2.Table",
+            "type.googleapis.com/google.bigtable.admin.v2.CreateTableFromSnapshotMetadata",
+        ))
+    }
+    #[doc = r" Calls `snapshot_table` and returns its operation typed by `operation_info`."]
+    pub async fn snapshot_table_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<SnapshotTableRequest>,
+    ) -> Result<crate::longrunning::Operation<Snapshot, SnapshotTableMetadata>, tonic::Status> {
+        let response = self.snapshot_table(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.bigtable.admin.v2.Snapshot",
+            "type.googleapis.com/google.bigtable.admin.v2.SnapshotTableMetadata",
+        ))
+    }
+    #[doc = r" Calls `create_backup` and returns its operation typed by `operation_info`."]
+    pub async fn create_backup_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<CreateBackupRequest>,
+    ) -> Result<crate::longrunning::Operation<Backup, CreateBackupMetadata>, tonic::Status> {
+        let response = self.create_backup(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.bigtable.admin.v2.Backup",
+            "type.googleapis.com/google.bigtable.admin.v2.CreateBackupMetadata",
+        ))
+    }
+    #[doc = r" Calls `restore_table` and returns its operation typed by `operation_info`."]
+    pub async fn restore_table_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<RestoreTableRequest>,
+    ) -> Result<crate::longrunning::Operation<Table, RestoreTableMetadata>, tonic::Status> {
+        let response = self.restore_table(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.bigtable.admin.v2.Table",
+            "type.googleapis.com/google.bigtable.admin.v2.RestoreTableMetadata",
+        ))
+    }
+}
@@ -471,3 +471,51 @@ pub mod build_service_server {
         const NAME: &'static str = "google.chromeos.moblab.v1beta1.BuildService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListBuildsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListBuildsResponse {
+    type Item = Build;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.builds
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> build_service_client::BuildServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_builds`, following `next_page_token`."]
+    pub fn list_builds_pages(
+        &self,
+        request: ListBuildsRequest,
+    ) -> impl futures::Stream<Item = Result<ListBuildsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_builds(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_builds`."]
+    pub fn list_builds_stream(
+        &self,
+        request: ListBuildsRequest,
+    ) -> impl futures::Stream<Item = Result<Build, tonic::Status>> {
+        crate::paging::items(self.list_builds_pages(request))
+    }
+}
@@ -927,3 +927,51 @@ pub mod access_approval_server {
         const NAME: &'static str = "google.cloud.accessapproval.v1.AccessApproval";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListApprovalRequestsMessage {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListApprovalRequestsResponse {
+    type Item = ApprovalRequest;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.approval_requests
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> access_approval_client::AccessApprovalClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_approval_requests`, following `next_page_token`."]
+    pub fn list_approval_requests_pages(
+        &self,
+        request: ListApprovalRequestsMessage,
+    ) -> impl futures::Stream<Item = Result<ListApprovalRequestsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_approval_requests(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_approval_requests`."]
+    pub fn list_approval_requests_stream(
+        &self,
+        request: ListApprovalRequestsMessage,
+    ) -> impl futures::Stream<Item = Result<ApprovalRequest, tonic::Status>> {
+        crate::paging::items(self.list_approval_requests_pages(request))
+    }
+}
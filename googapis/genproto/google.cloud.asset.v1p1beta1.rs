@@ -435,3 +435,90 @@ pub mod asset_service_server {
         const NAME: &'static str = "google.cloud.asset.v1p1beta1.AssetService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchAllResourcesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchAllResourcesResponse {
+    type Item = StandardResourceMetadata;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.results
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchAllIamPoliciesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchAllIamPoliciesResponse {
+    type Item = IamPolicySearchResult;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.results
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> asset_service_client::AssetServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search_all_resources`, following `next_page_token`."]
+    pub fn search_all_resources_pages(
+        &self,
+        request: SearchAllResourcesRequest,
+    ) -> impl futures::Stream<Item = Result<SearchAllResourcesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_all_resources(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_all_resources`."]
+    pub fn search_all_resources_stream(
+        &self,
+        request: SearchAllResourcesRequest,
+    ) -> impl futures::Stream<Item = Result<StandardResourceMetadata, tonic::Status>> {
+        crate::paging::items(self.search_all_resources_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_all_iam_policies`, following `next_page_token`."]
+    pub fn search_all_iam_policies_pages(
+        &self,
+        request: SearchAllIamPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<SearchAllIamPoliciesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_all_iam_policies(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_all_iam_policies`."]
+    pub fn search_all_iam_policies_stream(
+        &self,
+        request: SearchAllIamPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<IamPolicySearchResult, tonic::Status>> {
+        crate::paging::items(self.search_all_iam_policies_pages(request))
+    }
+}
@@ -5699,3 +5699,207 @@ pub mod auto_ml_server {
         const NAME: &'static str = "google.cloud.automl.v1beta1.AutoMl";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDatasetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDatasetsResponse {
+    type Item = Dataset;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.datasets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTableSpecsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTableSpecsResponse {
+    type Item = TableSpec;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.table_specs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListColumnSpecsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListColumnSpecsResponse {
+    type Item = ColumnSpec;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.column_specs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListModelsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListModelsResponse {
+    type Item = Model;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.model
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListModelEvaluationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListModelEvaluationsResponse {
+    type Item = ModelEvaluation;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.model_evaluation
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> auto_ml_client::AutoMlClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_datasets`, following `next_page_token`."]
+    pub fn list_datasets_pages(
+        &self,
+        request: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDatasetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_datasets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_datasets`."]
+    pub fn list_datasets_stream(
+        &self,
+        request: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<Dataset, tonic::Status>> {
+        crate::paging::items(self.list_datasets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_table_specs`, following `next_page_token`."]
+    pub fn list_table_specs_pages(
+        &self,
+        request: ListTableSpecsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTableSpecsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_table_specs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_table_specs`."]
+    pub fn list_table_specs_stream(
+        &self,
+        request: ListTableSpecsRequest,
+    ) -> impl futures::Stream<Item = Result<TableSpec, tonic::Status>> {
+        crate::paging::items(self.list_table_specs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_column_specs`, following `next_page_token`."]
+    pub fn list_column_specs_pages(
+        &self,
+        request: ListColumnSpecsRequest,
+    ) -> impl futures::Stream<Item = Result<ListColumnSpecsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_column_specs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_column_specs`."]
+    pub fn list_column_specs_stream(
+        &self,
+        request: ListColumnSpecsRequest,
+    ) -> impl futures::Stream<Item = Result<ColumnSpec, tonic::Status>> {
+        crate::paging::items(self.list_column_specs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_models`, following `next_page_token`."]
+    pub fn list_models_pages(
+        &self,
+        request: ListModelsRequest,
+    ) -> impl futures::Stream<Item = Result<ListModelsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_models(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_models`."]
+    pub fn list_models_stream(
+        &self,
+        request: ListModelsRequest,
+    ) -> impl futures::Stream<Item = Result<Model, tonic::Status>> {
+        crate::paging::items(self.list_models_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_model_evaluations`, following `next_page_token`."]
+    pub fn list_model_evaluations_pages(
+        &self,
+        request: ListModelEvaluationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListModelEvaluationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_model_evaluations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_model_evaluations`."]
+    pub fn list_model_evaluations_stream(
+        &self,
+        request: ListModelEvaluationsRequest,
+    ) -> impl futures::Stream<Item = Result<ModelEvaluation, tonic::Status>> {
+        crate::paging::items(self.list_model_evaluations_pages(request))
+    }
+}
@@ -771,3 +771,51 @@ pub mod connection_service_server {
         const NAME: &'static str = "google.cloud.bigquery.connection.v1.ConnectionService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListConnectionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListConnectionsResponse {
+    type Item = Connection;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.connections
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> connection_service_client::ConnectionServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_connections`, following `next_page_token`."]
+    pub fn list_connections_pages(
+        &self,
+        request: ListConnectionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListConnectionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_connections(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_connections`."]
+    pub fn list_connections_stream(
+        &self,
+        request: ListConnectionsRequest,
+    ) -> impl futures::Stream<Item = Result<Connection, tonic::Status>> {
+        crate::paging::items(self.list_connections_pages(request))
+    }
+}
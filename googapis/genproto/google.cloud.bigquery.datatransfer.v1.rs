@@ -1285,3 +1285,168 @@ pub mod data_transfer_service_server {
         const NAME: &'static str = "google.cloud.bigquery.datatransfer.v1.DataTransferService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDataSourcesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDataSourcesResponse {
+    type Item = DataSource;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.data_sources
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTransferConfigsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTransferConfigsResponse {
+    type Item = TransferConfig;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.transfer_configs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTransferRunsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTransferRunsResponse {
+    type Item = TransferRun;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.transfer_runs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTransferLogsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTransferLogsResponse {
+    type Item = TransferMessage;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.transfer_messages
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> data_transfer_service_client::DataTransferServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_data_sources`, following `next_page_token`."]
+    pub fn list_data_sources_pages(
+        &self,
+        request: ListDataSourcesRequest,
+    ) -> impl futures::Stream<Item = Result<ListDataSourcesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_data_sources(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_data_sources`."]
+    pub fn list_data_sources_stream(
+        &self,
+        request: ListDataSourcesRequest,
+    ) -> impl futures::Stream<Item = Result<DataSource, tonic::Status>> {
+        crate::paging::items(self.list_data_sources_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_transfer_configs`, following `next_page_token`."]
+    pub fn list_transfer_configs_pages(
+        &self,
+        request: ListTransferConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTransferConfigsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_transfer_configs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_transfer_configs`."]
+    pub fn list_transfer_configs_stream(
+        &self,
+        request: ListTransferConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<TransferConfig, tonic::Status>> {
+        crate::paging::items(self.list_transfer_configs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_transfer_runs`, following `next_page_token`."]
+    pub fn list_transfer_runs_pages(
+        &self,
+        request: ListTransferRunsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTransferRunsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_transfer_runs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_transfer_runs`."]
+    pub fn list_transfer_runs_stream(
+        &self,
+        request: ListTransferRunsRequest,
+    ) -> impl futures::Stream<Item = Result<TransferRun, tonic::Status>> {
+        crate::paging::items(self.list_transfer_runs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_transfer_logs`, following `next_page_token`."]
+    pub fn list_transfer_logs_pages(
+        &self,
+        request: ListTransferLogsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTransferLogsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_transfer_logs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_transfer_logs`."]
+    pub fn list_transfer_logs_stream(
+        &self,
+        request: ListTransferLogsRequest,
+    ) -> impl futures::Stream<Item = Result<TransferMessage, tonic::Status>> {
+        crate::paging::items(self.list_transfer_logs_pages(request))
+    }
+}
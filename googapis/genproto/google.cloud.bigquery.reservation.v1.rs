@@ -1281,3 +1281,168 @@ pub mod reservation_service_server {
         const NAME: &'static str = "google.cloud.bigquery.reservation.v1.ReservationService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListReservationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListReservationsResponse {
+    type Item = Reservation;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.reservations
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCapacityCommitmentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCapacityCommitmentsResponse {
+    type Item = CapacityCommitment;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.capacity_commitments
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAssignmentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAssignmentsResponse {
+    type Item = Assignment;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.assignments
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchAssignmentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchAssignmentsResponse {
+    type Item = Assignment;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.assignments
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> reservation_service_client::ReservationServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_reservations`, following `next_page_token`."]
+    pub fn list_reservations_pages(
+        &self,
+        request: ListReservationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListReservationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_reservations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_reservations`."]
+    pub fn list_reservations_stream(
+        &self,
+        request: ListReservationsRequest,
+    ) -> impl futures::Stream<Item = Result<Reservation, tonic::Status>> {
+        crate::paging::items(self.list_reservations_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_capacity_commitments`, following `next_page_token`."]
+    pub fn list_capacity_commitments_pages(
+        &self,
+        request: ListCapacityCommitmentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCapacityCommitmentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_capacity_commitments(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_capacity_commitments`."]
+    pub fn list_capacity_commitments_stream(
+        &self,
+        request: ListCapacityCommitmentsRequest,
+    ) -> impl futures::Stream<Item = Result<CapacityCommitment, tonic::Status>> {
+        crate::paging::items(self.list_capacity_commitments_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_assignments`, following `next_page_token`."]
+    pub fn list_assignments_pages(
+        &self,
+        request: ListAssignmentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAssignmentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_assignments(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_assignments`."]
+    pub fn list_assignments_stream(
+        &self,
+        request: ListAssignmentsRequest,
+    ) -> impl futures::Stream<Item = Result<Assignment, tonic::Status>> {
+        crate::paging::items(self.list_assignments_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_assignments`, following `next_page_token`."]
+    pub fn search_assignments_pages(
+        &self,
+        request: SearchAssignmentsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchAssignmentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_assignments(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_assignments`."]
+    pub fn search_assignments_stream(
+        &self,
+        request: SearchAssignmentsRequest,
+    ) -> impl futures::Stream<Item = Result<Assignment, tonic::Status>> {
+        crate::paging::items(self.search_assignments_pages(request))
+    }
+}
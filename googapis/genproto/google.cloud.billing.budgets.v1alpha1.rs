@@ -656,3 +656,51 @@ pub mod budget_service_server {
         const NAME: &'static str = "google.cloud.billing.budgets.v1alpha1.BudgetService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListBudgetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListBudgetsResponse {
+    type Item = Budget;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.budgets
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> budget_service_client::BudgetServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_budgets`, following `next_page_token`."]
+    pub fn list_budgets_pages(
+        &self,
+        request: ListBudgetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListBudgetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_budgets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_budgets`."]
+    pub fn list_budgets_stream(
+        &self,
+        request: ListBudgetsRequest,
+    ) -> impl futures::Stream<Item = Result<Budget, tonic::Status>> {
+        crate::paging::items(self.list_budgets_pages(request))
+    }
+}
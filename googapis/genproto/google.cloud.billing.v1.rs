@@ -1499,3 +1499,172 @@ pub mod cloud_catalog_server {
         const NAME: &'static str = "google.cloud.billing.v1.CloudCatalog";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListBillingAccountsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListBillingAccountsResponse {
+    type Item = BillingAccount;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.billing_accounts
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListProjectBillingInfoRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListProjectBillingInfoResponse {
+    type Item = ProjectBillingInfo;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.project_billing_info
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> cloud_billing_client::CloudBillingClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_billing_accounts`, following `next_page_token`."]
+    pub fn list_billing_accounts_pages(
+        &self,
+        request: ListBillingAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<ListBillingAccountsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_billing_accounts(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_billing_accounts`."]
+    pub fn list_billing_accounts_stream(
+        &self,
+        request: ListBillingAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<BillingAccount, tonic::Status>> {
+        crate::paging::items(self.list_billing_accounts_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_project_billing_info`, following `next_page_token`."]
+    pub fn list_project_billing_info_pages(
+        &self,
+        request: ListProjectBillingInfoRequest,
+    ) -> impl futures::Stream<Item = Result<ListProjectBillingInfoResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_project_billing_info(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_project_billing_info`."]
+    pub fn list_project_billing_info_stream(
+        &self,
+        request: ListProjectBillingInfoRequest,
+    ) -> impl futures::Stream<Item = Result<ProjectBillingInfo, tonic::Status>> {
+        crate::paging::items(self.list_project_billing_info_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListServicesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListServicesResponse {
+    type Item = Service;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.services
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSkusRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSkusResponse {
+    type Item = Sku;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.skus
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> cloud_catalog_client::CloudCatalogClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_services`, following `next_page_token`."]
+    pub fn list_services_pages(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListServicesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_services(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_services`."]
+    pub fn list_services_stream(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<Service, tonic::Status>> {
+        crate::paging::items(self.list_services_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_skus`, following `next_page_token`."]
+    pub fn list_skus_pages(
+        &self,
+        request: ListSkusRequest,
+    ) -> impl futures::Stream<Item = Result<ListSkusResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.list_skus(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_skus`."]
+    pub fn list_skus_stream(
+        &self,
+        request: ListSkusRequest,
+    ) -> impl futures::Stream<Item = Result<Sku, tonic::Status>> {
+        crate::paging::items(self.list_skus_pages(request))
+    }
+}
@@ -673,3 +673,51 @@ pub mod binauthz_management_service_v1_beta1_server {
             "google.cloud.binaryauthorization.v1beta1.BinauthzManagementServiceV1Beta1";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAttestorsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAttestorsResponse {
+    type Item = Attestor;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.attestors
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> binauthz_management_service_v1_beta1_client::BinauthzManagementServiceV1Beta1Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_attestors`, following `next_page_token`."]
+    pub fn list_attestors_pages(
+        &self,
+        request: ListAttestorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAttestorsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_attestors(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_attestors`."]
+    pub fn list_attestors_stream(
+        &self,
+        request: ListAttestorsRequest,
+    ) -> impl futures::Stream<Item = Result<Attestor, tonic::Status>> {
+        crate::paging::items(self.list_attestors_pages(request))
+    }
+}
@@ -3078,3 +3078,163 @@ pub mod data_catalog_server {
         const NAME: &'static str = "google.cloud.datacatalog.v1.DataCatalog";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchCatalogRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchCatalogResponse {
+    type Item = SearchCatalogResult;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.results
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListEntryGroupsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListEntryGroupsResponse {
+    type Item = EntryGroup;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.entry_groups
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListEntriesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListEntriesResponse {
+    type Item = Entry;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.entries
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTagsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTagsResponse {
+    type Item = Tag;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.tags
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> data_catalog_client::DataCatalogClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search_catalog`, following `next_page_token`."]
+    pub fn search_catalog_pages(
+        &self,
+        request: SearchCatalogRequest,
+    ) -> impl futures::Stream<Item = Result<SearchCatalogResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_catalog(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_catalog`."]
+    pub fn search_catalog_stream(
+        &self,
+        request: SearchCatalogRequest,
+    ) -> impl futures::Stream<Item = Result<SearchCatalogResult, tonic::Status>> {
+        crate::paging::items(self.search_catalog_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_entry_groups`, following `next_page_token`."]
+    pub fn list_entry_groups_pages(
+        &self,
+        request: ListEntryGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<ListEntryGroupsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_entry_groups(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_entry_groups`."]
+    pub fn list_entry_groups_stream(
+        &self,
+        request: ListEntryGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<EntryGroup, tonic::Status>> {
+        crate::paging::items(self.list_entry_groups_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_entries`, following `next_page_token`."]
+    pub fn list_entries_pages(
+        &self,
+        request: ListEntriesRequest,
+    ) -> impl futures::Stream<Item = Result<ListEntriesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_entries(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_entries`."]
+    pub fn list_entries_stream(
+        &self,
+        request: ListEntriesRequest,
+    ) -> impl futures::Stream<Item = Result<Entry, tonic::Status>> {
+        crate::paging::items(self.list_entries_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_tags`, following `next_page_token`."]
+    pub fn list_tags_pages(
+        &self,
+        request: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTagsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.list_tags(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_tags`."]
+    pub fn list_tags_stream(
+        &self,
+        request: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, tonic::Status>> {
+        crate::paging::items(self.list_tags_pages(request))
+    }
+}
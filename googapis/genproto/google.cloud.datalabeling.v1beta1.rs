@@ -3407,3 +3407,365 @@ pub struct CreateInstructionMetadata {
     #[prost(message, optional, tag = "3")]
     pub create_time: ::std::option::Option<::prost_types::Timestamp>,
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDatasetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDatasetsResponse {
+    type Item = Dataset;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.datasets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDataItemsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDataItemsResponse {
+    type Item = DataItem;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.data_items
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAnnotatedDatasetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAnnotatedDatasetsResponse {
+    type Item = AnnotatedDataset;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.annotated_datasets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListExamplesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListExamplesResponse {
+    type Item = Example;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.examples
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAnnotationSpecSetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAnnotationSpecSetsResponse {
+    type Item = AnnotationSpecSet;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.annotation_spec_sets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListInstructionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListInstructionsResponse {
+    type Item = Instruction;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.instructions
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchEvaluationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchEvaluationsResponse {
+    type Item = Evaluation;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.evaluations
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchExampleComparisonsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchExampleComparisonsResponse {
+    type Item = search_example_comparisons_response::ExampleComparison;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.example_comparisons
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListEvaluationJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListEvaluationJobsResponse {
+    type Item = EvaluationJob;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.evaluation_jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> data_labeling_service_client::DataLabelingServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_datasets`, following `next_page_token`."]
+    pub fn list_datasets_pages(
+        &self,
+        request: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDatasetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_datasets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_datasets`."]
+    pub fn list_datasets_stream(
+        &self,
+        request: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<Dataset, tonic::Status>> {
+        crate::paging::items(self.list_datasets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_data_items`, following `next_page_token`."]
+    pub fn list_data_items_pages(
+        &self,
+        request: ListDataItemsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDataItemsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_data_items(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_data_items`."]
+    pub fn list_data_items_stream(
+        &self,
+        request: ListDataItemsRequest,
+    ) -> impl futures::Stream<Item = Result<DataItem, tonic::Status>> {
+        crate::paging::items(self.list_data_items_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_annotated_datasets`, following `next_page_token`."]
+    pub fn list_annotated_datasets_pages(
+        &self,
+        request: ListAnnotatedDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAnnotatedDatasetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_annotated_datasets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_annotated_datasets`."]
+    pub fn list_annotated_datasets_stream(
+        &self,
+        request: ListAnnotatedDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<AnnotatedDataset, tonic::Status>> {
+        crate::paging::items(self.list_annotated_datasets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_examples`, following `next_page_token`."]
+    pub fn list_examples_pages(
+        &self,
+        request: ListExamplesRequest,
+    ) -> impl futures::Stream<Item = Result<ListExamplesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_examples(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_examples`."]
+    pub fn list_examples_stream(
+        &self,
+        request: ListExamplesRequest,
+    ) -> impl futures::Stream<Item = Result<Example, tonic::Status>> {
+        crate::paging::items(self.list_examples_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_annotation_spec_sets`, following `next_page_token`."]
+    pub fn list_annotation_spec_sets_pages(
+        &self,
+        request: ListAnnotationSpecSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAnnotationSpecSetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_annotation_spec_sets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_annotation_spec_sets`."]
+    pub fn list_annotation_spec_sets_stream(
+        &self,
+        request: ListAnnotationSpecSetsRequest,
+    ) -> impl futures::Stream<Item = Result<AnnotationSpecSet, tonic::Status>> {
+        crate::paging::items(self.list_annotation_spec_sets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_instructions`, following `next_page_token`."]
+    pub fn list_instructions_pages(
+        &self,
+        request: ListInstructionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListInstructionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_instructions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_instructions`."]
+    pub fn list_instructions_stream(
+        &self,
+        request: ListInstructionsRequest,
+    ) -> impl futures::Stream<Item = Result<Instruction, tonic::Status>> {
+        crate::paging::items(self.list_instructions_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_evaluations`, following `next_page_token`."]
+    pub fn search_evaluations_pages(
+        &self,
+        request: SearchEvaluationsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchEvaluationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_evaluations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_evaluations`."]
+    pub fn search_evaluations_stream(
+        &self,
+        request: SearchEvaluationsRequest,
+    ) -> impl futures::Stream<Item = Result<Evaluation, tonic::Status>> {
+        crate::paging::items(self.search_evaluations_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_example_comparisons`, following `next_page_token`."]
+    pub fn search_example_comparisons_pages(
+        &self,
+        request: SearchExampleComparisonsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchExampleComparisonsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_example_comparisons(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_example_comparisons`."]
+    pub fn search_example_comparisons_stream(
+        &self,
+        request: SearchExampleComparisonsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<search_example_comparisons_response::ExampleComparison, tonic::Status>,
+    > {
+        crate::paging::items(self.search_example_comparisons_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_evaluation_jobs`, following `next_page_token`."]
+    pub fn list_evaluation_jobs_pages(
+        &self,
+        request: ListEvaluationJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListEvaluationJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_evaluation_jobs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_evaluation_jobs`."]
+    pub fn list_evaluation_jobs_stream(
+        &self,
+        request: ListEvaluationJobsRequest,
+    ) -> impl futures::Stream<Item = Result<EvaluationJob, tonic::Status>> {
+        crate::paging::items(self.list_evaluation_jobs_pages(request))
+    }
+}
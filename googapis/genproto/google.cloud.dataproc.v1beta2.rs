@@ -4308,3 +4308,190 @@ pub mod workflow_template_service_server {
         const NAME: &'static str = "google.cloud.dataproc.v1beta2.WorkflowTemplateService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAutoscalingPoliciesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAutoscalingPoliciesResponse {
+    type Item = AutoscalingPolicy;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.policies
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> autoscaling_policy_service_client::AutoscalingPolicyServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_autoscaling_policies`, following `next_page_token`."]
+    pub fn list_autoscaling_policies_pages(
+        &self,
+        request: ListAutoscalingPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<ListAutoscalingPoliciesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_autoscaling_policies(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_autoscaling_policies`."]
+    pub fn list_autoscaling_policies_stream(
+        &self,
+        request: ListAutoscalingPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<AutoscalingPolicy, tonic::Status>> {
+        crate::paging::items(self.list_autoscaling_policies_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListClustersRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListClustersResponse {
+    type Item = Cluster;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.clusters
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> cluster_controller_client::ClusterControllerClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_clusters`, following `next_page_token`."]
+    pub fn list_clusters_pages(
+        &self,
+        request: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<ListClustersResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_clusters(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_clusters`."]
+    pub fn list_clusters_stream(
+        &self,
+        request: ListClustersRequest,
+    ) -> impl futures::Stream<Item = Result<Cluster, tonic::Status>> {
+        crate::paging::items(self.list_clusters_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListJobsResponse {
+    type Item = Job;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> job_controller_client::JobControllerClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_jobs`, following `next_page_token`."]
+    pub fn list_jobs_pages(
+        &self,
+        request: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.list_jobs(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_jobs`."]
+    pub fn list_jobs_stream(
+        &self,
+        request: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<Job, tonic::Status>> {
+        crate::paging::items(self.list_jobs_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListWorkflowTemplatesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListWorkflowTemplatesResponse {
+    type Item = WorkflowTemplate;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.templates
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> workflow_template_service_client::WorkflowTemplateServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_workflow_templates`, following `next_page_token`."]
+    pub fn list_workflow_templates_pages(
+        &self,
+        request: ListWorkflowTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<ListWorkflowTemplatesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_workflow_templates(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_workflow_templates`."]
+    pub fn list_workflow_templates_stream(
+        &self,
+        request: ListWorkflowTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<WorkflowTemplate, tonic::Status>> {
+        crate::paging::items(self.list_workflow_templates_pages(request))
+    }
+}
@@ -6366,3 +6366,291 @@ pub struct OriginalDetectIntentRequest {
     #[prost(message, optional, tag = "3")]
     pub payload: ::std::option::Option<::prost_types::Struct>,
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchAgentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchAgentsResponse {
+    type Item = Agent;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.agents
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> agents_client::AgentsClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search_agents`, following `next_page_token`."]
+    pub fn search_agents_pages(
+        &self,
+        request: SearchAgentsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchAgentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_agents(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_agents`."]
+    pub fn search_agents_stream(
+        &self,
+        request: SearchAgentsRequest,
+    ) -> impl futures::Stream<Item = Result<Agent, tonic::Status>> {
+        crate::paging::items(self.search_agents_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListContextsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListContextsResponse {
+    type Item = Context;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.contexts
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> contexts_client::ContextsClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_contexts`, following `next_page_token`."]
+    pub fn list_contexts_pages(
+        &self,
+        request: ListContextsRequest,
+    ) -> impl futures::Stream<Item = Result<ListContextsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_contexts(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_contexts`."]
+    pub fn list_contexts_stream(
+        &self,
+        request: ListContextsRequest,
+    ) -> impl futures::Stream<Item = Result<Context, tonic::Status>> {
+        crate::paging::items(self.list_contexts_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListEntityTypesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListEntityTypesResponse {
+    type Item = EntityType;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.entity_types
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> entity_types_client::EntityTypesClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_entity_types`, following `next_page_token`."]
+    pub fn list_entity_types_pages(
+        &self,
+        request: ListEntityTypesRequest,
+    ) -> impl futures::Stream<Item = Result<ListEntityTypesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_entity_types(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_entity_types`."]
+    pub fn list_entity_types_stream(
+        &self,
+        request: ListEntityTypesRequest,
+    ) -> impl futures::Stream<Item = Result<EntityType, tonic::Status>> {
+        crate::paging::items(self.list_entity_types_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListEnvironmentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListEnvironmentsResponse {
+    type Item = Environment;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.environments
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> environments_client::EnvironmentsClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_environments`, following `next_page_token`."]
+    pub fn list_environments_pages(
+        &self,
+        request: ListEnvironmentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListEnvironmentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_environments(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_environments`."]
+    pub fn list_environments_stream(
+        &self,
+        request: ListEnvironmentsRequest,
+    ) -> impl futures::Stream<Item = Result<Environment, tonic::Status>> {
+        crate::paging::items(self.list_environments_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListIntentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListIntentsResponse {
+    type Item = Intent;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.intents
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> intents_client::IntentsClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_intents`, following `next_page_token`."]
+    pub fn list_intents_pages(
+        &self,
+        request: ListIntentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListIntentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_intents(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_intents`."]
+    pub fn list_intents_stream(
+        &self,
+        request: ListIntentsRequest,
+    ) -> impl futures::Stream<Item = Result<Intent, tonic::Status>> {
+        crate::paging::items(self.list_intents_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSessionEntityTypesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSessionEntityTypesResponse {
+    type Item = SessionEntityType;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.session_entity_types
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> session_entity_types_client::SessionEntityTypesClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_session_entity_types`, following `next_page_token`."]
+    pub fn list_session_entity_types_pages(
+        &self,
+        request: ListSessionEntityTypesRequest,
+    ) -> impl futures::Stream<Item = Result<ListSessionEntityTypesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_session_entity_types(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_session_entity_types`."]
+    pub fn list_session_entity_types_stream(
+        &self,
+        request: ListSessionEntityTypesRequest,
+    ) -> impl futures::Stream<Item = Result<SessionEntityType, tonic::Status>> {
+        crate::paging::items(self.list_session_entity_types_pages(request))
+    }
+}
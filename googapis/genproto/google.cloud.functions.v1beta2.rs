@@ -1086,3 +1086,51 @@ pub enum OperationType {
     /// Triggered by DeleteFunction call.
     DeleteFunction = 3,
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListFunctionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListFunctionsResponse {
+    type Item = CloudFunction;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.functions
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> cloud_functions_service_client::CloudFunctionsServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_functions`, following `next_page_token`."]
+    pub fn list_functions_pages(
+        &self,
+        request: ListFunctionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFunctionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_functions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_functions`."]
+    pub fn list_functions_stream(
+        &self,
+        request: ListFunctionsRequest,
+    ) -> impl futures::Stream<Item = Result<CloudFunction, tonic::Status>> {
+        crate::paging::items(self.list_functions_pages(request))
+    }
+}
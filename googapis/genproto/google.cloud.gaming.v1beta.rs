@@ -2503,3 +2503,195 @@ pub mod realms_service_server {
         const NAME: &'static str = "google.cloud.gaming.v1beta.RealmsService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListGameServerClustersRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListGameServerClustersResponse {
+    type Item = GameServerCluster;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.game_server_clusters
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> game_server_clusters_service_client::GameServerClustersServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_game_server_clusters`, following `next_page_token`."]
+    pub fn list_game_server_clusters_pages(
+        &self,
+        request: ListGameServerClustersRequest,
+    ) -> impl futures::Stream<Item = Result<ListGameServerClustersResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_game_server_clusters(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_game_server_clusters`."]
+    pub fn list_game_server_clusters_stream(
+        &self,
+        request: ListGameServerClustersRequest,
+    ) -> impl futures::Stream<Item = Result<GameServerCluster, tonic::Status>> {
+        crate::paging::items(self.list_game_server_clusters_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListGameServerConfigsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListGameServerConfigsResponse {
+    type Item = GameServerConfig;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.game_server_configs
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> game_server_configs_service_client::GameServerConfigsServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_game_server_configs`, following `next_page_token`."]
+    pub fn list_game_server_configs_pages(
+        &self,
+        request: ListGameServerConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ListGameServerConfigsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_game_server_configs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_game_server_configs`."]
+    pub fn list_game_server_configs_stream(
+        &self,
+        request: ListGameServerConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<GameServerConfig, tonic::Status>> {
+        crate::paging::items(self.list_game_server_configs_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListGameServerDeploymentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListGameServerDeploymentsResponse {
+    type Item = GameServerDeployment;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.game_server_deployments
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> game_server_deployments_service_client::GameServerDeploymentsServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_game_server_deployments`, following `next_page_token`."]
+    pub fn list_game_server_deployments_pages(
+        &self,
+        request: ListGameServerDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListGameServerDeploymentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_game_server_deployments(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_game_server_deployments`."]
+    pub fn list_game_server_deployments_stream(
+        &self,
+        request: ListGameServerDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<GameServerDeployment, tonic::Status>> {
+        crate::paging::items(self.list_game_server_deployments_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListRealmsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListRealmsResponse {
+    type Item = Realm;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.realms
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> realms_service_client::RealmsServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_realms`, following `next_page_token`."]
+    pub fn list_realms_pages(
+        &self,
+        request: ListRealmsRequest,
+    ) -> impl futures::Stream<Item = Result<ListRealmsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_realms(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_realms`."]
+    pub fn list_realms_stream(
+        &self,
+        request: ListRealmsRequest,
+    ) -> impl futures::Stream<Item = Result<Realm, tonic::Status>> {
+        crate::paging::items(self.list_realms_pages(request))
+    }
+}
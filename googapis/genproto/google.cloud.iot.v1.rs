@@ -2034,3 +2034,90 @@ pub mod device_manager_server {
         const NAME: &'static str = "google.cloud.iot.v1.DeviceManager";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDeviceRegistriesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDeviceRegistriesResponse {
+    type Item = DeviceRegistry;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.device_registries
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDevicesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDevicesResponse {
+    type Item = Device;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.devices
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> device_manager_client::DeviceManagerClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_device_registries`, following `next_page_token`."]
+    pub fn list_device_registries_pages(
+        &self,
+        request: ListDeviceRegistriesRequest,
+    ) -> impl futures::Stream<Item = Result<ListDeviceRegistriesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_device_registries(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_device_registries`."]
+    pub fn list_device_registries_stream(
+        &self,
+        request: ListDeviceRegistriesRequest,
+    ) -> impl futures::Stream<Item = Result<DeviceRegistry, tonic::Status>> {
+        crate::paging::items(self.list_device_registries_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_devices`, following `next_page_token`."]
+    pub fn list_devices_pages(
+        &self,
+        request: ListDevicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListDevicesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_devices(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_devices`."]
+    pub fn list_devices_stream(
+        &self,
+        request: ListDevicesRequest,
+    ) -> impl futures::Stream<Item = Result<Device, tonic::Status>> {
+        crate::paging::items(self.list_devices_pages(request))
+    }
+}
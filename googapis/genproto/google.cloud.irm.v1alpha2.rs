@@ -3096,3 +3096,321 @@ pub mod incident_service_server {
         const NAME: &'static str = "google.cloud.irm.v1alpha2.IncidentService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchIncidentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchIncidentsResponse {
+    type Item = Incident;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.incidents
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchSimilarIncidentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchSimilarIncidentsResponse {
+    type Item = search_similar_incidents_response::Result;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.results
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAnnotationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAnnotationsResponse {
+    type Item = Annotation;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.annotations
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTagsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTagsResponse {
+    type Item = Tag;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.tags
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchSignalsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchSignalsResponse {
+    type Item = Signal;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.signals
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListArtifactsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListArtifactsResponse {
+    type Item = Artifact;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.artifacts
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSubscriptionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSubscriptionsResponse {
+    type Item = Subscription;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.subscriptions
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListIncidentRoleAssignmentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListIncidentRoleAssignmentsResponse {
+    type Item = IncidentRoleAssignment;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.incident_role_assignments
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> incident_service_client::IncidentServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search_incidents`, following `next_page_token`."]
+    pub fn search_incidents_pages(
+        &self,
+        request: SearchIncidentsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchIncidentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_incidents(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_incidents`."]
+    pub fn search_incidents_stream(
+        &self,
+        request: SearchIncidentsRequest,
+    ) -> impl futures::Stream<Item = Result<Incident, tonic::Status>> {
+        crate::paging::items(self.search_incidents_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_similar_incidents`, following `next_page_token`."]
+    pub fn search_similar_incidents_pages(
+        &self,
+        request: SearchSimilarIncidentsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchSimilarIncidentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_similar_incidents(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_similar_incidents`."]
+    pub fn search_similar_incidents_stream(
+        &self,
+        request: SearchSimilarIncidentsRequest,
+    ) -> impl futures::Stream<Item = Result<search_similar_incidents_response::Result, tonic::Status>>
+    {
+        crate::paging::items(self.search_similar_incidents_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_annotations`, following `next_page_token`."]
+    pub fn list_annotations_pages(
+        &self,
+        request: ListAnnotationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAnnotationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_annotations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_annotations`."]
+    pub fn list_annotations_stream(
+        &self,
+        request: ListAnnotationsRequest,
+    ) -> impl futures::Stream<Item = Result<Annotation, tonic::Status>> {
+        crate::paging::items(self.list_annotations_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_tags`, following `next_page_token`."]
+    pub fn list_tags_pages(
+        &self,
+        request: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTagsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.list_tags(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_tags`."]
+    pub fn list_tags_stream(
+        &self,
+        request: ListTagsRequest,
+    ) -> impl futures::Stream<Item = Result<Tag, tonic::Status>> {
+        crate::paging::items(self.list_tags_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_signals`, following `next_page_token`."]
+    pub fn search_signals_pages(
+        &self,
+        request: SearchSignalsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchSignalsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_signals(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_signals`."]
+    pub fn search_signals_stream(
+        &self,
+        request: SearchSignalsRequest,
+    ) -> impl futures::Stream<Item = Result<Signal, tonic::Status>> {
+        crate::paging::items(self.search_signals_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_artifacts`, following `next_page_token`."]
+    pub fn list_artifacts_pages(
+        &self,
+        request: ListArtifactsRequest,
+    ) -> impl futures::Stream<Item = Result<ListArtifactsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_artifacts(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_artifacts`."]
+    pub fn list_artifacts_stream(
+        &self,
+        request: ListArtifactsRequest,
+    ) -> impl futures::Stream<Item = Result<Artifact, tonic::Status>> {
+        crate::paging::items(self.list_artifacts_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_subscriptions`, following `next_page_token`."]
+    pub fn list_subscriptions_pages(
+        &self,
+        request: ListSubscriptionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListSubscriptionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_subscriptions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_subscriptions`."]
+    pub fn list_subscriptions_stream(
+        &self,
+        request: ListSubscriptionsRequest,
+    ) -> impl futures::Stream<Item = Result<Subscription, tonic::Status>> {
+        crate::paging::items(self.list_subscriptions_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_incident_role_assignments`, following `next_page_token`."]
+    pub fn list_incident_role_assignments_pages(
+        &self,
+        request: ListIncidentRoleAssignmentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListIncidentRoleAssignmentsResponse, tonic::Status>>
+    {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_incident_role_assignments(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_incident_role_assignments`."]
+    pub fn list_incident_role_assignments_stream(
+        &self,
+        request: ListIncidentRoleAssignmentsRequest,
+    ) -> impl futures::Stream<Item = Result<IncidentRoleAssignment, tonic::Status>> {
+        crate::paging::items(self.list_incident_role_assignments_pages(request))
+    }
+}
@@ -2577,3 +2577,168 @@ pub mod key_management_service_server {
         const NAME: &'static str = "google.cloud.kms.v1.KeyManagementService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListKeyRingsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListKeyRingsResponse {
+    type Item = KeyRing;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.key_rings
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCryptoKeysRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCryptoKeysResponse {
+    type Item = CryptoKey;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.crypto_keys
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCryptoKeyVersionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCryptoKeyVersionsResponse {
+    type Item = CryptoKeyVersion;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.crypto_key_versions
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListImportJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListImportJobsResponse {
+    type Item = ImportJob;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.import_jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> key_management_service_client::KeyManagementServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_key_rings`, following `next_page_token`."]
+    pub fn list_key_rings_pages(
+        &self,
+        request: ListKeyRingsRequest,
+    ) -> impl futures::Stream<Item = Result<ListKeyRingsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_key_rings(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_key_rings`."]
+    pub fn list_key_rings_stream(
+        &self,
+        request: ListKeyRingsRequest,
+    ) -> impl futures::Stream<Item = Result<KeyRing, tonic::Status>> {
+        crate::paging::items(self.list_key_rings_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_crypto_keys`, following `next_page_token`."]
+    pub fn list_crypto_keys_pages(
+        &self,
+        request: ListCryptoKeysRequest,
+    ) -> impl futures::Stream<Item = Result<ListCryptoKeysResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_crypto_keys(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_crypto_keys`."]
+    pub fn list_crypto_keys_stream(
+        &self,
+        request: ListCryptoKeysRequest,
+    ) -> impl futures::Stream<Item = Result<CryptoKey, tonic::Status>> {
+        crate::paging::items(self.list_crypto_keys_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_crypto_key_versions`, following `next_page_token`."]
+    pub fn list_crypto_key_versions_pages(
+        &self,
+        request: ListCryptoKeyVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCryptoKeyVersionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_crypto_key_versions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_crypto_key_versions`."]
+    pub fn list_crypto_key_versions_stream(
+        &self,
+        request: ListCryptoKeyVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<CryptoKeyVersion, tonic::Status>> {
+        crate::paging::items(self.list_crypto_key_versions_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_import_jobs`, following `next_page_token`."]
+    pub fn list_import_jobs_pages(
+        &self,
+        request: ListImportJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListImportJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_import_jobs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_import_jobs`."]
+    pub fn list_import_jobs_stream(
+        &self,
+        request: ListImportJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ImportJob, tonic::Status>> {
+        crate::paging::items(self.list_import_jobs_pages(request))
+    }
+}
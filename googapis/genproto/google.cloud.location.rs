@@ -290,3 +290,51 @@ pub mod locations_server {
         const NAME: &'static str = "google.cloud.location.Locations";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListLocationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListLocationsResponse {
+    type Item = Location;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.locations
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> locations_client::LocationsClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_locations`, following `next_page_token`."]
+    pub fn list_locations_pages(
+        &self,
+        request: ListLocationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListLocationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_locations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_locations`."]
+    pub fn list_locations_stream(
+        &self,
+        request: ListLocationsRequest,
+    ) -> impl futures::Stream<Item = Result<Location, tonic::Status>> {
+        crate::paging::items(self.list_locations_pages(request))
+    }
+}
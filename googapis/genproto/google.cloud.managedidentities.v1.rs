@@ -807,3 +807,51 @@ pub mod managed_identities_service_server {
         const NAME: &'static str = "google.cloud.managedidentities.v1.ManagedIdentitiesService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDomainsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDomainsResponse {
+    type Item = Domain;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.domains
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> managed_identities_service_client::ManagedIdentitiesServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_domains`, following `next_page_token`."]
+    pub fn list_domains_pages(
+        &self,
+        request: ListDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDomainsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_domains(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_domains`."]
+    pub fn list_domains_stream(
+        &self,
+        request: ListDomainsRequest,
+    ) -> impl futures::Stream<Item = Result<Domain, tonic::Status>> {
+        crate::paging::items(self.list_domains_pages(request))
+    }
+}
@@ -2513,3 +2513,133 @@ pub mod project_management_service_server {
         const NAME: &'static str = "google.cloud.ml.v1.ProjectManagementService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListJobsResponse {
+    type Item = Job;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> job_service_client::JobServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_jobs`, following `next_page_token`."]
+    pub fn list_jobs_pages(
+        &self,
+        request: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.list_jobs(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_jobs`."]
+    pub fn list_jobs_stream(
+        &self,
+        request: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<Job, tonic::Status>> {
+        crate::paging::items(self.list_jobs_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListModelsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListModelsResponse {
+    type Item = Model;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.models
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListVersionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListVersionsResponse {
+    type Item = Version;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.versions
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> model_service_client::ModelServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_models`, following `next_page_token`."]
+    pub fn list_models_pages(
+        &self,
+        request: ListModelsRequest,
+    ) -> impl futures::Stream<Item = Result<ListModelsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_models(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_models`."]
+    pub fn list_models_stream(
+        &self,
+        request: ListModelsRequest,
+    ) -> impl futures::Stream<Item = Result<Model, tonic::Status>> {
+        crate::paging::items(self.list_models_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_versions`, following `next_page_token`."]
+    pub fn list_versions_pages(
+        &self,
+        request: ListVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListVersionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_versions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_versions`."]
+    pub fn list_versions_stream(
+        &self,
+        request: ListVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<Version, tonic::Status>> {
+        crate::paging::items(self.list_versions_pages(request))
+    }
+}
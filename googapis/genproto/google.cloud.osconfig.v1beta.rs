@@ -2600,3 +2600,169 @@ pub mod os_config_service_server {
         const NAME: &'static str = "google.cloud.osconfig.v1beta.OsConfigService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListPatchJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListPatchJobsResponse {
+    type Item = PatchJob;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.patch_jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListPatchJobInstanceDetailsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListPatchJobInstanceDetailsResponse {
+    type Item = PatchJobInstanceDetails;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.patch_job_instance_details
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListPatchDeploymentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListPatchDeploymentsResponse {
+    type Item = PatchDeployment;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.patch_deployments
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListGuestPoliciesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListGuestPoliciesResponse {
+    type Item = GuestPolicy;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.guest_policies
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> os_config_service_client::OsConfigServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_patch_jobs`, following `next_page_token`."]
+    pub fn list_patch_jobs_pages(
+        &self,
+        request: ListPatchJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListPatchJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_patch_jobs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_patch_jobs`."]
+    pub fn list_patch_jobs_stream(
+        &self,
+        request: ListPatchJobsRequest,
+    ) -> impl futures::Stream<Item = Result<PatchJob, tonic::Status>> {
+        crate::paging::items(self.list_patch_jobs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_patch_job_instance_details`, following `next_page_token`."]
+    pub fn list_patch_job_instance_details_pages(
+        &self,
+        request: ListPatchJobInstanceDetailsRequest,
+    ) -> impl futures::Stream<Item = Result<ListPatchJobInstanceDetailsResponse, tonic::Status>>
+    {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_patch_job_instance_details(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_patch_job_instance_details`."]
+    pub fn list_patch_job_instance_details_stream(
+        &self,
+        request: ListPatchJobInstanceDetailsRequest,
+    ) -> impl futures::Stream<Item = Result<PatchJobInstanceDetails, tonic::Status>> {
+        crate::paging::items(self.list_patch_job_instance_details_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_patch_deployments`, following `next_page_token`."]
+    pub fn list_patch_deployments_pages(
+        &self,
+        request: ListPatchDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListPatchDeploymentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_patch_deployments(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_patch_deployments`."]
+    pub fn list_patch_deployments_stream(
+        &self,
+        request: ListPatchDeploymentsRequest,
+    ) -> impl futures::Stream<Item = Result<PatchDeployment, tonic::Status>> {
+        crate::paging::items(self.list_patch_deployments_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_guest_policies`, following `next_page_token`."]
+    pub fn list_guest_policies_pages(
+        &self,
+        request: ListGuestPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<ListGuestPoliciesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_guest_policies(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_guest_policies`."]
+    pub fn list_guest_policies_stream(
+        &self,
+        request: ListGuestPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<GuestPolicy, tonic::Status>> {
+        crate::paging::items(self.list_guest_policies_pages(request))
+    }
+}
@@ -582,3 +582,46 @@ pub mod recaptcha_enterprise_service_v1_beta1_server {
             "google.cloud.recaptchaenterprise.v1beta1.RecaptchaEnterpriseServiceV1Beta1";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListKeysRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListKeysResponse {
+    type Item = Key;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.keys
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> recaptcha_enterprise_service_v1_beta1_client::RecaptchaEnterpriseServiceV1Beta1Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_keys`, following `next_page_token`."]
+    pub fn list_keys_pages(
+        &self,
+        request: ListKeysRequest,
+    ) -> impl futures::Stream<Item = Result<ListKeysResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.list_keys(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_keys`."]
+    pub fn list_keys_stream(
+        &self,
+        request: ListKeysRequest,
+    ) -> impl futures::Stream<Item = Result<Key, tonic::Status>> {
+        crate::paging::items(self.list_keys_pages(request))
+    }
+}
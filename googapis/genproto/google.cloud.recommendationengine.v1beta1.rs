@@ -2405,3 +2405,192 @@ pub mod user_event_service_server {
         const NAME: &'static str = "google.cloud.recommendationengine.v1beta1.UserEventService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCatalogItemsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCatalogItemsResponse {
+    type Item = CatalogItem;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.catalog_items
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> catalog_service_client::CatalogServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_catalog_items`, following `next_page_token`."]
+    pub fn list_catalog_items_pages(
+        &self,
+        request: ListCatalogItemsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCatalogItemsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_catalog_items(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_catalog_items`."]
+    pub fn list_catalog_items_stream(
+        &self,
+        request: ListCatalogItemsRequest,
+    ) -> impl futures::Stream<Item = Result<CatalogItem, tonic::Status>> {
+        crate::paging::items(self.list_catalog_items_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListPredictionApiKeyRegistrationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListPredictionApiKeyRegistrationsResponse {
+    type Item = PredictionApiKeyRegistration;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.prediction_api_key_registrations
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> prediction_api_key_registry_client::PredictionApiKeyRegistryClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_prediction_api_key_registrations`, following `next_page_token`."]
+    pub fn list_prediction_api_key_registrations_pages(
+        &self,
+        request: ListPredictionApiKeyRegistrationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListPredictionApiKeyRegistrationsResponse, tonic::Status>>
+    {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_prediction_api_key_registrations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_prediction_api_key_registrations`."]
+    pub fn list_prediction_api_key_registrations_stream(
+        &self,
+        request: ListPredictionApiKeyRegistrationsRequest,
+    ) -> impl futures::Stream<Item = Result<PredictionApiKeyRegistration, tonic::Status>> {
+        crate::paging::items(self.list_prediction_api_key_registrations_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for PredictRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for PredictResponse {
+    type Item = predict_response::PredictionResult;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.results
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> prediction_service_client::PredictionServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `predict`, following `next_page_token`."]
+    pub fn predict_pages(
+        &self,
+        request: PredictRequest,
+    ) -> impl futures::Stream<Item = Result<PredictResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.predict(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `predict`."]
+    pub fn predict_stream(
+        &self,
+        request: PredictRequest,
+    ) -> impl futures::Stream<Item = Result<predict_response::PredictionResult, tonic::Status>>
+    {
+        crate::paging::items(self.predict_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListUserEventsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListUserEventsResponse {
+    type Item = UserEvent;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.user_events
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> user_event_service_client::UserEventServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_user_events`, following `next_page_token`."]
+    pub fn list_user_events_pages(
+        &self,
+        request: ListUserEventsRequest,
+    ) -> impl futures::Stream<Item = Result<ListUserEventsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_user_events(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_user_events`."]
+    pub fn list_user_events_stream(
+        &self,
+        request: ListUserEventsRequest,
+    ) -> impl futures::Stream<Item = Result<UserEvent, tonic::Status>> {
+        crate::paging::items(self.list_user_events_pages(request))
+    }
+}
@@ -1176,3 +1176,90 @@ pub mod recommender_server {
         const NAME: &'static str = "google.cloud.recommender.v1beta1.Recommender";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListInsightsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListInsightsResponse {
+    type Item = Insight;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.insights
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListRecommendationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListRecommendationsResponse {
+    type Item = Recommendation;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.recommendations
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> recommender_client::RecommenderClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_insights`, following `next_page_token`."]
+    pub fn list_insights_pages(
+        &self,
+        request: ListInsightsRequest,
+    ) -> impl futures::Stream<Item = Result<ListInsightsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_insights(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_insights`."]
+    pub fn list_insights_stream(
+        &self,
+        request: ListInsightsRequest,
+    ) -> impl futures::Stream<Item = Result<Insight, tonic::Status>> {
+        crate::paging::items(self.list_insights_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_recommendations`, following `next_page_token`."]
+    pub fn list_recommendations_pages(
+        &self,
+        request: ListRecommendationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListRecommendationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_recommendations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_recommendations`."]
+    pub fn list_recommendations_stream(
+        &self,
+        request: ListRecommendationsRequest,
+    ) -> impl futures::Stream<Item = Result<Recommendation, tonic::Status>> {
+        crate::paging::items(self.list_recommendations_pages(request))
+    }
+}
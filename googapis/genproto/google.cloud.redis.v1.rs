@@ -1111,3 +1111,51 @@ pub mod cloud_redis_server {
         const NAME: &'static str = "google.cloud.redis.v1.CloudRedis";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListInstancesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListInstancesResponse {
+    type Item = Instance;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.instances
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> cloud_redis_client::CloudRedisClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_instances`, following `next_page_token`."]
+    pub fn list_instances_pages(
+        &self,
+        request: ListInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<ListInstancesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_instances(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_instances`."]
+    pub fn list_instances_stream(
+        &self,
+        request: ListInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<Instance, tonic::Status>> {
+        crate::paging::items(self.list_instances_pages(request))
+    }
+}
@@ -1176,3 +1176,90 @@ pub mod folders_server {
         const NAME: &'static str = "google.cloud.resourcemanager.v2.Folders";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListFoldersRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListFoldersResponse {
+    type Item = Folder;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.folders
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchFoldersRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchFoldersResponse {
+    type Item = Folder;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.folders
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> folders_client::FoldersClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_folders`, following `next_page_token`."]
+    pub fn list_folders_pages(
+        &self,
+        request: ListFoldersRequest,
+    ) -> impl futures::Stream<Item = Result<ListFoldersResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_folders(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_folders`."]
+    pub fn list_folders_stream(
+        &self,
+        request: ListFoldersRequest,
+    ) -> impl futures::Stream<Item = Result<Folder, tonic::Status>> {
+        crate::paging::items(self.list_folders_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_folders`, following `next_page_token`."]
+    pub fn search_folders_pages(
+        &self,
+        request: SearchFoldersRequest,
+    ) -> impl futures::Stream<Item = Result<SearchFoldersResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_folders(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_folders`."]
+    pub fn search_folders_stream(
+        &self,
+        request: SearchFoldersRequest,
+    ) -> impl futures::Stream<Item = Result<Folder, tonic::Status>> {
+        crate::paging::items(self.search_folders_pages(request))
+    }
+}
@@ -1530,3 +1530,129 @@ pub mod runtime_config_manager_server {
         const NAME: &'static str = "google.cloud.runtimeconfig.v1beta1.RuntimeConfigManager";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListConfigsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListConfigsResponse {
+    type Item = RuntimeConfig;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.configs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListVariablesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListVariablesResponse {
+    type Item = Variable;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.variables
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListWaitersRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListWaitersResponse {
+    type Item = Waiter;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.waiters
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> runtime_config_manager_client::RuntimeConfigManagerClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_configs`, following `next_page_token`."]
+    pub fn list_configs_pages(
+        &self,
+        request: ListConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ListConfigsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_configs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_configs`."]
+    pub fn list_configs_stream(
+        &self,
+        request: ListConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<RuntimeConfig, tonic::Status>> {
+        crate::paging::items(self.list_configs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_variables`, following `next_page_token`."]
+    pub fn list_variables_pages(
+        &self,
+        request: ListVariablesRequest,
+    ) -> impl futures::Stream<Item = Result<ListVariablesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_variables(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_variables`."]
+    pub fn list_variables_stream(
+        &self,
+        request: ListVariablesRequest,
+    ) -> impl futures::Stream<Item = Result<Variable, tonic::Status>> {
+        crate::paging::items(self.list_variables_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_waiters`, following `next_page_token`."]
+    pub fn list_waiters_pages(
+        &self,
+        request: ListWaitersRequest,
+    ) -> impl futures::Stream<Item = Result<ListWaitersResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_waiters(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_waiters`."]
+    pub fn list_waiters_stream(
+        &self,
+        request: ListWaitersRequest,
+    ) -> impl futures::Stream<Item = Result<Waiter, tonic::Status>> {
+        crate::paging::items(self.list_waiters_pages(request))
+    }
+}
@@ -1239,3 +1239,46 @@ pub mod cloud_scheduler_server {
         const NAME: &'static str = "google.cloud.scheduler.v1beta1.CloudScheduler";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListJobsResponse {
+    type Item = Job;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> cloud_scheduler_client::CloudSchedulerClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_jobs`, following `next_page_token`."]
+    pub fn list_jobs_pages(
+        &self,
+        request: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.list_jobs(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_jobs`."]
+    pub fn list_jobs_stream(
+        &self,
+        request: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<Job, tonic::Status>> {
+        crate::paging::items(self.list_jobs_pages(request))
+    }
+}
@@ -1342,3 +1342,90 @@ pub mod secret_manager_service_server {
         const NAME: &'static str = "google.cloud.secrets.v1beta1.SecretManagerService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSecretsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSecretsResponse {
+    type Item = Secret;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.secrets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSecretVersionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSecretVersionsResponse {
+    type Item = SecretVersion;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.versions
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> secret_manager_service_client::SecretManagerServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_secrets`, following `next_page_token`."]
+    pub fn list_secrets_pages(
+        &self,
+        request: ListSecretsRequest,
+    ) -> impl futures::Stream<Item = Result<ListSecretsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_secrets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_secrets`."]
+    pub fn list_secrets_stream(
+        &self,
+        request: ListSecretsRequest,
+    ) -> impl futures::Stream<Item = Result<Secret, tonic::Status>> {
+        crate::paging::items(self.list_secrets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_secret_versions`, following `next_page_token`."]
+    pub fn list_secret_versions_pages(
+        &self,
+        request: ListSecretVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListSecretVersionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_secret_versions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_secret_versions`."]
+    pub fn list_secret_versions_stream(
+        &self,
+        request: ListSecretVersionsRequest,
+    ) -> impl futures::Stream<Item = Result<SecretVersion, tonic::Status>> {
+        crate::paging::items(self.list_secret_versions_pages(request))
+    }
+}
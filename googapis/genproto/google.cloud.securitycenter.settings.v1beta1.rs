@@ -1036,3 +1036,90 @@ pub mod security_center_settings_service_server {
             "google.cloud.securitycenter.settings.v1beta1.SecurityCenterSettingsService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDetectorsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDetectorsResponse {
+    type Item = Detector;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.detectors
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListComponentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListComponentsResponse {
+    type Item = std::string::String;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.components
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> security_center_settings_service_client::SecurityCenterSettingsServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_detectors`, following `next_page_token`."]
+    pub fn list_detectors_pages(
+        &self,
+        request: ListDetectorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDetectorsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_detectors(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_detectors`."]
+    pub fn list_detectors_stream(
+        &self,
+        request: ListDetectorsRequest,
+    ) -> impl futures::Stream<Item = Result<Detector, tonic::Status>> {
+        crate::paging::items(self.list_detectors_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_components`, following `next_page_token`."]
+    pub fn list_components_pages(
+        &self,
+        request: ListComponentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListComponentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_components(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_components`."]
+    pub fn list_components_stream(
+        &self,
+        request: ListComponentsRequest,
+    ) -> impl futures::Stream<Item = Result<std::string::String, tonic::Status>> {
+        crate::paging::items(self.list_components_pages(request))
+    }
+}
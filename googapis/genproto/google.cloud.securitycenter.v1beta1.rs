@@ -1394,3 +1394,208 @@ pub mod security_center_server {
         const NAME: &'static str = "google.cloud.securitycenter.v1beta1.SecurityCenter";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for GroupAssetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for GroupAssetsResponse {
+    type Item = GroupResult;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.group_by_results
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for GroupFindingsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for GroupFindingsResponse {
+    type Item = GroupResult;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.group_by_results
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAssetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAssetsResponse {
+    type Item = list_assets_response::ListAssetsResult;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.list_assets_results
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListFindingsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListFindingsResponse {
+    type Item = Finding;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.findings
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSourcesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSourcesResponse {
+    type Item = Source;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.sources
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> security_center_client::SecurityCenterClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `group_assets`, following `next_page_token`."]
+    pub fn group_assets_pages(
+        &self,
+        request: GroupAssetsRequest,
+    ) -> impl futures::Stream<Item = Result<GroupAssetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .group_assets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `group_assets`."]
+    pub fn group_assets_stream(
+        &self,
+        request: GroupAssetsRequest,
+    ) -> impl futures::Stream<Item = Result<GroupResult, tonic::Status>> {
+        crate::paging::items(self.group_assets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `group_findings`, following `next_page_token`."]
+    pub fn group_findings_pages(
+        &self,
+        request: GroupFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<GroupFindingsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .group_findings(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `group_findings`."]
+    pub fn group_findings_stream(
+        &self,
+        request: GroupFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<GroupResult, tonic::Status>> {
+        crate::paging::items(self.group_findings_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_assets`, following `next_page_token`."]
+    pub fn list_assets_pages(
+        &self,
+        request: ListAssetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListAssetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_assets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_assets`."]
+    pub fn list_assets_stream(
+        &self,
+        request: ListAssetsRequest,
+    ) -> impl futures::Stream<Item = Result<list_assets_response::ListAssetsResult, tonic::Status>>
+    {
+        crate::paging::items(self.list_assets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_findings`, following `next_page_token`."]
+    pub fn list_findings_pages(
+        &self,
+        request: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFindingsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_findings(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_findings`."]
+    pub fn list_findings_stream(
+        &self,
+        request: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<Finding, tonic::Status>> {
+        crate::paging::items(self.list_findings_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_sources`, following `next_page_token`."]
+    pub fn list_sources_pages(
+        &self,
+        request: ListSourcesRequest,
+    ) -> impl futures::Stream<Item = Result<ListSourcesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_sources(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_sources`."]
+    pub fn list_sources_stream(
+        &self,
+        request: ListSourcesRequest,
+    ) -> impl futures::Stream<Item = Result<Source, tonic::Status>> {
+        crate::paging::items(self.list_sources_pages(request))
+    }
+}
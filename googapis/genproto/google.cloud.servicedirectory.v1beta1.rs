@@ -1765,3 +1765,129 @@ pub mod registration_service_server {
         const NAME: &'static str = "google.cloud.servicedirectory.v1beta1.RegistrationService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListNamespacesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListNamespacesResponse {
+    type Item = Namespace;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.namespaces
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListServicesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListServicesResponse {
+    type Item = Service;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.services
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListEndpointsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListEndpointsResponse {
+    type Item = Endpoint;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.endpoints
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> registration_service_client::RegistrationServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_namespaces`, following `next_page_token`."]
+    pub fn list_namespaces_pages(
+        &self,
+        request: ListNamespacesRequest,
+    ) -> impl futures::Stream<Item = Result<ListNamespacesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_namespaces(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_namespaces`."]
+    pub fn list_namespaces_stream(
+        &self,
+        request: ListNamespacesRequest,
+    ) -> impl futures::Stream<Item = Result<Namespace, tonic::Status>> {
+        crate::paging::items(self.list_namespaces_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_services`, following `next_page_token`."]
+    pub fn list_services_pages(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListServicesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_services(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_services`."]
+    pub fn list_services_stream(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<Service, tonic::Status>> {
+        crate::paging::items(self.list_services_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_endpoints`, following `next_page_token`."]
+    pub fn list_endpoints_pages(
+        &self,
+        request: ListEndpointsRequest,
+    ) -> impl futures::Stream<Item = Result<ListEndpointsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_endpoints(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_endpoints`."]
+    pub fn list_endpoints_stream(
+        &self,
+        request: ListEndpointsRequest,
+    ) -> impl futures::Stream<Item = Result<Endpoint, tonic::Status>> {
+        crate::paging::items(self.list_endpoints_pages(request))
+    }
+}
@@ -752,3 +752,90 @@ pub mod cloud_support_server {
         const NAME: &'static str = "google.cloud.support.v1alpha1.CloudSupport";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSupportAccountsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSupportAccountsResponse {
+    type Item = super::common::SupportAccount;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.accounts
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCasesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCasesResponse {
+    type Item = super::common::Case;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.cases
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> cloud_support_client::CloudSupportClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_support_accounts`, following `next_page_token`."]
+    pub fn list_support_accounts_pages(
+        &self,
+        request: ListSupportAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<ListSupportAccountsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_support_accounts(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_support_accounts`."]
+    pub fn list_support_accounts_stream(
+        &self,
+        request: ListSupportAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<super::common::SupportAccount, tonic::Status>> {
+        crate::paging::items(self.list_support_accounts_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_cases`, following `next_page_token`."]
+    pub fn list_cases_pages(
+        &self,
+        request: ListCasesRequest,
+    ) -> impl futures::Stream<Item = Result<ListCasesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_cases(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_cases`."]
+    pub fn list_cases_stream(
+        &self,
+        request: ListCasesRequest,
+    ) -> impl futures::Stream<Item = Result<super::common::Case, tonic::Status>> {
+        crate::paging::items(self.list_cases_pages(request))
+    }
+}
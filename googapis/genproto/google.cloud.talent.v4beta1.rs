@@ -7487,3 +7487,339 @@ pub mod tenant_service_server {
         const NAME: &'static str = "google.cloud.talent.v4beta1.TenantService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListApplicationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListApplicationsResponse {
+    type Item = Application;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.applications
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> application_service_client::ApplicationServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_applications`, following `next_page_token`."]
+    pub fn list_applications_pages(
+        &self,
+        request: ListApplicationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListApplicationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_applications(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_applications`."]
+    pub fn list_applications_stream(
+        &self,
+        request: ListApplicationsRequest,
+    ) -> impl futures::Stream<Item = Result<Application, tonic::Status>> {
+        crate::paging::items(self.list_applications_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCompaniesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCompaniesResponse {
+    type Item = Company;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.companies
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> company_service_client::CompanyServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_companies`, following `next_page_token`."]
+    pub fn list_companies_pages(
+        &self,
+        request: ListCompaniesRequest,
+    ) -> impl futures::Stream<Item = Result<ListCompaniesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_companies(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_companies`."]
+    pub fn list_companies_stream(
+        &self,
+        request: ListCompaniesRequest,
+    ) -> impl futures::Stream<Item = Result<Company, tonic::Status>> {
+        crate::paging::items(self.list_companies_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListJobsResponse {
+    type Item = Job;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchJobsResponse {
+    type Item = search_jobs_response::MatchingJob;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.matching_jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> job_service_client::JobServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_jobs`, following `next_page_token`."]
+    pub fn list_jobs_pages(
+        &self,
+        request: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.list_jobs(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_jobs`."]
+    pub fn list_jobs_stream(
+        &self,
+        request: ListJobsRequest,
+    ) -> impl futures::Stream<Item = Result<Job, tonic::Status>> {
+        crate::paging::items(self.list_jobs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_jobs`, following `next_page_token`."]
+    pub fn search_jobs_pages(
+        &self,
+        request: SearchJobsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_jobs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_jobs`."]
+    pub fn search_jobs_stream(
+        &self,
+        request: SearchJobsRequest,
+    ) -> impl futures::Stream<Item = Result<search_jobs_response::MatchingJob, tonic::Status>> {
+        crate::paging::items(self.search_jobs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_jobs_for_alert`, following `next_page_token`."]
+    pub fn search_jobs_for_alert_pages(
+        &self,
+        request: SearchJobsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_jobs_for_alert(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_jobs_for_alert`."]
+    pub fn search_jobs_for_alert_stream(
+        &self,
+        request: SearchJobsRequest,
+    ) -> impl futures::Stream<Item = Result<search_jobs_response::MatchingJob, tonic::Status>> {
+        crate::paging::items(self.search_jobs_for_alert_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListProfilesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListProfilesResponse {
+    type Item = Profile;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.profiles
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchProfilesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchProfilesResponse {
+    type Item = HistogramQueryResult;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.histogram_query_results
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> profile_service_client::ProfileServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_profiles`, following `next_page_token`."]
+    pub fn list_profiles_pages(
+        &self,
+        request: ListProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<ListProfilesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_profiles(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_profiles`."]
+    pub fn list_profiles_stream(
+        &self,
+        request: ListProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<Profile, tonic::Status>> {
+        crate::paging::items(self.list_profiles_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_profiles`, following `next_page_token`."]
+    pub fn search_profiles_pages(
+        &self,
+        request: SearchProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<SearchProfilesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_profiles(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_profiles`."]
+    pub fn search_profiles_stream(
+        &self,
+        request: SearchProfilesRequest,
+    ) -> impl futures::Stream<Item = Result<HistogramQueryResult, tonic::Status>> {
+        crate::paging::items(self.search_profiles_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTenantsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTenantsResponse {
+    type Item = Tenant;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.tenants
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> tenant_service_client::TenantServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_tenants`, following `next_page_token`."]
+    pub fn list_tenants_pages(
+        &self,
+        request: ListTenantsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTenantsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_tenants(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_tenants`."]
+    pub fn list_tenants_stream(
+        &self,
+        request: ListTenantsRequest,
+    ) -> impl futures::Stream<Item = Result<Tenant, tonic::Status>> {
+        crate::paging::items(self.list_tenants_pages(request))
+    }
+}
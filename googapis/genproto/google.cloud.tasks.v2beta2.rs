@@ -2916,3 +2916,90 @@ pub mod cloud_tasks_server {
         const NAME: &'static str = "google.cloud.tasks.v2beta2.CloudTasks";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListQueuesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListQueuesResponse {
+    type Item = Queue;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.queues
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTasksRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTasksResponse {
+    type Item = Task;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.tasks
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> cloud_tasks_client::CloudTasksClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_queues`, following `next_page_token`."]
+    pub fn list_queues_pages(
+        &self,
+        request: ListQueuesRequest,
+    ) -> impl futures::Stream<Item = Result<ListQueuesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_queues(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_queues`."]
+    pub fn list_queues_stream(
+        &self,
+        request: ListQueuesRequest,
+    ) -> impl futures::Stream<Item = Result<Queue, tonic::Status>> {
+        crate::paging::items(self.list_queues_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_tasks`, following `next_page_token`."]
+    pub fn list_tasks_pages(
+        &self,
+        request: ListTasksRequest,
+    ) -> impl futures::Stream<Item = Result<ListTasksResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_tasks(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_tasks`."]
+    pub fn list_tasks_stream(
+        &self,
+        request: ListTasksRequest,
+    ) -> impl futures::Stream<Item = Result<Task, tonic::Status>> {
+        crate::paging::items(self.list_tasks_pages(request))
+    }
+}
@@ -1384,3 +1384,51 @@ pub mod translation_service_server {
         const NAME: &'static str = "google.cloud.translation.v3.TranslationService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListGlossariesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListGlossariesResponse {
+    type Item = Glossary;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.glossaries
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> translation_service_client::TranslationServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_glossaries`, following `next_page_token`."]
+    pub fn list_glossaries_pages(
+        &self,
+        request: ListGlossariesRequest,
+    ) -> impl futures::Stream<Item = Result<ListGlossariesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_glossaries(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_glossaries`."]
+    pub fn list_glossaries_stream(
+        &self,
+        request: ListGlossariesRequest,
+    ) -> impl futures::Stream<Item = Result<Glossary, tonic::Status>> {
+        crate::paging::items(self.list_glossaries_pages(request))
+    }
+}
@@ -3842,3 +3842,168 @@ pub mod image_annotator_server {
         const NAME: &'static str = "google.cloud.vision.v1p4beta1.ImageAnnotator";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListProductSetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListProductSetsResponse {
+    type Item = ProductSet;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.product_sets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListProductsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListProductsResponse {
+    type Item = Product;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.products
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListReferenceImagesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListReferenceImagesResponse {
+    type Item = ReferenceImage;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.reference_images
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListProductsInProductSetRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListProductsInProductSetResponse {
+    type Item = Product;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.products
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> product_search_client::ProductSearchClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_product_sets`, following `next_page_token`."]
+    pub fn list_product_sets_pages(
+        &self,
+        request: ListProductSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListProductSetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_product_sets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_product_sets`."]
+    pub fn list_product_sets_stream(
+        &self,
+        request: ListProductSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ProductSet, tonic::Status>> {
+        crate::paging::items(self.list_product_sets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_products`, following `next_page_token`."]
+    pub fn list_products_pages(
+        &self,
+        request: ListProductsRequest,
+    ) -> impl futures::Stream<Item = Result<ListProductsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_products(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_products`."]
+    pub fn list_products_stream(
+        &self,
+        request: ListProductsRequest,
+    ) -> impl futures::Stream<Item = Result<Product, tonic::Status>> {
+        crate::paging::items(self.list_products_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_reference_images`, following `next_page_token`."]
+    pub fn list_reference_images_pages(
+        &self,
+        request: ListReferenceImagesRequest,
+    ) -> impl futures::Stream<Item = Result<ListReferenceImagesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_reference_images(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_reference_images`."]
+    pub fn list_reference_images_stream(
+        &self,
+        request: ListReferenceImagesRequest,
+    ) -> impl futures::Stream<Item = Result<ReferenceImage, tonic::Status>> {
+        crate::paging::items(self.list_reference_images_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_products_in_product_set`, following `next_page_token`."]
+    pub fn list_products_in_product_set_pages(
+        &self,
+        request: ListProductsInProductSetRequest,
+    ) -> impl futures::Stream<Item = Result<ListProductsInProductSetResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_products_in_product_set(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_products_in_product_set`."]
+    pub fn list_products_in_product_set_stream(
+        &self,
+        request: ListProductsInProductSetRequest,
+    ) -> impl futures::Stream<Item = Result<Product, tonic::Status>> {
+        crate::paging::items(self.list_products_in_product_set_pages(request))
+    }
+}
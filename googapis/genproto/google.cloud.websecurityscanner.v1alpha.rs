@@ -1023,3 +1023,168 @@ pub mod web_security_scanner_server {
         const NAME: &'static str = "google.cloud.websecurityscanner.v1alpha.WebSecurityScanner";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListScanConfigsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListScanConfigsResponse {
+    type Item = ScanConfig;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.scan_configs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListScanRunsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListScanRunsResponse {
+    type Item = ScanRun;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.scan_runs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCrawledUrlsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCrawledUrlsResponse {
+    type Item = CrawledUrl;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.crawled_urls
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListFindingsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListFindingsResponse {
+    type Item = Finding;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.findings
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> web_security_scanner_client::WebSecurityScannerClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_scan_configs`, following `next_page_token`."]
+    pub fn list_scan_configs_pages(
+        &self,
+        request: ListScanConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ListScanConfigsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_scan_configs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_scan_configs`."]
+    pub fn list_scan_configs_stream(
+        &self,
+        request: ListScanConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ScanConfig, tonic::Status>> {
+        crate::paging::items(self.list_scan_configs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_scan_runs`, following `next_page_token`."]
+    pub fn list_scan_runs_pages(
+        &self,
+        request: ListScanRunsRequest,
+    ) -> impl futures::Stream<Item = Result<ListScanRunsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_scan_runs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_scan_runs`."]
+    pub fn list_scan_runs_stream(
+        &self,
+        request: ListScanRunsRequest,
+    ) -> impl futures::Stream<Item = Result<ScanRun, tonic::Status>> {
+        crate::paging::items(self.list_scan_runs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_crawled_urls`, following `next_page_token`."]
+    pub fn list_crawled_urls_pages(
+        &self,
+        request: ListCrawledUrlsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCrawledUrlsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_crawled_urls(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_crawled_urls`."]
+    pub fn list_crawled_urls_stream(
+        &self,
+        request: ListCrawledUrlsRequest,
+    ) -> impl futures::Stream<Item = Result<CrawledUrl, tonic::Status>> {
+        crate::paging::items(self.list_crawled_urls_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_findings`, following `next_page_token`."]
+    pub fn list_findings_pages(
+        &self,
+        request: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFindingsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_findings(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_findings`."]
+    pub fn list_findings_stream(
+        &self,
+        request: ListFindingsRequest,
+    ) -> impl futures::Stream<Item = Result<Finding, tonic::Status>> {
+        crate::paging::items(self.list_findings_pages(request))
+    }
+}
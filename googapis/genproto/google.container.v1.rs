@@ -4299,3 +4299,51 @@ pub mod cluster_manager_server {
         const NAME: &'static str = "google.container.v1.ClusterManager";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListUsableSubnetworksRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListUsableSubnetworksResponse {
+    type Item = UsableSubnetwork;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.subnetworks
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> cluster_manager_client::ClusterManagerClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_usable_subnetworks`, following `next_page_token`."]
+    pub fn list_usable_subnetworks_pages(
+        &self,
+        request: ListUsableSubnetworksRequest,
+    ) -> impl futures::Stream<Item = Result<ListUsableSubnetworksResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_usable_subnetworks(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_usable_subnetworks`."]
+    pub fn list_usable_subnetworks_stream(
+        &self,
+        request: ListUsableSubnetworksRequest,
+    ) -> impl futures::Stream<Item = Result<UsableSubnetwork, tonic::Status>> {
+        crate::paging::items(self.list_usable_subnetworks_pages(request))
+    }
+}
@@ -861,3 +861,87 @@ pub mod datastore_admin_server {
         const NAME: &'static str = "google.datastore.admin.v1.DatastoreAdmin";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListIndexesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListIndexesResponse {
+    type Item = Index;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.indexes
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> datastore_admin_client::DatastoreAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_indexes`, following `next_page_token`."]
+    pub fn list_indexes_pages(
+        &self,
+        request: ListIndexesRequest,
+    ) -> impl futures::Stream<Item = Result<ListIndexesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_indexes(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_indexes`."]
+    pub fn list_indexes_stream(
+        &self,
+        request: ListIndexesRequest,
+    ) -> impl futures::Stream<Item = Result<Index, tonic::Status>> {
+        crate::paging::items(self.list_indexes_pages(request))
+    }
+}
+#[cfg(feature = "longrunning")]
+impl<T> datastore_admin_client::DatastoreAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody>,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Calls `export_entities` and returns its operation typed by `operation_info`."]
+    pub async fn export_entities_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<ExportEntitiesRequest>,
+    ) -> Result<
+        crate::longrunning::Operation<ExportEntitiesResponse, ExportEntitiesMetadata>,
+        tonic::Status,
+    > {
+        let response = self.export_entities(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.datastore.admin.v1.ExportEntitiesResponse",
+            "type.googleapis.com/google.datastore.admin.v1.ExportEntitiesMetadata",
+        ))
+    }
+    #[doc = r" Calls `import_entities` and returns its operation typed by `operation_info`."]
+    pub async fn import_entities_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<ImportEntitiesRequest>,
+    ) -> Result<crate::longrunning::Operation<(), ImportEntitiesMetadata>, tonic::Status> {
+        let response = self.import_entities(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.protobuf.Empty",
+            "type.googleapis.com/google.datastore.admin.v1.ImportEntitiesMetadata",
+        ))
+    }
+}
@@ -1187,3 +1187,90 @@ pub mod report_errors_service_server {
             "google.devtools.clouderrorreporting.v1beta1.ReportErrorsService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListGroupStatsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListGroupStatsResponse {
+    type Item = ErrorGroupStats;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.error_group_stats
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListEventsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListEventsResponse {
+    type Item = ErrorEvent;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.error_events
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> error_stats_service_client::ErrorStatsServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_group_stats`, following `next_page_token`."]
+    pub fn list_group_stats_pages(
+        &self,
+        request: ListGroupStatsRequest,
+    ) -> impl futures::Stream<Item = Result<ListGroupStatsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_group_stats(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_group_stats`."]
+    pub fn list_group_stats_stream(
+        &self,
+        request: ListGroupStatsRequest,
+    ) -> impl futures::Stream<Item = Result<ErrorGroupStats, tonic::Status>> {
+        crate::paging::items(self.list_group_stats_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_events`, following `next_page_token`."]
+    pub fn list_events_pages(
+        &self,
+        request: ListEventsRequest,
+    ) -> impl futures::Stream<Item = Result<ListEventsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_events(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_events`."]
+    pub fn list_events_stream(
+        &self,
+        request: ListEventsRequest,
+    ) -> impl futures::Stream<Item = Result<ErrorEvent, tonic::Status>> {
+        crate::paging::items(self.list_events_pages(request))
+    }
+}
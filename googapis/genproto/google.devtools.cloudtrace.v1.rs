@@ -532,3 +532,51 @@ pub mod trace_service_server {
         const NAME: &'static str = "google.devtools.cloudtrace.v1.TraceService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTracesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTracesResponse {
+    type Item = Trace;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.traces
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> trace_service_client::TraceServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_traces`, following `next_page_token`."]
+    pub fn list_traces_pages(
+        &self,
+        request: ListTracesRequest,
+    ) -> impl futures::Stream<Item = Result<ListTracesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_traces(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_traces`."]
+    pub fn list_traces_stream(
+        &self,
+        request: ListTracesRequest,
+    ) -> impl futures::Stream<Item = Result<Trace, tonic::Status>> {
+        crate::paging::items(self.list_traces_pages(request))
+    }
+}
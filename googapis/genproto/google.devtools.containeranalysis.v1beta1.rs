@@ -384,3 +384,51 @@ pub mod container_analysis_v1_beta1_server {
             "google.devtools.containeranalysis.v1beta1.ContainerAnalysisV1Beta1";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListScanConfigsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListScanConfigsResponse {
+    type Item = ScanConfig;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.scan_configs
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> container_analysis_v1_beta1_client::ContainerAnalysisV1Beta1Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_scan_configs`, following `next_page_token`."]
+    pub fn list_scan_configs_pages(
+        &self,
+        request: ListScanConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ListScanConfigsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_scan_configs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_scan_configs`."]
+    pub fn list_scan_configs_stream(
+        &self,
+        request: ListScanConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ScanConfig, tonic::Status>> {
+        crate::paging::items(self.list_scan_configs_pages(request))
+    }
+}
@@ -633,3 +633,51 @@ pub mod source_repo_server {
         const NAME: &'static str = "google.devtools.sourcerepo.v1.SourceRepo";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListReposRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListReposResponse {
+    type Item = Repo;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.repos
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> source_repo_client::SourceRepoClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_repos`, following `next_page_token`."]
+    pub fn list_repos_pages(
+        &self,
+        request: ListReposRequest,
+    ) -> impl futures::Stream<Item = Result<ListReposResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_repos(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_repos`."]
+    pub fn list_repos_stream(
+        &self,
+        request: ListReposRequest,
+    ) -> impl futures::Stream<Item = Result<Repo, tonic::Status>> {
+        crate::paging::items(self.list_repos_pages(request))
+    }
+}
@@ -913,3 +913,90 @@ pub mod library_service_server {
         const NAME: &'static str = "google.example.library.v1.LibraryService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListShelvesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListShelvesResponse {
+    type Item = Shelf;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.shelves
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListBooksRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListBooksResponse {
+    type Item = Book;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.books
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> library_service_client::LibraryServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_shelves`, following `next_page_token`."]
+    pub fn list_shelves_pages(
+        &self,
+        request: ListShelvesRequest,
+    ) -> impl futures::Stream<Item = Result<ListShelvesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_shelves(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_shelves`."]
+    pub fn list_shelves_stream(
+        &self,
+        request: ListShelvesRequest,
+    ) -> impl futures::Stream<Item = Result<Shelf, tonic::Status>> {
+        crate::paging::items(self.list_shelves_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_books`, following `next_page_token`."]
+    pub fn list_books_pages(
+        &self,
+        request: ListBooksRequest,
+    ) -> impl futures::Stream<Item = Result<ListBooksResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_books(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_books`."]
+    pub fn list_books_stream(
+        &self,
+        request: ListBooksRequest,
+    ) -> impl futures::Stream<Item = Result<Book, tonic::Status>> {
+        crate::paging::items(self.list_books_pages(request))
+    }
+}
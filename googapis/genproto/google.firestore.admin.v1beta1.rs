@@ -903,3 +903,51 @@ pub mod firestore_admin_server {
 /// The metadata message for [google.cloud.location.Location.metadata][google.cloud.location.Location.metadata].
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LocationMetadata {}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListIndexesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListIndexesResponse {
+    type Item = Index;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.indexes
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> firestore_admin_client::FirestoreAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_indexes`, following `next_page_token`."]
+    pub fn list_indexes_pages(
+        &self,
+        request: ListIndexesRequest,
+    ) -> impl futures::Stream<Item = Result<ListIndexesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_indexes(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_indexes`."]
+    pub fn list_indexes_stream(
+        &self,
+        request: ListIndexesRequest,
+    ) -> impl futures::Stream<Item = Result<Index, tonic::Status>> {
+        crate::paging::items(self.list_indexes_pages(request))
+    }
+}
@@ -1219,3 +1219,90 @@ pub enum OperationState {
     /// google.longrunning.Operations.CancelOperation.
     Cancelled = 7,
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListIndexesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListIndexesResponse {
+    type Item = Index;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.indexes
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListFieldsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListFieldsResponse {
+    type Item = Field;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.fields
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> firestore_admin_client::FirestoreAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_indexes`, following `next_page_token`."]
+    pub fn list_indexes_pages(
+        &self,
+        request: ListIndexesRequest,
+    ) -> impl futures::Stream<Item = Result<ListIndexesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_indexes(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_indexes`."]
+    pub fn list_indexes_stream(
+        &self,
+        request: ListIndexesRequest,
+    ) -> impl futures::Stream<Item = Result<Index, tonic::Status>> {
+        crate::paging::items(self.list_indexes_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_fields`, following `next_page_token`."]
+    pub fn list_fields_pages(
+        &self,
+        request: ListFieldsRequest,
+    ) -> impl futures::Stream<Item = Result<ListFieldsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_fields(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_fields`."]
+    pub fn list_fields_stream(
+        &self,
+        request: ListFieldsRequest,
+    ) -> impl futures::Stream<Item = Result<Field, tonic::Status>> {
+        crate::paging::items(self.list_fields_pages(request))
+    }
+}
@@ -2255,3 +2255,90 @@ pub mod firestore_server {
         const NAME: &'static str = "google.firestore.v1.Firestore";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDocumentsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDocumentsResponse {
+    type Item = Document;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.documents
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCollectionIdsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCollectionIdsResponse {
+    type Item = std::string::String;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.collection_ids
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> firestore_client::FirestoreClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_documents`, following `next_page_token`."]
+    pub fn list_documents_pages(
+        &self,
+        request: ListDocumentsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDocumentsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_documents(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_documents`."]
+    pub fn list_documents_stream(
+        &self,
+        request: ListDocumentsRequest,
+    ) -> impl futures::Stream<Item = Result<Document, tonic::Status>> {
+        crate::paging::items(self.list_documents_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_collection_ids`, following `next_page_token`."]
+    pub fn list_collection_ids_pages(
+        &self,
+        request: ListCollectionIdsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCollectionIdsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_collection_ids(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_collection_ids`."]
+    pub fn list_collection_ids_stream(
+        &self,
+        request: ListCollectionIdsRequest,
+    ) -> impl futures::Stream<Item = Result<std::string::String, tonic::Status>> {
+        crate::paging::items(self.list_collection_ids_pages(request))
+    }
+}
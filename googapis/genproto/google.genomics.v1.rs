@@ -6912,3 +6912,477 @@ pub mod variant_service_v1_server {
         const NAME: &'static str = "google.genomics.v1.VariantServiceV1";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchAnnotationSetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchAnnotationSetsResponse {
+    type Item = AnnotationSet;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.annotation_sets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchAnnotationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchAnnotationsResponse {
+    type Item = Annotation;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.annotations
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> annotation_service_v1_client::AnnotationServiceV1Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search_annotation_sets`, following `next_page_token`."]
+    pub fn search_annotation_sets_pages(
+        &self,
+        request: SearchAnnotationSetsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchAnnotationSetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_annotation_sets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_annotation_sets`."]
+    pub fn search_annotation_sets_stream(
+        &self,
+        request: SearchAnnotationSetsRequest,
+    ) -> impl futures::Stream<Item = Result<AnnotationSet, tonic::Status>> {
+        crate::paging::items(self.search_annotation_sets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_annotations`, following `next_page_token`."]
+    pub fn search_annotations_pages(
+        &self,
+        request: SearchAnnotationsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchAnnotationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_annotations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_annotations`."]
+    pub fn search_annotations_stream(
+        &self,
+        request: SearchAnnotationsRequest,
+    ) -> impl futures::Stream<Item = Result<Annotation, tonic::Status>> {
+        crate::paging::items(self.search_annotations_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDatasetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDatasetsResponse {
+    type Item = Dataset;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.datasets
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> dataset_service_v1_client::DatasetServiceV1Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_datasets`, following `next_page_token`."]
+    pub fn list_datasets_pages(
+        &self,
+        request: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDatasetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_datasets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_datasets`."]
+    pub fn list_datasets_stream(
+        &self,
+        request: ListDatasetsRequest,
+    ) -> impl futures::Stream<Item = Result<Dataset, tonic::Status>> {
+        crate::paging::items(self.list_datasets_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchReadGroupSetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchReadGroupSetsResponse {
+    type Item = ReadGroupSet;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.read_group_sets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListCoverageBucketsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListCoverageBucketsResponse {
+    type Item = CoverageBucket;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.coverage_buckets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchReadsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchReadsResponse {
+    type Item = Read;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.alignments
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> read_service_v1_client::ReadServiceV1Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search_read_group_sets`, following `next_page_token`."]
+    pub fn search_read_group_sets_pages(
+        &self,
+        request: SearchReadGroupSetsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchReadGroupSetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_read_group_sets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_read_group_sets`."]
+    pub fn search_read_group_sets_stream(
+        &self,
+        request: SearchReadGroupSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ReadGroupSet, tonic::Status>> {
+        crate::paging::items(self.search_read_group_sets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_coverage_buckets`, following `next_page_token`."]
+    pub fn list_coverage_buckets_pages(
+        &self,
+        request: ListCoverageBucketsRequest,
+    ) -> impl futures::Stream<Item = Result<ListCoverageBucketsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_coverage_buckets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_coverage_buckets`."]
+    pub fn list_coverage_buckets_stream(
+        &self,
+        request: ListCoverageBucketsRequest,
+    ) -> impl futures::Stream<Item = Result<CoverageBucket, tonic::Status>> {
+        crate::paging::items(self.list_coverage_buckets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_reads`, following `next_page_token`."]
+    pub fn search_reads_pages(
+        &self,
+        request: SearchReadsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchReadsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_reads(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_reads`."]
+    pub fn search_reads_stream(
+        &self,
+        request: SearchReadsRequest,
+    ) -> impl futures::Stream<Item = Result<Read, tonic::Status>> {
+        crate::paging::items(self.search_reads_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchReferenceSetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchReferenceSetsResponse {
+    type Item = ReferenceSet;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.reference_sets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchReferencesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchReferencesResponse {
+    type Item = Reference;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.references
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> reference_service_v1_client::ReferenceServiceV1Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search_reference_sets`, following `next_page_token`."]
+    pub fn search_reference_sets_pages(
+        &self,
+        request: SearchReferenceSetsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchReferenceSetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_reference_sets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_reference_sets`."]
+    pub fn search_reference_sets_stream(
+        &self,
+        request: SearchReferenceSetsRequest,
+    ) -> impl futures::Stream<Item = Result<ReferenceSet, tonic::Status>> {
+        crate::paging::items(self.search_reference_sets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_references`, following `next_page_token`."]
+    pub fn search_references_pages(
+        &self,
+        request: SearchReferencesRequest,
+    ) -> impl futures::Stream<Item = Result<SearchReferencesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_references(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_references`."]
+    pub fn search_references_stream(
+        &self,
+        request: SearchReferencesRequest,
+    ) -> impl futures::Stream<Item = Result<Reference, tonic::Status>> {
+        crate::paging::items(self.search_references_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchVariantSetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchVariantSetsResponse {
+    type Item = VariantSet;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.variant_sets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchVariantsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchVariantsResponse {
+    type Item = Variant;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.variants
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for SearchCallSetsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for SearchCallSetsResponse {
+    type Item = CallSet;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.call_sets
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> variant_service_v1_client::VariantServiceV1Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `search_variant_sets`, following `next_page_token`."]
+    pub fn search_variant_sets_pages(
+        &self,
+        request: SearchVariantSetsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchVariantSetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_variant_sets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_variant_sets`."]
+    pub fn search_variant_sets_stream(
+        &self,
+        request: SearchVariantSetsRequest,
+    ) -> impl futures::Stream<Item = Result<VariantSet, tonic::Status>> {
+        crate::paging::items(self.search_variant_sets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_variants`, following `next_page_token`."]
+    pub fn search_variants_pages(
+        &self,
+        request: SearchVariantsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchVariantsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_variants(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_variants`."]
+    pub fn search_variants_stream(
+        &self,
+        request: SearchVariantsRequest,
+    ) -> impl futures::Stream<Item = Result<Variant, tonic::Status>> {
+        crate::paging::items(self.search_variants_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `search_call_sets`, following `next_page_token`."]
+    pub fn search_call_sets_pages(
+        &self,
+        request: SearchCallSetsRequest,
+    ) -> impl futures::Stream<Item = Result<SearchCallSetsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .search_call_sets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `search_call_sets`."]
+    pub fn search_call_sets_stream(
+        &self,
+        request: SearchCallSetsRequest,
+    ) -> impl futures::Stream<Item = Result<CallSet, tonic::Status>> {
+        crate::paging::items(self.search_call_sets_pages(request))
+    }
+}
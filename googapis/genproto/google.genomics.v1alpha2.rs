@@ -1117,3 +1117,51 @@ pub mod pipelines_v1_alpha2_server {
         const NAME: &'static str = "google.genomics.v1alpha2.PipelinesV1Alpha2";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListPipelinesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListPipelinesResponse {
+    type Item = Pipeline;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.pipelines
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> pipelines_v1_alpha2_client::PipelinesV1Alpha2Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_pipelines`, following `next_page_token`."]
+    pub fn list_pipelines_pages(
+        &self,
+        request: ListPipelinesRequest,
+    ) -> impl futures::Stream<Item = Result<ListPipelinesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_pipelines(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_pipelines`."]
+    pub fn list_pipelines_stream(
+        &self,
+        request: ListPipelinesRequest,
+    ) -> impl futures::Stream<Item = Result<Pipeline, tonic::Status>> {
+        crate::paging::items(self.list_pipelines_pages(request))
+    }
+}
@@ -2180,3 +2180,168 @@ pub mod iam_server {
         const NAME: &'static str = "google.iam.admin.v1.IAM";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListServiceAccountsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListServiceAccountsResponse {
+    type Item = ServiceAccount;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.accounts
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for QueryGrantableRolesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for QueryGrantableRolesResponse {
+    type Item = Role;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.roles
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListRolesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListRolesResponse {
+    type Item = Role;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.roles
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for QueryTestablePermissionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for QueryTestablePermissionsResponse {
+    type Item = Permission;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.permissions
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> iam_client::IamClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_service_accounts`, following `next_page_token`."]
+    pub fn list_service_accounts_pages(
+        &self,
+        request: ListServiceAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<ListServiceAccountsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_service_accounts(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_service_accounts`."]
+    pub fn list_service_accounts_stream(
+        &self,
+        request: ListServiceAccountsRequest,
+    ) -> impl futures::Stream<Item = Result<ServiceAccount, tonic::Status>> {
+        crate::paging::items(self.list_service_accounts_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `query_grantable_roles`, following `next_page_token`."]
+    pub fn query_grantable_roles_pages(
+        &self,
+        request: QueryGrantableRolesRequest,
+    ) -> impl futures::Stream<Item = Result<QueryGrantableRolesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .query_grantable_roles(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `query_grantable_roles`."]
+    pub fn query_grantable_roles_stream(
+        &self,
+        request: QueryGrantableRolesRequest,
+    ) -> impl futures::Stream<Item = Result<Role, tonic::Status>> {
+        crate::paging::items(self.query_grantable_roles_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_roles`, following `next_page_token`."]
+    pub fn list_roles_pages(
+        &self,
+        request: ListRolesRequest,
+    ) -> impl futures::Stream<Item = Result<ListRolesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_roles(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_roles`."]
+    pub fn list_roles_stream(
+        &self,
+        request: ListRolesRequest,
+    ) -> impl futures::Stream<Item = Result<Role, tonic::Status>> {
+        crate::paging::items(self.list_roles_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `query_testable_permissions`, following `next_page_token`."]
+    pub fn query_testable_permissions_pages(
+        &self,
+        request: QueryTestablePermissionsRequest,
+    ) -> impl futures::Stream<Item = Result<QueryTestablePermissionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .query_testable_permissions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `query_testable_permissions`."]
+    pub fn query_testable_permissions_stream(
+        &self,
+        request: QueryTestablePermissionsRequest,
+    ) -> impl futures::Stream<Item = Result<Permission, tonic::Status>> {
+        crate::paging::items(self.query_testable_permissions_pages(request))
+    }
+}
@@ -3133,3 +3133,300 @@ pub mod metrics_service_v2_server {
         const NAME: &'static str = "google.logging.v2.MetricsServiceV2";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListBucketsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListBucketsResponse {
+    type Item = LogBucket;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.buckets
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSinksRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSinksResponse {
+    type Item = LogSink;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.sinks
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListExclusionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListExclusionsResponse {
+    type Item = LogExclusion;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.exclusions
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> config_service_v2_client::ConfigServiceV2Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_buckets`, following `next_page_token`."]
+    pub fn list_buckets_pages(
+        &self,
+        request: ListBucketsRequest,
+    ) -> impl futures::Stream<Item = Result<ListBucketsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_buckets(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_buckets`."]
+    pub fn list_buckets_stream(
+        &self,
+        request: ListBucketsRequest,
+    ) -> impl futures::Stream<Item = Result<LogBucket, tonic::Status>> {
+        crate::paging::items(self.list_buckets_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_sinks`, following `next_page_token`."]
+    pub fn list_sinks_pages(
+        &self,
+        request: ListSinksRequest,
+    ) -> impl futures::Stream<Item = Result<ListSinksResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_sinks(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_sinks`."]
+    pub fn list_sinks_stream(
+        &self,
+        request: ListSinksRequest,
+    ) -> impl futures::Stream<Item = Result<LogSink, tonic::Status>> {
+        crate::paging::items(self.list_sinks_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_exclusions`, following `next_page_token`."]
+    pub fn list_exclusions_pages(
+        &self,
+        request: ListExclusionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListExclusionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_exclusions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_exclusions`."]
+    pub fn list_exclusions_stream(
+        &self,
+        request: ListExclusionsRequest,
+    ) -> impl futures::Stream<Item = Result<LogExclusion, tonic::Status>> {
+        crate::paging::items(self.list_exclusions_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListLogEntriesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListLogEntriesResponse {
+    type Item = LogEntry;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.entries
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListMonitoredResourceDescriptorsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListMonitoredResourceDescriptorsResponse {
+    type Item = super::super::api::MonitoredResourceDescriptor;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.resource_descriptors
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListLogsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListLogsResponse {
+    type Item = std::string::String;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.log_names
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> logging_service_v2_client::LoggingServiceV2Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_log_entries`, following `next_page_token`."]
+    pub fn list_log_entries_pages(
+        &self,
+        request: ListLogEntriesRequest,
+    ) -> impl futures::Stream<Item = Result<ListLogEntriesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_log_entries(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_log_entries`."]
+    pub fn list_log_entries_stream(
+        &self,
+        request: ListLogEntriesRequest,
+    ) -> impl futures::Stream<Item = Result<LogEntry, tonic::Status>> {
+        crate::paging::items(self.list_log_entries_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_monitored_resource_descriptors`, following `next_page_token`."]
+    pub fn list_monitored_resource_descriptors_pages(
+        &self,
+        request: ListMonitoredResourceDescriptorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListMonitoredResourceDescriptorsResponse, tonic::Status>>
+    {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_monitored_resource_descriptors(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_monitored_resource_descriptors`."]
+    pub fn list_monitored_resource_descriptors_stream(
+        &self,
+        request: ListMonitoredResourceDescriptorsRequest,
+    ) -> impl futures::Stream<Item = Result<super::super::api::MonitoredResourceDescriptor, tonic::Status>>
+    {
+        crate::paging::items(self.list_monitored_resource_descriptors_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_logs`, following `next_page_token`."]
+    pub fn list_logs_pages(
+        &self,
+        request: ListLogsRequest,
+    ) -> impl futures::Stream<Item = Result<ListLogsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move { client.list_logs(req).await.map(tonic::Response::into_inner) }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_logs`."]
+    pub fn list_logs_stream(
+        &self,
+        request: ListLogsRequest,
+    ) -> impl futures::Stream<Item = Result<std::string::String, tonic::Status>> {
+        crate::paging::items(self.list_logs_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListLogMetricsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListLogMetricsResponse {
+    type Item = LogMetric;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.metrics
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> metrics_service_v2_client::MetricsServiceV2Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_log_metrics`, following `next_page_token`."]
+    pub fn list_log_metrics_pages(
+        &self,
+        request: ListLogMetricsRequest,
+    ) -> impl futures::Stream<Item = Result<ListLogMetricsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_log_metrics(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_log_metrics`."]
+    pub fn list_log_metrics_stream(
+        &self,
+        request: ListLogMetricsRequest,
+    ) -> impl futures::Stream<Item = Result<LogMetric, tonic::Status>> {
+        crate::paging::items(self.list_log_metrics_pages(request))
+    }
+}
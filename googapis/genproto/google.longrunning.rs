@@ -607,3 +607,51 @@ pub mod operations_server {
         const NAME: &'static str = "google.longrunning.Operations";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListOperationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListOperationsResponse {
+    type Item = Operation;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.operations
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> operations_client::OperationsClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_operations`, following `next_page_token`."]
+    pub fn list_operations_pages(
+        &self,
+        request: ListOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListOperationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_operations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_operations`."]
+    pub fn list_operations_stream(
+        &self,
+        request: ListOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<Operation, tonic::Status>> {
+        crate::paging::items(self.list_operations_pages(request))
+    }
+}
@@ -1385,3 +1385,51 @@ pub mod dashboards_service_server {
         const NAME: &'static str = "google.monitoring.dashboard.v1.DashboardsService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDashboardsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDashboardsResponse {
+    type Item = Dashboard;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.dashboards
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> dashboards_service_client::DashboardsServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_dashboards`, following `next_page_token`."]
+    pub fn list_dashboards_pages(
+        &self,
+        request: ListDashboardsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDashboardsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_dashboards(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_dashboards`."]
+    pub fn list_dashboards_stream(
+        &self,
+        request: ListDashboardsRequest,
+    ) -> impl futures::Stream<Item = Result<Dashboard, tonic::Status>> {
+        crate::paging::items(self.list_dashboards_pages(request))
+    }
+}
@@ -6205,3 +6205,531 @@ pub mod uptime_check_service_server {
         const NAME: &'static str = "google.monitoring.v3.UptimeCheckService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListAlertPoliciesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListAlertPoliciesResponse {
+    type Item = AlertPolicy;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.alert_policies
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> alert_policy_service_client::AlertPolicyServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_alert_policies`, following `next_page_token`."]
+    pub fn list_alert_policies_pages(
+        &self,
+        request: ListAlertPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<ListAlertPoliciesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_alert_policies(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_alert_policies`."]
+    pub fn list_alert_policies_stream(
+        &self,
+        request: ListAlertPoliciesRequest,
+    ) -> impl futures::Stream<Item = Result<AlertPolicy, tonic::Status>> {
+        crate::paging::items(self.list_alert_policies_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListGroupsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListGroupsResponse {
+    type Item = Group;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.group
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListGroupMembersRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListGroupMembersResponse {
+    type Item = super::super::api::MonitoredResource;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.members
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> group_service_client::GroupServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_groups`, following `next_page_token`."]
+    pub fn list_groups_pages(
+        &self,
+        request: ListGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<ListGroupsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_groups(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_groups`."]
+    pub fn list_groups_stream(
+        &self,
+        request: ListGroupsRequest,
+    ) -> impl futures::Stream<Item = Result<Group, tonic::Status>> {
+        crate::paging::items(self.list_groups_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_group_members`, following `next_page_token`."]
+    pub fn list_group_members_pages(
+        &self,
+        request: ListGroupMembersRequest,
+    ) -> impl futures::Stream<Item = Result<ListGroupMembersResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_group_members(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_group_members`."]
+    pub fn list_group_members_stream(
+        &self,
+        request: ListGroupMembersRequest,
+    ) -> impl futures::Stream<Item = Result<super::super::api::MonitoredResource, tonic::Status>>
+    {
+        crate::paging::items(self.list_group_members_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListMonitoredResourceDescriptorsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListMonitoredResourceDescriptorsResponse {
+    type Item = super::super::api::MonitoredResourceDescriptor;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.resource_descriptors
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListMetricDescriptorsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListMetricDescriptorsResponse {
+    type Item = super::super::api::MetricDescriptor;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.metric_descriptors
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTimeSeriesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTimeSeriesResponse {
+    type Item = TimeSeries;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.time_series
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> metric_service_client::MetricServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_monitored_resource_descriptors`, following `next_page_token`."]
+    pub fn list_monitored_resource_descriptors_pages(
+        &self,
+        request: ListMonitoredResourceDescriptorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListMonitoredResourceDescriptorsResponse, tonic::Status>>
+    {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_monitored_resource_descriptors(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_monitored_resource_descriptors`."]
+    pub fn list_monitored_resource_descriptors_stream(
+        &self,
+        request: ListMonitoredResourceDescriptorsRequest,
+    ) -> impl futures::Stream<Item = Result<super::super::api::MonitoredResourceDescriptor, tonic::Status>>
+    {
+        crate::paging::items(self.list_monitored_resource_descriptors_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_metric_descriptors`, following `next_page_token`."]
+    pub fn list_metric_descriptors_pages(
+        &self,
+        request: ListMetricDescriptorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListMetricDescriptorsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_metric_descriptors(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_metric_descriptors`."]
+    pub fn list_metric_descriptors_stream(
+        &self,
+        request: ListMetricDescriptorsRequest,
+    ) -> impl futures::Stream<Item = Result<super::super::api::MetricDescriptor, tonic::Status>>
+    {
+        crate::paging::items(self.list_metric_descriptors_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_time_series`, following `next_page_token`."]
+    pub fn list_time_series_pages(
+        &self,
+        request: ListTimeSeriesRequest,
+    ) -> impl futures::Stream<Item = Result<ListTimeSeriesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_time_series(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_time_series`."]
+    pub fn list_time_series_stream(
+        &self,
+        request: ListTimeSeriesRequest,
+    ) -> impl futures::Stream<Item = Result<TimeSeries, tonic::Status>> {
+        crate::paging::items(self.list_time_series_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListNotificationChannelDescriptorsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListNotificationChannelDescriptorsResponse {
+    type Item = NotificationChannelDescriptor;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.channel_descriptors
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListNotificationChannelsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListNotificationChannelsResponse {
+    type Item = NotificationChannel;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.notification_channels
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> notification_channel_service_client::NotificationChannelServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_notification_channel_descriptors`, following `next_page_token`."]
+    pub fn list_notification_channel_descriptors_pages(
+        &self,
+        request: ListNotificationChannelDescriptorsRequest,
+    ) -> impl futures::Stream<Item = Result<ListNotificationChannelDescriptorsResponse, tonic::Status>>
+    {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_notification_channel_descriptors(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_notification_channel_descriptors`."]
+    pub fn list_notification_channel_descriptors_stream(
+        &self,
+        request: ListNotificationChannelDescriptorsRequest,
+    ) -> impl futures::Stream<Item = Result<NotificationChannelDescriptor, tonic::Status>> {
+        crate::paging::items(self.list_notification_channel_descriptors_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_notification_channels`, following `next_page_token`."]
+    pub fn list_notification_channels_pages(
+        &self,
+        request: ListNotificationChannelsRequest,
+    ) -> impl futures::Stream<Item = Result<ListNotificationChannelsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_notification_channels(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_notification_channels`."]
+    pub fn list_notification_channels_stream(
+        &self,
+        request: ListNotificationChannelsRequest,
+    ) -> impl futures::Stream<Item = Result<NotificationChannel, tonic::Status>> {
+        crate::paging::items(self.list_notification_channels_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListServicesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListServicesResponse {
+    type Item = Service;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.services
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListServiceLevelObjectivesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListServiceLevelObjectivesResponse {
+    type Item = ServiceLevelObjective;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.service_level_objectives
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> service_monitoring_service_client::ServiceMonitoringServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_services`, following `next_page_token`."]
+    pub fn list_services_pages(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<ListServicesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_services(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_services`."]
+    pub fn list_services_stream(
+        &self,
+        request: ListServicesRequest,
+    ) -> impl futures::Stream<Item = Result<Service, tonic::Status>> {
+        crate::paging::items(self.list_services_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_service_level_objectives`, following `next_page_token`."]
+    pub fn list_service_level_objectives_pages(
+        &self,
+        request: ListServiceLevelObjectivesRequest,
+    ) -> impl futures::Stream<Item = Result<ListServiceLevelObjectivesResponse, tonic::Status>>
+    {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_service_level_objectives(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_service_level_objectives`."]
+    pub fn list_service_level_objectives_stream(
+        &self,
+        request: ListServiceLevelObjectivesRequest,
+    ) -> impl futures::Stream<Item = Result<ServiceLevelObjective, tonic::Status>> {
+        crate::paging::items(self.list_service_level_objectives_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListUptimeCheckConfigsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListUptimeCheckConfigsResponse {
+    type Item = UptimeCheckConfig;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.uptime_check_configs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListUptimeCheckIpsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListUptimeCheckIpsResponse {
+    type Item = UptimeCheckIp;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.uptime_check_ips
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> uptime_check_service_client::UptimeCheckServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_uptime_check_configs`, following `next_page_token`."]
+    pub fn list_uptime_check_configs_pages(
+        &self,
+        request: ListUptimeCheckConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ListUptimeCheckConfigsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_uptime_check_configs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_uptime_check_configs`."]
+    pub fn list_uptime_check_configs_stream(
+        &self,
+        request: ListUptimeCheckConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<UptimeCheckConfig, tonic::Status>> {
+        crate::paging::items(self.list_uptime_check_configs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_uptime_check_ips`, following `next_page_token`."]
+    pub fn list_uptime_check_ips_pages(
+        &self,
+        request: ListUptimeCheckIpsRequest,
+    ) -> impl futures::Stream<Item = Result<ListUptimeCheckIpsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_uptime_check_ips(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_uptime_check_ips`."]
+    pub fn list_uptime_check_ips_stream(
+        &self,
+        request: ListUptimeCheckIpsRequest,
+    ) -> impl futures::Stream<Item = Result<UptimeCheckIp, tonic::Status>> {
+        crate::paging::items(self.list_uptime_check_ips_pages(request))
+    }
+}
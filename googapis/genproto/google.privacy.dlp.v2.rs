@@ -6575,3 +6575,207 @@ pub mod dlp_service_server {
         const NAME: &'static str = "google.privacy.dlp.v2.DlpService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListInspectTemplatesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListInspectTemplatesResponse {
+    type Item = InspectTemplate;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.inspect_templates
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDeidentifyTemplatesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDeidentifyTemplatesResponse {
+    type Item = DeidentifyTemplate;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.deidentify_templates
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListJobTriggersRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListJobTriggersResponse {
+    type Item = JobTrigger;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.job_triggers
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDlpJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDlpJobsResponse {
+    type Item = DlpJob;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListStoredInfoTypesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListStoredInfoTypesResponse {
+    type Item = StoredInfoType;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.stored_info_types
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> dlp_service_client::DlpServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_inspect_templates`, following `next_page_token`."]
+    pub fn list_inspect_templates_pages(
+        &self,
+        request: ListInspectTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<ListInspectTemplatesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_inspect_templates(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_inspect_templates`."]
+    pub fn list_inspect_templates_stream(
+        &self,
+        request: ListInspectTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<InspectTemplate, tonic::Status>> {
+        crate::paging::items(self.list_inspect_templates_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_deidentify_templates`, following `next_page_token`."]
+    pub fn list_deidentify_templates_pages(
+        &self,
+        request: ListDeidentifyTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<ListDeidentifyTemplatesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_deidentify_templates(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_deidentify_templates`."]
+    pub fn list_deidentify_templates_stream(
+        &self,
+        request: ListDeidentifyTemplatesRequest,
+    ) -> impl futures::Stream<Item = Result<DeidentifyTemplate, tonic::Status>> {
+        crate::paging::items(self.list_deidentify_templates_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_job_triggers`, following `next_page_token`."]
+    pub fn list_job_triggers_pages(
+        &self,
+        request: ListJobTriggersRequest,
+    ) -> impl futures::Stream<Item = Result<ListJobTriggersResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_job_triggers(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_job_triggers`."]
+    pub fn list_job_triggers_stream(
+        &self,
+        request: ListJobTriggersRequest,
+    ) -> impl futures::Stream<Item = Result<JobTrigger, tonic::Status>> {
+        crate::paging::items(self.list_job_triggers_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_dlp_jobs`, following `next_page_token`."]
+    pub fn list_dlp_jobs_pages(
+        &self,
+        request: ListDlpJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDlpJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_dlp_jobs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_dlp_jobs`."]
+    pub fn list_dlp_jobs_stream(
+        &self,
+        request: ListDlpJobsRequest,
+    ) -> impl futures::Stream<Item = Result<DlpJob, tonic::Status>> {
+        crate::paging::items(self.list_dlp_jobs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_stored_info_types`, following `next_page_token`."]
+    pub fn list_stored_info_types_pages(
+        &self,
+        request: ListStoredInfoTypesRequest,
+    ) -> impl futures::Stream<Item = Result<ListStoredInfoTypesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_stored_info_types(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_stored_info_types`."]
+    pub fn list_stored_info_types_stream(
+        &self,
+        request: ListStoredInfoTypesRequest,
+    ) -> impl futures::Stream<Item = Result<StoredInfoType, tonic::Status>> {
+        crate::paging::items(self.list_stored_info_types_pages(request))
+    }
+}
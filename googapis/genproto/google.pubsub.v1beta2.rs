@@ -1354,3 +1354,138 @@ pub mod publisher_server {
         const NAME: &'static str = "google.pubsub.v1beta2.Publisher";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSubscriptionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSubscriptionsResponse {
+    type Item = Subscription;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.subscriptions
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> subscriber_client::SubscriberClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_subscriptions`, following `next_page_token`."]
+    pub fn list_subscriptions_pages(
+        &self,
+        request: ListSubscriptionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListSubscriptionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_subscriptions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_subscriptions`."]
+    pub fn list_subscriptions_stream(
+        &self,
+        request: ListSubscriptionsRequest,
+    ) -> impl futures::Stream<Item = Result<Subscription, tonic::Status>> {
+        crate::paging::items(self.list_subscriptions_pages(request))
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTopicsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTopicsResponse {
+    type Item = Topic;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.topics
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTopicSubscriptionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTopicSubscriptionsResponse {
+    type Item = std::string::String;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.subscriptions
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> publisher_client::PublisherClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_topics`, following `next_page_token`."]
+    pub fn list_topics_pages(
+        &self,
+        request: ListTopicsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTopicsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_topics(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_topics`."]
+    pub fn list_topics_stream(
+        &self,
+        request: ListTopicsRequest,
+    ) -> impl futures::Stream<Item = Result<Topic, tonic::Status>> {
+        crate::paging::items(self.list_topics_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_topic_subscriptions`, following `next_page_token`."]
+    pub fn list_topic_subscriptions_pages(
+        &self,
+        request: ListTopicSubscriptionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTopicSubscriptionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_topic_subscriptions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_topic_subscriptions`."]
+    pub fn list_topic_subscriptions_stream(
+        &self,
+        request: ListTopicSubscriptionsRequest,
+    ) -> impl futures::Stream<Item = Result<std::string::String, tonic::Status>> {
+        crate::paging::items(self.list_topic_subscriptions_pages(request))
+    }
+}
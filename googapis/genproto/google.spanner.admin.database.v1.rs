@@ -2029,3 +2029,231 @@ pub mod database_admin_server {
         const NAME: &'static str = "google.spanner.admin.database.v1.DatabaseAdmin";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDatabasesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDatabasesResponse {
+    type Item = Database;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.databases
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListBackupsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListBackupsResponse {
+    type Item = Backup;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.backups
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListDatabaseOperationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListDatabaseOperationsResponse {
+    type Item = super::super::super::super::longrunning::Operation;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.operations
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListBackupOperationsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListBackupOperationsResponse {
+    type Item = super::super::super::super::longrunning::Operation;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.operations
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> database_admin_client::DatabaseAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_databases`, following `next_page_token`."]
+    pub fn list_databases_pages(
+        &self,
+        request: ListDatabasesRequest,
+    ) -> impl futures::Stream<Item = Result<ListDatabasesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_databases(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_databases`."]
+    pub fn list_databases_stream(
+        &self,
+        request: ListDatabasesRequest,
+    ) -> impl futures::Stream<Item = Result<Database, tonic::Status>> {
+        crate::paging::items(self.list_databases_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_backups`, following `next_page_token`."]
+    pub fn list_backups_pages(
+        &self,
+        request: ListBackupsRequest,
+    ) -> impl futures::Stream<Item = Result<ListBackupsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_backups(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_backups`."]
+    pub fn list_backups_stream(
+        &self,
+        request: ListBackupsRequest,
+    ) -> impl futures::Stream<Item = Result<Backup, tonic::Status>> {
+        crate::paging::items(self.list_backups_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_database_operations`, following `next_page_token`."]
+    pub fn list_database_operations_pages(
+        &self,
+        request: ListDatabaseOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListDatabaseOperationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_database_operations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_database_operations`."]
+    pub fn list_database_operations_stream(
+        &self,
+        request: ListDatabaseOperationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<super::super::super::super::longrunning::Operation, tonic::Status>,
+    > {
+        crate::paging::items(self.list_database_operations_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_backup_operations`, following `next_page_token`."]
+    pub fn list_backup_operations_pages(
+        &self,
+        request: ListBackupOperationsRequest,
+    ) -> impl futures::Stream<Item = Result<ListBackupOperationsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_backup_operations(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_backup_operations`."]
+    pub fn list_backup_operations_stream(
+        &self,
+        request: ListBackupOperationsRequest,
+    ) -> impl futures::Stream<
+        Item = Result<super::super::super::super::longrunning::Operation, tonic::Status>,
+    > {
+        crate::paging::items(self.list_backup_operations_pages(request))
+    }
+}
+#[cfg(feature = "longrunning")]
+impl<T> database_admin_client::DatabaseAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody>,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Calls `create_database` and returns its operation typed by `operation_info`."]
+    pub async fn create_database_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<CreateDatabaseRequest>,
+    ) -> Result<crate::longrunning::Operation<Database, CreateDatabaseMetadata>, tonic::Status>
+    {
+        let response = self.create_database(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.spanner.admin.database.v1.Database",
+            "type.googleapis.com/google.spanner.admin.database.v1.CreateDatabaseMetadata",
+        ))
+    }
+    #[doc = r" Calls `update_database_ddl` and returns its operation typed by `operation_info`."]
+    pub async fn update_database_ddl_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<UpdateDatabaseDdlRequest>,
+    ) -> Result<crate::longrunning::Operation<(), UpdateDatabaseDdlMetadata>, tonic::Status> {
+        let response = self.update_database_ddl(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.protobuf.Empty",
+            "type.googleapis.com/google.spanner.admin.database.v1.UpdateDatabaseDdlMetadata",
+        ))
+    }
+    #[doc = r" Calls `create_backup` and returns its operation typed by `operation_info`."]
+    pub async fn create_backup_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<CreateBackupRequest>,
+    ) -> Result<crate::longrunning::Operation<Backup, CreateBackupMetadata>, tonic::Status> {
+        let response = self.create_backup(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.spanner.admin.database.v1.Backup",
+            "type.googleapis.com/google.spanner.admin.database.v1.CreateBackupMetadata",
+        ))
+    }
+    #[doc = r" Calls `restore_database` and returns its operation typed by `operation_info`."]
+    pub async fn restore_database_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<RestoreDatabaseRequest>,
+    ) -> Result<crate::longrunning::Operation<Database, RestoreDatabaseMetadata>, tonic::Status>
+    {
+        let response = self.restore_database(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.spanner.admin.database.v1.Database",
+            "type.googleapis.com/google.spanner.admin.database.v1.RestoreDatabaseMetadata",
+        ))
+    }
+}
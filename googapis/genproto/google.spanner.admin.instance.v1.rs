@@ -1278,3 +1278,125 @@ pub mod instance_admin_server {
         const NAME: &'static str = "google.spanner.admin.instance.v1.InstanceAdmin";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListInstanceConfigsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListInstanceConfigsResponse {
+    type Item = InstanceConfig;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.instance_configs
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListInstancesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListInstancesResponse {
+    type Item = Instance;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.instances
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> instance_admin_client::InstanceAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_instance_configs`, following `next_page_token`."]
+    pub fn list_instance_configs_pages(
+        &self,
+        request: ListInstanceConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<ListInstanceConfigsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_instance_configs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_instance_configs`."]
+    pub fn list_instance_configs_stream(
+        &self,
+        request: ListInstanceConfigsRequest,
+    ) -> impl futures::Stream<Item = Result<InstanceConfig, tonic::Status>> {
+        crate::paging::items(self.list_instance_configs_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_instances`, following `next_page_token`."]
+    pub fn list_instances_pages(
+        &self,
+        request: ListInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<ListInstancesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_instances(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_instances`."]
+    pub fn list_instances_stream(
+        &self,
+        request: ListInstancesRequest,
+    ) -> impl futures::Stream<Item = Result<Instance, tonic::Status>> {
+        crate::paging::items(self.list_instances_pages(request))
+    }
+}
+#[cfg(feature = "longrunning")]
+impl<T> instance_admin_client::InstanceAdminClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody>,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Calls `create_instance` and returns its operation typed by `operation_info`."]
+    pub async fn create_instance_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<CreateInstanceRequest>,
+    ) -> Result<crate::longrunning::Operation<Instance, CreateInstanceMetadata>, tonic::Status>
+    {
+        let response = self.create_instance(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.spanner.admin.instance.v1.Instance",
+            "type.googleapis.com/google.spanner.admin.instance.v1.CreateInstanceMetadata",
+        ))
+    }
+    #[doc = r" Calls `update_instance` and returns its operation typed by `operation_info`."]
+    pub async fn update_instance_operation(
+        &mut self,
+        request: impl tonic::IntoRequest<UpdateInstanceRequest>,
+    ) -> Result<crate::longrunning::Operation<Instance, UpdateInstanceMetadata>, tonic::Status>
+    {
+        let response = self.update_instance(request).await?;
+        Ok(crate::longrunning::Operation::with_types(
+            response.into_inner(),
+            "type.googleapis.com/google.spanner.admin.instance.v1.Instance",
+            "type.googleapis.com/google.spanner.admin.instance.v1.UpdateInstanceMetadata",
+        ))
+    }
+}
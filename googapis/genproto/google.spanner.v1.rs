@@ -2785,3 +2785,51 @@ pub mod spanner_server {
         const NAME: &'static str = "google.spanner.v1.Spanner";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListSessionsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListSessionsResponse {
+    type Item = Session;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.sessions
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> spanner_client::SpannerClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_sessions`, following `next_page_token`."]
+    pub fn list_sessions_pages(
+        &self,
+        request: ListSessionsRequest,
+    ) -> impl futures::Stream<Item = Result<ListSessionsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_sessions(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_sessions`."]
+    pub fn list_sessions_stream(
+        &self,
+        request: ListSessionsRequest,
+    ) -> impl futures::Stream<Item = Result<Session, tonic::Status>> {
+        crate::paging::items(self.list_sessions_pages(request))
+    }
+}
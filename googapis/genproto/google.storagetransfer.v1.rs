@@ -1074,3 +1074,51 @@ pub mod storage_transfer_service_server {
         const NAME: &'static str = "google.storagetransfer.v1.StorageTransferService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListTransferJobsRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListTransferJobsResponse {
+    type Item = TransferJob;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.transfer_jobs
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> storage_transfer_service_client::StorageTransferServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_transfer_jobs`, following `next_page_token`."]
+    pub fn list_transfer_jobs_pages(
+        &self,
+        request: ListTransferJobsRequest,
+    ) -> impl futures::Stream<Item = Result<ListTransferJobsResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_transfer_jobs(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_transfer_jobs`."]
+    pub fn list_transfer_jobs_stream(
+        &self,
+        request: ListTransferJobsRequest,
+    ) -> impl futures::Stream<Item = Result<TransferJob, tonic::Status>> {
+        crate::paging::items(self.list_transfer_jobs_pages(request))
+    }
+}
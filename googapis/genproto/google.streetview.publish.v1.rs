@@ -1324,3 +1324,51 @@ pub mod street_view_publish_service_server {
         const NAME: &'static str = "google.streetview.publish.v1.StreetViewPublishService";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListPhotosRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListPhotosResponse {
+    type Item = Photo;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.photos
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> street_view_publish_service_client::StreetViewPublishServiceClient<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_photos`, following `next_page_token`."]
+    pub fn list_photos_pages(
+        &self,
+        request: ListPhotosRequest,
+    ) -> impl futures::Stream<Item = Result<ListPhotosResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_photos(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_photos`."]
+    pub fn list_photos_stream(
+        &self,
+        request: ListPhotosRequest,
+    ) -> impl futures::Stream<Item = Result<Photo, tonic::Status>> {
+        crate::paging::items(self.list_photos_pages(request))
+    }
+}
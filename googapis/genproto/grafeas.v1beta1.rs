@@ -1456,3 +1456,129 @@ pub mod grafeas_v1_beta1_server {
         const NAME: &'static str = "grafeas.v1beta1.GrafeasV1Beta1";
     }
 }
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListOccurrencesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListOccurrencesResponse {
+    type Item = Occurrence;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.occurrences
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListNotesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListNotesResponse {
+    type Item = Note;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.notes
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for ListNoteOccurrencesRequest {
+    fn set_page_token(&mut self, page_token: std::string::String) {
+        self.page_token = page_token;
+    }
+}
+#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for ListNoteOccurrencesResponse {
+    type Item = Occurrence;
+    fn next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {
+        self.occurrences
+    }
+}
+#[cfg(feature = "paging")]
+impl<T> grafeas_v1_beta1_client::GrafeasV1Beta1Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    #[doc = r" Returns a stream of the pages of `list_occurrences`, following `next_page_token`."]
+    pub fn list_occurrences_pages(
+        &self,
+        request: ListOccurrencesRequest,
+    ) -> impl futures::Stream<Item = Result<ListOccurrencesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_occurrences(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_occurrences`."]
+    pub fn list_occurrences_stream(
+        &self,
+        request: ListOccurrencesRequest,
+    ) -> impl futures::Stream<Item = Result<Occurrence, tonic::Status>> {
+        crate::paging::items(self.list_occurrences_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_notes`, following `next_page_token`."]
+    pub fn list_notes_pages(
+        &self,
+        request: ListNotesRequest,
+    ) -> impl futures::Stream<Item = Result<ListNotesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_notes(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_notes`."]
+    pub fn list_notes_stream(
+        &self,
+        request: ListNotesRequest,
+    ) -> impl futures::Stream<Item = Result<Note, tonic::Status>> {
+        crate::paging::items(self.list_notes_pages(request))
+    }
+    #[doc = r" Returns a stream of the pages of `list_note_occurrences`, following `next_page_token`."]
+    pub fn list_note_occurrences_pages(
+        &self,
+        request: ListNoteOccurrencesRequest,
+    ) -> impl futures::Stream<Item = Result<ListNoteOccurrencesResponse, tonic::Status>> {
+        let client = self.clone();
+        crate::paging::pages(request, move |req| {
+            let mut client = client.clone();
+            async move {
+                client
+                    .list_note_occurrences(req)
+                    .await
+                    .map(tonic::Response::into_inner)
+            }
+        })
+    }
+    #[doc = r" Returns a stream of the items of all pages of `list_note_occurrences`."]
+    pub fn list_note_occurrences_stream(
+        &self,
+        request: ListNoteOccurrencesRequest,
+    ) -> impl futures::Stream<Item = Result<Occurrence, tonic::Status>> {
+        crate::paging::items(self.list_note_occurrences_pages(request))
+    }
+}
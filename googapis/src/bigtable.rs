@@ -0,0 +1,102 @@
+//! High-level Bigtable clients on top of [`google.bigtable.v2`].
+//!
+//! The `bigtable` feature provides a [`read_rows::ReadRowsStream`] that merges the cell chunks
+//! streamed by `ReadRows` into [`Row`]s, and resumes interrupted reads after the last row it
+//! received. The rows are selected with the ranges of [`row_set`] and the filters of [`filter`],
+//! which are validated before the request is sent.
+//!
+//! It also provides a [`mutate_rows::BulkMutator`] that applies mutations in batches with
+//! `MutateRows`, and retries the idempotent entries that failed.
+//!
+//! The `bigtable-admin` feature provides builders of the garbage collection rules of column
+//! families in [`gc_rule`], and a reconciler of the column families of tables in [`schema`].
+//!
+//! Reading rows:
+//!
+//! ```ignore
+//! use futures::TryStreamExt;
+//! use googapis::{
+//!     bigtable::{filter, read_rows::ReadRowsStream, row_set},
+//!     google::bigtable::v2::{bigtable_client::BigtableClient, ReadRowsRequest},
+//! };
+//!
+//! let mut rows = ReadRowsStream::read_rows(
+//!     BigtableClient::new(channel),
+//!     ReadRowsRequest {
+//!         table_name: "projects/my-project/instances/my-instance/tables/my-table".into(),
+//!         rows: Some(row_set::RowSetBuilder::new().prefix("user#").build()),
+//!         filter: Some(filter::chain(vec![filter::family("profile"), filter::latest(1)])),
+//!         ..Default::default()
+//!     },
+//! );
+//! while let Some(row) = rows.try_next().await? {
+//!     println!("{:?}", row.key);
+//! }
+//! ```
+//!
+//! [`Row`]: crate::google::bigtable::v2::Row
+//! [`google.bigtable.v2`]: https://github.com/googleapis/googleapis/tree/master/google/bigtable/v2
+
+use std::{error, fmt, ops::Bound};
+
+#[cfg(test)]
+mod fake;
+pub mod filter;
+#[cfg(feature = "bigtable-admin")]
+pub mod gc_rule;
+pub mod mutate_rows;
+pub mod read_rows;
+pub mod row_set;
+#[cfg(feature = "bigtable-admin")]
+pub mod schema;
+
+pub use crate::flow_control::{FlowControlSettings, LimitExceededBehavior};
+
+/// An invalid field of a request, found before the request is sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    path: String,
+    message: String,
+}
+
+impl ValidationError {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Returns the path of the invalid field, e.g. `filter.chain.filters[1]`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl error::Error for ValidationError {}
+
+// Returns whether no value is between `start` and `end`, where an unbounded start is the empty
+// value.
+fn is_empty_range(start: Bound<&[u8]>, end: Bound<&[u8]>) -> bool {
+    let start = match start {
+        Bound::Unbounded => Bound::Included(&[][..]),
+        start => start,
+    };
+    match (start, end) {
+        (_, Bound::Unbounded) | (Bound::Unbounded, _) => false,
+        (Bound::Included(s), Bound::Included(e)) => s > e,
+        (Bound::Included(s), Bound::Excluded(e))
+        | (Bound::Excluded(s), Bound::Included(e))
+        | (Bound::Excluded(s), Bound::Excluded(e)) => s >= e,
+    }
+}
@@ -0,0 +1,397 @@
+// In-memory fakes of the Bigtable services for the tests of the `bigtable` module.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use tonic::{Code, Request, Response, Status};
+
+use crate::google::bigtable::v2::{
+    bigtable_client::BigtableClient, bigtable_server, mutate_rows_response,
+    CheckAndMutateRowRequest, CheckAndMutateRowResponse, MutateRowRequest, MutateRowResponse,
+    MutateRowsRequest, MutateRowsResponse, ReadModifyWriteRowRequest, ReadModifyWriteRowResponse,
+    ReadRowsRequest, ReadRowsResponse, SampleRowKeysRequest, SampleRowKeysResponse,
+};
+use crate::google::rpc::Status as RpcStatus;
+
+type ResponseStream<T> = tokio::stream::Iter<std::vec::IntoIter<Result<T, Status>>>;
+
+pub(crate) type FakeServer = bigtable_server::BigtableServer<Arc<FakeBigtable>>;
+
+#[derive(Default)]
+struct State {
+    requests: Vec<MutateRowsRequest>,
+    // The codes of the next attempts of a row key, which succeed once they are used up.
+    failures: HashMap<Vec<u8>, VecDeque<Code>>,
+    // The codes of the next calls, which fail before applying any entry.
+    call_failures: VecDeque<Code>,
+}
+
+#[derive(Default)]
+pub(crate) struct FakeBigtable {
+    state: Mutex<State>,
+}
+
+impl FakeBigtable {
+    pub(crate) fn client(self: &Arc<Self>) -> BigtableClient<FakeServer> {
+        BigtableClient::new(bigtable_server::BigtableServer::new(self.clone()))
+    }
+
+    // The requests of the calls of `mutate_rows`.
+    pub(crate) fn requests(&self) -> Vec<MutateRowsRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    // The row keys of the entries of each call of `mutate_rows`.
+    pub(crate) fn row_keys(&self) -> Vec<Vec<String>> {
+        let state = self.state.lock().unwrap();
+        state
+            .requests
+            .iter()
+            .map(|r| {
+                r.entries
+                    .iter()
+                    .map(|e| String::from_utf8(e.row_key.clone()).unwrap())
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Fails the next attempts of the entries of `row_key` with `codes`.
+    pub(crate) fn fail(&self, row_key: &str, codes: Vec<Code>) {
+        let mut state = self.state.lock().unwrap();
+        state.failures.insert(row_key.into(), codes.into());
+    }
+
+    // Fails the next call of `mutate_rows` with `code`, before applying any entry.
+    pub(crate) fn fail_call(&self, code: Code) {
+        self.state.lock().unwrap().call_failures.push_back(code);
+    }
+}
+
+fn unimplemented<T>() -> Result<Response<T>, Status> {
+    Err(Status::unimplemented("not implemented by the fake"))
+}
+
+#[tonic::async_trait]
+impl bigtable_server::Bigtable for Arc<FakeBigtable> {
+    type ReadRowsStream = ResponseStream<ReadRowsResponse>;
+
+    async fn read_rows(
+        &self,
+        _: Request<ReadRowsRequest>,
+    ) -> Result<Response<Self::ReadRowsStream>, Status> {
+        unimplemented()
+    }
+
+    type SampleRowKeysStream = ResponseStream<SampleRowKeysResponse>;
+
+    async fn sample_row_keys(
+        &self,
+        _: Request<SampleRowKeysRequest>,
+    ) -> Result<Response<Self::SampleRowKeysStream>, Status> {
+        unimplemented()
+    }
+
+    async fn mutate_row(
+        &self,
+        _: Request<MutateRowRequest>,
+    ) -> Result<Response<MutateRowResponse>, Status> {
+        unimplemented()
+    }
+
+    type MutateRowsStream = ResponseStream<MutateRowsResponse>;
+
+    // Applies the entries, except the ones of the row keys given to `fail`.
+    async fn mutate_rows(
+        &self,
+        request: Request<MutateRowsRequest>,
+    ) -> Result<Response<Self::MutateRowsStream>, Status> {
+        let request = request.into_inner();
+        let mut state = self.state.lock().unwrap();
+        state.requests.push(request.clone());
+        if let Some(code) = state.call_failures.pop_front() {
+            return Err(Status::new(code, "call failure"));
+        }
+        let entries = request
+            .entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let code = state
+                    .failures
+                    .get_mut(&entry.row_key)
+                    .and_then(|codes| codes.pop_front())
+                    .unwrap_or(Code::Ok);
+                mutate_rows_response::Entry {
+                    index: index as i64,
+                    status: Some(RpcStatus {
+                        code: code as i32,
+                        message: format!("{:?}", code),
+                        details: vec![],
+                    }),
+                }
+            })
+            .collect();
+        let responses = vec![Ok(MutateRowsResponse { entries })];
+        Ok(Response::new(tokio::stream::iter(responses)))
+    }
+
+    async fn check_and_mutate_row(
+        &self,
+        _: Request<CheckAndMutateRowRequest>,
+    ) -> Result<Response<CheckAndMutateRowResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn read_modify_write_row(
+        &self,
+        _: Request<ReadModifyWriteRowRequest>,
+    ) -> Result<Response<ReadModifyWriteRowResponse>, Status> {
+        unimplemented()
+    }
+}
+
+#[cfg(feature = "bigtable-admin")]
+pub(crate) use self::admin::FakeTableAdmin;
+
+#[cfg(feature = "bigtable-admin")]
+mod admin {
+    use std::sync::{Arc, Mutex};
+
+    use tonic::{Request, Response, Status};
+
+    use super::unimplemented;
+    use crate::google::{
+        bigtable::admin::v2::{
+            bigtable_table_admin_client::BigtableTableAdminClient, bigtable_table_admin_server,
+            modify_column_families_request::modification::Mod, Backup, CheckConsistencyRequest,
+            CheckConsistencyResponse, CreateBackupRequest, CreateTableFromSnapshotRequest,
+            CreateTableRequest, DeleteBackupRequest, DeleteSnapshotRequest, DeleteTableRequest,
+            DropRowRangeRequest, GenerateConsistencyTokenRequest, GenerateConsistencyTokenResponse,
+            GetBackupRequest, GetSnapshotRequest, GetTableRequest, ListBackupsRequest,
+            ListBackupsResponse, ListSnapshotsRequest, ListSnapshotsResponse, ListTablesRequest,
+            ListTablesResponse, ModifyColumnFamiliesRequest, RestoreTableRequest, Snapshot,
+            SnapshotTableRequest, Table, UpdateBackupRequest,
+        },
+        iam::v1::{
+            GetIamPolicyRequest, Policy, SetIamPolicyRequest, TestIamPermissionsRequest,
+            TestIamPermissionsResponse,
+        },
+        longrunning::Operation,
+    };
+
+    pub(crate) type FakeTableAdminServer =
+        bigtable_table_admin_server::BigtableTableAdminServer<Arc<FakeTableAdmin>>;
+
+    #[derive(Default)]
+    struct State {
+        table: Table,
+        modifications: Vec<ModifyColumnFamiliesRequest>,
+    }
+
+    // A fake of the table admin service with a single table.
+    #[derive(Default)]
+    pub(crate) struct FakeTableAdmin {
+        state: Mutex<State>,
+    }
+
+    impl FakeTableAdmin {
+        pub(crate) fn client(self: &Arc<Self>) -> BigtableTableAdminClient<FakeTableAdminServer> {
+            BigtableTableAdminClient::new(
+                bigtable_table_admin_server::BigtableTableAdminServer::new(self.clone()),
+            )
+        }
+
+        pub(crate) fn table(&self) -> Table {
+            self.state.lock().unwrap().table.clone()
+        }
+
+        pub(crate) fn set_table(&self, table: Table) {
+            self.state.lock().unwrap().table = table;
+        }
+
+        // The requests of the calls of `modify_column_families`.
+        pub(crate) fn modifications(&self) -> Vec<ModifyColumnFamiliesRequest> {
+            self.state.lock().unwrap().modifications.clone()
+        }
+    }
+
+    #[tonic::async_trait]
+    impl bigtable_table_admin_server::BigtableTableAdmin for Arc<FakeTableAdmin> {
+        async fn create_table(
+            &self,
+            _: Request<CreateTableRequest>,
+        ) -> Result<Response<Table>, Status> {
+            unimplemented()
+        }
+
+        async fn create_table_from_snapshot(
+            &self,
+            _: Request<CreateTableFromSnapshotRequest>,
+        ) -> Result<Response<Operation>, Status> {
+            unimplemented()
+        }
+
+        async fn list_tables(
+            &self,
+            _: Request<ListTablesRequest>,
+        ) -> Result<Response<ListTablesResponse>, Status> {
+            unimplemented()
+        }
+
+        async fn get_table(
+            &self,
+            request: Request<GetTableRequest>,
+        ) -> Result<Response<Table>, Status> {
+            let state = self.state.lock().unwrap();
+            if request.get_ref().name != state.table.name {
+                return Err(Status::not_found("table not found"));
+            }
+            Ok(Response::new(state.table.clone()))
+        }
+
+        async fn delete_table(
+            &self,
+            _: Request<DeleteTableRequest>,
+        ) -> Result<Response<()>, Status> {
+            unimplemented()
+        }
+
+        // Applies the modifications to the table.
+        async fn modify_column_families(
+            &self,
+            request: Request<ModifyColumnFamiliesRequest>,
+        ) -> Result<Response<Table>, Status> {
+            let request = request.into_inner();
+            let mut state = self.state.lock().unwrap();
+            for modification in &request.modifications {
+                let id = modification.id.clone();
+                match modification.r#mod.clone() {
+                    Some(Mod::Create(family)) | Some(Mod::Update(family)) => {
+                        state.table.column_families.insert(id, family);
+                    }
+                    Some(Mod::Drop(_)) => {
+                        state.table.column_families.remove(&id);
+                    }
+                    None => return Err(Status::invalid_argument("missing mod")),
+                }
+            }
+            state.modifications.push(request);
+            Ok(Response::new(state.table.clone()))
+        }
+
+        async fn drop_row_range(
+            &self,
+            _: Request<DropRowRangeRequest>,
+        ) -> Result<Response<()>, Status> {
+            unimplemented()
+        }
+
+        async fn generate_consistency_token(
+            &self,
+            _: Request<GenerateConsistencyTokenRequest>,
+        ) -> Result<Response<GenerateConsistencyTokenResponse>, Status> {
+            unimplemented()
+        }
+
+        async fn check_consistency(
+            &self,
+            _: Request<CheckConsistencyRequest>,
+        ) -> Result<Response<CheckConsistencyResponse>, Status> {
+            unimplemented()
+        }
+
+        async fn snapshot_table(
+            &self,
+            _: Request<SnapshotTableRequest>,
+        ) -> Result<Response<Operation>, Status> {
+            unimplemented()
+        }
+
+        async fn get_snapshot(
+            &self,
+            _: Request<GetSnapshotRequest>,
+        ) -> Result<Response<Snapshot>, Status> {
+            unimplemented()
+        }
+
+        async fn list_snapshots(
+            &self,
+            _: Request<ListSnapshotsRequest>,
+        ) -> Result<Response<ListSnapshotsResponse>, Status> {
+            unimplemented()
+        }
+
+        async fn delete_snapshot(
+            &self,
+            _: Request<DeleteSnapshotRequest>,
+        ) -> Result<Response<()>, Status> {
+            unimplemented()
+        }
+
+        async fn create_backup(
+            &self,
+            _: Request<CreateBackupRequest>,
+        ) -> Result<Response<Operation>, Status> {
+            unimplemented()
+        }
+
+        async fn get_backup(
+            &self,
+            _: Request<GetBackupRequest>,
+        ) -> Result<Response<Backup>, Status> {
+            unimplemented()
+        }
+
+        async fn update_backup(
+            &self,
+            _: Request<UpdateBackupRequest>,
+        ) -> Result<Response<Backup>, Status> {
+            unimplemented()
+        }
+
+        async fn delete_backup(
+            &self,
+            _: Request<DeleteBackupRequest>,
+        ) -> Result<Response<()>, Status> {
+            unimplemented()
+        }
+
+        async fn list_backups(
+            &self,
+            _: Request<ListBackupsRequest>,
+        ) -> Result<Response<ListBackupsResponse>, Status> {
+            unimplemented()
+        }
+
+        async fn restore_table(
+            &self,
+            _: Request<RestoreTableRequest>,
+        ) -> Result<Response<Operation>, Status> {
+            unimplemented()
+        }
+
+        async fn get_iam_policy(
+            &self,
+            _: Request<GetIamPolicyRequest>,
+        ) -> Result<Response<Policy>, Status> {
+            unimplemented()
+        }
+
+        async fn set_iam_policy(
+            &self,
+            _: Request<SetIamPolicyRequest>,
+        ) -> Result<Response<Policy>, Status> {
+            unimplemented()
+        }
+
+        async fn test_iam_permissions(
+            &self,
+            _: Request<TestIamPermissionsRequest>,
+        ) -> Result<Response<TestIamPermissionsResponse>, Status> {
+            unimplemented()
+        }
+    }
+}
@@ -0,0 +1,537 @@
+//! Builders of the filters of reads and conditional mutations.
+//!
+//! A `RowFilter` is either a primitive filter, which selects or transforms the cells of a row, or
+//! a combination of filters: a [`chain`] applies filters in sequence, an [`interleave`] unions
+//! their results, and a [`condition`] applies one filter or another depending on whether a
+//! predicate filter matches any cell of the row:
+//!
+//! ```ignore
+//! use std::ops::Bound;
+//! use googapis::bigtable::filter;
+//!
+//! // The latest value of the columns "a" to "f" of the family "stats", and the rows that have a
+//! // cell in the family "flags" are labelled.
+//! let filter = filter::chain(vec![
+//!     filter::interleave(vec![
+//!         filter::column_range("stats", Bound::Included("a"), Bound::Excluded("g")),
+//!         filter::condition(filter::family("flags"), filter::label("flagged"), None),
+//!     ]),
+//!     filter::latest(1),
+//! ]);
+//! filter::validate(&filter)?;
+//! ```
+//!
+//! Regular expressions use the [RE2 syntax](https://github.com/google/re2/wiki/Syntax) in raw
+//! byte mode, and must match the whole row key, family, qualifier or value. [`family`],
+//! [`qualifier`] and [`value`] match literal values with escaped expressions.
+
+use std::ops::{Bound, RangeBounds};
+
+use super::{is_empty_range, ValidationError};
+use crate::google::bigtable::v2::{
+    column_range::{EndQualifier, StartQualifier},
+    row_filter::{Chain, Condition, Filter, Interleave},
+    value_range::{EndValue, StartValue},
+    ColumnRange, RowFilter, TimestampRange, ValueRange,
+};
+
+// The maximum length of a label.
+const MAX_LABEL_LENGTH: usize = 15;
+
+fn filter(filter: Filter) -> RowFilter {
+    RowFilter {
+        filter: Some(filter),
+    }
+}
+
+/// Matches all cells.
+pub fn pass_all() -> RowFilter {
+    filter(Filter::PassAllFilter(true))
+}
+
+/// Matches no cell.
+pub fn block_all() -> RowFilter {
+    filter(Filter::BlockAllFilter(true))
+}
+
+/// Outputs the cells that reach it directly, without applying the filters after it in a chain.
+///
+/// It cannot be used within a condition.
+pub fn sink() -> RowFilter {
+    filter(Filter::Sink(true))
+}
+
+/// Matches the cells of the rows whose key matches `regex`.
+pub fn row_key_regex(regex: impl Into<Vec<u8>>) -> RowFilter {
+    filter(Filter::RowKeyRegexFilter(regex.into()))
+}
+
+/// Matches all the cells of a random sample of the rows, each row is included with
+/// `probability`, between 0 and 1 exclusive.
+pub fn row_sample(probability: f64) -> RowFilter {
+    filter(Filter::RowSampleFilter(probability))
+}
+
+/// Matches the cells of the families whose name matches `regex`.
+pub fn family_regex(regex: impl Into<String>) -> RowFilter {
+    filter(Filter::FamilyNameRegexFilter(regex.into()))
+}
+
+/// Matches the cells of a family.
+pub fn family(name: &str) -> RowFilter {
+    let regex = escape_regex(name.as_bytes());
+    family_regex(String::from_utf8(regex).expect("escaped UTF-8 is UTF-8"))
+}
+
+/// Matches the cells of the columns whose qualifier matches `regex`, in any family.
+pub fn qualifier_regex(regex: impl Into<Vec<u8>>) -> RowFilter {
+    filter(Filter::ColumnQualifierRegexFilter(regex.into()))
+}
+
+/// Matches the cells of the columns with the qualifier, in any family.
+pub fn qualifier(qualifier: impl AsRef<[u8]>) -> RowFilter {
+    qualifier_regex(escape_regex(qualifier.as_ref()))
+}
+
+/// Matches the cells of the columns of `family` whose qualifier is between `start` and `end`.
+pub fn column_range<S, E>(family: impl Into<String>, start: Bound<S>, end: Bound<E>) -> RowFilter
+where
+    S: Into<Vec<u8>>,
+    E: Into<Vec<u8>>,
+{
+    let start_qualifier = match start {
+        Bound::Included(q) => Some(StartQualifier::StartQualifierClosed(q.into())),
+        Bound::Excluded(q) => Some(StartQualifier::StartQualifierOpen(q.into())),
+        Bound::Unbounded => None,
+    };
+    let end_qualifier = match end {
+        Bound::Included(q) => Some(EndQualifier::EndQualifierClosed(q.into())),
+        Bound::Excluded(q) => Some(EndQualifier::EndQualifierOpen(q.into())),
+        Bound::Unbounded => None,
+    };
+    filter(Filter::ColumnRangeFilter(ColumnRange {
+        family_name: family.into(),
+        start_qualifier,
+        end_qualifier,
+    }))
+}
+
+/// Matches the cells whose timestamp in microseconds is in `range`, e.g. `1_000..2_000` or
+/// `1_000..`.
+///
+/// The timestamps of tables with the default granularity are multiples of 1000.
+pub fn timestamp_range(range: impl RangeBounds<i64>) -> RowFilter {
+    let start_timestamp_micros = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    // Zero is the infinite upper bound.
+    let end_timestamp_micros = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => 0,
+    };
+    filter(Filter::TimestampRangeFilter(TimestampRange {
+        start_timestamp_micros,
+        end_timestamp_micros,
+    }))
+}
+
+/// Matches the cells whose value matches `regex`.
+pub fn value_regex(regex: impl Into<Vec<u8>>) -> RowFilter {
+    filter(Filter::ValueRegexFilter(regex.into()))
+}
+
+/// Matches the cells whose value is `value`.
+pub fn value(value: impl AsRef<[u8]>) -> RowFilter {
+    value_regex(escape_regex(value.as_ref()))
+}
+
+/// Matches the cells whose value is between `start` and `end`.
+pub fn value_range<S, E>(start: Bound<S>, end: Bound<E>) -> RowFilter
+where
+    S: Into<Vec<u8>>,
+    E: Into<Vec<u8>>,
+{
+    let start_value = match start {
+        Bound::Included(v) => Some(StartValue::StartValueClosed(v.into())),
+        Bound::Excluded(v) => Some(StartValue::StartValueOpen(v.into())),
+        Bound::Unbounded => None,
+    };
+    let end_value = match end {
+        Bound::Included(v) => Some(EndValue::EndValueClosed(v.into())),
+        Bound::Excluded(v) => Some(EndValue::EndValueOpen(v.into())),
+        Bound::Unbounded => None,
+    };
+    filter(Filter::ValueRangeFilter(ValueRange {
+        start_value,
+        end_value,
+    }))
+}
+
+/// Skips the first `offset` cells of each row.
+pub fn cells_per_row_offset(offset: i32) -> RowFilter {
+    filter(Filter::CellsPerRowOffsetFilter(offset))
+}
+
+/// Matches the first `limit` cells of each row.
+pub fn cells_per_row_limit(limit: i32) -> RowFilter {
+    filter(Filter::CellsPerRowLimitFilter(limit))
+}
+
+/// Matches the latest `n` cells of each column.
+pub fn latest(n: i32) -> RowFilter {
+    filter(Filter::CellsPerColumnLimitFilter(n))
+}
+
+/// Replaces the values of the cells with empty values.
+pub fn strip_value() -> RowFilter {
+    filter(Filter::StripValueTransformer(true))
+}
+
+/// Labels the cells with `label`, which is at most 15 characters of `a-z`, `0-9` and `-`.
+///
+/// A cell can only have one label, so only one filter of a chain can add labels.
+pub fn label(label: impl Into<String>) -> RowFilter {
+    filter(Filter::ApplyLabelTransformer(label.into()))
+}
+
+/// Applies `filters` in sequence, each to the output of the previous one.
+///
+/// Chains in `filters` are flattened, and a chain of a single filter is that filter.
+pub fn chain<I: IntoIterator<Item = RowFilter>>(filters: I) -> RowFilter {
+    let mut flattened = Vec::new();
+    for f in filters {
+        match f.filter {
+            Some(Filter::Chain(chain)) => flattened.extend(chain.filters),
+            _ => flattened.push(f),
+        }
+    }
+    if flattened.len() == 1 {
+        return flattened.pop().unwrap();
+    }
+    filter(Filter::Chain(Chain { filters: flattened }))
+}
+
+/// Applies `filters` to the row and merges their outputs, which may contain the same cell twice.
+///
+/// Interleaves in `filters` are flattened, and an interleave of a single filter is that filter.
+pub fn interleave<I: IntoIterator<Item = RowFilter>>(filters: I) -> RowFilter {
+    let mut flattened = Vec::new();
+    for f in filters {
+        match f.filter {
+            Some(Filter::Interleave(interleave)) => flattened.extend(interleave.filters),
+            _ => flattened.push(f),
+        }
+    }
+    if flattened.len() == 1 {
+        return flattened.pop().unwrap();
+    }
+    filter(Filter::Interleave(Interleave { filters: flattened }))
+}
+
+/// Applies `true_filter` to the rows where `predicate` matches a cell, and `false_filter` to the
+/// others. The rows are dropped when the filter that applies is `None`.
+///
+/// The predicate is not evaluated atomically with the rest of the read.
+pub fn condition(
+    predicate: RowFilter,
+    true_filter: impl Into<Option<RowFilter>>,
+    false_filter: impl Into<Option<RowFilter>>,
+) -> RowFilter {
+    filter(Filter::Condition(Box::new(Condition {
+        predicate_filter: Some(Box::new(predicate)),
+        true_filter: true_filter.into().map(Box::new),
+        false_filter: false_filter.into().map(Box::new),
+    })))
+}
+
+/// Returns a regular expression matching `literal`.
+pub fn escape_regex(literal: &[u8]) -> Vec<u8> {
+    let mut regex = Vec::with_capacity(literal.len());
+    for &b in literal {
+        if b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80 {
+            regex.push(b);
+        } else if b == 0 {
+            regex.extend_from_slice(b"\\x00");
+        } else {
+            regex.push(b'\\');
+            regex.push(b);
+        }
+    }
+    regex
+}
+
+/// Checks the combinations and arguments of filters that are rejected by the server.
+pub fn validate(filter: &RowFilter) -> Result<(), ValidationError> {
+    validate_at(filter, "filter", false)
+}
+
+fn validate_at(filter: &RowFilter, path: &str, in_condition: bool) -> Result<(), ValidationError> {
+    let error = |message: &str| Err(ValidationError::new(path, message));
+    let filter = match &filter.filter {
+        Some(filter) => filter,
+        None => return error("missing filter"),
+    };
+    match filter {
+        Filter::Chain(chain) => {
+            let mut labelled = 0;
+            for (i, f) in chain.filters.iter().enumerate() {
+                validate_at(f, &format!("{}.chain.filters[{}]", path, i), in_condition)?;
+                labelled += has_label(f) as usize;
+            }
+            if labelled > 1 {
+                return error("more than one filter of the chain adds labels");
+            }
+        }
+        Filter::Interleave(interleave) => {
+            for (i, f) in interleave.filters.iter().enumerate() {
+                validate_at(
+                    f,
+                    &format!("{}.interleave.filters[{}]", path, i),
+                    in_condition,
+                )?;
+            }
+        }
+        Filter::Condition(condition) => {
+            let predicate = match &condition.predicate_filter {
+                Some(predicate) => predicate,
+                None => return error("missing predicate of the condition"),
+            };
+            validate_at(
+                predicate,
+                &format!("{}.condition.predicate_filter", path),
+                true,
+            )?;
+            if let Some(f) = &condition.true_filter {
+                validate_at(f, &format!("{}.condition.true_filter", path), true)?;
+            }
+            if let Some(f) = &condition.false_filter {
+                validate_at(f, &format!("{}.condition.false_filter", path), true)?;
+            }
+        }
+        Filter::Sink(true) if in_condition => return error("sink within a condition"),
+        Filter::Sink(false)
+        | Filter::PassAllFilter(false)
+        | Filter::BlockAllFilter(false)
+        | Filter::StripValueTransformer(false) => return error("flag filter set to false"),
+        Filter::RowSampleFilter(p) if !(*p > 0.0 && *p < 1.0) => {
+            return error("row sample probability must be between 0 and 1 exclusive")
+        }
+        Filter::FamilyNameRegexFilter(regex) if regex.contains(':') => {
+            return error("family regex must not contain ':'")
+        }
+        Filter::ColumnRangeFilter(range) => {
+            if range.family_name.is_empty() {
+                return error("missing family of the column range");
+            }
+            let start = match &range.start_qualifier {
+                Some(StartQualifier::StartQualifierClosed(q)) => Bound::Included(&q[..]),
+                Some(StartQualifier::StartQualifierOpen(q)) => Bound::Excluded(&q[..]),
+                None => Bound::Unbounded,
+            };
+            let end = match &range.end_qualifier {
+                Some(EndQualifier::EndQualifierClosed(q)) => Bound::Included(&q[..]),
+                Some(EndQualifier::EndQualifierOpen(q)) => Bound::Excluded(&q[..]),
+                None => Bound::Unbounded,
+            };
+            if is_empty_range(start, end) {
+                return error("empty column range");
+            }
+        }
+        Filter::TimestampRangeFilter(range) => {
+            if range.start_timestamp_micros < 0 || range.end_timestamp_micros < 0 {
+                return error("negative timestamp");
+            }
+            if range.end_timestamp_micros != 0
+                && range.end_timestamp_micros <= range.start_timestamp_micros
+            {
+                return error("empty timestamp range");
+            }
+        }
+        Filter::ValueRangeFilter(range) => {
+            let start = match &range.start_value {
+                Some(StartValue::StartValueClosed(v)) => Bound::Included(&v[..]),
+                Some(StartValue::StartValueOpen(v)) => Bound::Excluded(&v[..]),
+                None => Bound::Unbounded,
+            };
+            let end = match &range.end_value {
+                Some(EndValue::EndValueClosed(v)) => Bound::Included(&v[..]),
+                Some(EndValue::EndValueOpen(v)) => Bound::Excluded(&v[..]),
+                None => Bound::Unbounded,
+            };
+            if is_empty_range(start, end) {
+                return error("empty value range");
+            }
+        }
+        Filter::CellsPerRowOffsetFilter(offset) if *offset < 0 => {
+            return error("negative cells per row offset")
+        }
+        Filter::CellsPerRowLimitFilter(limit) | Filter::CellsPerColumnLimitFilter(limit)
+            if *limit <= 0 =>
+        {
+            return error("cell limit must be positive")
+        }
+        Filter::ApplyLabelTransformer(label)
+            if label.is_empty()
+                || label.len() > MAX_LABEL_LENGTH
+                || !label
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-') =>
+        {
+            return error("label must be 1 to 15 characters of a-z, 0-9 and -")
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+// Returns whether `filter` adds labels.
+fn has_label(filter: &RowFilter) -> bool {
+    match &filter.filter {
+        Some(Filter::ApplyLabelTransformer(_)) => true,
+        Some(Filter::Chain(Chain { filters }))
+        | Some(Filter::Interleave(Interleave { filters })) => filters.iter().any(has_label),
+        Some(Filter::Condition(condition)) => {
+            let filters = [&condition.true_filter, &condition.false_filter];
+            filters.iter().filter_map(|f| f.as_deref()).any(has_label)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain() {
+        let filter = chain(vec![
+            family("cf"),
+            chain(vec![qualifier("q"), latest(2)]),
+            chain(vec![strip_value()]),
+        ]);
+        assert_eq!(
+            filter,
+            RowFilter {
+                filter: Some(Filter::Chain(Chain {
+                    filters: vec![family("cf"), qualifier("q"), latest(2), strip_value()],
+                })),
+            }
+        );
+        assert_eq!(chain(vec![pass_all()]), pass_all());
+        assert_eq!(
+            interleave(vec![interleave(vec![sink(), block_all()]), label("a")]),
+            RowFilter {
+                filter: Some(Filter::Interleave(Interleave {
+                    filters: vec![sink(), block_all(), label("a")],
+                })),
+            }
+        );
+        validate(&filter).unwrap();
+    }
+
+    #[test]
+    fn test_ranges() {
+        assert_eq!(
+            column_range("cf", Bound::Included("a"), Bound::<&str>::Unbounded),
+            RowFilter {
+                filter: Some(Filter::ColumnRangeFilter(ColumnRange {
+                    family_name: "cf".into(),
+                    start_qualifier: Some(StartQualifier::StartQualifierClosed(b"a".to_vec())),
+                    end_qualifier: None,
+                })),
+            }
+        );
+        let range = |filter: RowFilter| match filter.filter {
+            Some(Filter::TimestampRangeFilter(range)) => {
+                (range.start_timestamp_micros, range.end_timestamp_micros)
+            }
+            filter => panic!("unexpected filter: {:?}", filter),
+        };
+        assert_eq!(range(timestamp_range(1000..2000)), (1000, 2000));
+        assert_eq!(range(timestamp_range(1000..=1999)), (1000, 2000));
+        assert_eq!(range(timestamp_range(..5000)), (0, 5000));
+        assert_eq!(range(timestamp_range(1000..)), (1000, 0));
+
+        let filter = value_range(Bound::Excluded(vec![1]), Bound::Included(vec![2]));
+        validate(&filter).unwrap();
+    }
+
+    #[test]
+    fn test_escape_regex() {
+        assert_eq!(escape_regex(b"a_b1"), b"a_b1");
+        assert_eq!(escape_regex(b"a.b*c"), b"a\\.b\\*c");
+        assert_eq!(escape_regex(b"\x00\xff"), b"\\x00\xff");
+        assert_eq!(family("my-family"), family_regex("my\\-family"));
+    }
+
+    #[test]
+    fn test_validate() {
+        let cases = vec![
+            (RowFilter::default(), "filter", "missing filter"),
+            (row_sample(1.0), "filter", "row sample"),
+            (row_sample(0.0), "filter", "row sample"),
+            (latest(0), "filter", "cell limit"),
+            (cells_per_row_offset(-1), "filter", "offset"),
+            (label("Upper"), "filter", "label"),
+            (label("a-very-long-label"), "filter", "label"),
+            (family_regex("a:b"), "filter", "':'"),
+            (
+                timestamp_range((Bound::Included(2000), Bound::Excluded(1000))),
+                "filter",
+                "empty timestamp range",
+            ),
+            (
+                column_range("", Bound::<&str>::Unbounded, Bound::<&str>::Unbounded),
+                "filter",
+                "missing family",
+            ),
+            (
+                column_range("cf", Bound::Excluded("b"), Bound::Included("b")),
+                "filter",
+                "empty column range",
+            ),
+            (
+                value_range(Bound::<&str>::Unbounded, Bound::Excluded("")),
+                "filter",
+                "empty value range",
+            ),
+            (
+                chain(vec![family("cf"), interleave(vec![sink(), latest(-1)])]),
+                "filter.chain.filters[1].interleave.filters[1]",
+                "cell limit",
+            ),
+            (
+                chain(vec![label("a"), condition(pass_all(), label("b"), None)]),
+                "filter",
+                "labels",
+            ),
+            (
+                condition(family("cf"), chain(vec![latest(1), sink()]), None),
+                "filter.condition.true_filter.chain.filters[1]",
+                "sink",
+            ),
+            (
+                RowFilter {
+                    filter: Some(Filter::Condition(Box::default())),
+                },
+                "filter",
+                "predicate",
+            ),
+        ];
+        for (filter, path, message) in cases {
+            let error = validate(&filter).unwrap_err();
+            assert_eq!(error.path(), path, "{:?}", filter);
+            assert!(error.message().contains(message), "{}", error);
+        }
+
+        validate(&interleave(vec![label("a"), label("b")])).unwrap();
+        validate(&chain(vec![
+            condition(row_key_regex("a.*"), label("a"), label("b")),
+            sink(),
+        ]))
+        .unwrap();
+    }
+}
@@ -0,0 +1,537 @@
+//! Garbage collection rules of column families.
+//!
+//! The constructors added to [`GcRule`] build the rules of `google.bigtable.admin.v2`, and rules
+//! are formatted and parsed in the syntax of `cbt`:
+//!
+//! - `maxversions=N`, the cells of a column beyond the `N` most recent ones
+//! - `maxage=D`, the cells older than `D`, a number followed by `d`, `h`, `m`, `s`, `ms`, `us`
+//!   or `ns`
+//! - `A && B`, the cells matched by all the rules
+//! - `A || B`, the cells matched by any of the rules, where `&&` and `||` can't be mixed without
+//!   parentheses
+//! - `never`, no cells
+//!
+//! ```ignore
+//! use std::time::Duration;
+//! use googapis::bigtable::gc_rule::GcRule;
+//!
+//! let rule = GcRule::union(vec![
+//!     GcRule::max_age(Duration::from_secs(7 * 24 * 3600)),
+//!     GcRule::max_versions(3),
+//! ]);
+//! assert_eq!(rule.to_string(), "maxage=7d || maxversions=3");
+//! assert_eq!(rule, "maxage=7d || maxversions=3".parse()?);
+//! ```
+
+use std::{convert::TryFrom, error, fmt, str::FromStr, time::Duration};
+
+use crate::google::bigtable::admin::v2::gc_rule::{Intersection, Rule, Union};
+pub use crate::google::bigtable::admin::v2::GcRule;
+
+// The units of durations, from the largest, with their number of nanoseconds.
+const UNITS: &[(&str, i128)] = &[
+    ("d", 86_400_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("m", 60_000_000_000),
+    ("s", 1_000_000_000),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+/// An error parsing a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    position: usize,
+    message: String,
+}
+
+impl Error {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the byte offset of the error in the rule.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl error::Error for Error {}
+
+impl GcRule {
+    /// Returns the rule that collects no cells.
+    pub fn never() -> Self {
+        GcRule { rule: None }
+    }
+
+    /// Returns the rule that collects the cells of a column beyond its `n` most recent ones.
+    pub fn max_versions(n: i32) -> Self {
+        GcRule {
+            rule: Some(Rule::MaxNumVersions(n)),
+        }
+    }
+
+    /// Returns the rule that collects the cells older than `age`.
+    pub fn max_age(age: Duration) -> Self {
+        GcRule {
+            rule: Some(Rule::MaxAge(prost_types::Duration {
+                seconds: age.as_secs() as i64,
+                nanos: age.subsec_nanos() as i32,
+            })),
+        }
+    }
+
+    /// Returns the rule that collects the cells collected by all the `rules`.
+    ///
+    /// Nested intersections are flattened, and a single rule is returned as is.
+    pub fn intersection(rules: impl IntoIterator<Item = GcRule>) -> Self {
+        let mut flat = Vec::new();
+        for rule in rules {
+            match rule.rule {
+                Some(Rule::Intersection(Intersection { rules })) => flat.extend(rules),
+                _ => flat.push(rule),
+            }
+        }
+        match flat.len() {
+            0 => Self::never(),
+            1 => flat.pop().unwrap(),
+            _ => GcRule {
+                rule: Some(Rule::Intersection(Intersection { rules: flat })),
+            },
+        }
+    }
+
+    /// Returns the rule that collects the cells collected by any of the `rules`.
+    ///
+    /// Nested unions are flattened, and a single rule is returned as is.
+    pub fn union(rules: impl IntoIterator<Item = GcRule>) -> Self {
+        let mut flat = Vec::new();
+        for rule in rules {
+            match rule.rule {
+                Some(Rule::Union(Union { rules })) => flat.extend(rules),
+                _ => flat.push(rule),
+            }
+        }
+        match flat.len() {
+            0 => Self::never(),
+            1 => flat.pop().unwrap(),
+            _ => GcRule {
+                rule: Some(Rule::Union(Union { rules: flat })),
+            },
+        }
+    }
+
+    /// Returns the rule with its nested intersections and unions flattened, the ones of a single
+    /// rule replaced by the rule, the empty ones replaced by [`GcRule::never`] and their rules
+    /// sorted by their formatting, so that rules collecting the same cells for these reasons are
+    /// equal.
+    pub fn normalize(&self) -> Self {
+        let mut normalized = match &self.rule {
+            Some(Rule::Intersection(Intersection { rules })) => {
+                Self::intersection(rules.iter().map(GcRule::normalize))
+            }
+            Some(Rule::Union(Union { rules })) => Self::union(rules.iter().map(GcRule::normalize)),
+            _ => return self.clone(),
+        };
+        match &mut normalized.rule {
+            Some(Rule::Intersection(Intersection { rules }))
+            | Some(Rule::Union(Union { rules })) => rules.sort_by_cached_key(GcRule::to_string),
+            _ => {}
+        }
+        normalized
+    }
+}
+
+// Formats the nanoseconds of `duration` with the largest unit that divides them.
+fn format_duration(duration: &prost_types::Duration) -> String {
+    let nanos = i128::from(duration.seconds) * 1_000_000_000 + i128::from(duration.nanos);
+    if nanos == 0 {
+        return "0s".to_owned();
+    }
+    let (unit, per_unit) = UNITS
+        .iter()
+        .find(|(_, per_unit)| nanos % per_unit == 0)
+        .unwrap();
+    format!("{}{}", nanos / per_unit, unit)
+}
+
+/// Formats the rule in the syntax of `cbt`, see the [module documentation](self).
+impl fmt::Display for GcRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rules, operator) = match &self.rule {
+            None => return f.write_str("never"),
+            Some(Rule::MaxNumVersions(n)) => return write!(f, "maxversions={}", n),
+            Some(Rule::MaxAge(age)) => return write!(f, "maxage={}", format_duration(age)),
+            Some(Rule::Intersection(Intersection { rules })) => (rules, " && "),
+            Some(Rule::Union(Union { rules })) => (rules, " || "),
+        };
+        match rules.len() {
+            0 => return f.write_str("never"),
+            1 => return rules[0].fmt(f),
+            _ => {}
+        }
+        for (i, rule) in rules.iter().enumerate() {
+            if i > 0 {
+                f.write_str(operator)?;
+            }
+            match &rule.rule {
+                Some(Rule::Intersection(Intersection { rules }))
+                | Some(Rule::Union(Union { rules }))
+                    if rules.len() > 1 =>
+                {
+                    write!(f, "({})", rule)?
+                }
+                _ => rule.fmt(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a rule in the syntax of `cbt`, see the [module documentation](self).
+impl FromStr for GcRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim() == "never" {
+            return Ok(Self::never());
+        }
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            index: 0,
+            end: s.len(),
+        };
+        let rule = parser.expression()?;
+        match parser.tokens.get(parser.index) {
+            Some((position, token)) => {
+                Err(Error::new(*position, format!("unexpected `{}`", token)))
+            }
+            None => Ok(rule),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    And,
+    Or,
+    LParen,
+    RParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Word(word) => f.write_str(word),
+            Token::And => f.write_str("&&"),
+            Token::Or => f.write_str("||"),
+            Token::LParen => f.write_str("("),
+            Token::RParen => f.write_str(")"),
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<(usize, Token)>, Error> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some(&(position, c)) = chars.peek() {
+        let token = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '&' | '|' => {
+                chars.next();
+                match chars.peek() {
+                    Some(&(_, next)) if next == c => {}
+                    _ => return Err(Error::new(position, format!("expected `{}{}`", c, c))),
+                }
+                if c == '&' {
+                    Token::And
+                } else {
+                    Token::Or
+                }
+            }
+            c if c.is_ascii_alphanumeric() || c == '=' || c == '_' => {
+                let mut word = String::new();
+                while let Some(&(_, c)) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '=' || c == '_') {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push((position, Token::Word(word)));
+                continue;
+            }
+            c => return Err(Error::new(position, format!("unexpected `{}`", c))),
+        };
+        chars.next();
+        tokens.push((position, token));
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    index: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Result<(usize, Token), Error> {
+        let token = self
+            .tokens
+            .get(self.index)
+            .cloned()
+            .ok_or_else(|| Error::new(self.end, "unexpected end of rule"))?;
+        self.index += 1;
+        Ok(token)
+    }
+
+    fn operator(&self) -> Option<(usize, Token)> {
+        match self.tokens.get(self.index) {
+            Some((position, token)) if *token == Token::And || *token == Token::Or => {
+                Some((*position, token.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    // `&&` and `||` can't be mixed without parentheses.
+    fn expression(&mut self) -> Result<GcRule, Error> {
+        let first = self.term()?;
+        let operator = match self.operator() {
+            Some((_, operator)) => operator,
+            None => return Ok(first),
+        };
+        let mut rules = vec![first];
+        while let Some((position, next)) = self.operator() {
+            if next != operator {
+                return Err(Error::new(
+                    position,
+                    format!("`{}` and `{}` need parentheses", operator, next),
+                ));
+            }
+            self.index += 1;
+            rules.push(self.term()?);
+        }
+        Ok(match operator {
+            Token::And => GcRule::intersection(rules),
+            _ => GcRule::union(rules),
+        })
+    }
+
+    fn term(&mut self) -> Result<GcRule, Error> {
+        let (position, token) = self.next()?;
+        let word = match token {
+            Token::LParen => {
+                let rule = self.expression()?;
+                match self.next()? {
+                    (_, Token::RParen) => return Ok(rule),
+                    (position, token) => {
+                        return Err(Error::new(
+                            position,
+                            format!("expected `)`, found `{}`", token),
+                        ))
+                    }
+                }
+            }
+            Token::Word(word) => word,
+            token => return Err(Error::new(position, format!("unexpected `{}`", token))),
+        };
+
+        let value_position = position + word.find('=').map_or(0, |i| i + 1);
+        if let Some(n) = word.strip_prefix("maxversions=") {
+            match n.parse::<i32>() {
+                Ok(n) if n > 0 => Ok(GcRule::max_versions(n)),
+                _ => Err(Error::new(
+                    value_position,
+                    "expected a positive number of versions",
+                )),
+            }
+        } else if let Some(age) = word.strip_prefix("maxage=") {
+            parse_duration(age)
+                .map(GcRule::max_age)
+                .ok_or_else(|| Error::new(value_position, "expected a positive duration, e.g. 7d"))
+        } else if word == "never" {
+            Err(Error::new(position, "`never` can't be combined"))
+        } else {
+            Err(Error::new(position, format!("unknown rule `{}`", word)))
+        }
+    }
+}
+
+fn parse_duration(s: &str) -> Option<Duration> {
+    let digits = s.find(|c: char| !c.is_ascii_digit())?;
+    let n = s[..digits].parse::<u64>().ok()?;
+    let (_, per_unit) = UNITS.iter().find(|(unit, _)| *unit == &s[digits..])?;
+    let nanos = u128::from(n).checked_mul(*per_unit as u128)?;
+    if nanos == 0 {
+        return None;
+    }
+    let seconds = u64::try_from(nanos / 1_000_000_000).ok()?;
+    Some(Duration::new(seconds, (nanos % 1_000_000_000) as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(n: u64) -> Duration {
+        Duration::from_secs(n * 86_400)
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(GcRule::never().to_string(), "never");
+        assert_eq!(GcRule::max_versions(2).to_string(), "maxversions=2");
+        assert_eq!(GcRule::max_age(days(7)).to_string(), "maxage=7d");
+        assert_eq!(
+            GcRule::max_age(Duration::from_secs(90)).to_string(),
+            "maxage=90s"
+        );
+        assert_eq!(
+            GcRule::max_age(Duration::from_millis(1500)).to_string(),
+            "maxage=1500ms"
+        );
+        let rule = GcRule::union(vec![
+            GcRule::intersection(vec![GcRule::max_age(days(30)), GcRule::max_versions(2)]),
+            GcRule::max_versions(10),
+        ]);
+        assert_eq!(
+            rule.to_string(),
+            "(maxage=30d && maxversions=2) || maxversions=10"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let rule: GcRule = "maxage=30d&&(maxversions=2 || maxage=36h)".parse().unwrap();
+        assert_eq!(
+            rule,
+            GcRule::intersection(vec![
+                GcRule::max_age(days(30)),
+                GcRule::union(vec![
+                    GcRule::max_versions(2),
+                    GcRule::max_age(Duration::from_secs(36 * 3600)),
+                ]),
+            ])
+        );
+        assert_eq!(
+            rule.to_string(),
+            "maxage=30d && (maxversions=2 || maxage=36h)"
+        );
+        assert_eq!(" never ".parse::<GcRule>().unwrap(), GcRule::never());
+
+        // Nested intersections are flattened.
+        let rule: GcRule = "maxversions=1 && (maxversions=2 && maxage=1d)"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            rule.to_string(),
+            "maxversions=1 && maxversions=2 && maxage=1d"
+        );
+
+        for s in &[
+            "maxversions=3",
+            "maxage=1500ms",
+            "maxage=1d || maxversions=1 || (maxage=2h && maxversions=5)",
+        ] {
+            assert_eq!(s.parse::<GcRule>().unwrap().to_string(), *s);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("", 0, "unexpected end of rule"),
+            (
+                "maxversions=0",
+                12,
+                "expected a positive number of versions",
+            ),
+            ("maxage=10y", 7, "expected a positive duration, e.g. 7d"),
+            (
+                "maxage=1d && maxversions=1 || maxage=2d",
+                27,
+                "`&&` and `||` need parentheses",
+            ),
+            ("maxage=1d & maxversions=1", 10, "expected `&&`"),
+            ("(maxage=1d", 10, "unexpected end of rule"),
+            ("maxage=1d)", 9, "unexpected `)`"),
+            ("never || maxversions=1", 0, "`never` can't be combined"),
+            ("versions=1", 0, "unknown rule `versions=1`"),
+        ];
+        for (s, position, message) in &cases {
+            let error = s.parse::<GcRule>().unwrap_err();
+            assert_eq!(
+                (error.position(), error.message()),
+                (*position, *message),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        let nested = GcRule {
+            rule: Some(Rule::Union(Union {
+                rules: vec![
+                    GcRule {
+                        rule: Some(Rule::Union(Union {
+                            rules: vec![GcRule::max_versions(1), GcRule::max_versions(2)],
+                        })),
+                    },
+                    GcRule {
+                        rule: Some(Rule::Intersection(Intersection {
+                            rules: vec![GcRule::max_age(days(1))],
+                        })),
+                    },
+                ],
+            })),
+        };
+        assert_eq!(
+            nested.normalize(),
+            GcRule::union(vec![
+                GcRule::max_age(days(1)),
+                GcRule::max_versions(1),
+                GcRule::max_versions(2),
+            ])
+        );
+        let swapped = GcRule::union(vec![
+            GcRule::max_versions(2),
+            GcRule::intersection(vec![GcRule::max_versions(1), GcRule::max_age(days(1))]),
+        ]);
+        assert_eq!(
+            swapped.normalize(),
+            GcRule::union(vec![
+                GcRule::intersection(vec![GcRule::max_age(days(1)), GcRule::max_versions(1)]),
+                GcRule::max_versions(2),
+            ])
+        );
+        let empty = GcRule {
+            rule: Some(Rule::Intersection(Intersection { rules: vec![] })),
+        };
+        assert_eq!(empty.normalize(), GcRule::never());
+        assert_eq!(empty.to_string(), "never");
+    }
+}
@@ -0,0 +1,671 @@
+//! A bulk mutator that batches the entries of `MutateRows` and retries the failed ones.
+//!
+//! ```ignore
+//! use googapis::{
+//!     bigtable::mutate_rows::{BatchSettings, BulkMutator, BulkMutatorSettings},
+//!     google::bigtable::v2::{
+//!         bigtable_client::BigtableClient,
+//!         mutate_rows_request::Entry,
+//!         mutation::{self, SetCell},
+//!         Mutation,
+//!     },
+//! };
+//!
+//! let mutator = BulkMutator::with_settings(
+//!     BigtableClient::new(channel),
+//!     "projects/my-project/instances/my-instance/tables/my-table",
+//!     BulkMutatorSettings::default().batch(BatchSettings::default().max_entries(500)),
+//! );
+//! let result = mutator
+//!     .mutate(Entry {
+//!         row_key: b"user#1".to_vec(),
+//!         mutations: vec![Mutation {
+//!             mutation: Some(mutation::Mutation::SetCell(SetCell {
+//!                 family_name: "profile".into(),
+//!                 column_qualifier: b"name".to_vec(),
+//!                 timestamp_micros: 1_600_000_000_000_000,
+//!                 value: b"Ada".to_vec(),
+//!             })),
+//!         }],
+//!     })
+//!     .await;
+//! mutator.flush().await;
+//! result.await?;
+//! ```
+//!
+//! Entries are buffered and sent in a batch when `max_entries` or `max_bytes` is reached, or
+//! `max_delay` after the first entry of the batch. The batches are sent concurrently, within the
+//! flow control limits of the outstanding entries.
+//!
+//! `MutateRows` applies each entry atomically but reports a status per entry, so a call can
+//! partially fail. Only the entries that failed with a retryable code are sent again, and only if
+//! all their mutations are idempotent, see [`is_idempotent`]: a cell set at the server time
+//! would be written again with a new timestamp. The other entries complete with their error.
+
+use std::{
+    error, fmt,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use prost::Message;
+use tokio::sync::oneshot;
+use tonic::{Code, Status};
+
+use super::ValidationError;
+use crate::flow_control::{FlowControlSettings, FlowController};
+use crate::google::bigtable::v2::{
+    bigtable_client::BigtableClient, mutate_rows_request::Entry, mutation, MutateRowsRequest,
+};
+use crate::google::rpc::Status as RpcStatus;
+use crate::retry::RetrySettings;
+
+// The maximum number of mutations of a `MutateRows` request, over all its entries.
+const MAX_MUTATIONS: usize = 100_000;
+
+// The timestamp of a cell set at the server time.
+const SERVER_TIME: i64 = -1;
+
+/// An error mutating a row.
+#[derive(Debug)]
+pub enum Error {
+    /// The entry failed, with the status of its last attempt.
+    Status(Status),
+    /// The entry is invalid and was not sent.
+    Invalid(ValidationError),
+    /// The flow control limits are exceeded, with [`LimitExceededBehavior::Error`].
+    ///
+    /// [`LimitExceededBehavior::Error`]: super::LimitExceededBehavior::Error
+    FlowControl,
+    /// The bulk mutator was dropped before the entry was applied.
+    Cancelled,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Status(status) => write!(f, "mutation failed: {}", status),
+            Error::Invalid(e) => write!(f, "invalid entry: {}", e),
+            Error::FlowControl => f.write_str("flow control limits exceeded"),
+            Error::Cancelled => f.write_str("bulk mutator dropped"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Status(status) => Some(status),
+            Error::Invalid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// When a batch of entries is sent.
+#[derive(Debug, Clone)]
+pub struct BatchSettings {
+    max_entries: usize,
+    max_bytes: usize,
+    max_delay: Duration,
+}
+
+impl Default for BatchSettings {
+    fn default() -> Self {
+        Self {
+            max_entries: 100,
+            max_bytes: 20_000_000,
+            max_delay: Duration::from_millis(10),
+        }
+    }
+}
+
+impl BatchSettings {
+    /// The number of entries of a batch, at most 100,000.
+    ///
+    /// A batch is also sent before its entries exceed 100,000 mutations.
+    pub fn max_entries(self, max_entries: usize) -> Self {
+        Self {
+            max_entries: max_entries.clamp(1, MAX_MUTATIONS),
+            ..self
+        }
+    }
+
+    /// The size of the entries of a batch, at most 256 MB.
+    pub fn max_bytes(self, max_bytes: usize) -> Self {
+        Self {
+            max_bytes: max_bytes.clamp(1, 256_000_000),
+            ..self
+        }
+    }
+
+    /// The delay after the first entry of a batch before it is sent.
+    pub fn max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay, ..self }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BulkMutatorSettings {
+    batch: BatchSettings,
+    flow_control: FlowControlSettings,
+    retry: RetrySettings,
+    app_profile_id: String,
+}
+
+impl Default for BulkMutatorSettings {
+    fn default() -> Self {
+        Self {
+            batch: BatchSettings::default(),
+            flow_control: FlowControlSettings::default(),
+            retry: RetrySettings::default().retry_codes(vec![
+                Code::Unavailable,
+                Code::DeadlineExceeded,
+                Code::Aborted,
+            ]),
+            app_profile_id: String::new(),
+        }
+    }
+}
+
+impl BulkMutatorSettings {
+    pub fn batch(self, batch: BatchSettings) -> Self {
+        Self { batch, ..self }
+    }
+
+    pub fn flow_control(self, flow_control: FlowControlSettings) -> Self {
+        Self {
+            flow_control,
+            ..self
+        }
+    }
+
+    /// The codes on which the failed entries are retried, and the backoff between attempts.
+    pub fn retry(self, retry: RetrySettings) -> Self {
+        Self { retry, ..self }
+    }
+
+    pub fn app_profile_id(self, app_profile_id: impl Into<String>) -> Self {
+        Self {
+            app_profile_id: app_profile_id.into(),
+            ..self
+        }
+    }
+}
+
+/// Returns whether applying the mutations of `entry` again has no effect, i.e. whether none of
+/// them sets a cell at the server time.
+pub fn is_idempotent(entry: &Entry) -> bool {
+    entry.mutations.iter().all(|m| match &m.mutation {
+        Some(mutation::Mutation::SetCell(cell)) => cell.timestamp_micros != SERVER_TIME,
+        _ => true,
+    })
+}
+
+/// Checks that the entry has a row key and between 1 and 100,000 mutations.
+pub fn validate(entry: &Entry) -> Result<(), ValidationError> {
+    if entry.row_key.is_empty() {
+        return Err(ValidationError::new("row_key", "empty row key"));
+    }
+    if entry.mutations.is_empty() {
+        return Err(ValidationError::new("mutations", "no mutations"));
+    }
+    if entry.mutations.len() > MAX_MUTATIONS {
+        return Err(ValidationError::new(
+            "mutations",
+            format!("more than {} mutations", MAX_MUTATIONS),
+        ));
+    }
+    Ok(())
+}
+
+/// The completion of an entry, once it is applied or has finally failed.
+#[derive(Debug)]
+pub struct MutateResult(oneshot::Receiver<Result<(), Error>>);
+
+impl MutateResult {
+    fn ready(result: Result<(), Error>) -> Self {
+        let (tx, rx) = oneshot::channel();
+        let _ = tx.send(result);
+        MutateResult(rx)
+    }
+}
+
+impl Future for MutateResult {
+    type Output = Result<(), Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0)
+            .poll(cx)
+            .map(|result| result.unwrap_or(Err(Error::Cancelled)))
+    }
+}
+
+#[derive(Debug)]
+struct Pending {
+    entry: Entry,
+    size: usize,
+    tx: oneshot::Sender<Result<(), Error>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    pending: Vec<Pending>,
+    pending_bytes: usize,
+    pending_mutations: usize,
+    // Incremented each time `pending` is taken, so that a timer only sends its own batch.
+    generation: u64,
+    // The batches sent but not completed.
+    batches: usize,
+    flush_waiters: Vec<oneshot::Sender<()>>,
+}
+
+impl State {
+    fn take(&mut self) -> Vec<Pending> {
+        self.generation += 1;
+        self.pending_bytes = 0;
+        self.pending_mutations = 0;
+        mem::take(&mut self.pending)
+    }
+}
+
+#[derive(Debug)]
+struct Inner<T> {
+    client: BigtableClient<T>,
+    table_name: String,
+    app_profile_id: String,
+    batch: BatchSettings,
+    retry: RetrySettings,
+    flow: FlowController,
+    state: Mutex<State>,
+}
+
+/// Applies entries to a table in batches with `MutateRows`.
+///
+/// The bulk mutator is cheap to clone, the clones share the batches.
+#[derive(Debug)]
+pub struct BulkMutator<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Clone for BulkMutator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+fn entry_result(status: Option<RpcStatus>) -> Result<(), Status> {
+    match status {
+        Some(status) if status.code != Code::Ok as i32 => {
+            Err(Status::new(Code::from(status.code), status.message))
+        }
+        _ => Ok(()),
+    }
+}
+
+impl<T> BulkMutator<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    pub fn new(client: BigtableClient<T>, table_name: impl Into<String>) -> Self {
+        Self::with_settings(client, table_name, BulkMutatorSettings::default())
+    }
+
+    pub fn with_settings(
+        client: BigtableClient<T>,
+        table_name: impl Into<String>,
+        settings: BulkMutatorSettings,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                client,
+                table_name: table_name.into(),
+                app_profile_id: settings.app_profile_id,
+                batch: settings.batch,
+                retry: settings.retry,
+                flow: FlowController::new(settings.flow_control),
+                state: Mutex::new(State::default()),
+            }),
+        }
+    }
+
+    /// Adds `entry` to a batch, waiting first for the flow control limits if needed.
+    ///
+    /// The returned [`MutateResult`] resolves once the entry is applied, or has failed after
+    /// its retries.
+    pub async fn mutate(&self, entry: Entry) -> MutateResult {
+        if let Err(e) = validate(&entry) {
+            return MutateResult::ready(Err(Error::Invalid(e)));
+        }
+        let size = entry.encoded_len();
+        if !self.inner.flow.acquire(size).await {
+            return MutateResult::ready(Err(Error::FlowControl));
+        }
+
+        let (tx, rx) = oneshot::channel();
+        let mutations = entry.mutations.len();
+        let mut state = self.inner.state.lock().unwrap();
+        if !state.pending.is_empty()
+            && (state.pending_bytes + size > self.inner.batch.max_bytes
+                || state.pending_mutations + mutations > MAX_MUTATIONS)
+        {
+            let batch = state.take();
+            Inner::dispatch(&self.inner, &mut state, batch);
+        }
+
+        state.pending.push(Pending { entry, size, tx });
+        state.pending_bytes += size;
+        state.pending_mutations += mutations;
+        if state.pending.len() >= self.inner.batch.max_entries
+            || state.pending_bytes >= self.inner.batch.max_bytes
+        {
+            let batch = state.take();
+            Inner::dispatch(&self.inner, &mut state, batch);
+        } else if state.pending.len() == 1 {
+            let (inner, generation) = (self.inner.clone(), state.generation);
+            tokio::spawn(async move {
+                tokio::time::delay_for(inner.batch.max_delay).await;
+                let mut state = inner.state.lock().unwrap();
+                if state.generation == generation && !state.pending.is_empty() {
+                    let batch = state.take();
+                    Inner::dispatch(&inner, &mut state, batch);
+                }
+            });
+        }
+        MutateResult(rx)
+    }
+
+    /// Sends the pending batch and waits until all the batches are completed, including their
+    /// retries.
+    pub async fn flush(&self) {
+        let rx = {
+            let mut state = self.inner.state.lock().unwrap();
+            if !state.pending.is_empty() {
+                let batch = state.take();
+                Inner::dispatch(&self.inner, &mut state, batch);
+            }
+            if state.batches == 0 {
+                return;
+            }
+            let (tx, rx) = oneshot::channel();
+            state.flush_waiters.push(tx);
+            rx
+        };
+        let _ = rx.await;
+    }
+}
+
+impl<T> Inner<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    fn dispatch(this: &Arc<Self>, state: &mut State, batch: Vec<Pending>) {
+        state.batches += 1;
+        tokio::spawn(Self::send(this.clone(), batch));
+    }
+
+    // Sends `batch`, then the entries to retry until none is left.
+    async fn send(this: Arc<Self>, mut batch: Vec<Pending>) {
+        let mut backoff = this.retry.backoff_iter();
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let exhausted = matches!(this.retry.max_attempts, Some(max) if attempts >= max);
+            let results = this.call(&batch).await;
+            let mut retries = Vec::new();
+            for (pending, result) in batch.into_iter().zip(results) {
+                match result {
+                    Err(status)
+                        if !exhausted
+                            && this.retry.is_retryable(&status)
+                            && is_idempotent(&pending.entry) =>
+                    {
+                        retries.push(pending)
+                    }
+                    result => {
+                        this.flow.release(pending.size);
+                        let _ = pending.tx.send(result.map_err(Error::Status));
+                    }
+                }
+            }
+            if retries.is_empty() {
+                break;
+            }
+            tokio::time::delay_for(backoff.next().unwrap()).await;
+            batch = retries;
+        }
+
+        let mut state = this.state.lock().unwrap();
+        state.batches -= 1;
+        if state.batches == 0 {
+            for tx in state.flush_waiters.drain(..) {
+                let _ = tx.send(());
+            }
+        }
+    }
+
+    // Calls `MutateRows` with the entries of `batch` and returns their results. The entries
+    // without a status fail with the error of the call.
+    async fn call(&self, batch: &[Pending]) -> Vec<Result<(), Status>> {
+        let request = MutateRowsRequest {
+            table_name: self.table_name.clone(),
+            app_profile_id: self.app_profile_id.clone(),
+            entries: batch.iter().map(|p| p.entry.clone()).collect(),
+        };
+        let mut results = batch.iter().map(|_| None).collect::<Vec<_>>();
+        let mut client = self.client.clone();
+        let outcome: Result<(), Status> = async {
+            let mut responses = client.mutate_rows(request).await?.into_inner();
+            while let Some(response) = responses.message().await? {
+                for entry in response.entries {
+                    if let Some(result) = results.get_mut(entry.index as usize) {
+                        *result = Some(entry_result(entry.status));
+                    }
+                }
+            }
+            Ok(())
+        }
+        .await;
+        results
+            .into_iter()
+            .map(|result| match (result, &outcome) {
+                (Some(result), _) => result,
+                (None, Err(status)) => Err(status.clone()),
+                (None, Ok(())) => Err(Status::new(Code::Internal, "missing entry status")),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigtable::fake::{FakeBigtable, FakeServer};
+    use crate::google::bigtable::v2::{
+        mutation::{DeleteFromRow, SetCell},
+        *,
+    };
+
+    fn mutator(fake: &Arc<FakeBigtable>, settings: BulkMutatorSettings) -> BulkMutator<FakeServer> {
+        BulkMutator::with_settings(fake.client(), "projects/p/instances/i/tables/t", settings)
+    }
+
+    // Sends batches of `max_entries` entries, and retries without delay.
+    fn settings(max_entries: usize) -> BulkMutatorSettings {
+        BulkMutatorSettings::default()
+            .batch(
+                BatchSettings::default()
+                    .max_entries(max_entries)
+                    .max_delay(Duration::from_secs(3600)),
+            )
+            .retry(
+                RetrySettings::default()
+                    .retry_codes(vec![Code::Unavailable, Code::DeadlineExceeded])
+                    .backoff(Duration::from_millis(1), Duration::from_millis(1), 1.0),
+            )
+    }
+
+    fn set_cell(row_key: &str, timestamp_micros: i64) -> Entry {
+        Entry {
+            row_key: row_key.into(),
+            mutations: vec![Mutation {
+                mutation: Some(mutation::Mutation::SetCell(SetCell {
+                    family_name: "f".into(),
+                    column_qualifier: b"q".to_vec(),
+                    timestamp_micros,
+                    value: b"v".to_vec(),
+                })),
+            }],
+        }
+    }
+
+    fn delete_row(row_key: &str) -> Entry {
+        Entry {
+            row_key: row_key.into(),
+            mutations: vec![Mutation {
+                mutation: Some(mutation::Mutation::DeleteFromRow(DeleteFromRow {})),
+            }],
+        }
+    }
+
+    fn code<T>(result: Result<T, Error>) -> Code {
+        match result {
+            Err(Error::Status(status)) => status.code(),
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => Code::Ok,
+        }
+    }
+
+    #[test]
+    fn test_is_idempotent() {
+        assert!(is_idempotent(&set_cell("a", 1000)));
+        assert!(is_idempotent(&delete_row("a")));
+        assert!(!is_idempotent(&set_cell("a", SERVER_TIME)));
+
+        let mut entry = delete_row("a");
+        entry.mutations.extend(set_cell("a", SERVER_TIME).mutations);
+        assert!(!is_idempotent(&entry));
+    }
+
+    #[tokio::test]
+    async fn test_batches() {
+        let fake = Arc::new(FakeBigtable::default());
+        let mutator = mutator(&fake, settings(2));
+
+        let mut results = Vec::new();
+        for key in &["a", "b", "c"] {
+            results.push(mutator.mutate(set_cell(key, 1000)).await);
+        }
+        mutator.flush().await;
+        for result in results {
+            result.await.unwrap();
+        }
+        assert_eq!(fake.row_keys(), vec![vec!["a", "b"], vec!["c"]]);
+        assert_eq!(
+            fake.requests()[0].table_name,
+            "projects/p/instances/i/tables/t"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_max_delay() {
+        let fake = Arc::new(FakeBigtable::default());
+        let settings = settings(100).batch(BatchSettings::default());
+        let mutator = mutator(&fake, settings);
+
+        mutator.mutate(delete_row("a")).await.await.unwrap();
+        assert_eq!(fake.row_keys(), vec![vec!["a"]]);
+    }
+
+    #[tokio::test]
+    async fn test_retry_failed_entries() {
+        let fake = Arc::new(FakeBigtable::default());
+        fake.fail("a", vec![Code::Unavailable, Code::DeadlineExceeded]);
+        fake.fail("c", vec![Code::Unavailable]);
+        fake.fail("d", vec![Code::InvalidArgument]);
+        let mutator = mutator(&fake, settings(4));
+
+        let a = mutator.mutate(set_cell("a", 1000)).await;
+        let b = mutator.mutate(set_cell("b", 1000)).await;
+        // Not retried, the cell would be set again at a new server time.
+        let c = mutator.mutate(set_cell("c", SERVER_TIME)).await;
+        let d = mutator.mutate(delete_row("d")).await;
+        mutator.flush().await;
+        assert_eq!(code(a.await), Code::Ok);
+        assert_eq!(code(b.await), Code::Ok);
+        assert_eq!(code(c.await), Code::Unavailable);
+        assert_eq!(code(d.await), Code::InvalidArgument);
+        assert_eq!(
+            fake.row_keys(),
+            vec![vec!["a", "b", "c", "d"], vec!["a"], vec!["a"]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_failed_call() {
+        let fake = Arc::new(FakeBigtable::default());
+        fake.fail_call(Code::Unavailable);
+        let mutator = mutator(&fake, settings(2));
+
+        let a = mutator.mutate(set_cell("a", SERVER_TIME)).await;
+        let b = mutator.mutate(delete_row("b")).await;
+        mutator.flush().await;
+        assert_eq!(code(a.await), Code::Unavailable);
+        assert_eq!(code(b.await), Code::Ok);
+        assert_eq!(fake.row_keys(), vec![vec!["a", "b"], vec!["b"]]);
+    }
+
+    #[tokio::test]
+    async fn test_max_attempts() {
+        let fake = Arc::new(FakeBigtable::default());
+        fake.fail("a", vec![Code::Unavailable; 3]);
+        let settings = settings(1).retry(
+            RetrySettings::default()
+                .backoff(Duration::from_millis(1), Duration::from_millis(1), 1.0)
+                .max_attempts(2),
+        );
+        let mutator = mutator(&fake, settings);
+
+        let a = mutator.mutate(delete_row("a")).await;
+        mutator.flush().await;
+        assert_eq!(code(a.await), Code::Unavailable);
+        assert_eq!(fake.row_keys(), vec![vec!["a"], vec!["a"]]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_entry() {
+        let fake = Arc::new(FakeBigtable::default());
+        let mutator = mutator(&fake, settings(100));
+
+        let entry = Entry {
+            row_key: b"a".to_vec(),
+            mutations: vec![],
+        };
+        match mutator.mutate(entry).await.await {
+            Err(Error::Invalid(e)) => assert_eq!(e.path(), "mutations"),
+            result => panic!("unexpected result: {:?}", result),
+        }
+        match mutator.mutate(delete_row("")).await.await {
+            Err(Error::Invalid(e)) => assert_eq!(e.to_string(), "row_key: empty row key"),
+            result => panic!("unexpected result: {:?}", result),
+        }
+        mutator.flush().await;
+        assert!(fake.row_keys().is_empty());
+    }
+}
@@ -0,0 +1,846 @@
+//! Rows read with `ReadRows`.
+//!
+//! The server streams the cells of the rows as `CellChunk`s, whose values may be split across
+//! several chunks, and which only carry the parts of the row key, family and qualifier that
+//! changed since the previous chunk. A row is complete when a chunk commits it, and the chunks
+//! received since the last commit are discarded when a chunk resets the row.
+//!
+//! A [`ReadRowsStream`] merges the chunks into [`Row`]s and checks that the chunks follow these
+//! rules. When the stream fails with a retryable error, it is reopened with a request reading the
+//! rows after the last committed row, and the remaining number of rows of its limit.
+//!
+//! [`ReadRowsStream::read_rows`] checks the request with [`validate`] first, and fails with
+//! `INVALID_ARGUMENT` without calling the server when it is invalid.
+
+use std::{
+    collections::VecDeque,
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    future::BoxFuture,
+    stream::{BoxStream, Stream},
+    FutureExt, StreamExt,
+};
+use tonic::{Code, Status};
+
+use super::{filter, row_set, ValidationError};
+use crate::{
+    google::bigtable::v2::{
+        bigtable_client::BigtableClient,
+        read_rows_response::{cell_chunk::RowStatus, CellChunk},
+        row_range::{EndKey, StartKey},
+        Cell, Column, Family, ReadRowsRequest, ReadRowsResponse, Row, RowRange, RowSet,
+    },
+    retry::{self, Backoff, RetrySettings},
+};
+
+type Open =
+    Box<dyn FnMut(ReadRowsRequest) -> BoxFuture<'static, Result<ChunkStream, Status>> + Send>;
+type ChunkStream = BoxStream<'static, Result<ReadRowsResponse, Status>>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    // Waiting for the first chunk of a row.
+    NewRow,
+    // Waiting for the next cell of a row, or for its commit.
+    RowInProgress,
+    // Waiting for the rest of the value of a cell.
+    CellInProgress,
+}
+
+// Merges cell chunks into rows.
+#[derive(Debug)]
+struct Merger {
+    state: State,
+    row: Row,
+    // The family, qualifier and cell of the current chunk.
+    family: String,
+    qualifier: Vec<u8>,
+    cell: Cell,
+    // The key of the last committed row, or the last scanned key once all rows are committed.
+    last_key: Vec<u8>,
+    committed: i64,
+    ready: VecDeque<Row>,
+}
+
+impl Merger {
+    fn new() -> Self {
+        Self {
+            state: State::NewRow,
+            row: Row::default(),
+            family: String::new(),
+            qualifier: Vec::new(),
+            cell: Cell::default(),
+            last_key: Vec::new(),
+            committed: 0,
+            ready: VecDeque::new(),
+        }
+    }
+
+    fn add(&mut self, chunk: CellChunk) -> Result<(), Status> {
+        match self.state {
+            State::NewRow => self.validate_new_row(&chunk)?,
+            State::RowInProgress => self.validate_row_in_progress(&chunk)?,
+            State::CellInProgress => validate_cell_in_progress(&chunk)?,
+        }
+        if let Some(RowStatus::ResetRow(true)) = chunk.row_status {
+            self.reset();
+            return Ok(());
+        }
+
+        if self.state != State::CellInProgress {
+            if self.state == State::NewRow {
+                self.row.key = chunk.row_key;
+            }
+            if let Some(family) = chunk.family_name {
+                self.family = family;
+            }
+            if let Some(qualifier) = chunk.qualifier {
+                self.qualifier = qualifier;
+            }
+            self.cell = Cell {
+                timestamp_micros: chunk.timestamp_micros,
+                value: Vec::with_capacity(chunk.value_size.max(0) as usize),
+                labels: chunk.labels,
+            };
+        }
+        self.cell.value.extend(chunk.value);
+
+        if chunk.value_size > 0 {
+            self.state = State::CellInProgress;
+            return Ok(());
+        }
+        self.push_cell();
+        self.state = State::RowInProgress;
+        if let Some(RowStatus::CommitRow(true)) = chunk.row_status {
+            self.commit();
+        }
+        Ok(())
+    }
+
+    fn validate_new_row(&self, chunk: &CellChunk) -> Result<(), Status> {
+        if let Some(RowStatus::ResetRow(true)) = chunk.row_status {
+            return Err(invalid_chunk("reset_row between rows"));
+        }
+        if chunk.row_key.is_empty() || chunk.family_name.is_none() || chunk.qualifier.is_none() {
+            return Err(invalid_chunk(
+                "missing row key, family or qualifier of a new row",
+            ));
+        }
+        if !self.last_key.is_empty() && chunk.row_key <= self.last_key {
+            return Err(invalid_chunk("row keys are not in increasing order"));
+        }
+        Ok(())
+    }
+
+    fn validate_row_in_progress(&self, chunk: &CellChunk) -> Result<(), Status> {
+        validate_row_status(chunk)?;
+        if !chunk.row_key.is_empty() && chunk.row_key != self.row.key {
+            return Err(invalid_chunk("new row key before the commit of the row"));
+        }
+        if chunk.family_name.is_some() && chunk.qualifier.is_none() {
+            return Err(invalid_chunk("new family without a qualifier"));
+        }
+        Ok(())
+    }
+
+    // Adds the current cell to the row, in the column of its family and qualifier.
+    fn push_cell(&mut self) {
+        let (name, qualifier) = (&self.family, &self.qualifier);
+        let families = &mut self.row.families;
+        let family = match families.iter().position(|f| &f.name == name) {
+            Some(i) => &mut families[i],
+            None => {
+                families.push(Family {
+                    name: name.clone(),
+                    columns: Vec::new(),
+                });
+                families.last_mut().unwrap()
+            }
+        };
+        let columns = &mut family.columns;
+        let column = match columns.iter().position(|c| &c.qualifier == qualifier) {
+            Some(i) => &mut columns[i],
+            None => {
+                columns.push(Column {
+                    qualifier: qualifier.clone(),
+                    cells: Vec::new(),
+                });
+                columns.last_mut().unwrap()
+            }
+        };
+        column.cells.push(mem::take(&mut self.cell));
+    }
+
+    fn commit(&mut self) {
+        let row = mem::take(&mut self.row);
+        self.last_key = row.key.clone();
+        self.committed += 1;
+        self.ready.push_back(row);
+        self.state = State::NewRow;
+    }
+
+    // Discards the row in progress.
+    fn reset(&mut self) {
+        self.row = Row::default();
+        self.family.clear();
+        self.qualifier.clear();
+        self.cell = Cell::default();
+        self.state = State::NewRow;
+    }
+
+    // Skips the rows up to `key`, which the server scanned without returning them.
+    fn scanned(&mut self, key: Vec<u8>) {
+        if self.state == State::NewRow && key > self.last_key {
+            self.last_key = key;
+        }
+    }
+
+    fn finish(&self) -> Result<(), Status> {
+        if self.state != State::NewRow {
+            return Err(invalid_chunk("stream ended before the commit of a row"));
+        }
+        Ok(())
+    }
+}
+
+// Checks that a reset or a commit is not combined with other parts of a cell.
+fn validate_row_status(chunk: &CellChunk) -> Result<(), Status> {
+    match chunk.row_status {
+        Some(RowStatus::ResetRow(true))
+            if !chunk.row_key.is_empty()
+                || chunk.family_name.is_some()
+                || chunk.qualifier.is_some()
+                || chunk.timestamp_micros != 0
+                || !chunk.labels.is_empty()
+                || !chunk.value.is_empty()
+                || chunk.value_size != 0 =>
+        {
+            Err(invalid_chunk("reset_row with cell data"))
+        }
+        Some(RowStatus::CommitRow(true)) if chunk.value_size > 0 => {
+            Err(invalid_chunk("commit_row before the end of a split value"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_cell_in_progress(chunk: &CellChunk) -> Result<(), Status> {
+    validate_row_status(chunk)?;
+    let reset = matches!(chunk.row_status, Some(RowStatus::ResetRow(true)));
+    if !reset
+        && (!chunk.row_key.is_empty()
+            || chunk.family_name.is_some()
+            || chunk.qualifier.is_some()
+            || chunk.timestamp_micros != 0)
+    {
+        return Err(invalid_chunk("cell key in the middle of a split value"));
+    }
+    Ok(())
+}
+
+fn invalid_chunk(message: &str) -> Status {
+    Status::internal(format!("invalid ReadRows chunk: {}", message))
+}
+
+/// Checks the row set and the filter of a request.
+pub fn validate(request: &ReadRowsRequest) -> Result<(), ValidationError> {
+    if request.rows_limit < 0 {
+        return Err(ValidationError::new("rows_limit", "negative rows limit"));
+    }
+    if let Some(rows) = &request.rows {
+        row_set::validate(rows)?;
+    }
+    if let Some(filter) = &request.filter {
+        filter::validate(filter)?;
+    }
+    Ok(())
+}
+
+/// Returns the request reading the rows of `request` after `last_key`, when `read` rows were
+/// received, or `None` if no rows are left to read.
+pub fn resume_request(
+    request: &ReadRowsRequest,
+    last_key: &[u8],
+    read: i64,
+) -> Option<ReadRowsRequest> {
+    let rows_limit = if request.rows_limit > 0 {
+        let remaining = request.rows_limit - read;
+        if remaining <= 0 {
+            return None;
+        }
+        remaining
+    } else {
+        0
+    };
+    if last_key.is_empty() {
+        return Some(ReadRowsRequest {
+            rows_limit,
+            ..request.clone()
+        });
+    }
+
+    let rows = match &request.rows {
+        Some(rows) if !rows.row_keys.is_empty() || !rows.row_ranges.is_empty() => {
+            let row_keys = rows
+                .row_keys
+                .iter()
+                .filter(|key| key.as_slice() > last_key)
+                .cloned()
+                .collect();
+            let row_ranges = rows
+                .row_ranges
+                .iter()
+                .filter_map(|range| range_after(range, last_key))
+                .collect();
+            let rows = RowSet {
+                row_keys,
+                row_ranges,
+            };
+            // An empty row set reads the whole table.
+            if rows.row_keys.is_empty() && rows.row_ranges.is_empty() {
+                return None;
+            }
+            rows
+        }
+        _ => RowSet {
+            row_keys: Vec::new(),
+            row_ranges: vec![RowRange {
+                start_key: Some(StartKey::StartKeyOpen(last_key.to_vec())),
+                end_key: None,
+            }],
+        },
+    };
+    Some(ReadRowsRequest {
+        rows: Some(rows),
+        rows_limit,
+        ..request.clone()
+    })
+}
+
+// Returns the part of `range` after `key`, if any.
+fn range_after(range: &RowRange, key: &[u8]) -> Option<RowRange> {
+    let ended = match &range.end_key {
+        Some(EndKey::EndKeyOpen(end)) => !end.is_empty() && end.as_slice() <= key,
+        Some(EndKey::EndKeyClosed(end)) => !end.is_empty() && end.as_slice() <= key,
+        None => false,
+    };
+    if ended {
+        return None;
+    }
+    let started = match &range.start_key {
+        Some(StartKey::StartKeyClosed(start)) => start.as_slice() <= key,
+        Some(StartKey::StartKeyOpen(start)) => start.as_slice() < key,
+        None => true,
+    };
+    let start_key = if started {
+        Some(StartKey::StartKeyOpen(key.to_vec()))
+    } else {
+        range.start_key.clone()
+    };
+    Some(RowRange {
+        start_key,
+        end_key: range.end_key.clone(),
+    })
+}
+
+enum StreamState {
+    Opening(BoxFuture<'static, Result<ChunkStream, Status>>),
+    Streaming(ChunkStream),
+    Waiting(BoxFuture<'static, ()>),
+    Done,
+}
+
+/// A stream of the rows read with `ReadRows`, see the [module documentation](self).
+pub struct ReadRowsStream {
+    request: ReadRowsRequest,
+    open: Open,
+    state: StreamState,
+    merger: Merger,
+    settings: RetrySettings,
+    backoff: Backoff,
+    attempts: usize,
+    // The error returned after the rows committed before it.
+    error: Option<Status>,
+}
+
+impl ReadRowsStream {
+    /// Creates a stream that calls `open` with `request` to open the stream of responses, and
+    /// with the request of the remaining rows to resume it.
+    pub fn new<F, Fut, S>(request: ReadRowsRequest, mut open: F) -> Self
+    where
+        F: FnMut(ReadRowsRequest) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<S, Status>> + Send + 'static,
+        S: Stream<Item = Result<ReadRowsResponse, Status>> + Send + 'static,
+    {
+        let mut open: Open = Box::new(move |request| {
+            open(request)
+                .map(|result| result.map(StreamExt::boxed))
+                .boxed()
+        });
+        let settings = RetrySettings::default().retry_codes(vec![
+            Code::Unavailable,
+            Code::DeadlineExceeded,
+            Code::Aborted,
+        ]);
+        Self {
+            state: StreamState::Opening(open(request.clone())),
+            request,
+            open,
+            merger: Merger::new(),
+            backoff: settings.backoff_iter(),
+            settings,
+            attempts: 0,
+            error: None,
+        }
+    }
+
+    /// Reads rows with `ReadRows`.
+    pub fn read_rows<T>(client: BigtableClient<T>, request: ReadRowsRequest) -> Self
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+        T::Future: Send,
+        T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+        T::Error: Into<tonic::codegen::StdError>,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+    {
+        let invalid = validate(&request).err();
+        Self::new(request, move |request| {
+            let mut client = client.clone();
+            let invalid = invalid.clone();
+            async move {
+                if let Some(e) = invalid {
+                    return Err(Status::invalid_argument(e.to_string()));
+                }
+                let response = client.read_rows(request).await?;
+                Ok(response.into_inner())
+            }
+        })
+    }
+
+    /// The codes on which the stream is resumed, and the backoff between attempts. The attempts
+    /// are counted from the last received response.
+    pub fn retry_settings(self, settings: RetrySettings) -> Self {
+        Self {
+            backoff: settings.backoff_iter(),
+            settings,
+            ..self
+        }
+    }
+
+    /// The key of the last row that was read, or scanned by the server.
+    pub fn last_key(&self) -> &[u8] {
+        &self.merger.last_key
+    }
+
+    fn add(&mut self, response: ReadRowsResponse) -> Result<(), Status> {
+        self.attempts = 0;
+        self.backoff = self.settings.backoff_iter();
+        for chunk in response.chunks {
+            self.merger.add(chunk)?;
+        }
+        if !response.last_scanned_row_key.is_empty() {
+            self.merger.scanned(response.last_scanned_row_key);
+        }
+        Ok(())
+    }
+
+    // Schedules the resumption of the stream, or returns the error if it cannot be resumed.
+    fn fail(&mut self, status: Status) -> Option<Status> {
+        self.attempts += 1;
+        let exhausted = matches!(self.settings.max_attempts, Some(max) if self.attempts >= max);
+        if exhausted || !self.settings.is_retryable(&status) {
+            self.state = StreamState::Done;
+            return Some(status);
+        }
+        self.merger.reset();
+        let delay = retry::retry_delay(&status).unwrap_or_else(|| self.backoff.next().unwrap());
+        self.state = StreamState::Waiting(tokio::time::delay_for(delay).boxed());
+        None
+    }
+}
+
+impl Stream for ReadRowsStream {
+    type Item = Result<Row, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(row) = this.merger.ready.pop_front() {
+                return Poll::Ready(Some(Ok(row)));
+            }
+            if let Some(status) = this.error.take() {
+                return Poll::Ready(Some(Err(status)));
+            }
+            match &mut this.state {
+                StreamState::Opening(open) => match open.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(stream)) => this.state = StreamState::Streaming(stream),
+                    Poll::Ready(Err(status)) => {
+                        if let Some(status) = this.fail(status) {
+                            return Poll::Ready(Some(Err(status)));
+                        }
+                    }
+                },
+                StreamState::Streaming(stream) => match stream.as_mut().poll_next(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Some(Ok(response))) => {
+                        if let Err(status) = this.add(response) {
+                            this.state = StreamState::Done;
+                            this.error = Some(status);
+                        }
+                    }
+                    Poll::Ready(Some(Err(status))) => {
+                        if let Some(status) = this.fail(status) {
+                            return Poll::Ready(Some(Err(status)));
+                        }
+                    }
+                    Poll::Ready(None) => {
+                        this.state = StreamState::Done;
+                        this.error = this.merger.finish().err();
+                    }
+                },
+                StreamState::Waiting(delay) => match delay.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(()) => {
+                        let merger = &this.merger;
+                        match resume_request(&this.request, &merger.last_key, merger.committed) {
+                            Some(request) => {
+                                this.state = StreamState::Opening((this.open)(request))
+                            }
+                            None => this.state = StreamState::Done,
+                        }
+                    }
+                },
+                StreamState::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for ReadRowsStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReadRowsStream")
+            .field("request", &self.request)
+            .field("last_key", &self.merger.last_key)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex};
+
+    fn bytes(value: &Value) -> Vec<u8> {
+        base64::decode(value.as_str().unwrap()).unwrap()
+    }
+
+    fn parse_chunk(chunk: &Value) -> CellChunk {
+        let row_status = if chunk["resetRow"].as_bool() == Some(true) {
+            Some(RowStatus::ResetRow(true))
+        } else if chunk["commitRow"].as_bool() == Some(true) {
+            Some(RowStatus::CommitRow(true))
+        } else {
+            None
+        };
+        CellChunk {
+            row_key: chunk.get("rowKey").map(bytes).unwrap_or_default(),
+            family_name: chunk["familyName"].as_str().map(Into::into),
+            qualifier: chunk.get("qualifier").map(bytes),
+            timestamp_micros: chunk["timestampMicros"]
+                .as_str()
+                .map_or(0, |ts| ts.parse().unwrap()),
+            labels: chunk["labels"]
+                .as_array()
+                .map(|labels| labels.iter().map(|l| l.as_str().unwrap().into()).collect())
+                .unwrap_or_default(),
+            value: chunk.get("value").map(bytes).unwrap_or_default(),
+            value_size: chunk["valueSize"].as_i64().unwrap_or(0) as i32,
+            row_status,
+        }
+    }
+
+    // Flattens a row into the results of the acceptance tests, one per cell.
+    fn results(row: Row) -> Vec<Value> {
+        let mut results = Vec::new();
+        for family in &row.families {
+            for column in &family.columns {
+                for cell in &column.cells {
+                    results.push(serde_json::json!({
+                        "rowKey": String::from_utf8(row.key.clone()).unwrap(),
+                        "familyName": family.name,
+                        "qualifier": String::from_utf8(column.qualifier.clone()).unwrap(),
+                        "timestampMicros": cell.timestamp_micros.to_string(),
+                        "value": String::from_utf8(cell.value.clone()).unwrap(),
+                        "label": cell.labels.first().cloned().unwrap_or_default(),
+                    }));
+                }
+            }
+        }
+        results
+    }
+
+    #[test]
+    fn test_acceptance() {
+        let corpus: Value =
+            serde_json::from_str(include_str!("../../data/bigtable/readrows.json")).unwrap();
+        let tests = corpus["readRowsTests"].as_array().unwrap();
+        assert!(!tests.is_empty());
+        for test in tests {
+            let description = test["description"].as_str().unwrap();
+            let mut merger = Merger::new();
+            let mut result = Ok(());
+            for chunk in test["chunks"].as_array().unwrap() {
+                result = merger.add(parse_chunk(chunk));
+                if result.is_err() {
+                    break;
+                }
+            }
+            let result = result.and_then(|_| merger.finish());
+
+            let mut actual: Vec<Value> = merger.ready.drain(..).flat_map(results).collect();
+            if result.is_err() {
+                actual.push(serde_json::json!({ "error": true }));
+            }
+            let expected = test["results"].as_array().cloned().unwrap_or_default();
+            assert_eq!(actual, expected, "{}", description);
+        }
+    }
+
+    fn chunk(key: &str, value: &str, commit: bool) -> CellChunk {
+        CellChunk {
+            row_key: key.into(),
+            family_name: Some("f".into()),
+            qualifier: Some(b"q".to_vec()),
+            value: value.into(),
+            row_status: Some(RowStatus::CommitRow(commit)),
+            ..Default::default()
+        }
+    }
+
+    fn response(chunks: Vec<CellChunk>) -> ReadRowsResponse {
+        ReadRowsResponse {
+            chunks,
+            last_scanned_row_key: Vec::new(),
+        }
+    }
+
+    fn range(start: Option<StartKey>, end: Option<EndKey>) -> RowRange {
+        RowRange {
+            start_key: start,
+            end_key: end,
+        }
+    }
+
+    // Returns a stream that replays the responses of each call of `open`, and records the
+    // requests it was called with.
+    fn stream(
+        request: ReadRowsRequest,
+        calls: Vec<Vec<Result<ReadRowsResponse, Status>>>,
+        requests: Arc<Mutex<Vec<ReadRowsRequest>>>,
+    ) -> ReadRowsStream {
+        let mut calls = calls.into_iter();
+        ReadRowsStream::new(request, move |request| {
+            requests.lock().unwrap().push(request);
+            let responses = calls.next().expect("unexpected call");
+            async move { Ok(futures::stream::iter(responses)) }
+        })
+        .retry_settings(RetrySettings::default().backoff(
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_millis(1),
+            1.0,
+        ))
+    }
+
+    #[test]
+    fn test_resume_request() {
+        let request = ReadRowsRequest {
+            table_name: "t".into(),
+            rows: Some(RowSet {
+                row_keys: vec![b"a".to_vec(), b"c".to_vec(), b"e".to_vec()],
+                row_ranges: vec![
+                    range(None, Some(EndKey::EndKeyClosed(b"c".to_vec()))),
+                    range(
+                        Some(StartKey::StartKeyClosed(b"b".to_vec())),
+                        Some(EndKey::EndKeyOpen(b"d".to_vec())),
+                    ),
+                    range(Some(StartKey::StartKeyOpen(b"x".to_vec())), None),
+                ],
+            }),
+            rows_limit: 10,
+            ..Default::default()
+        };
+        let resumed = resume_request(&request, b"c", 4).unwrap();
+        assert_eq!(resumed.rows_limit, 6);
+        assert_eq!(
+            resumed.rows,
+            Some(RowSet {
+                row_keys: vec![b"e".to_vec()],
+                row_ranges: vec![
+                    range(
+                        Some(StartKey::StartKeyOpen(b"c".to_vec())),
+                        Some(EndKey::EndKeyOpen(b"d".to_vec())),
+                    ),
+                    range(Some(StartKey::StartKeyOpen(b"x".to_vec())), None),
+                ],
+            })
+        );
+
+        let resumed = resume_request(&request, b"z", 4).unwrap().rows.unwrap();
+        assert!(resumed.row_keys.is_empty());
+        assert_eq!(
+            resumed.row_ranges,
+            vec![range(Some(StartKey::StartKeyOpen(b"z".to_vec())), None)]
+        );
+        let keys = ReadRowsRequest {
+            rows: Some(RowSet {
+                row_keys: vec![b"a".to_vec()],
+                row_ranges: Vec::new(),
+            }),
+            ..Default::default()
+        };
+        assert!(resume_request(&keys, b"a", 1).is_none());
+        assert!(resume_request(&request, b"a", 10).is_none());
+        assert_eq!(resume_request(&request, b"", 0), Some(request));
+
+        let table = ReadRowsRequest::default();
+        assert_eq!(
+            resume_request(&table, b"k", 1).unwrap().rows,
+            Some(RowSet {
+                row_keys: Vec::new(),
+                row_ranges: vec![range(Some(StartKey::StartKeyOpen(b"k".to_vec())), None)],
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut request = ReadRowsRequest {
+            rows: Some(row_set::RowSetBuilder::new().prefix("a").build()),
+            filter: Some(filter::latest(1)),
+            ..Default::default()
+        };
+        validate(&request).unwrap();
+        request.filter = Some(filter::chain(vec![filter::pass_all(), filter::latest(0)]));
+        assert_eq!(
+            validate(&request).unwrap_err().path(),
+            "filter.chain.filters[1]"
+        );
+        request.rows_limit = -1;
+        assert_eq!(validate(&request).unwrap_err().path(), "rows_limit");
+    }
+
+    #[tokio::test]
+    async fn test_resume() {
+        let request = ReadRowsRequest {
+            table_name: "t".into(),
+            rows_limit: 3,
+            ..Default::default()
+        };
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let rows: Vec<Row> = stream(
+            request.clone(),
+            vec![
+                vec![
+                    Ok(response(vec![
+                        chunk("a", "1", true),
+                        chunk("b", "2", false),
+                    ])),
+                    Err(Status::unavailable("connection reset")),
+                ],
+                vec![Ok(response(vec![
+                    chunk("b", "2", true),
+                    chunk("c", "3", true),
+                ]))],
+            ],
+            requests.clone(),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+        let keys: Vec<_> = rows.iter().map(|row| row.key.clone()).collect();
+        assert_eq!(keys, vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0], request);
+        assert_eq!(requests[1].rows_limit, 2);
+        assert_eq!(
+            requests[1].rows.as_ref().unwrap().row_ranges,
+            vec![range(Some(StartKey::StartKeyOpen(b"a".to_vec())), None)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resume_after_scanned_key() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let mut rows = stream(
+            ReadRowsRequest {
+                rows: Some(RowSet {
+                    row_keys: vec![b"a".to_vec(), b"m".to_vec(), b"z".to_vec()],
+                    row_ranges: Vec::new(),
+                }),
+                ..Default::default()
+            },
+            vec![
+                vec![
+                    Ok(ReadRowsResponse {
+                        chunks: vec![chunk("a", "1", true)],
+                        last_scanned_row_key: b"m".to_vec(),
+                    }),
+                    Err(Status::unavailable("connection reset")),
+                ],
+                vec![Ok(response(vec![chunk("z", "2", true)]))],
+            ],
+            requests.clone(),
+        );
+        assert_eq!(rows.try_next().await.unwrap().unwrap().key, b"a");
+        assert_eq!(rows.try_next().await.unwrap().unwrap().key, b"z");
+        assert!(rows.try_next().await.unwrap().is_none());
+        assert_eq!(rows.last_key(), b"z");
+        assert_eq!(
+            requests.lock().unwrap()[1].rows.as_ref().unwrap().row_keys,
+            vec![b"z".to_vec()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_errors() {
+        let requests = Arc::default();
+        let mut rows = stream(
+            ReadRowsRequest::default(),
+            vec![vec![
+                Ok(response(vec![chunk("a", "1", true)])),
+                Err(Status::permission_denied("denied")),
+            ]],
+            requests,
+        );
+        assert!(rows.try_next().await.unwrap().is_some());
+        let status = rows.try_next().await.unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+        assert!(rows.try_next().await.unwrap().is_none());
+
+        // The rows committed before an invalid chunk are returned before the error.
+        let requests = Arc::default();
+        let mut rows = stream(
+            ReadRowsRequest::default(),
+            vec![vec![Ok(response(vec![
+                chunk("b", "1", true),
+                chunk("a", "2", true),
+            ]))]],
+            requests,
+        );
+        assert_eq!(rows.try_next().await.unwrap().unwrap().key, b"b");
+        let status = rows.try_next().await.unwrap_err();
+        assert_eq!(status.code(), Code::Internal);
+        assert!(rows.try_next().await.unwrap().is_none());
+    }
+}
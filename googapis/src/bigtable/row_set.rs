@@ -0,0 +1,202 @@
+//! Builders of the sets of rows read by `ReadRows`.
+//!
+//! ```ignore
+//! use std::ops::Bound;
+//! use googapis::bigtable::row_set::{self, RowSetBuilder};
+//!
+//! let rows = RowSetBuilder::new()
+//!     .key("user#1")
+//!     .prefix("order#2020-")
+//!     .range(row_set::range(Bound::Included("a"), Bound::Excluded("c")))
+//!     .build();
+//! ```
+//!
+//! An empty row set reads all the rows of the table.
+
+use std::ops::Bound;
+
+use super::{is_empty_range, ValidationError};
+use crate::google::bigtable::v2::{
+    row_range::{EndKey, StartKey},
+    RowRange, RowSet,
+};
+
+/// Returns the range of row keys between `start` and `end`.
+pub fn range<S, E>(start: Bound<S>, end: Bound<E>) -> RowRange
+where
+    S: Into<Vec<u8>>,
+    E: Into<Vec<u8>>,
+{
+    let start_key = match start {
+        Bound::Included(key) => Some(StartKey::StartKeyClosed(key.into())),
+        Bound::Excluded(key) => Some(StartKey::StartKeyOpen(key.into())),
+        Bound::Unbounded => None,
+    };
+    let end_key = match end {
+        Bound::Included(key) => Some(EndKey::EndKeyClosed(key.into())),
+        Bound::Excluded(key) => Some(EndKey::EndKeyOpen(key.into())),
+        Bound::Unbounded => None,
+    };
+    RowRange { start_key, end_key }
+}
+
+/// Returns the range of the row keys starting with `prefix`.
+pub fn prefix(prefix: impl Into<Vec<u8>>) -> RowRange {
+    let prefix = prefix.into();
+    let end = match prefix_end(&prefix) {
+        Some(end) => Bound::Excluded(end),
+        None => Bound::Unbounded,
+    };
+    range(Bound::Included(prefix), end)
+}
+
+// Returns the first key after all the keys starting with `prefix`, i.e. `prefix` without its
+// trailing 0xff bytes and with its last byte incremented, or `None` if there is none.
+fn prefix_end(prefix: &[u8]) -> Option<Vec<u8>> {
+    let last = prefix.iter().rposition(|&b| b != 0xff)?;
+    let mut end = prefix[..=last].to_vec();
+    end[last] += 1;
+    Some(end)
+}
+
+/// A builder of a set of row keys and ranges.
+#[derive(Debug, Clone, Default)]
+pub struct RowSetBuilder {
+    row_set: RowSet,
+}
+
+impl RowSetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a row key.
+    pub fn key(mut self, key: impl Into<Vec<u8>>) -> Self {
+        self.row_set.row_keys.push(key.into());
+        self
+    }
+
+    /// Adds a range of row keys, see [`range`] and [`prefix`](fn@prefix).
+    pub fn range(mut self, range: RowRange) -> Self {
+        self.row_set.row_ranges.push(range);
+        self
+    }
+
+    /// Adds the range of the row keys starting with `prefix`.
+    pub fn prefix(self, prefix: impl Into<Vec<u8>>) -> Self {
+        self.range(self::prefix(prefix))
+    }
+
+    pub fn build(self) -> RowSet {
+        self.row_set
+    }
+}
+
+impl From<RowSetBuilder> for RowSet {
+    fn from(builder: RowSetBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Checks that the row keys are not empty and that the ranges are not empty.
+pub fn validate(row_set: &RowSet) -> Result<(), ValidationError> {
+    for (i, key) in row_set.row_keys.iter().enumerate() {
+        if key.is_empty() {
+            return Err(ValidationError::new(
+                format!("rows.row_keys[{}]", i),
+                "empty row key",
+            ));
+        }
+    }
+    for (i, range) in row_set.row_ranges.iter().enumerate() {
+        let start = match &range.start_key {
+            Some(StartKey::StartKeyClosed(key)) => Bound::Included(&key[..]),
+            Some(StartKey::StartKeyOpen(key)) => Bound::Excluded(&key[..]),
+            None => Bound::Unbounded,
+        };
+        // An empty end key is the infinite row key.
+        let end = match &range.end_key {
+            Some(EndKey::EndKeyClosed(key)) if !key.is_empty() => Bound::Included(&key[..]),
+            Some(EndKey::EndKeyOpen(key)) if !key.is_empty() => Bound::Excluded(&key[..]),
+            _ => Bound::Unbounded,
+        };
+        if is_empty_range(start, end) {
+            return Err(ValidationError::new(
+                format!("rows.row_ranges[{}]", i),
+                "empty row range",
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix() {
+        assert_eq!(
+            prefix("abc"),
+            range(Bound::Included("abc"), Bound::Excluded("abd"))
+        );
+        assert_eq!(
+            prefix(b"a\xff\xff".to_vec()),
+            range(Bound::Included(b"a\xff\xff".to_vec()), Bound::Excluded("b"))
+        );
+        assert_eq!(
+            prefix(b"\xff".to_vec()),
+            range(
+                Bound::Included(b"\xff".to_vec()),
+                Bound::<Vec<u8>>::Unbounded
+            )
+        );
+        assert_eq!(
+            prefix(""),
+            range(Bound::Included(""), Bound::<Vec<u8>>::Unbounded)
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let row_set = RowSetBuilder::new()
+            .key("k")
+            .prefix("p")
+            .range(range(Bound::Excluded("a"), Bound::Included("b")))
+            .build();
+        assert_eq!(row_set.row_keys, vec![b"k".to_vec()]);
+        assert_eq!(
+            row_set.row_ranges,
+            vec![
+                RowRange {
+                    start_key: Some(StartKey::StartKeyClosed(b"p".to_vec())),
+                    end_key: Some(EndKey::EndKeyOpen(b"q".to_vec())),
+                },
+                RowRange {
+                    start_key: Some(StartKey::StartKeyOpen(b"a".to_vec())),
+                    end_key: Some(EndKey::EndKeyClosed(b"b".to_vec())),
+                },
+            ]
+        );
+        validate(&row_set).unwrap();
+    }
+
+    #[test]
+    fn test_validate() {
+        let error = validate(&RowSetBuilder::new().key("a").key("").build()).unwrap_err();
+        assert_eq!(error.path(), "rows.row_keys[1]");
+
+        let row_set = RowSetBuilder::new()
+            .range(range(Bound::Included("a"), Bound::Excluded("")))
+            .range(range(Bound::Excluded("b"), Bound::Excluded("b")))
+            .build();
+        let error = validate(&row_set).unwrap_err();
+        assert_eq!(error.path(), "rows.row_ranges[1]");
+        assert_eq!(error.to_string(), "rows.row_ranges[1]: empty row range");
+
+        let row_set = RowSetBuilder::new()
+            .range(range(Bound::Included("b"), Bound::Included("b")))
+            .build();
+        validate(&row_set).unwrap();
+    }
+}
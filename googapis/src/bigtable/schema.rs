@@ -0,0 +1,295 @@
+//! Declarative column families of Bigtable tables.
+//!
+//! The `bigtable-admin` feature provides a [`TableSpec`] listing the column families of a table
+//! with their [`GcRule`]s, and a [`Reconciler`] that reads the table with `GetTable` and applies
+//! the modifications to match the spec with a single `ModifyColumnFamilies` call:
+//!
+//! ```ignore
+//! use googapis::bigtable::{gc_rule::GcRule, schema::{Reconciler, TableSpec}};
+//!
+//! let spec = TableSpec::new()
+//!     .column_family("profile", GcRule::max_versions(1))
+//!     .column_family("events", "maxage=30d || maxversions=10".parse()?);
+//! let reconciler = Reconciler::new(admin_client).dry_run(true);
+//! if let Some(request) = reconciler.reconcile(TABLE_NAME, &spec).await? {
+//!     for modification in &request.modifications {
+//!         println!("{:?}", modification);
+//!     }
+//! }
+//! ```
+//!
+//! The rules are compared once normalized, see [`GcRule::normalize`], so that only the column
+//! families whose rules collect other cells are updated. The column families missing from the
+//! spec are kept unless [`TableSpec::drop_unlisted`] is set, as dropping them deletes their
+//! data.
+
+use std::collections::BTreeMap;
+
+use tonic::Status;
+
+use super::gc_rule::GcRule;
+use crate::google::bigtable::admin::v2::{
+    bigtable_table_admin_client::BigtableTableAdminClient,
+    modify_column_families_request::{modification::Mod, Modification},
+    table, ColumnFamily, GetTableRequest, ModifyColumnFamiliesRequest, Table,
+};
+
+/// The desired column families of a table.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableSpec {
+    column_families: BTreeMap<String, GcRule>,
+    drop_unlisted: bool,
+}
+
+impl TableSpec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a column family with its garbage collection rule, [`GcRule::never`] to keep all the
+    /// cells.
+    pub fn column_family(mut self, id: impl Into<String>, gc_rule: GcRule) -> Self {
+        self.column_families.insert(id.into(), gc_rule.normalize());
+        self
+    }
+
+    /// Whether the column families of the table missing from the spec are dropped, with their
+    /// data. Defaults to false.
+    pub fn drop_unlisted(self, drop_unlisted: bool) -> Self {
+        Self {
+            drop_unlisted,
+            ..self
+        }
+    }
+
+    /// Returns the request modifying the column families of `table` to match the spec, or `None`
+    /// if they already match.
+    ///
+    /// The column families are created or updated in the order of their IDs, then the unlisted
+    /// ones are dropped.
+    pub fn diff(&self, table: &Table) -> Option<ModifyColumnFamiliesRequest> {
+        let mut modifications = Vec::new();
+        for (id, gc_rule) in &self.column_families {
+            let family = ColumnFamily {
+                gc_rule: Some(gc_rule.clone()),
+            };
+            let r#mod = match table.column_families.get(id) {
+                None => Mod::Create(family),
+                Some(existing) => {
+                    let existing = existing.gc_rule.clone().unwrap_or_default().normalize();
+                    if existing == *gc_rule {
+                        continue;
+                    }
+                    Mod::Update(family)
+                }
+            };
+            modifications.push(Modification {
+                id: id.clone(),
+                r#mod: Some(r#mod),
+            });
+        }
+        if self.drop_unlisted {
+            let mut unlisted = table
+                .column_families
+                .keys()
+                .filter(|id| !self.column_families.contains_key(*id))
+                .collect::<Vec<_>>();
+            unlisted.sort();
+            modifications.extend(unlisted.into_iter().map(|id| Modification {
+                id: id.clone(),
+                r#mod: Some(Mod::Drop(true)),
+            }));
+        }
+
+        if modifications.is_empty() {
+            return None;
+        }
+        Some(ModifyColumnFamiliesRequest {
+            name: table.name.clone(),
+            modifications,
+        })
+    }
+}
+
+/// Applies [`TableSpec`]s to tables.
+#[derive(Debug, Clone)]
+pub struct Reconciler<T> {
+    client: BigtableTableAdminClient<T>,
+    dry_run: bool,
+}
+
+impl<T> Reconciler<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    pub fn new(client: BigtableTableAdminClient<T>) -> Self {
+        Self {
+            client,
+            dry_run: false,
+        }
+    }
+
+    /// Whether the modifications are only computed and returned, without being applied.
+    pub fn dry_run(self, dry_run: bool) -> Self {
+        Self { dry_run, ..self }
+    }
+
+    /// Modifies the column families of `table_name` to match `spec`, and returns the request
+    /// that was sent, or would be sent in a dry run, or `None` if the table already matches.
+    pub async fn reconcile(
+        &self,
+        table_name: &str,
+        spec: &TableSpec,
+    ) -> Result<Option<ModifyColumnFamiliesRequest>, Status> {
+        let mut client = self.client.clone();
+        let request = GetTableRequest {
+            name: table_name.to_owned(),
+            view: table::View::SchemaView as i32,
+        };
+        let table = client.get_table(request).await?.into_inner();
+        let request = match spec.diff(&table) {
+            Some(request) => request,
+            None => return Ok(None),
+        };
+        if !self.dry_run {
+            client.modify_column_families(request.clone()).await?;
+        }
+        Ok(Some(request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bigtable::fake::FakeTableAdmin,
+        google::bigtable::admin::v2::gc_rule::{Rule, Union},
+    };
+    use std::{sync::Arc, time::Duration};
+
+    const TABLE: &str = "projects/p/instances/i/tables/t";
+
+    fn family(gc_rule: Option<GcRule>) -> ColumnFamily {
+        ColumnFamily { gc_rule }
+    }
+
+    fn table(families: Vec<(&str, Option<GcRule>)>) -> Table {
+        Table {
+            name: TABLE.into(),
+            column_families: families
+                .into_iter()
+                .map(|(id, gc_rule)| (id.to_owned(), family(gc_rule)))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn modification(id: &str, r#mod: Mod) -> Modification {
+        Modification {
+            id: id.into(),
+            r#mod: Some(r#mod),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let week = GcRule::max_age(Duration::from_secs(7 * 86_400));
+        let spec = TableSpec::new()
+            .column_family("a", GcRule::max_versions(1))
+            .column_family("b", GcRule::max_versions(2))
+            .column_family("c", GcRule::never())
+            .column_family(
+                "d",
+                GcRule::union(vec![week.clone(), GcRule::max_versions(3)]),
+            );
+        // `d` only differs by a nested union.
+        let nested = GcRule {
+            rule: Some(Rule::Union(Union {
+                rules: vec![week, GcRule::union(vec![GcRule::max_versions(3)])],
+            })),
+        };
+        let existing = table(vec![
+            ("b", Some(GcRule::max_versions(1))),
+            ("c", None),
+            ("d", Some(nested)),
+            ("z", None),
+        ]);
+
+        let request = spec.diff(&existing).unwrap();
+        assert_eq!(request.name, TABLE);
+        assert_eq!(
+            request.modifications,
+            vec![
+                modification("a", Mod::Create(family(Some(GcRule::max_versions(1))))),
+                modification("b", Mod::Update(family(Some(GcRule::max_versions(2))))),
+            ]
+        );
+
+        let request = spec.clone().drop_unlisted(true).diff(&existing).unwrap();
+        assert_eq!(
+            request.modifications.last(),
+            Some(&modification("z", Mod::Drop(true)))
+        );
+
+        let matching = table(vec![
+            ("a", Some(GcRule::max_versions(1))),
+            ("b", Some(GcRule::max_versions(2))),
+            ("c", Some(GcRule::never())),
+            ("d", existing.column_families["d"].gc_rule.clone()),
+        ]);
+        assert_eq!(spec.diff(&matching), None);
+
+        // The rules only differ by the order of their operands.
+        let spec =
+            TableSpec::new().column_family("e", "maxversions=1 || maxage=1d".parse().unwrap());
+        let reordered = table(vec![(
+            "e",
+            Some("maxage=1d || maxversions=1".parse().unwrap()),
+        )]);
+        assert_eq!(spec.diff(&reordered), None);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile() {
+        let admin = Arc::new(FakeTableAdmin::default());
+        admin.set_table(table(vec![("old", None), ("keep", None)]));
+        let reconciler = Reconciler::new(admin.client());
+        let spec = TableSpec::new()
+            .column_family("keep", GcRule::max_versions(5))
+            .column_family("new", "maxage=1d".parse().unwrap())
+            .drop_unlisted(true);
+
+        let planned = reconciler
+            .clone()
+            .dry_run(true)
+            .reconcile(TABLE, &spec)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(admin.modifications().is_empty());
+        assert_eq!(planned.modifications.len(), 3);
+
+        let applied = reconciler.reconcile(TABLE, &spec).await.unwrap();
+        assert_eq!(applied, Some(planned));
+        assert_eq!(admin.modifications().len(), 1);
+        let table = admin.table();
+        let mut ids = table.column_families.keys().collect::<Vec<_>>();
+        ids.sort();
+        assert_eq!(ids, vec!["keep", "new"]);
+
+        // The table now matches the spec.
+        assert_eq!(reconciler.reconcile(TABLE, &spec).await.unwrap(), None);
+        assert_eq!(admin.modifications().len(), 1);
+
+        let error = reconciler
+            .reconcile("projects/p/instances/i/tables/missing", &spec)
+            .await
+            .unwrap_err();
+        assert_eq!(error.code(), tonic::Code::NotFound);
+    }
+}
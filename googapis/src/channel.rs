@@ -0,0 +1,299 @@
+//! Helpers for connecting a [`Channel`] to Google APIs or to their local emulators.
+//!
+//! The official emulators are configured with environment variables such as
+//! `PUBSUB_EMULATOR_HOST`. When the variable of a [`Service`] is set, [`Connection::from_env`]
+//! connects to the emulator with plaintext and without credentials, otherwise it connects to the
+//! production endpoint with TLS. This way the same binary works against both.
+//!
+//! # Example
+//! ```ignore
+//! use googapis::{
+//!     channel::{Connection, Service},
+//!     google::pubsub::v1::publisher_client::PublisherClient,
+//! };
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let token = gouth::Token::new()?;
+//! let conn = Connection::from_env(Service::Pubsub).await?;
+//! let client = PublisherClient::with_interceptor(
+//!     conn.channel(),
+//!     conn.interceptor(move || {
+//!         token
+//!             .header_value()
+//!             .map(|v| v.to_string())
+//!             .map_err(|e| tonic::Status::unauthenticated(e.to_string()))
+//!     }),
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{env, error, fmt};
+
+use tonic::{
+    metadata::MetadataValue,
+    transport::{Certificate, Channel, ClientTlsConfig, Endpoint},
+    Request, Status,
+};
+
+use crate::CERTIFICATES;
+
+/// A Google API service that has an official emulator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Service {
+    /// `google.pubsub.v1`, used by `publisher_client` and `subscriber_client`.
+    Pubsub,
+    /// `google.spanner.v1` and `google.spanner.admin.*`.
+    Spanner,
+    /// `google.firestore.v1` and `google.firestore.admin.*`.
+    Firestore,
+    /// `google.datastore.v1` and `google.datastore.admin.*`.
+    Datastore,
+    /// `google.bigtable.v2`, used by `bigtable_client`.
+    Bigtable,
+    /// `google.bigtable.admin.v2`, used by `bigtable_table_admin_client` and
+    /// `bigtable_instance_admin_client`.
+    BigtableAdmin,
+}
+
+impl Service {
+    /// Returns the domain name of the production endpoint.
+    pub fn domain_name(self) -> &'static str {
+        match self {
+            Service::Pubsub => "pubsub.googleapis.com",
+            Service::Spanner => "spanner.googleapis.com",
+            Service::Firestore => "firestore.googleapis.com",
+            Service::Datastore => "datastore.googleapis.com",
+            Service::Bigtable => "bigtable.googleapis.com",
+            Service::BigtableAdmin => "bigtableadmin.googleapis.com",
+        }
+    }
+
+    /// Returns the name of the environment variable holding the emulator address.
+    pub fn emulator_host_env(self) -> &'static str {
+        match self {
+            Service::Pubsub => "PUBSUB_EMULATOR_HOST",
+            Service::Spanner => "SPANNER_EMULATOR_HOST",
+            Service::Firestore => "FIRESTORE_EMULATOR_HOST",
+            Service::Datastore => "DATASTORE_EMULATOR_HOST",
+            Service::Bigtable | Service::BigtableAdmin => "BIGTABLE_EMULATOR_HOST",
+        }
+    }
+
+    /// Returns the emulator address if the environment variable is set and not empty.
+    pub fn emulator_host(self) -> Option<String> {
+        env::var(self.emulator_host_env())
+            .ok()
+            .map(|v| v.trim().to_owned())
+            .filter(|v| !v.is_empty())
+    }
+}
+
+/// Where a [`Connection`] goes to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// A production endpoint, connected with TLS.
+    Production {
+        /// The domain name of the endpoint, e.g. `spanner.googleapis.com`.
+        domain_name: String,
+    },
+    /// An emulator, connected with plaintext and no credentials.
+    Emulator {
+        /// The `host:port` of the emulator, e.g. `localhost:8085`.
+        host: String,
+    },
+}
+
+impl Target {
+    /// Returns the emulator target if its environment variable is set, otherwise the production
+    /// target of the service.
+    pub fn from_env(service: Service) -> Self {
+        match service.emulator_host() {
+            Some(host) => Target::emulator(host),
+            None => Target::production(service),
+        }
+    }
+
+    /// Returns the production target of the service.
+    pub fn production(service: Service) -> Self {
+        Target::Production {
+            domain_name: service.domain_name().to_owned(),
+        }
+    }
+
+    /// Returns an emulator target listening on `host`.
+    pub fn emulator(host: impl Into<String>) -> Self {
+        Target::Emulator { host: host.into() }
+    }
+
+    pub fn is_emulator(&self) -> bool {
+        matches!(self, Target::Emulator { .. })
+    }
+
+    /// Returns the URI of the target.
+    pub fn uri(&self) -> String {
+        match self {
+            Target::Production { domain_name } => format!("https://{}", domain_name),
+            Target::Emulator { host } if host.contains("://") => host.clone(),
+            Target::Emulator { host } => format!("http://{}", host),
+        }
+    }
+
+    /// Returns an endpoint for the target, configured with TLS for production targets.
+    pub fn endpoint(&self) -> Result<Endpoint, Error> {
+        let endpoint =
+            Channel::from_shared(self.uri()).map_err(|e| Error::InvalidUri(e.to_string()))?;
+        Ok(match self {
+            Target::Production { domain_name } => endpoint.tls_config(
+                ClientTlsConfig::new()
+                    .ca_certificate(Certificate::from_pem(CERTIFICATES))
+                    .domain_name(domain_name.as_str()),
+            ),
+            Target::Emulator { .. } => endpoint,
+        })
+    }
+}
+
+/// The error type of [`Connection`].
+#[derive(Debug)]
+pub enum Error {
+    InvalidUri(String),
+    Transport(tonic::transport::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidUri(e) => write!(f, "invalid uri: {}", e),
+            Error::Transport(e) => write!(f, "transport error: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::InvalidUri(_) => None,
+            Error::Transport(e) => Some(e),
+        }
+    }
+}
+
+impl From<tonic::transport::Error> for Error {
+    fn from(e: tonic::transport::Error) -> Self {
+        Error::Transport(e)
+    }
+}
+
+/// A connected [`Channel`] together with the [`Target`] it is connected to.
+#[derive(Debug, Clone)]
+pub struct Connection {
+    channel: Channel,
+    target: Target,
+}
+
+impl Connection {
+    /// Connects to the emulator if its environment variable is set, otherwise to production.
+    pub async fn from_env(service: Service) -> Result<Self, Error> {
+        Self::connect(Target::from_env(service)).await
+    }
+
+    /// Connects to the production endpoint regardless of the environment.
+    pub async fn production(service: Service) -> Result<Self, Error> {
+        Self::connect(Target::production(service)).await
+    }
+
+    /// Connects to an emulator listening on `host`.
+    pub async fn emulator(host: impl Into<String>) -> Result<Self, Error> {
+        Self::connect(Target::emulator(host)).await
+    }
+
+    pub async fn connect(target: Target) -> Result<Self, Error> {
+        let channel = target.endpoint()?.connect().await?;
+        Ok(Self { channel, target })
+    }
+
+    pub fn channel(&self) -> Channel {
+        self.channel.clone()
+    }
+
+    pub fn target(&self) -> &Target {
+        &self.target
+    }
+
+    pub fn is_emulator(&self) -> bool {
+        self.target.is_emulator()
+    }
+
+    /// Returns an interceptor for the generated `*_client::with_interceptor` constructors.
+    ///
+    /// For production targets the value returned by `authorization` (e.g. `Bearer ...`) is set to
+    /// the `authorization` header of each request. For emulator targets `authorization` is never
+    /// called and requests are sent without credentials.
+    pub fn interceptor<F>(
+        &self,
+        authorization: F,
+    ) -> impl Fn(Request<()>) -> Result<Request<()>, Status> + Send + Sync + 'static
+    where
+        F: Fn() -> Result<String, Status> + Send + Sync + 'static,
+    {
+        let emulator = self.is_emulator();
+        move |mut req: Request<()>| {
+            if !emulator {
+                let value = authorization()?;
+                let meta = MetadataValue::from_str(&value)
+                    .map_err(|e| Status::unauthenticated(e.to_string()))?;
+                req.metadata_mut().insert("authorization", meta);
+            }
+            Ok(req)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_uri() {
+        assert_eq!(
+            Target::production(Service::Spanner).uri(),
+            "https://spanner.googleapis.com"
+        );
+        assert_eq!(
+            Target::emulator("localhost:9010").uri(),
+            "http://localhost:9010"
+        );
+        assert_eq!(
+            Target::emulator("http://[::1]:8085").uri(),
+            "http://[::1]:8085"
+        );
+    }
+
+    #[test]
+    fn test_target_from_env() {
+        env::remove_var("DATASTORE_EMULATOR_HOST");
+        assert_eq!(
+            Target::from_env(Service::Datastore),
+            Target::production(Service::Datastore)
+        );
+
+        env::set_var("DATASTORE_EMULATOR_HOST", " ");
+        assert!(!Target::from_env(Service::Datastore).is_emulator());
+
+        env::set_var("DATASTORE_EMULATOR_HOST", "localhost:8081");
+        assert_eq!(
+            Target::from_env(Service::Datastore),
+            Target::emulator("localhost:8081")
+        );
+        env::remove_var("DATASTORE_EMULATOR_HOST");
+    }
+
+    #[test]
+    fn test_emulator_host_env() {
+        assert_eq!(
+            Service::Bigtable.emulator_host_env(),
+            Service::BigtableAdmin.emulator_host_env()
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! Conversions between the date and time messages and the [`chrono`] and [`time`] crates.
+//!
+//! The `chrono` and `time` features provide `TryFrom` conversions both ways between the
+//! `google.type` messages (`Date`, `DateTime`, `TimeOfDay`, `DayOfWeek`, `Month`) and the types
+//! of each crate, and conversions of the `prost_types::Timestamp` and `prost_types::Duration`
+//! well-known types.
+//!
+//! A `google.type.Date` may be partial, e.g. a credit card expiration date has no day. Such
+//! dates fail to convert to a calendar date with [`Error::PartialDate`], and are represented by
+//! [`PartialDate`] instead:
+//!
+//! ```ignore
+//! use std::convert::TryFrom;
+//! use googapis::{datetime::PartialDate, google::r#type::Date};
+//!
+//! let date = Date { year: 2020, month: 5, day: 0 };
+//! assert_eq!(
+//!     PartialDate::try_from(&date),
+//!     Ok(PartialDate::YearMonth { year: 2020, month: 5 })
+//! );
+//! ```
+//!
+//! [`chrono`]: https://docs.rs/chrono
+//! [`time`]: https://docs.rs/time
+
+use std::{error, fmt};
+
+#[cfg(feature = "chrono")]
+pub mod chrono;
+#[cfg(feature = "time")]
+pub mod time;
+
+/// An error converting a date or time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A field is out of range, e.g. a month of 13.
+    OutOfRange(&'static str),
+    /// The date has no year, month or day, see [`PartialDate`].
+    PartialDate,
+    /// The date time has neither a UTC offset nor a time zone.
+    MissingOffset,
+    /// The time zone is not supported, only `UTC` is.
+    UnsupportedTimeZone(String),
+    /// The enum value is unspecified or unknown.
+    Unspecified(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::OutOfRange(field) => write!(f, "{} is out of range", field),
+            Error::PartialDate => f.write_str("partial date"),
+            Error::MissingOffset => f.write_str("missing utc offset"),
+            Error::UnsupportedTimeZone(id) => write!(f, "unsupported time zone: {}", id),
+            Error::Unspecified(name) => write!(f, "unspecified {}", name),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+// Returns `value` if it is in `min..=max`.
+fn check(field: &'static str, value: i64, min: i64, max: i64) -> Result<i64, Error> {
+    if min <= value && value <= max {
+        Ok(value)
+    } else {
+        Err(Error::OutOfRange(field))
+    }
+}
+
+// The range of `google.protobuf.Timestamp`, 0001-01-01T00:00:00Z to 9999-12-31T23:59:59Z.
+const MIN_TIMESTAMP_SECONDS: i64 = -62_135_596_800;
+const MAX_TIMESTAMP_SECONDS: i64 = 253_402_300_799;
+
+// The range of `google.protobuf.Duration`, about +-10,000 years.
+const MAX_DURATION_SECONDS: i64 = 315_576_000_000;
+
+fn check_timestamp(ts: &prost_types::Timestamp) -> Result<(i64, u32), Error> {
+    let seconds = check(
+        "seconds",
+        ts.seconds,
+        MIN_TIMESTAMP_SECONDS,
+        MAX_TIMESTAMP_SECONDS,
+    )?;
+    let nanos = check("nanos", ts.nanos.into(), 0, 999_999_999)?;
+    Ok((seconds, nanos as u32))
+}
+
+fn check_duration(d: &prost_types::Duration) -> Result<(i64, i32), Error> {
+    let seconds = check(
+        "seconds",
+        d.seconds,
+        -MAX_DURATION_SECONDS,
+        MAX_DURATION_SECONDS,
+    )?;
+    let nanos = check("nanos", d.nanos.into(), -999_999_999, 999_999_999)?;
+    if (seconds < 0 && nanos > 0) || (seconds > 0 && nanos < 0) {
+        return Err(Error::OutOfRange("nanos"));
+    }
+    Ok((seconds, nanos as i32))
+}
+
+#[cfg(feature = "google-type")]
+pub use self::partial::PartialDate;
+
+#[cfg(feature = "google-type")]
+mod partial {
+    use std::convert::TryFrom;
+
+    use super::{check, Error};
+    use crate::google::r#type::{date_time::TimeOffset, Date, DateTime};
+
+    fn is_leap_year(year: i32) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    // February has 29 days when the year is unknown, e.g. a birthday on February 29.
+    fn days_in_month(year: Option<i32>, month: u32) -> u32 {
+        match month {
+            4 | 6 | 9 | 11 => 30,
+            2 => match year {
+                Some(year) if !is_leap_year(year) => 28,
+                _ => 29,
+            },
+            _ => 31,
+        }
+    }
+
+    /// A `google.type.Date` that may have no year, month or day.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum PartialDate {
+        /// A full date, e.g. a birthday.
+        Full { year: i32, month: u32, day: u32 },
+        /// A month and day without a year, e.g. an anniversary.
+        MonthDay { month: u32, day: u32 },
+        /// A year and month without a day, e.g. a credit card expiration date.
+        YearMonth { year: i32, month: u32 },
+        /// A year on its own.
+        Year(i32),
+    }
+
+    impl TryFrom<&Date> for PartialDate {
+        type Error = Error;
+
+        fn try_from(date: &Date) -> Result<Self, Self::Error> {
+            let year = check("year", date.year.into(), 0, 9999)? as i32;
+            let month = check("month", date.month.into(), 0, 12)? as u32;
+            let known_year = if year == 0 { None } else { Some(year) };
+            let max_day = if month == 0 {
+                0
+            } else {
+                days_in_month(known_year, month)
+            };
+            let day = check("day", date.day.into(), 0, max_day.into())? as u32;
+
+            match (year, month, day) {
+                (0, 0, _) => Err(Error::OutOfRange("year")),
+                (0, _, 0) => Err(Error::OutOfRange("day")),
+                (0, month, day) => Ok(PartialDate::MonthDay { month, day }),
+                (year, 0, _) => Ok(PartialDate::Year(year)),
+                (year, month, 0) => Ok(PartialDate::YearMonth { year, month }),
+                (year, month, day) => Ok(PartialDate::Full { year, month, day }),
+            }
+        }
+    }
+
+    impl TryFrom<Date> for PartialDate {
+        type Error = Error;
+
+        fn try_from(date: Date) -> Result<Self, Self::Error> {
+            Self::try_from(&date)
+        }
+    }
+
+    impl From<PartialDate> for Date {
+        fn from(date: PartialDate) -> Self {
+            let (year, month, day) = match date {
+                PartialDate::Full { year, month, day } => (year, month, day),
+                PartialDate::MonthDay { month, day } => (0, month, day),
+                PartialDate::YearMonth { year, month } => (year, month, 0),
+                PartialDate::Year(year) => (year, 0, 0),
+            };
+            Date {
+                year,
+                month: month as i32,
+                day: day as i32,
+            }
+        }
+    }
+
+    // Returns the full date of `date`.
+    pub(super) fn full_date(date: &Date) -> Result<(i32, u32, u32), Error> {
+        match PartialDate::try_from(date)? {
+            PartialDate::Full { year, month, day } => Ok((year, month, day)),
+            _ => Err(Error::PartialDate),
+        }
+    }
+
+    // Returns the hours, minutes, seconds and nanos of a time, with a second of 60 for a leap
+    // second.
+    pub(super) fn time_of_day(
+        hours: i32,
+        minutes: i32,
+        seconds: i32,
+        nanos: i32,
+    ) -> Result<(u32, u32, u32, u32), Error> {
+        Ok((
+            check("hours", hours.into(), 0, 23)? as u32,
+            check("minutes", minutes.into(), 0, 59)? as u32,
+            check("seconds", seconds.into(), 0, 60)? as u32,
+            check("nanos", nanos.into(), 0, 999_999_999)? as u32,
+        ))
+    }
+
+    // Returns the UTC offset in seconds of `dt`, `None` for local time.
+    pub(super) fn utc_offset(dt: &DateTime) -> Result<Option<i32>, Error> {
+        match &dt.time_offset {
+            None => Ok(None),
+            Some(TimeOffset::UtcOffset(d)) => {
+                if d.nanos != 0 {
+                    return Err(Error::OutOfRange("utc_offset"));
+                }
+                let seconds = check("utc_offset", d.seconds, -18 * 3600, 18 * 3600)?;
+                Ok(Some(seconds as i32))
+            }
+            Some(TimeOffset::TimeZone(tz)) => match tz.id.as_str() {
+                "UTC" | "Etc/UTC" => Ok(Some(0)),
+                id => Err(Error::UnsupportedTimeZone(id.to_owned())),
+            },
+        }
+    }
+
+    pub(super) fn offset(seconds: i32) -> Option<TimeOffset> {
+        Some(TimeOffset::UtcOffset(prost_types::Duration {
+            seconds: seconds.into(),
+            nanos: 0,
+        }))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn date(year: i32, month: i32, day: i32) -> Date {
+            Date { year, month, day }
+        }
+
+        #[test]
+        fn test_partial_date() {
+            assert_eq!(
+                PartialDate::try_from(date(2020, 5, 10)),
+                Ok(PartialDate::Full {
+                    year: 2020,
+                    month: 5,
+                    day: 10
+                })
+            );
+            assert_eq!(
+                PartialDate::try_from(date(0, 2, 29)),
+                Ok(PartialDate::MonthDay { month: 2, day: 29 })
+            );
+            assert_eq!(
+                PartialDate::try_from(date(2020, 5, 0)),
+                Ok(PartialDate::YearMonth {
+                    year: 2020,
+                    month: 5
+                })
+            );
+            assert_eq!(
+                PartialDate::try_from(date(2020, 0, 0)),
+                Ok(PartialDate::Year(2020))
+            );
+        }
+
+        #[test]
+        fn test_partial_date_invalid() {
+            assert_eq!(
+                PartialDate::try_from(date(0, 0, 0)),
+                Err(Error::OutOfRange("year"))
+            );
+            assert_eq!(
+                PartialDate::try_from(date(0, 5, 0)),
+                Err(Error::OutOfRange("day"))
+            );
+            assert_eq!(
+                PartialDate::try_from(date(2020, 0, 1)),
+                Err(Error::OutOfRange("day"))
+            );
+            assert_eq!(
+                PartialDate::try_from(date(2019, 2, 29)),
+                Err(Error::OutOfRange("day"))
+            );
+            assert_eq!(
+                PartialDate::try_from(date(2020, 13, 1)),
+                Err(Error::OutOfRange("month"))
+            );
+            assert_eq!(
+                PartialDate::try_from(date(10000, 1, 1)),
+                Err(Error::OutOfRange("year"))
+            );
+        }
+
+        #[test]
+        fn test_date_from_partial_date() {
+            for d in &[date(2020, 5, 10), date(0, 2, 29), date(2020, 5, 0)] {
+                assert_eq!(&Date::from(PartialDate::try_from(d).unwrap()), d);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "google-type")]
+use self::partial::{full_date, offset, time_of_day, utc_offset};
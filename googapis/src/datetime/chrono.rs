@@ -0,0 +1,518 @@
+//! Conversions to and from the [`chrono`](https://docs.rs/chrono) types.
+//!
+//! - `google.type.Date`: `NaiveDate`
+//! - `google.type.TimeOfDay`: `NaiveTime`
+//! - `google.type.DateTime`: `NaiveDateTime`, `DateTime<FixedOffset>`, `DateTime<Utc>`
+//! - `google.type.DayOfWeek`: `Weekday`
+//! - `google.type.Month`: `Month`
+//! - `google.protobuf.Timestamp`: `DateTime<Utc>`, see [`from_timestamp`]
+//! - `google.protobuf.Duration`: `Duration`, see [`from_duration`]
+
+use ::chrono::{DateTime, Duration, TimeZone, Utc};
+
+use super::{check, check_duration, check_timestamp, Error, MAX_DURATION_SECONDS};
+
+/// Converts a `google.protobuf.Timestamp`.
+pub fn from_timestamp(ts: &prost_types::Timestamp) -> Result<DateTime<Utc>, Error> {
+    let (seconds, nanos) = check_timestamp(ts)?;
+    Utc.timestamp_opt(seconds, nanos)
+        .single()
+        .ok_or(Error::OutOfRange("seconds"))
+}
+
+/// Converts to a `google.protobuf.Timestamp`, failing outside of years 1 to 9999.
+pub fn to_timestamp<Tz: TimeZone>(dt: &DateTime<Tz>) -> Result<prost_types::Timestamp, Error> {
+    let ts = prost_types::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: dt.timestamp_subsec_nanos() as i32,
+    };
+    // Leap seconds are represented by nanos over one second.
+    let ts = if ts.nanos >= 1_000_000_000 {
+        prost_types::Timestamp {
+            seconds: ts.seconds,
+            nanos: 999_999_999,
+        }
+    } else {
+        ts
+    };
+    check_timestamp(&ts)?;
+    Ok(ts)
+}
+
+/// Converts a `google.protobuf.Duration`.
+pub fn from_duration(d: &prost_types::Duration) -> Result<Duration, Error> {
+    let (seconds, nanos) = check_duration(d)?;
+    Ok(Duration::seconds(seconds) + Duration::nanoseconds(nanos.into()))
+}
+
+/// Converts to a `google.protobuf.Duration`, failing outside of about +-10,000 years.
+pub fn to_duration(d: Duration) -> Result<prost_types::Duration, Error> {
+    let seconds = check(
+        "seconds",
+        d.num_seconds(),
+        -MAX_DURATION_SECONDS,
+        MAX_DURATION_SECONDS,
+    )?;
+    let nanos = (d - Duration::seconds(seconds))
+        .num_nanoseconds()
+        .unwrap_or(0);
+    Ok(prost_types::Duration {
+        seconds,
+        nanos: nanos as i32,
+    })
+}
+
+#[cfg(feature = "google-type")]
+pub use self::types::period_start;
+
+#[cfg(feature = "google-type")]
+mod types {
+    use std::convert::TryFrom;
+
+    use ::chrono::{
+        DateTime as ChronoDateTime, Datelike, Duration, FixedOffset, Month as ChronoMonth,
+        NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc, Weekday,
+    };
+
+    use super::super::{full_date, offset, time_of_day, utc_offset, Error};
+    use crate::google::r#type::{CalendarPeriod, Date, DateTime, DayOfWeek, Month, TimeOfDay};
+
+    impl TryFrom<Date> for NaiveDate {
+        type Error = Error;
+
+        fn try_from(date: Date) -> Result<Self, Self::Error> {
+            let (year, month, day) = full_date(&date)?;
+            NaiveDate::from_ymd_opt(year, month, day).ok_or(Error::OutOfRange("day"))
+        }
+    }
+
+    impl TryFrom<NaiveDate> for Date {
+        type Error = Error;
+
+        fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+            if !(1..=9999).contains(&date.year()) {
+                return Err(Error::OutOfRange("year"));
+            }
+            Ok(Date {
+                year: date.year(),
+                month: date.month() as i32,
+                day: date.day() as i32,
+            })
+        }
+    }
+
+    // A leap second is represented by a second of 60 in `TimeOfDay`, and by nanos over one
+    // second in `NaiveTime`.
+    fn naive_time(hours: i32, minutes: i32, seconds: i32, nanos: i32) -> Result<NaiveTime, Error> {
+        let (h, m, s, n) = time_of_day(hours, minutes, seconds, nanos)?;
+        let (s, n) = if s == 60 {
+            (59, n + 1_000_000_000)
+        } else {
+            (s, n)
+        };
+        NaiveTime::from_hms_nano_opt(h, m, s, n).ok_or(Error::OutOfRange("seconds"))
+    }
+
+    fn time_fields(time: &NaiveTime) -> (i32, i32, i32, i32) {
+        let (s, n) = if time.nanosecond() >= 1_000_000_000 {
+            (60, time.nanosecond() - 1_000_000_000)
+        } else {
+            (time.second(), time.nanosecond())
+        };
+        (time.hour() as i32, time.minute() as i32, s as i32, n as i32)
+    }
+
+    impl TryFrom<TimeOfDay> for NaiveTime {
+        type Error = Error;
+
+        fn try_from(time: TimeOfDay) -> Result<Self, Self::Error> {
+            naive_time(time.hours, time.minutes, time.seconds, time.nanos)
+        }
+    }
+
+    impl TryFrom<NaiveTime> for TimeOfDay {
+        type Error = Error;
+
+        fn try_from(time: NaiveTime) -> Result<Self, Self::Error> {
+            let (hours, minutes, seconds, nanos) = time_fields(&time);
+            Ok(TimeOfDay {
+                hours,
+                minutes,
+                seconds,
+                nanos,
+            })
+        }
+    }
+
+    /// Converts the civil date and time, ignoring the UTC offset or time zone.
+    impl TryFrom<DateTime> for NaiveDateTime {
+        type Error = Error;
+
+        fn try_from(dt: DateTime) -> Result<Self, Self::Error> {
+            let date = Date {
+                year: dt.year,
+                month: dt.month,
+                day: dt.day,
+            };
+            Ok(NaiveDateTime::new(
+                NaiveDate::try_from(date)?,
+                naive_time(dt.hours, dt.minutes, dt.seconds, dt.nanos)?,
+            ))
+        }
+    }
+
+    /// Converts to a date time in local time, without a UTC offset.
+    impl TryFrom<NaiveDateTime> for DateTime {
+        type Error = Error;
+
+        fn try_from(dt: NaiveDateTime) -> Result<Self, Self::Error> {
+            let date = Date::try_from(dt.date())?;
+            let (hours, minutes, seconds, nanos) = time_fields(&dt.time());
+            Ok(DateTime {
+                year: date.year,
+                month: date.month,
+                day: date.day,
+                hours,
+                minutes,
+                seconds,
+                nanos,
+                time_offset: None,
+            })
+        }
+    }
+
+    /// Fails with [`Error::MissingOffset`] for a date time in local time.
+    impl TryFrom<DateTime> for ChronoDateTime<FixedOffset> {
+        type Error = Error;
+
+        fn try_from(dt: DateTime) -> Result<Self, Self::Error> {
+            let offset = utc_offset(&dt)?.ok_or(Error::MissingOffset)?;
+            let naive = NaiveDateTime::try_from(dt)?;
+            FixedOffset::east_opt(offset)
+                .ok_or(Error::OutOfRange("utc_offset"))?
+                .from_local_datetime(&naive)
+                .single()
+                .ok_or(Error::OutOfRange("utc_offset"))
+        }
+    }
+
+    impl TryFrom<DateTime> for ChronoDateTime<Utc> {
+        type Error = Error;
+
+        fn try_from(dt: DateTime) -> Result<Self, Self::Error> {
+            ChronoDateTime::<FixedOffset>::try_from(dt).map(|dt| dt.with_timezone(&Utc))
+        }
+    }
+
+    impl TryFrom<ChronoDateTime<FixedOffset>> for DateTime {
+        type Error = Error;
+
+        fn try_from(dt: ChronoDateTime<FixedOffset>) -> Result<Self, Self::Error> {
+            Ok(DateTime {
+                time_offset: offset(dt.offset().local_minus_utc()),
+                ..DateTime::try_from(dt.naive_local())?
+            })
+        }
+    }
+
+    impl TryFrom<ChronoDateTime<Utc>> for DateTime {
+        type Error = Error;
+
+        fn try_from(dt: ChronoDateTime<Utc>) -> Result<Self, Self::Error> {
+            Ok(DateTime {
+                time_offset: offset(0),
+                ..DateTime::try_from(dt.naive_utc())?
+            })
+        }
+    }
+
+    impl TryFrom<DayOfWeek> for Weekday {
+        type Error = Error;
+
+        fn try_from(day: DayOfWeek) -> Result<Self, Self::Error> {
+            Ok(match day {
+                DayOfWeek::Unspecified => return Err(Error::Unspecified("day of week")),
+                DayOfWeek::Monday => Weekday::Mon,
+                DayOfWeek::Tuesday => Weekday::Tue,
+                DayOfWeek::Wednesday => Weekday::Wed,
+                DayOfWeek::Thursday => Weekday::Thu,
+                DayOfWeek::Friday => Weekday::Fri,
+                DayOfWeek::Saturday => Weekday::Sat,
+                DayOfWeek::Sunday => Weekday::Sun,
+            })
+        }
+    }
+
+    impl From<Weekday> for DayOfWeek {
+        fn from(day: Weekday) -> Self {
+            match day {
+                Weekday::Mon => DayOfWeek::Monday,
+                Weekday::Tue => DayOfWeek::Tuesday,
+                Weekday::Wed => DayOfWeek::Wednesday,
+                Weekday::Thu => DayOfWeek::Thursday,
+                Weekday::Fri => DayOfWeek::Friday,
+                Weekday::Sat => DayOfWeek::Saturday,
+                Weekday::Sun => DayOfWeek::Sunday,
+            }
+        }
+    }
+
+    impl TryFrom<Month> for ChronoMonth {
+        type Error = Error;
+
+        fn try_from(month: Month) -> Result<Self, Self::Error> {
+            Ok(match month {
+                Month::Unspecified => return Err(Error::Unspecified("month")),
+                Month::January => ChronoMonth::January,
+                Month::February => ChronoMonth::February,
+                Month::March => ChronoMonth::March,
+                Month::April => ChronoMonth::April,
+                Month::May => ChronoMonth::May,
+                Month::June => ChronoMonth::June,
+                Month::July => ChronoMonth::July,
+                Month::August => ChronoMonth::August,
+                Month::September => ChronoMonth::September,
+                Month::October => ChronoMonth::October,
+                Month::November => ChronoMonth::November,
+                Month::December => ChronoMonth::December,
+            })
+        }
+    }
+
+    impl From<ChronoMonth> for Month {
+        fn from(month: ChronoMonth) -> Self {
+            match month {
+                ChronoMonth::January => Month::January,
+                ChronoMonth::February => Month::February,
+                ChronoMonth::March => Month::March,
+                ChronoMonth::April => Month::April,
+                ChronoMonth::May => Month::May,
+                ChronoMonth::June => Month::June,
+                ChronoMonth::July => Month::July,
+                ChronoMonth::August => Month::August,
+                ChronoMonth::September => Month::September,
+                ChronoMonth::October => Month::October,
+                ChronoMonth::November => Month::November,
+                ChronoMonth::December => Month::December,
+            }
+        }
+    }
+
+    /// Returns the first day of the `period` containing `date`.
+    ///
+    /// Weeks begin on Monday, and fortnights at the start of the odd ISO 8601 weeks.
+    pub fn period_start(period: CalendarPeriod, date: NaiveDate) -> Result<NaiveDate, Error> {
+        let week_start = date - Duration::days(date.weekday().num_days_from_monday().into());
+        let month_start =
+            |month| NaiveDate::from_ymd_opt(date.year(), month, 1).expect("valid month");
+        Ok(match period {
+            CalendarPeriod::Unspecified => return Err(Error::Unspecified("calendar period")),
+            CalendarPeriod::Day => date,
+            CalendarPeriod::Week => week_start,
+            CalendarPeriod::Fortnight if date.iso_week().week() % 2 == 1 => week_start,
+            CalendarPeriod::Fortnight => week_start - Duration::days(7),
+            CalendarPeriod::Month => month_start(date.month()),
+            CalendarPeriod::Quarter => month_start((date.month() - 1) / 3 * 3 + 1),
+            CalendarPeriod::Half => month_start((date.month() - 1) / 6 * 6 + 1),
+            CalendarPeriod::Year => month_start(1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::chrono::NaiveDate;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let ts = prost_types::Timestamp {
+            seconds: 1_589_068_800,
+            nanos: 500,
+        };
+        let dt = from_timestamp(&ts).unwrap();
+        assert_eq!(
+            dt,
+            Utc.from_utc_datetime(&ymd(2020, 5, 10).and_hms_nano_opt(0, 0, 0, 500).unwrap())
+        );
+        assert_eq!(to_timestamp(&dt).unwrap(), ts);
+
+        let ts = prost_types::Timestamp {
+            seconds: 0,
+            nanos: -1,
+        };
+        assert_eq!(from_timestamp(&ts), Err(Error::OutOfRange("nanos")));
+        let dt = Utc.from_utc_datetime(&ymd(10000, 1, 1).and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(to_timestamp(&dt), Err(Error::OutOfRange("seconds")));
+    }
+
+    #[test]
+    fn test_duration() {
+        let d = prost_types::Duration {
+            seconds: -1,
+            nanos: -500,
+        };
+        let chrono = from_duration(&d).unwrap();
+        assert_eq!(chrono, Duration::nanoseconds(-1_000_000_500));
+        assert_eq!(to_duration(chrono).unwrap(), d);
+
+        let d = prost_types::Duration {
+            seconds: 1,
+            nanos: -500,
+        };
+        assert_eq!(from_duration(&d), Err(Error::OutOfRange("nanos")));
+    }
+
+    #[cfg(feature = "google-type")]
+    mod types {
+        use super::{super::period_start, ymd};
+        use crate::{
+            datetime::Error,
+            google::r#type::{
+                date_time::TimeOffset, CalendarPeriod, Date, DateTime, DayOfWeek, TimeOfDay,
+                TimeZone as TimeZoneMessage,
+            },
+        };
+        use ::chrono::{
+            DateTime as ChronoDateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+            Utc, Weekday,
+        };
+        use std::convert::TryFrom;
+
+        fn date_time(time_offset: Option<TimeOffset>) -> DateTime {
+            DateTime {
+                year: 2020,
+                month: 5,
+                day: 10,
+                hours: 12,
+                minutes: 30,
+                seconds: 15,
+                nanos: 0,
+                time_offset,
+            }
+        }
+
+        #[test]
+        fn test_date() {
+            let date = Date {
+                year: 2020,
+                month: 2,
+                day: 29,
+            };
+            let naive = ymd(2020, 2, 29);
+            assert_eq!(NaiveDate::try_from(date.clone()), Ok(naive));
+            assert_eq!(Date::try_from(naive), Ok(date));
+
+            let partial = Date {
+                year: 0,
+                month: 2,
+                day: 29,
+            };
+            assert_eq!(NaiveDate::try_from(partial), Err(Error::PartialDate));
+            assert_eq!(Date::try_from(ymd(0, 1, 1)), Err(Error::OutOfRange("year")));
+        }
+
+        #[test]
+        fn test_time_of_day() {
+            let time = TimeOfDay {
+                hours: 23,
+                minutes: 59,
+                seconds: 60,
+                nanos: 5,
+            };
+            let naive = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_000_000_005).unwrap();
+            assert_eq!(NaiveTime::try_from(time.clone()), Ok(naive));
+            assert_eq!(TimeOfDay::try_from(naive), Ok(time));
+
+            let closing = TimeOfDay {
+                hours: 24,
+                ..Default::default()
+            };
+            assert_eq!(
+                NaiveTime::try_from(closing),
+                Err(Error::OutOfRange("hours"))
+            );
+        }
+
+        #[test]
+        fn test_date_time() {
+            let naive = NaiveDateTime::new(
+                ymd(2020, 5, 10),
+                NaiveTime::from_hms_opt(12, 30, 15).unwrap(),
+            );
+            assert_eq!(NaiveDateTime::try_from(date_time(None)), Ok(naive));
+            assert_eq!(DateTime::try_from(naive), Ok(date_time(None)));
+            assert_eq!(
+                ChronoDateTime::<Utc>::try_from(date_time(None)),
+                Err(Error::MissingOffset)
+            );
+
+            let utc_offset = TimeOffset::UtcOffset(prost_types::Duration {
+                seconds: -4 * 3600,
+                nanos: 0,
+            });
+            let dt = FixedOffset::west_opt(4 * 3600)
+                .unwrap()
+                .from_local_datetime(&naive)
+                .unwrap();
+            assert_eq!(
+                ChronoDateTime::<FixedOffset>::try_from(date_time(Some(utc_offset.clone()))),
+                Ok(dt)
+            );
+            assert_eq!(DateTime::try_from(dt), Ok(date_time(Some(utc_offset))));
+
+            let utc = TimeOffset::TimeZone(TimeZoneMessage {
+                id: "UTC".into(),
+                version: String::new(),
+            });
+            assert_eq!(
+                ChronoDateTime::<Utc>::try_from(date_time(Some(utc))),
+                Ok(Utc.from_utc_datetime(&naive))
+            );
+            let new_york = TimeOffset::TimeZone(TimeZoneMessage {
+                id: "America/New_York".into(),
+                version: String::new(),
+            });
+            assert_eq!(
+                ChronoDateTime::<Utc>::try_from(date_time(Some(new_york))),
+                Err(Error::UnsupportedTimeZone("America/New_York".into()))
+            );
+        }
+
+        #[test]
+        fn test_day_of_week() {
+            assert_eq!(Weekday::try_from(DayOfWeek::Sunday), Ok(Weekday::Sun));
+            assert_eq!(DayOfWeek::from(Weekday::Mon), DayOfWeek::Monday);
+            assert_eq!(
+                Weekday::try_from(DayOfWeek::Unspecified),
+                Err(Error::Unspecified("day of week"))
+            );
+        }
+
+        #[test]
+        fn test_period_start() {
+            // Thursday of the 19th week.
+            let date = ymd(2020, 5, 7);
+            let start = |period| period_start(period, date).unwrap();
+            assert_eq!(start(CalendarPeriod::Day), date);
+            assert_eq!(start(CalendarPeriod::Week), ymd(2020, 5, 4));
+            assert_eq!(start(CalendarPeriod::Fortnight), ymd(2020, 5, 4));
+            assert_eq!(
+                period_start(CalendarPeriod::Fortnight, ymd(2020, 5, 12)),
+                Ok(ymd(2020, 5, 4))
+            );
+            assert_eq!(start(CalendarPeriod::Month), ymd(2020, 5, 1));
+            assert_eq!(start(CalendarPeriod::Quarter), ymd(2020, 4, 1));
+            assert_eq!(start(CalendarPeriod::Half), ymd(2020, 1, 1));
+            assert_eq!(start(CalendarPeriod::Year), ymd(2020, 1, 1));
+            assert_eq!(
+                period_start(CalendarPeriod::Unspecified, date),
+                Err(Error::Unspecified("calendar period"))
+            );
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! Conversions to and from the [`time`](https://docs.rs/time) types.
+//!
+//! - `google.type.Date`: `Date`
+//! - `google.type.TimeOfDay`: `Time`, leap seconds are not supported
+//! - `google.type.DateTime`: `PrimitiveDateTime`, `OffsetDateTime`
+//! - `google.type.DayOfWeek`: `Weekday`
+//! - `google.protobuf.Timestamp`: `OffsetDateTime`, see [`from_timestamp`]
+//! - `google.protobuf.Duration`: `Duration`, see [`from_duration`]
+
+use ::time::{Duration, OffsetDateTime};
+
+use super::{check, check_duration, check_timestamp, Error, MAX_DURATION_SECONDS};
+
+/// Converts a `google.protobuf.Timestamp`.
+pub fn from_timestamp(ts: &prost_types::Timestamp) -> Result<OffsetDateTime, Error> {
+    let (seconds, nanos) = check_timestamp(ts)?;
+    Ok(OffsetDateTime::from_unix_timestamp(seconds) + Duration::nanoseconds(nanos.into()))
+}
+
+/// Converts to a `google.protobuf.Timestamp`, failing outside of years 1 to 9999.
+pub fn to_timestamp(dt: OffsetDateTime) -> Result<prost_types::Timestamp, Error> {
+    let ts = prost_types::Timestamp {
+        seconds: dt.unix_timestamp(),
+        nanos: dt.nanosecond() as i32,
+    };
+    check_timestamp(&ts)?;
+    Ok(ts)
+}
+
+/// Converts a `google.protobuf.Duration`.
+pub fn from_duration(d: &prost_types::Duration) -> Result<Duration, Error> {
+    let (seconds, nanos) = check_duration(d)?;
+    Ok(Duration::new(seconds, nanos))
+}
+
+/// Converts to a `google.protobuf.Duration`, failing outside of about +-10,000 years.
+pub fn to_duration(d: Duration) -> Result<prost_types::Duration, Error> {
+    let seconds = check(
+        "seconds",
+        d.whole_seconds(),
+        -MAX_DURATION_SECONDS,
+        MAX_DURATION_SECONDS,
+    )?;
+    Ok(prost_types::Duration {
+        seconds,
+        nanos: d.subsec_nanoseconds(),
+    })
+}
+
+#[cfg(feature = "google-type")]
+pub use self::types::period_start;
+
+#[cfg(feature = "google-type")]
+mod types {
+    use std::convert::TryFrom;
+
+    use ::time::{
+        Date as TimeDate, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
+    };
+
+    use super::super::{full_date, offset, time_of_day, utc_offset, Error};
+    use crate::google::r#type::{CalendarPeriod, Date, DateTime, DayOfWeek, TimeOfDay};
+
+    fn date(year: i32, month: u32, day: u32) -> Result<TimeDate, Error> {
+        TimeDate::try_from_ymd(year, month as u8, day as u8).map_err(|_| Error::OutOfRange("day"))
+    }
+
+    fn time(hours: i32, minutes: i32, seconds: i32, nanos: i32) -> Result<Time, Error> {
+        let (h, m, s, n) = time_of_day(hours, minutes, seconds, nanos)?;
+        Time::try_from_hms_nano(h as u8, m as u8, s as u8, n)
+            .map_err(|_| Error::OutOfRange("seconds"))
+    }
+
+    impl TryFrom<Date> for TimeDate {
+        type Error = Error;
+
+        fn try_from(d: Date) -> Result<Self, Self::Error> {
+            let (year, month, day) = full_date(&d)?;
+            date(year, month, day)
+        }
+    }
+
+    impl TryFrom<TimeDate> for Date {
+        type Error = Error;
+
+        fn try_from(date: TimeDate) -> Result<Self, Self::Error> {
+            if !(1..=9999).contains(&date.year()) {
+                return Err(Error::OutOfRange("year"));
+            }
+            Ok(Date {
+                year: date.year(),
+                month: date.month().into(),
+                day: date.day().into(),
+            })
+        }
+    }
+
+    impl TryFrom<TimeOfDay> for Time {
+        type Error = Error;
+
+        fn try_from(t: TimeOfDay) -> Result<Self, Self::Error> {
+            time(t.hours, t.minutes, t.seconds, t.nanos)
+        }
+    }
+
+    impl From<Time> for TimeOfDay {
+        fn from(time: Time) -> Self {
+            TimeOfDay {
+                hours: time.hour().into(),
+                minutes: time.minute().into(),
+                seconds: time.second().into(),
+                nanos: time.nanosecond() as i32,
+            }
+        }
+    }
+
+    /// Converts the civil date and time, ignoring the UTC offset or time zone.
+    impl TryFrom<DateTime> for PrimitiveDateTime {
+        type Error = Error;
+
+        fn try_from(dt: DateTime) -> Result<Self, Self::Error> {
+            let d = Date {
+                year: dt.year,
+                month: dt.month,
+                day: dt.day,
+            };
+            Ok(PrimitiveDateTime::new(
+                TimeDate::try_from(d)?,
+                time(dt.hours, dt.minutes, dt.seconds, dt.nanos)?,
+            ))
+        }
+    }
+
+    /// Converts to a date time in local time, without a UTC offset.
+    impl TryFrom<PrimitiveDateTime> for DateTime {
+        type Error = Error;
+
+        fn try_from(dt: PrimitiveDateTime) -> Result<Self, Self::Error> {
+            let date = Date::try_from(dt.date())?;
+            Ok(DateTime {
+                year: date.year,
+                month: date.month,
+                day: date.day,
+                hours: dt.hour().into(),
+                minutes: dt.minute().into(),
+                seconds: dt.second().into(),
+                nanos: dt.nanosecond() as i32,
+                time_offset: None,
+            })
+        }
+    }
+
+    /// Fails with [`Error::MissingOffset`] for a date time in local time.
+    impl TryFrom<DateTime> for OffsetDateTime {
+        type Error = Error;
+
+        fn try_from(dt: DateTime) -> Result<Self, Self::Error> {
+            let offset = utc_offset(&dt)?.ok_or(Error::MissingOffset)?;
+            let primitive = PrimitiveDateTime::try_from(dt)?;
+            Ok(primitive.assume_offset(UtcOffset::seconds(offset)))
+        }
+    }
+
+    impl TryFrom<OffsetDateTime> for DateTime {
+        type Error = Error;
+
+        fn try_from(dt: OffsetDateTime) -> Result<Self, Self::Error> {
+            let primitive = PrimitiveDateTime::new(dt.date(), dt.time());
+            Ok(DateTime {
+                time_offset: offset(dt.offset().as_seconds()),
+                ..DateTime::try_from(primitive)?
+            })
+        }
+    }
+
+    impl TryFrom<DayOfWeek> for Weekday {
+        type Error = Error;
+
+        fn try_from(day: DayOfWeek) -> Result<Self, Self::Error> {
+            Ok(match day {
+                DayOfWeek::Unspecified => return Err(Error::Unspecified("day of week")),
+                DayOfWeek::Monday => Weekday::Monday,
+                DayOfWeek::Tuesday => Weekday::Tuesday,
+                DayOfWeek::Wednesday => Weekday::Wednesday,
+                DayOfWeek::Thursday => Weekday::Thursday,
+                DayOfWeek::Friday => Weekday::Friday,
+                DayOfWeek::Saturday => Weekday::Saturday,
+                DayOfWeek::Sunday => Weekday::Sunday,
+            })
+        }
+    }
+
+    impl From<Weekday> for DayOfWeek {
+        fn from(day: Weekday) -> Self {
+            match day {
+                Weekday::Monday => DayOfWeek::Monday,
+                Weekday::Tuesday => DayOfWeek::Tuesday,
+                Weekday::Wednesday => DayOfWeek::Wednesday,
+                Weekday::Thursday => DayOfWeek::Thursday,
+                Weekday::Friday => DayOfWeek::Friday,
+                Weekday::Saturday => DayOfWeek::Saturday,
+                Weekday::Sunday => DayOfWeek::Sunday,
+            }
+        }
+    }
+
+    /// Returns the first day of the `period` containing `date`.
+    ///
+    /// Weeks begin on Monday, and fortnights at the start of the odd ISO 8601 weeks.
+    pub fn period_start(period: CalendarPeriod, date: TimeDate) -> Result<TimeDate, Error> {
+        let week_start = date - Duration::days(date.weekday().number_days_from_monday().into());
+        let month_start =
+            |month| TimeDate::try_from_ymd(date.year(), month, 1).expect("valid month");
+        Ok(match period {
+            CalendarPeriod::Unspecified => return Err(Error::Unspecified("calendar period")),
+            CalendarPeriod::Day => date,
+            CalendarPeriod::Week => week_start,
+            CalendarPeriod::Fortnight if date.iso_year_week().1 % 2 == 1 => week_start,
+            CalendarPeriod::Fortnight => week_start - Duration::days(7),
+            CalendarPeriod::Month => month_start(date.month()),
+            CalendarPeriod::Quarter => month_start((date.month() - 1) / 3 * 3 + 1),
+            CalendarPeriod::Half => month_start((date.month() - 1) / 6 * 6 + 1),
+            CalendarPeriod::Year => month_start(1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp() {
+        let ts = prost_types::Timestamp {
+            seconds: 1_589_068_800,
+            nanos: 500,
+        };
+        let dt = from_timestamp(&ts).unwrap();
+        assert_eq!(dt.unix_timestamp(), 1_589_068_800);
+        assert_eq!(dt.nanosecond(), 500);
+        assert_eq!(to_timestamp(dt).unwrap(), ts);
+
+        let ts = prost_types::Timestamp {
+            seconds: 253_402_300_800,
+            nanos: 0,
+        };
+        assert_eq!(from_timestamp(&ts), Err(Error::OutOfRange("seconds")));
+    }
+
+    #[test]
+    fn test_duration() {
+        let d = prost_types::Duration {
+            seconds: -1,
+            nanos: -500,
+        };
+        let time = from_duration(&d).unwrap();
+        assert_eq!(time, Duration::nanoseconds(-1_000_000_500));
+        assert_eq!(to_duration(time).unwrap(), d);
+    }
+
+    #[cfg(feature = "google-type")]
+    mod types {
+        use super::super::period_start;
+        use crate::{
+            datetime::Error,
+            google::r#type::{
+                date_time::TimeOffset, CalendarPeriod, Date, DateTime, DayOfWeek, TimeOfDay,
+            },
+        };
+        use ::time::{
+            Date as TimeDate, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
+        };
+        use std::convert::TryFrom;
+
+        fn ymd(year: i32, month: u8, day: u8) -> TimeDate {
+            TimeDate::try_from_ymd(year, month, day).unwrap()
+        }
+
+        #[test]
+        fn test_date() {
+            let date = Date {
+                year: 2020,
+                month: 2,
+                day: 29,
+            };
+            assert_eq!(TimeDate::try_from(date.clone()), Ok(ymd(2020, 2, 29)));
+            assert_eq!(Date::try_from(ymd(2020, 2, 29)), Ok(date));
+
+            let partial = Date {
+                year: 2020,
+                month: 2,
+                day: 0,
+            };
+            assert_eq!(TimeDate::try_from(partial), Err(Error::PartialDate));
+        }
+
+        #[test]
+        fn test_time_of_day() {
+            let time = TimeOfDay {
+                hours: 12,
+                minutes: 30,
+                seconds: 15,
+                nanos: 5,
+            };
+            let t = Time::try_from_hms_nano(12, 30, 15, 5).unwrap();
+            assert_eq!(Time::try_from(time.clone()), Ok(t));
+            assert_eq!(TimeOfDay::from(t), time);
+
+            let leap = TimeOfDay {
+                hours: 23,
+                minutes: 59,
+                seconds: 60,
+                nanos: 0,
+            };
+            assert_eq!(Time::try_from(leap), Err(Error::OutOfRange("seconds")));
+        }
+
+        #[test]
+        fn test_date_time() {
+            let dt = DateTime {
+                year: 2020,
+                month: 5,
+                day: 10,
+                hours: 12,
+                minutes: 30,
+                seconds: 15,
+                nanos: 0,
+                time_offset: None,
+            };
+            let primitive =
+                PrimitiveDateTime::new(ymd(2020, 5, 10), Time::try_from_hms(12, 30, 15).unwrap());
+            assert_eq!(PrimitiveDateTime::try_from(dt.clone()), Ok(primitive));
+            assert_eq!(DateTime::try_from(primitive), Ok(dt.clone()));
+            assert_eq!(
+                OffsetDateTime::try_from(dt.clone()),
+                Err(Error::MissingOffset)
+            );
+
+            let dt = DateTime {
+                time_offset: Some(TimeOffset::UtcOffset(prost_types::Duration {
+                    seconds: 9 * 3600,
+                    nanos: 0,
+                })),
+                ..dt
+            };
+            let offset = primitive.assume_offset(UtcOffset::hours(9));
+            assert_eq!(OffsetDateTime::try_from(dt.clone()), Ok(offset));
+            assert_eq!(offset.unix_timestamp(), 1_589_081_415);
+            assert_eq!(DateTime::try_from(offset), Ok(dt));
+        }
+
+        #[test]
+        fn test_day_of_week() {
+            assert_eq!(Weekday::try_from(DayOfWeek::Sunday), Ok(Weekday::Sunday));
+            assert_eq!(DayOfWeek::from(Weekday::Monday), DayOfWeek::Monday);
+        }
+
+        #[test]
+        fn test_period_start() {
+            let date = ymd(2020, 5, 12);
+            let start = |period| period_start(period, date).unwrap();
+            assert_eq!(start(CalendarPeriod::Week), ymd(2020, 5, 11));
+            assert_eq!(start(CalendarPeriod::Fortnight), ymd(2020, 5, 4));
+            assert_eq!(start(CalendarPeriod::Quarter), ymd(2020, 4, 1));
+            assert_eq!(start(CalendarPeriod::Year), ymd(2020, 1, 1));
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! High-level Firestore clients on top of [`google.firestore.v1`].
+//!
+//! The `firestore` feature provides conversions of Rust types implementing `Serialize` and
+//! `Deserialize` to and from the fields of [`Document`]s in [`value`], and a builder of structured
+//! queries in [`query`], which checks them before they are run. A [`listen::Listener`] listens
+//! to queries and documents in real time, and returns consistent snapshots of their documents
+//! with the changes since the previous ones.
+//!
+//! ```ignore
+//! use googapis::{
+//!     firestore::value,
+//!     google::firestore::v1::{firestore_client::FirestoreClient, CreateDocumentRequest},
+//! };
+//!
+//! let document = FirestoreClient::new(channel)
+//!     .create_document(CreateDocumentRequest {
+//!         parent: "projects/my-project/databases/(default)/documents".into(),
+//!         collection_id: "cities".into(),
+//!         document: Some(Document {
+//!             fields: value::to_fields(&city)?,
+//!             ..Default::default()
+//!         }),
+//!         ..Default::default()
+//!     })
+//!     .await?
+//!     .into_inner();
+//! let city: City = value::from_fields(&document.fields)?;
+//! ```
+//!
+//! [`Document`]: crate::google::firestore::v1::Document
+//! [`google.firestore.v1`]: https://github.com/googleapis/googleapis/tree/master/google/firestore/v1
+
+#[cfg(test)]
+mod fake;
+pub mod listen;
+pub mod query;
+pub mod value;
+
+use std::collections::HashMap;
+
+use crate::google::firestore::v1::{value::ValueType, Value};
+
+// Quotes a field name with backticks, unless it is a simple name, as in field paths.
+pub(crate) fn quote_field_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let simple = match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    };
+    if simple {
+        return name.to_owned();
+    }
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push('`');
+    for c in name.chars() {
+        if c == '`' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('`');
+    quoted
+}
+
+// Splits a field path into its field names, e.g. ``address.`zip code` `` into `address` and
+// `zip code`, or returns `None` when it is invalid.
+pub(crate) fn split_field_path(path: &str) -> Option<Vec<String>> {
+    let mut names = Vec::new();
+    let mut chars = path.chars().peekable();
+    loop {
+        let mut name = String::new();
+        if chars.peek() == Some(&'`') {
+            chars.next();
+            loop {
+                match chars.next()? {
+                    '`' => break,
+                    '\\' => name.push(chars.next()?),
+                    c => name.push(c),
+                }
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c == '.' {
+                    break;
+                }
+                name.push(c);
+                chars.next();
+            }
+            if name.is_empty() {
+                return None;
+            }
+        }
+        names.push(name);
+        match chars.next() {
+            Some('.') => continue,
+            Some(_) => return None,
+            None => return Some(names),
+        }
+    }
+}
+
+// Returns the value of the field at `path` in `fields`, looking into map values.
+pub(crate) fn get_field<'a>(fields: &'a HashMap<String, Value>, path: &str) -> Option<&'a Value> {
+    let names = split_field_path(path)?;
+    let (last, names) = names.split_last()?;
+    let mut fields = fields;
+    for name in names {
+        match &fields.get(name)?.value_type {
+            Some(ValueType::MapValue(map)) => fields = &map.fields,
+            _ => return None,
+        }
+    }
+    fields.get(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_field_name() {
+        assert_eq!(quote_field_name("zip"), "zip");
+        assert_eq!(quote_field_name("_zip2"), "_zip2");
+        assert_eq!(quote_field_name("2zip"), "`2zip`");
+        assert_eq!(quote_field_name("zip code"), "`zip code`");
+        assert_eq!(quote_field_name("a`b\\c"), "`a\\`b\\\\c`");
+        assert_eq!(quote_field_name(""), "``");
+    }
+
+    #[test]
+    fn test_split_field_path() {
+        assert_eq!(split_field_path("zip"), Some(vec!["zip".to_owned()]));
+        assert_eq!(
+            split_field_path("address.`zip code`.`a\\`b`"),
+            Some(vec![
+                "address".to_owned(),
+                "zip code".to_owned(),
+                "a`b".to_owned()
+            ])
+        );
+        assert_eq!(split_field_path("``"), Some(vec!["".to_owned()]));
+        assert_eq!(split_field_path(""), None);
+        assert_eq!(split_field_path("a..b"), None);
+        assert_eq!(split_field_path("a."), None);
+        assert_eq!(split_field_path("`a"), None);
+        assert_eq!(split_field_path("`a`b"), None);
+    }
+}
@@ -0,0 +1,191 @@
+// An in-memory fake of the Firestore service for the tests of the `firestore` module.
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use futures::{stream, Stream, StreamExt};
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::google::firestore::v1::{
+    firestore_client::FirestoreClient, firestore_server, BatchGetDocumentsRequest,
+    BatchGetDocumentsResponse, BeginTransactionRequest, BeginTransactionResponse, CommitRequest,
+    CommitResponse, CreateDocumentRequest, DeleteDocumentRequest, Document, GetDocumentRequest,
+    ListCollectionIdsRequest, ListCollectionIdsResponse, ListDocumentsRequest,
+    ListDocumentsResponse, ListenRequest, ListenResponse, RollbackRequest, RunQueryRequest,
+    RunQueryResponse, UpdateDocumentRequest, WriteRequest, WriteResponse,
+};
+
+type ResponseStream<T> = tokio::stream::Iter<std::vec::IntoIter<Result<T, Status>>>;
+
+type ListenResponseStream =
+    Pin<Box<dyn Stream<Item = Result<ListenResponse, Status>> + Send + Sync>>;
+
+pub(crate) type FakeServer = firestore_server::FirestoreServer<Arc<FakeFirestore>>;
+
+#[derive(Default)]
+struct State {
+    queries: Vec<RunQueryRequest>,
+    // The responses of the next calls of `run_query`.
+    query_responses: VecDeque<Vec<Result<RunQueryResponse, Status>>>,
+    // The requests of the calls of `listen`.
+    listens: Vec<Vec<ListenRequest>>,
+    // The responses of the next calls of `listen`.
+    listen_responses: VecDeque<Vec<Result<ListenResponse, Status>>>,
+}
+
+#[derive(Default)]
+pub(crate) struct FakeFirestore {
+    state: Mutex<State>,
+}
+
+impl FakeFirestore {
+    pub(crate) fn client(self: &Arc<Self>) -> FirestoreClient<FakeServer> {
+        FirestoreClient::new(firestore_server::FirestoreServer::new(self.clone()))
+    }
+
+    // The requests of the calls of `run_query`.
+    pub(crate) fn queries(&self) -> Vec<RunQueryRequest> {
+        self.state.lock().unwrap().queries.clone()
+    }
+
+    // Streams `responses` to the next call of `run_query`.
+    pub(crate) fn respond_to_query(&self, responses: Vec<Result<RunQueryResponse, Status>>) {
+        let mut state = self.state.lock().unwrap();
+        state.query_responses.push_back(responses);
+    }
+
+    // The requests received by the calls of `listen`.
+    pub(crate) fn listens(&self) -> Vec<Vec<ListenRequest>> {
+        self.state.lock().unwrap().listens.clone()
+    }
+
+    // Streams `responses` to the next call of `listen`, and then keeps the stream open.
+    pub(crate) fn respond_to_listen(&self, responses: Vec<Result<ListenResponse, Status>>) {
+        let mut state = self.state.lock().unwrap();
+        state.listen_responses.push_back(responses);
+    }
+}
+
+fn unimplemented<T>() -> Result<Response<T>, Status> {
+    Err(Status::unimplemented("not implemented by the fake"))
+}
+
+#[tonic::async_trait]
+impl firestore_server::Firestore for Arc<FakeFirestore> {
+    async fn get_document(
+        &self,
+        _: Request<GetDocumentRequest>,
+    ) -> Result<Response<Document>, Status> {
+        unimplemented()
+    }
+
+    async fn list_documents(
+        &self,
+        _: Request<ListDocumentsRequest>,
+    ) -> Result<Response<ListDocumentsResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn update_document(
+        &self,
+        _: Request<UpdateDocumentRequest>,
+    ) -> Result<Response<Document>, Status> {
+        unimplemented()
+    }
+
+    async fn delete_document(
+        &self,
+        _: Request<DeleteDocumentRequest>,
+    ) -> Result<Response<()>, Status> {
+        unimplemented()
+    }
+
+    type BatchGetDocumentsStream = ResponseStream<BatchGetDocumentsResponse>;
+
+    async fn batch_get_documents(
+        &self,
+        _: Request<BatchGetDocumentsRequest>,
+    ) -> Result<Response<Self::BatchGetDocumentsStream>, Status> {
+        unimplemented()
+    }
+
+    async fn begin_transaction(
+        &self,
+        _: Request<BeginTransactionRequest>,
+    ) -> Result<Response<BeginTransactionResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn commit(&self, _: Request<CommitRequest>) -> Result<Response<CommitResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn rollback(&self, _: Request<RollbackRequest>) -> Result<Response<()>, Status> {
+        unimplemented()
+    }
+
+    type RunQueryStream = ResponseStream<RunQueryResponse>;
+
+    // Streams the responses given to `respond_to_query`, or nothing.
+    async fn run_query(
+        &self,
+        request: Request<RunQueryRequest>,
+    ) -> Result<Response<Self::RunQueryStream>, Status> {
+        let mut state = self.state.lock().unwrap();
+        state.queries.push(request.into_inner());
+        let responses = state.query_responses.pop_front().unwrap_or_default();
+        Ok(Response::new(tokio::stream::iter(responses)))
+    }
+
+    type WriteStream = ResponseStream<WriteResponse>;
+
+    async fn write(
+        &self,
+        _: Request<Streaming<WriteRequest>>,
+    ) -> Result<Response<Self::WriteStream>, Status> {
+        unimplemented()
+    }
+
+    type ListenStream = ListenResponseStream;
+
+    // Waits for the first request, and streams the responses given to `respond_to_listen`, or
+    // fails with `UNAVAILABLE` when there are none.
+    async fn listen(
+        &self,
+        request: Request<Streaming<ListenRequest>>,
+    ) -> Result<Response<Self::ListenStream>, Status> {
+        let mut requests = request.into_inner();
+        let first = requests.message().await?;
+        let (index, responses) = {
+            let mut state = self.state.lock().unwrap();
+            state.listens.push(first.into_iter().collect());
+            (state.listens.len() - 1, state.listen_responses.pop_front())
+        };
+        let fake = self.clone();
+        tokio::spawn(async move {
+            while let Ok(Some(request)) = requests.message().await {
+                fake.state.lock().unwrap().listens[index].push(request);
+            }
+        });
+        let responses = responses.ok_or_else(|| Status::unavailable("no listen responses"))?;
+        let responses = stream::iter(responses).chain(stream::pending());
+        Ok(Response::new(Box::pin(responses)))
+    }
+
+    async fn list_collection_ids(
+        &self,
+        _: Request<ListCollectionIdsRequest>,
+    ) -> Result<Response<ListCollectionIdsResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn create_document(
+        &self,
+        _: Request<CreateDocumentRequest>,
+    ) -> Result<Response<Document>, Status> {
+        unimplemented()
+    }
+}
@@ -0,0 +1,958 @@
+//! Real-time snapshots of queries and documents with `Listen`.
+//!
+//! The server streams the changes of the documents of the targets of a [`Listener`], and marks
+//! the targets `CURRENT` once it has sent all their changes committed before they were added.
+//! The changes are only applied at the consistent points of the whole stream, signalled by a
+//! target change without targets and with a read time. At these points the listener applies the
+//! changes of the current targets to their views, and returns a [`QuerySnapshot`] for each target
+//! whose documents changed, and for the first snapshot of each target.
+//!
+//! When the server sends an existence filter whose count is not the number of documents of a
+//! target, the documents of the target are reset and the stream is reopened to get them again.
+//! When the stream fails with a retryable error, it is reopened with the resume tokens of the last
+//! consistent point, and the server sends the changes after it.
+//!
+//! ```ignore
+//! use futures::TryStreamExt;
+//! use googapis::{
+//!     firestore::{listen::{self, Listener}, query::{Operator, Query}},
+//!     google::firestore::v1::firestore_client::FirestoreClient,
+//! };
+//!
+//! let query = Query::collection("projects/my-project/databases/(default)/documents/cities")
+//!     .filter("capital", Operator::Equal, &true);
+//! let mut snapshots = Listener::listen(
+//!     FirestoreClient::new(channel),
+//!     "projects/my-project/databases/(default)",
+//!     vec![listen::query_target(1, query)?],
+//! );
+//! while let Some(snapshot) = snapshots.try_next().await? {
+//!     for change in snapshot.changes {
+//!         println!("{:?} {}", change.kind, change.document.name);
+//!     }
+//! }
+//! ```
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    future::Future,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    future::BoxFuture,
+    stream::{self, BoxStream, Stream},
+    FutureExt, StreamExt,
+};
+use tonic::{Code, Request, Status};
+
+use super::{
+    get_field,
+    query::{self, order_field, result_orders, Direction, Query, DOCUMENT_ID},
+    value,
+};
+use crate::{
+    google::firestore::v1::{
+        firestore_client::FirestoreClient,
+        listen_request,
+        listen_response::ResponseType,
+        run_query_request,
+        structured_query::Order,
+        target::{query_target, DocumentsTarget, QueryTarget, ResumeType, TargetType},
+        target_change::TargetChangeType,
+        Document, ExistenceFilter, ListenRequest, ListenResponse, Target, TargetChange,
+    },
+    retry::{self, Backoff, RetrySettings},
+};
+
+type Open =
+    Box<dyn FnMut(Vec<ListenRequest>) -> BoxFuture<'static, Result<ResponseStream, Status>> + Send>;
+type ResponseStream = BoxStream<'static, Result<ListenResponse, Status>>;
+
+/// Returns a target listening to the documents of `query`.
+pub fn query_target(target_id: i32, query: Query) -> Result<Target, query::Error> {
+    let request = query.build()?;
+    let query_type = request.query_type.map(|query_type| match query_type {
+        run_query_request::QueryType::StructuredQuery(query) => {
+            query_target::QueryType::StructuredQuery(query)
+        }
+    });
+    Ok(Target {
+        target_id,
+        target_type: Some(TargetType::Query(QueryTarget {
+            parent: request.parent,
+            query_type,
+        })),
+        ..Default::default()
+    })
+}
+
+/// Returns a target listening to the documents with the names `documents`.
+pub fn documents_target<I>(target_id: i32, documents: I) -> Target
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    Target {
+        target_id,
+        target_type: Some(TargetType::Documents(DocumentsTarget {
+            documents: documents.into_iter().map(Into::into).collect(),
+        })),
+        ..Default::default()
+    }
+}
+
+/// The kind of the change of a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Removed,
+}
+
+/// A change of a document of a target.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// The document after the change, or before it was removed.
+    pub document: Document,
+    /// The index of the document in the documents of the target before the change, if it was
+    /// not added.
+    pub old_index: Option<usize>,
+    /// The index of the document in the documents of the target after the change, if it was not
+    /// removed.
+    pub new_index: Option<usize>,
+}
+
+/// The documents of a target at a consistent point of the stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuerySnapshot {
+    pub target_id: i32,
+    pub read_time: prost_types::Timestamp,
+    /// The documents, in the order of the query of the target, or by name.
+    pub documents: Vec<Document>,
+    /// The changes since the previous snapshot of the target. Applying them in order to the
+    /// documents of the previous snapshot, at their indices, gives the documents.
+    pub changes: Vec<Change>,
+}
+
+// The documents of a target, and their changes since the last consistent point.
+#[derive(Debug)]
+struct View {
+    target: Target,
+    orders: Vec<Order>,
+    // The documents sorted by `orders`, and by name.
+    documents: Vec<Document>,
+    names: HashMap<String, Document>,
+    // The changed documents, or `None` for the removed ones.
+    changes: HashMap<String, Option<Document>>,
+    current: bool,
+    // The resume token of the last consistent point, and the last one received.
+    resume_token: Vec<u8>,
+    next_resume_token: Vec<u8>,
+    snapshots: usize,
+}
+
+impl View {
+    fn new(target: Target) -> Self {
+        let orders = match &target.target_type {
+            Some(TargetType::Query(QueryTarget {
+                query_type: Some(query_target::QueryType::StructuredQuery(query)),
+                ..
+            })) => result_orders(query),
+            _ => Vec::new(),
+        };
+        Self {
+            target,
+            orders,
+            documents: Vec::new(),
+            names: HashMap::new(),
+            changes: HashMap::new(),
+            current: false,
+            resume_token: Vec::new(),
+            next_resume_token: Vec::new(),
+            snapshots: 0,
+        }
+    }
+
+    fn request(&self, database: &str) -> ListenRequest {
+        let resume_type = if self.resume_token.is_empty() {
+            None
+        } else {
+            Some(ResumeType::ResumeToken(self.resume_token.clone()))
+        };
+        ListenRequest {
+            database: database.to_owned(),
+            target_change: Some(listen_request::TargetChange::AddTarget(Target {
+                resume_type,
+                ..self.target.clone()
+            })),
+            ..Default::default()
+        }
+    }
+
+    // Removes all the documents, which the server sends again from scratch.
+    fn reset(&mut self) {
+        self.changes = self.names.keys().map(|name| (name.clone(), None)).collect();
+        self.current = false;
+        self.resume_token.clear();
+        self.next_resume_token.clear();
+    }
+
+    // Prepares the view for a reopened stream.
+    fn reopen(&mut self) {
+        if self.resume_token.is_empty() {
+            self.reset();
+        } else {
+            self.current = false;
+            self.next_resume_token.clear();
+        }
+    }
+
+    // The number of documents with the changes applied.
+    fn size(&self) -> usize {
+        let mut size = self.names.len();
+        for (name, change) in &self.changes {
+            match (self.names.contains_key(name), change) {
+                (true, None) => size -= 1,
+                (false, Some(_)) => size += 1,
+                _ => {}
+            }
+        }
+        size
+    }
+
+    // Applies the changes, and returns the snapshot of the view if they changed its documents,
+    // or if it is the first one.
+    fn snapshot(&mut self, read_time: &prost_types::Timestamp) -> Option<QuerySnapshot> {
+        let orders = &self.orders;
+        let compare = |a: &Document, b: &Document| compare_documents(orders, a, b);
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        let mut modified = Vec::new();
+        for (name, change) in self.changes.drain() {
+            match (self.names.get(&name), change) {
+                (Some(old), None) => removed.push(old.clone()),
+                (None, Some(new)) => added.push(new),
+                (Some(old), Some(new)) if *old != new => modified.push((old.clone(), new)),
+                _ => {}
+            }
+        }
+        removed.sort_by(compare);
+        added.sort_by(compare);
+        modified.sort_by(|a, b| compare(&a.1, &b.1));
+
+        // The indices are the ones of the documents with the previous changes applied.
+        let mut changes = Vec::new();
+        for document in removed {
+            let index = remove_document(&mut self.documents, orders, &document);
+            self.names.remove(&document.name);
+            changes.push(Change {
+                kind: ChangeKind::Removed,
+                document,
+                old_index: Some(index),
+                new_index: None,
+            });
+        }
+        let updates = added
+            .into_iter()
+            .map(|document| (None, document))
+            .chain(modified.into_iter().map(|(old, new)| (Some(old), new)));
+        for (old, document) in updates {
+            let (kind, old_index) = match old {
+                Some(old) => (
+                    ChangeKind::Modified,
+                    Some(remove_document(&mut self.documents, orders, &old)),
+                ),
+                None => (ChangeKind::Added, None),
+            };
+            let index = insert_document(&mut self.documents, orders, document.clone());
+            self.names.insert(document.name.clone(), document.clone());
+            changes.push(Change {
+                kind,
+                document,
+                old_index,
+                new_index: Some(index),
+            });
+        }
+
+        self.resume_token = mem::take(&mut self.next_resume_token);
+        if changes.is_empty() && self.snapshots > 0 {
+            return None;
+        }
+        self.snapshots += 1;
+        Some(QuerySnapshot {
+            target_id: self.target.target_id,
+            read_time: read_time.clone(),
+            documents: self.documents.clone(),
+            changes,
+        })
+    }
+}
+
+// Removes `document` from `documents` sorted by `orders`, and returns its index.
+fn remove_document(documents: &mut Vec<Document>, orders: &[Order], document: &Document) -> usize {
+    let index = documents
+        .binary_search_by(|d| compare_documents(orders, d, document))
+        .expect("document of the view");
+    documents.remove(index);
+    index
+}
+
+// Inserts `document` in `documents` sorted by `orders`, and returns its index.
+fn insert_document(documents: &mut Vec<Document>, orders: &[Order], document: Document) -> usize {
+    let index = documents
+        .binary_search_by(|d| compare_documents(orders, d, &document))
+        .unwrap_or_else(|index| index);
+    documents.insert(index, document);
+    index
+}
+
+fn compare_documents(orders: &[Order], a: &Document, b: &Document) -> Ordering {
+    for order in orders {
+        let field = order_field(order);
+        let ordering = if field == DOCUMENT_ID {
+            compare_names(&a.name, &b.name)
+        } else {
+            match (get_field(&a.fields, field), get_field(&b.fields, field)) {
+                (Some(a), Some(b)) => value::compare(a, b),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            }
+        };
+        let ordering = if order.direction == Direction::Descending as i32 {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    compare_names(&a.name, &b.name)
+}
+
+fn compare_names(a: &str, b: &str) -> Ordering {
+    a.split('/').cmp(b.split('/'))
+}
+
+enum StreamState {
+    Opening(BoxFuture<'static, Result<ResponseStream, Status>>),
+    Streaming(ResponseStream),
+    Waiting(BoxFuture<'static, ()>),
+    Done,
+}
+
+/// A stream of the snapshots of targets listened to with `Listen`, see the
+/// [module documentation](self).
+pub struct Listener {
+    database: String,
+    open: Open,
+    state: StreamState,
+    views: BTreeMap<i32, View>,
+    ready: VecDeque<QuerySnapshot>,
+    settings: RetrySettings,
+    backoff: Backoff,
+    attempts: usize,
+}
+
+impl Listener {
+    /// Creates a stream that calls `open` with the requests adding the targets to open the stream
+    /// of responses, and to reopen it. The stream fails with `INVALID_ARGUMENT` without calling
+    /// `open` when the ids of the targets are not unique and positive.
+    pub fn new<F, Fut, S>(database: impl Into<String>, targets: Vec<Target>, mut open: F) -> Self
+    where
+        F: FnMut(Vec<ListenRequest>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<S, Status>> + Send + 'static,
+        S: Stream<Item = Result<ListenResponse, Status>> + Send + 'static,
+    {
+        let open: Open = Box::new(move |requests| {
+            open(requests)
+                .map(|result| result.map(StreamExt::boxed))
+                .boxed()
+        });
+        let settings = RetrySettings::default().retry_codes(vec![
+            Code::Unavailable,
+            Code::DeadlineExceeded,
+            Code::ResourceExhausted,
+            Code::Internal,
+            Code::Unknown,
+        ]);
+        let mut ids = HashSet::new();
+        let valid = targets
+            .iter()
+            .all(|target| target.target_id > 0 && ids.insert(target.target_id));
+        let views = targets
+            .into_iter()
+            .map(|target| (target.target_id, View::new(target)))
+            .collect();
+        let mut listener = Self {
+            database: database.into(),
+            open,
+            state: StreamState::Done,
+            views,
+            ready: VecDeque::new(),
+            backoff: settings.backoff_iter(),
+            settings,
+            attempts: 0,
+        };
+        listener.state = if valid {
+            let requests = listener.requests();
+            StreamState::Opening((listener.open)(requests))
+        } else {
+            let status = Status::invalid_argument("target ids must be unique and positive");
+            StreamState::Opening(async move { Err(status) }.boxed())
+        };
+        listener
+    }
+
+    /// Listens to `targets` of `database`, e.g. `projects/p/databases/(default)`, with `Listen`.
+    pub fn listen<T>(
+        client: FirestoreClient<T>,
+        database: impl Into<String>,
+        targets: Vec<Target>,
+    ) -> Self
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+        T::Future: Send,
+        T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+        T::Error: Into<tonic::codegen::StdError>,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+    {
+        Self::new(database, targets, move |requests| {
+            let mut client = client.clone();
+            async move {
+                // The request stream stays open, the server ends the listen when it is closed.
+                let requests = stream::iter(requests).chain(stream::pending());
+                let response = client.listen(Request::new(requests)).await?;
+                Ok(response.into_inner())
+            }
+        })
+    }
+
+    /// The codes on which the stream is reopened, and the backoff between attempts. The attempts
+    /// are counted from the last received response.
+    pub fn retry_settings(self, settings: RetrySettings) -> Self {
+        Self {
+            backoff: settings.backoff_iter(),
+            settings,
+            ..self
+        }
+    }
+
+    fn requests(&self) -> Vec<ListenRequest> {
+        self.views
+            .values()
+            .map(|view| view.request(&self.database))
+            .collect()
+    }
+
+    // The views of `target_ids`, or all of them when it is empty.
+    fn views_mut<'a>(&'a mut self, target_ids: &'a [i32]) -> impl Iterator<Item = &'a mut View> {
+        self.views
+            .iter_mut()
+            .filter(move |(id, _)| target_ids.is_empty() || target_ids.contains(id))
+            .map(|(_, view)| view)
+    }
+
+    // Handles a response, and returns whether the stream must be reopened.
+    fn add(&mut self, response: ListenResponse) -> Result<bool, Status> {
+        self.attempts = 0;
+        self.backoff = self.settings.backoff_iter();
+        match response.response_type {
+            Some(ResponseType::TargetChange(change)) => self.target_change(change)?,
+            Some(ResponseType::DocumentChange(change)) => {
+                if let Some(document) = change.document {
+                    for id in &change.target_ids {
+                        if let Some(view) = self.views.get_mut(id) {
+                            view.changes
+                                .insert(document.name.clone(), Some(document.clone()));
+                        }
+                    }
+                    self.remove(&document.name, &change.removed_target_ids);
+                }
+            }
+            Some(ResponseType::DocumentDelete(delete)) => {
+                self.remove(&delete.document, &delete.removed_target_ids)
+            }
+            Some(ResponseType::DocumentRemove(remove)) => {
+                self.remove(&remove.document, &remove.removed_target_ids)
+            }
+            Some(ResponseType::Filter(ExistenceFilter { target_id, count })) => {
+                if let Some(view) = self.views.get_mut(&target_id) {
+                    if view.size() != count as usize {
+                        view.reset();
+                        return Ok(true);
+                    }
+                }
+            }
+            None => {}
+        }
+        Ok(false)
+    }
+
+    fn remove(&mut self, name: &str, target_ids: &[i32]) {
+        for id in target_ids {
+            if let Some(view) = self.views.get_mut(id) {
+                view.changes.insert(name.to_owned(), None);
+            }
+        }
+    }
+
+    fn target_change(&mut self, change: TargetChange) -> Result<(), Status> {
+        let change_type = TargetChangeType::from_i32(change.target_change_type)
+            .unwrap_or(TargetChangeType::NoChange);
+        match change_type {
+            TargetChangeType::Remove => {
+                return Err(match change.cause {
+                    Some(cause) => Status::new(Code::from(cause.code), cause.message),
+                    None => Status::internal("target removed by the server"),
+                });
+            }
+            TargetChangeType::Current => {
+                for view in self.views_mut(&change.target_ids) {
+                    view.current = true;
+                }
+            }
+            TargetChangeType::Reset => {
+                for view in self.views_mut(&change.target_ids) {
+                    view.reset();
+                }
+            }
+            TargetChangeType::Add | TargetChangeType::NoChange => {}
+        }
+        if !change.resume_token.is_empty() {
+            for view in self.views_mut(&change.target_ids) {
+                view.next_resume_token = change.resume_token.clone();
+            }
+        }
+        // A consistent point of the whole stream.
+        if let (true, TargetChangeType::NoChange, Some(read_time)) =
+            (change.target_ids.is_empty(), change_type, &change.read_time)
+        {
+            for view in self.views.values_mut().filter(|view| view.current) {
+                self.ready.extend(view.snapshot(read_time));
+            }
+        }
+        Ok(())
+    }
+
+    // Schedules the reopening of the stream, or returns the error if it cannot be reopened.
+    fn fail(&mut self, status: Status) -> Option<Status> {
+        self.attempts += 1;
+        let exhausted = matches!(self.settings.max_attempts, Some(max) if self.attempts >= max);
+        if exhausted || !self.settings.is_retryable(&status) {
+            self.state = StreamState::Done;
+            return Some(status);
+        }
+        let delay = retry::retry_delay(&status).unwrap_or_else(|| self.backoff.next().unwrap());
+        self.state = StreamState::Waiting(tokio::time::delay_for(delay).boxed());
+        None
+    }
+
+    fn reopen(&mut self) {
+        for view in self.views.values_mut() {
+            view.reopen();
+        }
+        let requests = self.requests();
+        self.state = StreamState::Opening((self.open)(requests));
+    }
+}
+
+impl Stream for Listener {
+    type Item = Result<QuerySnapshot, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(snapshot) = this.ready.pop_front() {
+                return Poll::Ready(Some(Ok(snapshot)));
+            }
+            match &mut this.state {
+                StreamState::Opening(open) => match open.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(stream)) => this.state = StreamState::Streaming(stream),
+                    Poll::Ready(Err(status)) => {
+                        if let Some(status) = this.fail(status) {
+                            return Poll::Ready(Some(Err(status)));
+                        }
+                    }
+                },
+                StreamState::Streaming(stream) => match stream.as_mut().poll_next(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Some(Ok(response))) => match this.add(response) {
+                        Ok(true) => this.reopen(),
+                        Ok(false) => {}
+                        Err(status) => {
+                            this.state = StreamState::Done;
+                            return Poll::Ready(Some(Err(status)));
+                        }
+                    },
+                    Poll::Ready(Some(Err(status))) => {
+                        if let Some(status) = this.fail(status) {
+                            return Poll::Ready(Some(Err(status)));
+                        }
+                    }
+                    Poll::Ready(None) => {
+                        if let Some(status) = this.fail(Status::unavailable("listen stream ended"))
+                        {
+                            return Poll::Ready(Some(Err(status)));
+                        }
+                    }
+                },
+                StreamState::Waiting(delay) => match delay.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(()) => this.reopen(),
+                },
+                StreamState::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Listener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Listener")
+            .field("database", &self.database)
+            .field("targets", &self.views.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        firestore::{fake::FakeFirestore, query::Direction},
+        google::{
+            firestore::v1::{
+                value::ValueType, DocumentChange, DocumentDelete, DocumentRemove, Value,
+            },
+            rpc,
+        },
+    };
+    use futures::TryStreamExt;
+    use std::{sync::Arc, time::Duration};
+
+    const DATABASE: &str = "projects/p/databases/(default)";
+    const CITIES: &str = "projects/p/databases/(default)/documents/cities";
+
+    fn city(name: &str, population: i64) -> Document {
+        let mut fields = HashMap::new();
+        fields.insert(
+            "population".to_owned(),
+            Value {
+                value_type: Some(ValueType::IntegerValue(population)),
+            },
+        );
+        Document {
+            name: format!("{}/{}", CITIES, name),
+            fields,
+            ..Default::default()
+        }
+    }
+
+    fn name(city: &str) -> String {
+        format!("{}/{}", CITIES, city)
+    }
+
+    fn target_change(
+        change_type: TargetChangeType,
+        target_ids: Vec<i32>,
+        resume_token: &str,
+        read_time: Option<i64>,
+    ) -> Result<ListenResponse, Status> {
+        Ok(ListenResponse {
+            response_type: Some(ResponseType::TargetChange(TargetChange {
+                target_change_type: change_type as i32,
+                target_ids,
+                resume_token: resume_token.into(),
+                read_time: read_time.map(|seconds| prost_types::Timestamp { seconds, nanos: 0 }),
+                ..Default::default()
+            })),
+        })
+    }
+
+    // A consistent point of the whole stream.
+    fn consistent(resume_token: &str, read_time: i64) -> Result<ListenResponse, Status> {
+        target_change(
+            TargetChangeType::NoChange,
+            vec![],
+            resume_token,
+            Some(read_time),
+        )
+    }
+
+    fn changed(document: Document, target_ids: Vec<i32>) -> Result<ListenResponse, Status> {
+        Ok(ListenResponse {
+            response_type: Some(ResponseType::DocumentChange(DocumentChange {
+                document: Some(document),
+                target_ids,
+                removed_target_ids: vec![],
+            })),
+        })
+    }
+
+    fn change(
+        kind: ChangeKind,
+        document: Document,
+        old_index: Option<usize>,
+        new_index: Option<usize>,
+    ) -> Change {
+        Change {
+            kind,
+            document,
+            old_index,
+            new_index,
+        }
+    }
+
+    fn listener(firestore: &Arc<FakeFirestore>, targets: Vec<Target>) -> Listener {
+        Listener::listen(firestore.client(), DATABASE, targets).retry_settings(
+            RetrySettings::default()
+                .retry_codes(vec![Code::Unavailable])
+                .backoff(Duration::from_millis(1), Duration::from_millis(1), 1.0),
+        )
+    }
+
+    fn cities_target() -> Target {
+        let query = Query::collection(CITIES).order_by("population", Direction::Ascending);
+        query_target(1, query).unwrap()
+    }
+
+    fn resume_type(request: &ListenRequest) -> Option<ResumeType> {
+        match &request.target_change {
+            Some(listen_request::TargetChange::AddTarget(target)) => target.resume_type.clone(),
+            other => panic!("unexpected target change {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshots() {
+        let firestore = Arc::new(FakeFirestore::default());
+        firestore.respond_to_listen(vec![
+            target_change(TargetChangeType::Add, vec![1], "", None),
+            changed(city("SF", 870_000), vec![1]),
+            changed(city("LA", 3_900_000), vec![1]),
+            target_change(TargetChangeType::Current, vec![1], "t1", None),
+            consistent("", 1),
+            changed(city("SF", 4_000_000), vec![1]),
+            changed(city("NY", 8_000_000), vec![1]),
+            Ok(ListenResponse {
+                response_type: Some(ResponseType::DocumentDelete(DocumentDelete {
+                    document: name("LA"),
+                    removed_target_ids: vec![1],
+                    ..Default::default()
+                })),
+            }),
+            consistent("t2", 2),
+            // No snapshot without changes.
+            consistent("t3", 3),
+            Ok(ListenResponse {
+                response_type: Some(ResponseType::DocumentRemove(DocumentRemove {
+                    document: name("NY"),
+                    removed_target_ids: vec![1],
+                    ..Default::default()
+                })),
+            }),
+            consistent("t4", 4),
+        ]);
+        let mut snapshots = listener(&firestore, vec![cities_target()]);
+
+        let snapshot = snapshots.try_next().await.unwrap().unwrap();
+        assert_eq!(snapshot.target_id, 1);
+        assert_eq!(snapshot.read_time.seconds, 1);
+        assert_eq!(
+            snapshot.documents,
+            vec![city("SF", 870_000), city("LA", 3_900_000)]
+        );
+        assert_eq!(
+            snapshot.changes,
+            vec![
+                change(ChangeKind::Added, city("SF", 870_000), None, Some(0)),
+                change(ChangeKind::Added, city("LA", 3_900_000), None, Some(1)),
+            ]
+        );
+
+        let snapshot = snapshots.try_next().await.unwrap().unwrap();
+        assert_eq!(snapshot.read_time.seconds, 2);
+        assert_eq!(
+            snapshot.documents,
+            vec![city("SF", 4_000_000), city("NY", 8_000_000)]
+        );
+        assert_eq!(
+            snapshot.changes,
+            vec![
+                change(ChangeKind::Removed, city("LA", 3_900_000), Some(1), None),
+                change(ChangeKind::Added, city("NY", 8_000_000), None, Some(1)),
+                change(
+                    ChangeKind::Modified,
+                    city("SF", 4_000_000),
+                    Some(0),
+                    Some(0)
+                ),
+            ]
+        );
+
+        let snapshot = snapshots.try_next().await.unwrap().unwrap();
+        assert_eq!(snapshot.read_time.seconds, 4);
+        assert_eq!(snapshot.documents, vec![city("SF", 4_000_000)]);
+        assert_eq!(
+            snapshot.changes,
+            vec![change(
+                ChangeKind::Removed,
+                city("NY", 8_000_000),
+                Some(1),
+                None
+            )]
+        );
+
+        let requests = &firestore.listens()[0];
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].database, DATABASE);
+        assert_eq!(resume_type(&requests[0]), None);
+    }
+
+    #[tokio::test]
+    async fn test_current_targets() {
+        let firestore = Arc::new(FakeFirestore::default());
+        firestore.respond_to_listen(vec![
+            target_change(TargetChangeType::Add, vec![1, 2], "", None),
+            changed(city("SF", 870_000), vec![1, 2]),
+            consistent("", 1),
+            target_change(TargetChangeType::Current, vec![1], "", None),
+            consistent("", 2),
+            target_change(TargetChangeType::Current, vec![2], "", None),
+            consistent("", 3),
+        ]);
+        let targets = vec![cities_target(), documents_target(2, vec![name("SF")])];
+        let mut snapshots = listener(&firestore, targets);
+
+        // The first snapshot of a target is at the first consistent point where it is current.
+        let snapshot = snapshots.try_next().await.unwrap().unwrap();
+        assert_eq!((snapshot.target_id, snapshot.read_time.seconds), (1, 2));
+        assert_eq!(snapshot.documents, vec![city("SF", 870_000)]);
+        let snapshot = snapshots.try_next().await.unwrap().unwrap();
+        assert_eq!((snapshot.target_id, snapshot.read_time.seconds), (2, 3));
+        assert_eq!(snapshot.documents, vec![city("SF", 870_000)]);
+    }
+
+    #[tokio::test]
+    async fn test_existence_filter() {
+        let firestore = Arc::new(FakeFirestore::default());
+        let filter = |count| {
+            Ok(ListenResponse {
+                response_type: Some(ResponseType::Filter(ExistenceFilter {
+                    target_id: 1,
+                    count,
+                })),
+            })
+        };
+        firestore.respond_to_listen(vec![
+            changed(city("SF", 870_000), vec![1]),
+            changed(city("LA", 3_900_000), vec![1]),
+            target_change(TargetChangeType::Current, vec![1], "", None),
+            consistent("t1", 1),
+            filter(2),
+            filter(1),
+        ]);
+        firestore.respond_to_listen(vec![
+            changed(city("LA", 3_900_000), vec![1]),
+            target_change(TargetChangeType::Current, vec![1], "", None),
+            consistent("t2", 2),
+        ]);
+        let mut snapshots = listener(&firestore, vec![cities_target()]);
+
+        let snapshot = snapshots.try_next().await.unwrap().unwrap();
+        assert_eq!(snapshot.documents.len(), 2);
+        let snapshot = snapshots.try_next().await.unwrap().unwrap();
+        assert_eq!(snapshot.read_time.seconds, 2);
+        assert_eq!(snapshot.documents, vec![city("LA", 3_900_000)]);
+        assert_eq!(
+            snapshot.changes,
+            vec![change(
+                ChangeKind::Removed,
+                city("SF", 870_000),
+                Some(0),
+                None
+            )]
+        );
+
+        // The documents are listened to again from scratch.
+        let listens = firestore.listens();
+        assert_eq!(listens.len(), 2);
+        assert_eq!(resume_type(&listens[1][0]), None);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect() {
+        let firestore = Arc::new(FakeFirestore::default());
+        firestore.respond_to_listen(vec![
+            changed(city("SF", 870_000), vec![1]),
+            target_change(TargetChangeType::Current, vec![1], "", None),
+            consistent("t1", 1),
+            changed(city("LA", 3_900_000), vec![1]),
+            target_change(TargetChangeType::NoChange, vec![1], "t2", None),
+            Err(Status::unavailable("unavailable")),
+        ]);
+        firestore.respond_to_listen(vec![
+            changed(city("LA", 3_900_000), vec![1]),
+            target_change(TargetChangeType::Current, vec![1], "", None),
+            consistent("t3", 2),
+        ]);
+        let mut snapshots = listener(&firestore, vec![cities_target()]);
+
+        let snapshot = snapshots.try_next().await.unwrap().unwrap();
+        assert_eq!(snapshot.documents, vec![city("SF", 870_000)]);
+        let snapshot = snapshots.try_next().await.unwrap().unwrap();
+        assert_eq!(snapshot.read_time.seconds, 2);
+        assert_eq!(
+            snapshot.changes,
+            vec![change(
+                ChangeKind::Added,
+                city("LA", 3_900_000),
+                None,
+                Some(1)
+            )]
+        );
+
+        // Resumed from the last consistent point.
+        let listens = firestore.listens();
+        assert_eq!(listens.len(), 2);
+        assert_eq!(
+            resume_type(&listens[1][0]),
+            Some(ResumeType::ResumeToken(b"t1".to_vec()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_errors() {
+        let firestore = Arc::new(FakeFirestore::default());
+        firestore.respond_to_listen(vec![Ok(ListenResponse {
+            response_type: Some(ResponseType::TargetChange(TargetChange {
+                target_change_type: TargetChangeType::Remove as i32,
+                target_ids: vec![1],
+                cause: Some(rpc::Status {
+                    code: Code::PermissionDenied as i32,
+                    message: "denied".into(),
+                    details: vec![],
+                }),
+                ..Default::default()
+            })),
+        })]);
+        let mut snapshots = listener(&firestore, vec![cities_target()]);
+        let status = snapshots.try_next().await.unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+        assert_eq!(status.message(), "denied");
+        assert!(snapshots.try_next().await.unwrap().is_none());
+
+        let targets = vec![cities_target(), cities_target()];
+        let mut snapshots = listener(&firestore, targets);
+        let status = snapshots.try_next().await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(firestore.listens().len(), 1);
+    }
+}
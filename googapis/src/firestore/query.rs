@@ -0,0 +1,1035 @@
+//! Structured queries built with a [`Query`], and run with `RunQuery`.
+//!
+//! The filters, orders and cursors of a query are checked when it is built, and fail with an
+//! [`Error`] instead of `INVALID_ARGUMENT` from the server. The values of filters and cursors are
+//! converted with [`value::to_value`].
+//!
+//! A cursor can also start or end a query at a document. Its values are the fields of the
+//! document the query is ordered by, and the query is ordered like Firestore orders it
+//! implicitly: by the field of its inequality filter when it has no order, and then by
+//! [`DOCUMENT_ID`] in the direction of its last order.
+//!
+//! ```ignore
+//! use futures::TryStreamExt;
+//! use googapis::{
+//!     firestore::query::{Direction, Operator, Query},
+//!     google::firestore::v1::firestore_client::FirestoreClient,
+//! };
+//!
+//! let mut documents = Query::collection("projects/my-project/databases/(default)/documents/cities")
+//!     .filter("population", Operator::GreaterThan, &1_000_000)
+//!     .filter("region", Operator::In, &["west_coast", "east_coast"])
+//!     .order_by("population", Direction::Descending)
+//!     .start_after_document(&last)
+//!     .limit(20)
+//!     .run(FirestoreClient::new(channel));
+//! while let Some(document) = documents.try_next().await? {
+//!     println!("{} read at {:?}", document.document.name, document.read_time);
+//! }
+//! ```
+
+use std::{
+    error, fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{
+    ready,
+    stream::{self, BoxStream, Stream},
+    StreamExt, TryStreamExt,
+};
+use serde::Serialize;
+use tonic::Status;
+
+use super::{get_field, split_field_path, value};
+use crate::google::firestore::v1::{
+    firestore_client::FirestoreClient,
+    run_query_request::QueryType,
+    structured_query::{
+        composite_filter, filter::FilterType, unary_filter, CollectionSelector, CompositeFilter,
+        FieldFilter, FieldReference, Filter, Order, Projection, UnaryFilter,
+    },
+    value::ValueType,
+    Cursor, Document, RunQueryRequest, RunQueryResponse, StructuredQuery, Value,
+};
+
+pub use crate::google::firestore::v1::structured_query::{field_filter::Operator, Direction};
+
+/// The field path of the name of documents, to filter or order queries by document.
+pub const DOCUMENT_ID: &str = "__name__";
+
+// The maximum number of values of `In` and `ArrayContainsAny` filters.
+const MAX_DISJUNCTION_VALUES: usize = 10;
+
+/// An invalid query.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// A value of a filter or of a cursor can't be converted.
+    Value(value::Error),
+    /// The query can't be run.
+    Invalid(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Value(e) => write!(f, "invalid value: {}", e),
+            Error::Invalid(message) => f.write_str(message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Value(e) => Some(e),
+            Error::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<value::Error> for Error {
+    fn from(e: value::Error) -> Self {
+        Error::Value(e)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Position {
+    Values(Vec<Value>),
+    Document(Document),
+}
+
+/// A builder of a structured query on a collection.
+#[derive(Debug, Clone)]
+pub struct Query {
+    parent: String,
+    query: StructuredQuery,
+    filters: Vec<Filter>,
+    // The positions of the cursors, and whether they are before them.
+    start: Option<(Position, bool)>,
+    end: Option<(Position, bool)>,
+    // The first error of the builder, returned by `build`.
+    error: Option<Error>,
+}
+
+impl Query {
+    fn new(parent: String, collection_id: String, all_descendants: bool) -> Self {
+        Self {
+            parent,
+            query: StructuredQuery {
+                from: vec![CollectionSelector {
+                    collection_id,
+                    all_descendants,
+                }],
+                ..Default::default()
+            },
+            filters: Vec::new(),
+            start: None,
+            end: None,
+            error: None,
+        }
+    }
+
+    /// Queries the documents of the collection at `path`, e.g.
+    /// `projects/p/databases/(default)/documents/cities` or
+    /// `projects/p/databases/(default)/documents/cities/SF/landmarks`.
+    pub fn collection(path: &str) -> Self {
+        let valid = match path.find("/documents/") {
+            Some(i) => {
+                let segments = path[i + "/documents/".len()..].split('/');
+                segments.clone().all(|s| !s.is_empty()) && segments.count() % 2 == 1
+            }
+            None => false,
+        };
+        let i = path.rfind('/').unwrap_or(0);
+        let query = Self::new(path[..i].to_owned(), path[i + 1..].to_owned(), false);
+        if valid {
+            query
+        } else {
+            query.fail(Error::Invalid(format!("invalid collection path {}", path)))
+        }
+    }
+
+    /// Queries the documents of all the collections with the id `collection_id` under `parent`,
+    /// e.g. `projects/p/databases/(default)/documents` or a document.
+    pub fn collection_group(parent: impl Into<String>, collection_id: impl Into<String>) -> Self {
+        Self::new(parent.into(), collection_id.into(), true)
+    }
+
+    /// Whether to also query the collections with the same id under the documents of the parent,
+    /// at any depth.
+    pub fn all_descendants(mut self, all_descendants: bool) -> Self {
+        self.query.from[0].all_descendants = all_descendants;
+        self
+    }
+
+    /// Only returns the fields at the paths of `fields` of the documents.
+    pub fn select<I>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.query.select = Some(Projection {
+            fields: fields.into_iter().map(field_reference).collect(),
+        });
+        self
+    }
+
+    /// Adds a filter comparing the field at the path `field` with `value`, e.g.
+    /// `filter("population", Operator::GreaterThan, &1_000_000)`. The values of `In` and
+    /// `ArrayContainsAny` filters are sequences of up to 10 values. The filters of a query are
+    /// combined with AND.
+    pub fn filter<V>(mut self, field: impl Into<String>, op: Operator, value: &V) -> Self
+    where
+        V: Serialize + ?Sized,
+    {
+        let value = match value::to_value(value) {
+            Ok(value) => value,
+            Err(e) => return self.fail(e.into()),
+        };
+        self.filters.push(Filter {
+            filter_type: Some(FilterType::FieldFilter(FieldFilter {
+                field: Some(field_reference(field)),
+                op: op as i32,
+                value: Some(value),
+            })),
+        });
+        self
+    }
+
+    /// Adds a filter on the field at the path `field` being null.
+    pub fn is_null(self, field: impl Into<String>) -> Self {
+        self.unary_filter(field, unary_filter::Operator::IsNull)
+    }
+
+    /// Adds a filter on the field at the path `field` being NaN.
+    pub fn is_nan(self, field: impl Into<String>) -> Self {
+        self.unary_filter(field, unary_filter::Operator::IsNan)
+    }
+
+    fn unary_filter(mut self, field: impl Into<String>, op: unary_filter::Operator) -> Self {
+        self.filters.push(Filter {
+            filter_type: Some(FilterType::UnaryFilter(UnaryFilter {
+                op: op as i32,
+                operand_type: Some(unary_filter::OperandType::Field(field_reference(field))),
+            })),
+        });
+        self
+    }
+
+    /// Orders the documents by the field at the path `field`, after the previous orders.
+    pub fn order_by(mut self, field: impl Into<String>, direction: Direction) -> Self {
+        self.query.order_by.push(Order {
+            field: Some(field_reference(field)),
+            direction: direction as i32,
+        });
+        self
+    }
+
+    /// Starts at the documents whose fields are `values`, in the order of the orders of the
+    /// query. `values` is a tuple or a sequence of values, or a single value.
+    pub fn start_at<V: Serialize + ?Sized>(self, values: &V) -> Self {
+        self.values_cursor(values, |query, position| {
+            query.start = Some((position, true))
+        })
+    }
+
+    /// Starts after the documents whose fields are `values`.
+    pub fn start_after<V: Serialize + ?Sized>(self, values: &V) -> Self {
+        self.values_cursor(values, |query, position| {
+            query.start = Some((position, false))
+        })
+    }
+
+    /// Ends before the documents whose fields are `values`.
+    pub fn end_before<V: Serialize + ?Sized>(self, values: &V) -> Self {
+        self.values_cursor(values, |query, position| query.end = Some((position, true)))
+    }
+
+    /// Ends at the documents whose fields are `values`.
+    pub fn end_at<V: Serialize + ?Sized>(self, values: &V) -> Self {
+        self.values_cursor(values, |query, position| {
+            query.end = Some((position, false))
+        })
+    }
+
+    fn values_cursor<V, F>(mut self, values: &V, set: F) -> Self
+    where
+        V: Serialize + ?Sized,
+        F: FnOnce(&mut Self, Position),
+    {
+        let values = match value::to_value(values) {
+            Ok(Value {
+                value_type: Some(ValueType::ArrayValue(array)),
+            }) => array.values,
+            Ok(value) => vec![value],
+            Err(e) => return self.fail(e.into()),
+        };
+        set(&mut self, Position::Values(values));
+        self
+    }
+
+    /// Starts at `document`.
+    pub fn start_at_document(mut self, document: &Document) -> Self {
+        self.start = Some((Position::Document(document.clone()), true));
+        self
+    }
+
+    /// Starts after `document`.
+    pub fn start_after_document(mut self, document: &Document) -> Self {
+        self.start = Some((Position::Document(document.clone()), false));
+        self
+    }
+
+    /// Ends before `document`.
+    pub fn end_before_document(mut self, document: &Document) -> Self {
+        self.end = Some((Position::Document(document.clone()), true));
+        self
+    }
+
+    /// Ends at `document`.
+    pub fn end_at_document(mut self, document: &Document) -> Self {
+        self.end = Some((Position::Document(document.clone()), false));
+        self
+    }
+
+    /// Skips the first `offset` documents.
+    pub fn offset(mut self, offset: i32) -> Self {
+        self.query.offset = offset;
+        self
+    }
+
+    /// Returns at most `limit` documents.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.query.limit = Some(limit);
+        self
+    }
+
+    fn fail(mut self, error: Error) -> Self {
+        self.error = self.error.or(Some(error));
+        self
+    }
+
+    /// Checks the query and returns the request running it.
+    pub fn build(self) -> Result<RunQueryRequest, Error> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        let mut query = self.query;
+        let inequality = validate_filters(&self.filters)?;
+        for order in &query.order_by {
+            check_field_path(order_field(order))?;
+        }
+        for field in query.select.iter().flat_map(|select| &select.fields) {
+            check_field_path(&field.field_path)?;
+        }
+        if let (Some(field), Some(order)) = (inequality, query.order_by.first()) {
+            if order_field(order) != field {
+                return Err(Error::Invalid(format!(
+                    "the first order of a query with an inequality filter on {} must be on {}",
+                    field, field
+                )));
+            }
+        }
+
+        let documents = |position: &Option<(Position, bool)>| {
+            matches!(position, Some((Position::Document(_), _)))
+        };
+        if documents(&self.start) || documents(&self.end) {
+            add_implicit_orders(&mut query.order_by, inequality);
+        }
+        query.start_at = cursor(self.start, &query.order_by)?;
+        query.end_at = cursor(self.end, &query.order_by)?;
+
+        let mut filters = self.filters;
+        query.r#where = match filters.len() {
+            0 => None,
+            1 => filters.pop(),
+            _ => Some(Filter {
+                filter_type: Some(FilterType::CompositeFilter(CompositeFilter {
+                    op: composite_filter::Operator::And as i32,
+                    filters,
+                })),
+            }),
+        };
+        Ok(RunQueryRequest {
+            parent: self.parent,
+            query_type: Some(QueryType::StructuredQuery(query)),
+            consistency_selector: None,
+        })
+    }
+
+    /// Builds the query and runs it with `RunQuery`. The stream fails with `INVALID_ARGUMENT`
+    /// without calling the server when the query is invalid.
+    pub fn run<T>(self, client: FirestoreClient<T>) -> RunQueryStream
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+        T::Future: Send,
+        T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+        T::Error: Into<tonic::codegen::StdError>,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+    {
+        match self.build() {
+            Ok(request) => RunQueryStream::run_query(client, request),
+            Err(e) => RunQueryStream::new(
+                stream::once(async move { Err(Status::invalid_argument(e.to_string())) }).boxed(),
+            ),
+        }
+    }
+}
+
+fn field_reference(field: impl Into<String>) -> FieldReference {
+    FieldReference {
+        field_path: field.into(),
+    }
+}
+
+pub(crate) fn order_field(order: &Order) -> &str {
+    order.field.as_ref().map_or("", |field| &field.field_path)
+}
+
+fn check_field_path(path: &str) -> Result<(), Error> {
+    match split_field_path(path) {
+        Some(_) => Ok(()),
+        None => Err(Error::Invalid(format!("invalid field path {}", path))),
+    }
+}
+
+// Checks the filters, and returns the field of their inequality filters, if any.
+fn validate_filters(filters: &[Filter]) -> Result<Option<&str>, Error> {
+    let mut inequality = None;
+    let mut array_contains = 0;
+    let mut disjunctions = 0;
+    for filter in filters {
+        let filter = match &filter.filter_type {
+            Some(FilterType::FieldFilter(filter)) => filter,
+            Some(FilterType::UnaryFilter(UnaryFilter {
+                operand_type: Some(unary_filter::OperandType::Field(field)),
+                ..
+            })) => {
+                check_field_path(&field.field_path)?;
+                continue;
+            }
+            _ => continue,
+        };
+        let field = filter.field.as_ref().map_or("", |field| &field.field_path);
+        check_field_path(field)?;
+        let op = Operator::from_i32(filter.op).unwrap_or(Operator::Unspecified);
+        match op {
+            Operator::LessThan
+            | Operator::LessThanOrEqual
+            | Operator::GreaterThan
+            | Operator::GreaterThanOrEqual => match inequality {
+                Some(other) if other != field => {
+                    return Err(Error::Invalid(format!(
+                    "a query can't have inequality filters on more than one field, found {} and {}",
+                    other, field
+                )))
+                }
+                _ => inequality = Some(field),
+            },
+            Operator::In | Operator::ArrayContainsAny => {
+                let name = if op == Operator::In {
+                    "in"
+                } else {
+                    "array-contains-any"
+                };
+                let values = match filter.value.as_ref().and_then(|v| v.value_type.as_ref()) {
+                    Some(ValueType::ArrayValue(array)) => array.values.len(),
+                    _ => 0,
+                };
+                if values == 0 || values > MAX_DISJUNCTION_VALUES {
+                    return Err(Error::Invalid(format!(
+                        "the value of an {} filter on {} must be 1 to {} values",
+                        name, field, MAX_DISJUNCTION_VALUES
+                    )));
+                }
+                disjunctions += 1;
+                if op == Operator::ArrayContainsAny {
+                    array_contains += 1;
+                }
+            }
+            Operator::ArrayContains => array_contains += 1,
+            Operator::Equal => {}
+            Operator::Unspecified => {
+                return Err(Error::Invalid(format!(
+                    "the filter on {} has no operator",
+                    field
+                )))
+            }
+        }
+    }
+    if array_contains > 1 {
+        return Err(Error::Invalid(
+            "a query can't have more than one array-contains or array-contains-any filter".into(),
+        ));
+    }
+    if disjunctions > 1 {
+        return Err(Error::Invalid(
+            "a query can't have more than one in or array-contains-any filter".into(),
+        ));
+    }
+    Ok(inequality)
+}
+
+// Adds the orders Firestore adds implicitly: by the field of the inequality filter when there is
+// no order, and then by document id in the direction of the last order.
+fn add_implicit_orders(orders: &mut Vec<Order>, inequality: Option<&str>) {
+    if let (true, Some(field)) = (orders.is_empty(), inequality) {
+        orders.push(Order {
+            field: Some(field_reference(field)),
+            direction: Direction::Ascending as i32,
+        });
+    }
+    if orders.iter().all(|order| order_field(order) != DOCUMENT_ID) {
+        let direction = orders
+            .last()
+            .map_or(Direction::Ascending as i32, |order| order.direction);
+        orders.push(Order {
+            field: Some(field_reference(DOCUMENT_ID)),
+            direction,
+        });
+    }
+}
+
+// Returns the orders of the results of `query`, with the orders Firestore adds implicitly.
+pub(crate) fn result_orders(query: &StructuredQuery) -> Vec<Order> {
+    let mut filters = query.r#where.iter().collect::<Vec<_>>();
+    let mut inequality = None;
+    while let Some(filter) = filters.pop() {
+        match &filter.filter_type {
+            Some(FilterType::CompositeFilter(composite)) => filters.extend(&composite.filters),
+            Some(FilterType::FieldFilter(FieldFilter {
+                field: Some(field),
+                op,
+                ..
+            })) => {
+                let inequality_op = matches!(
+                    Operator::from_i32(*op),
+                    Some(Operator::LessThan)
+                        | Some(Operator::LessThanOrEqual)
+                        | Some(Operator::GreaterThan)
+                        | Some(Operator::GreaterThanOrEqual)
+                );
+                if inequality_op {
+                    inequality = Some(field.field_path.as_str());
+                }
+            }
+            _ => {}
+        }
+    }
+    let mut orders = query.order_by.clone();
+    add_implicit_orders(&mut orders, inequality);
+    orders
+}
+
+fn cursor(position: Option<(Position, bool)>, orders: &[Order]) -> Result<Option<Cursor>, Error> {
+    let (values, before) = match position {
+        Some((Position::Values(values), before)) => (values, before),
+        Some((Position::Document(document), before)) => {
+            let values = orders
+                .iter()
+                .map(|order| document_value(&document, order_field(order)))
+                .collect::<Result<_, _>>()?;
+            (values, before)
+        }
+        None => return Ok(None),
+    };
+    if values.len() > orders.len() {
+        return Err(Error::Invalid(format!(
+            "a cursor can't have more values than the {} orders of the query",
+            orders.len()
+        )));
+    }
+    Ok(Some(Cursor { values, before }))
+}
+
+fn document_value(document: &Document, field: &str) -> Result<Value, Error> {
+    if field == DOCUMENT_ID {
+        return Ok(Value {
+            value_type: Some(ValueType::ReferenceValue(document.name.clone())),
+        });
+    }
+    get_field(&document.fields, field).cloned().ok_or_else(|| {
+        Error::Invalid(format!(
+            "the cursor document {} has no field {} to order by",
+            document.name, field
+        ))
+    })
+}
+
+/// A document returned by a query, with the time at which it was read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryDocument {
+    pub document: Document,
+    pub read_time: prost_types::Timestamp,
+}
+
+/// The documents returned by `RunQuery`.
+pub struct RunQueryStream {
+    responses: BoxStream<'static, Result<RunQueryResponse, Status>>,
+    transaction: Vec<u8>,
+    read_time: Option<prost_types::Timestamp>,
+    skipped_results: i32,
+}
+
+impl RunQueryStream {
+    fn new(responses: BoxStream<'static, Result<RunQueryResponse, Status>>) -> Self {
+        Self {
+            responses,
+            transaction: Vec::new(),
+            read_time: None,
+            skipped_results: 0,
+        }
+    }
+
+    /// Runs a query with `RunQuery`.
+    pub fn run_query<T>(client: FirestoreClient<T>, request: RunQueryRequest) -> Self
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+        T::Future: Send,
+        T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+        T::Error: Into<tonic::codegen::StdError>,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+    {
+        let mut client = client;
+        let responses = stream::once(async move {
+            let response = client.run_query(request).await?;
+            Ok::<_, Status>(response.into_inner())
+        })
+        .try_flatten();
+        Self::new(responses.boxed())
+    }
+
+    /// The transaction started by a request with a new transaction, empty otherwise.
+    pub fn transaction(&self) -> &[u8] {
+        &self.transaction
+    }
+
+    /// The read time of the last response, which is also sent when no document was returned.
+    pub fn read_time(&self) -> Option<&prost_types::Timestamp> {
+        self.read_time.as_ref()
+    }
+
+    /// The number of documents skipped by the offset of the query so far.
+    pub fn skipped_results(&self) -> i32 {
+        self.skipped_results
+    }
+}
+
+impl Stream for RunQueryStream {
+    type Item = Result<QueryDocument, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let response = match ready!(self.responses.poll_next_unpin(cx)) {
+                Some(Ok(response)) => response,
+                Some(Err(status)) => return Poll::Ready(Some(Err(status))),
+                None => return Poll::Ready(None),
+            };
+            if !response.transaction.is_empty() {
+                self.transaction = response.transaction;
+            }
+            if response.read_time.is_some() {
+                self.read_time = response.read_time.clone();
+            }
+            self.skipped_results += response.skipped_results;
+            if let Some(document) = response.document {
+                return Poll::Ready(Some(Ok(QueryDocument {
+                    document,
+                    read_time: response.read_time.unwrap_or_default(),
+                })));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::firestore::fake::FakeFirestore;
+    use std::{collections::HashMap, sync::Arc};
+    use tonic::Code;
+
+    const CITIES: &str = "projects/p/databases/(default)/documents/cities";
+
+    fn structured_query(request: RunQueryRequest) -> StructuredQuery {
+        match request.query_type {
+            Some(QueryType::StructuredQuery(query)) => query,
+            None => panic!("no query"),
+        }
+    }
+
+    fn integer(v: i64) -> Value {
+        Value {
+            value_type: Some(ValueType::IntegerValue(v)),
+        }
+    }
+
+    fn string(v: &str) -> Value {
+        Value {
+            value_type: Some(ValueType::StringValue(v.into())),
+        }
+    }
+
+    fn order(field: &str, direction: Direction) -> Order {
+        Order {
+            field: Some(field_reference(field)),
+            direction: direction as i32,
+        }
+    }
+
+    fn field_filter(field: &str, op: Operator, value: Value) -> Filter {
+        Filter {
+            filter_type: Some(FilterType::FieldFilter(FieldFilter {
+                field: Some(field_reference(field)),
+                op: op as i32,
+                value: Some(value),
+            })),
+        }
+    }
+
+    fn city(name: &str, population: i64) -> Document {
+        let mut fields = HashMap::new();
+        fields.insert("population".to_owned(), integer(population));
+        Document {
+            name: format!("{}/{}", CITIES, name),
+            fields,
+            ..Default::default()
+        }
+    }
+
+    fn invalid(query: Query) -> String {
+        match query.build().unwrap_err() {
+            Error::Invalid(message) => message,
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_collection() {
+        let request = Query::collection(CITIES).build().unwrap();
+        assert_eq!(request.parent, "projects/p/databases/(default)/documents");
+        assert_eq!(
+            structured_query(request).from,
+            vec![CollectionSelector {
+                collection_id: "cities".into(),
+                all_descendants: false,
+            }]
+        );
+
+        let request = Query::collection(&format!("{}/SF/landmarks", CITIES))
+            .all_descendants(true)
+            .build()
+            .unwrap();
+        assert_eq!(request.parent, format!("{}/SF", CITIES));
+        assert!(structured_query(request).from[0].all_descendants);
+
+        let request =
+            Query::collection_group("projects/p/databases/(default)/documents", "landmarks")
+                .build()
+                .unwrap();
+        assert_eq!(request.parent, "projects/p/databases/(default)/documents");
+        assert!(structured_query(request).from[0].all_descendants);
+
+        for path in &[
+            "cities",
+            "projects/p/databases/(default)/documents",
+            "projects/p/databases/(default)/documents/cities/SF",
+            "projects/p/databases/(default)/documents/cities//landmarks",
+        ] {
+            assert_eq!(
+                invalid(Query::collection(path)),
+                format!("invalid collection path {}", path)
+            );
+        }
+    }
+
+    #[test]
+    fn test_build() {
+        let query = structured_query(
+            Query::collection(CITIES)
+                .select(vec!["name", "population"])
+                .filter("population", Operator::GreaterThan, &1_000_000)
+                .filter("region", Operator::In, &["west", "east"])
+                .is_null("mayor")
+                .order_by("population", Direction::Descending)
+                .order_by("name", Direction::Ascending)
+                .start_at(&5_000_000)
+                .end_before(&(1_000_000, "LA"))
+                .offset(10)
+                .limit(20)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            query.select.unwrap().fields,
+            vec![field_reference("name"), field_reference("population")]
+        );
+        let regions = Value {
+            value_type: Some(ValueType::ArrayValue(
+                crate::google::firestore::v1::ArrayValue {
+                    values: vec![string("west"), string("east")],
+                },
+            )),
+        };
+        assert_eq!(
+            query.r#where,
+            Some(Filter {
+                filter_type: Some(FilterType::CompositeFilter(CompositeFilter {
+                    op: composite_filter::Operator::And as i32,
+                    filters: vec![
+                        field_filter("population", Operator::GreaterThan, integer(1_000_000)),
+                        field_filter("region", Operator::In, regions),
+                        Filter {
+                            filter_type: Some(FilterType::UnaryFilter(UnaryFilter {
+                                op: unary_filter::Operator::IsNull as i32,
+                                operand_type: Some(unary_filter::OperandType::Field(
+                                    field_reference("mayor")
+                                )),
+                            })),
+                        },
+                    ],
+                })),
+            })
+        );
+        // No implicit order without a document cursor.
+        assert_eq!(
+            query.order_by,
+            vec![
+                order("population", Direction::Descending),
+                order("name", Direction::Ascending)
+            ]
+        );
+        assert_eq!(
+            query.start_at,
+            Some(Cursor {
+                values: vec![integer(5_000_000)],
+                before: true,
+            })
+        );
+        assert_eq!(
+            query.end_at,
+            Some(Cursor {
+                values: vec![integer(1_000_000), string("LA")],
+                before: true,
+            })
+        );
+        assert_eq!(query.offset, 10);
+        assert_eq!(query.limit, Some(20));
+
+        let query = structured_query(
+            Query::collection(CITIES)
+                .filter("capital", Operator::Equal, &true)
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            query.r#where,
+            Some(field_filter(
+                "capital",
+                Operator::Equal,
+                Value {
+                    value_type: Some(ValueType::BooleanValue(true)),
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_document_cursors() {
+        // Ordered by the inequality field, then by document id.
+        let query = structured_query(
+            Query::collection(CITIES)
+                .filter("population", Operator::GreaterThan, &1_000)
+                .start_after_document(&city("SF", 870_000))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            query.order_by,
+            vec![
+                order("population", Direction::Ascending),
+                order(DOCUMENT_ID, Direction::Ascending)
+            ]
+        );
+        assert_eq!(
+            query.start_at,
+            Some(Cursor {
+                values: vec![
+                    integer(870_000),
+                    Value {
+                        value_type: Some(ValueType::ReferenceValue(format!("{}/SF", CITIES))),
+                    }
+                ],
+                before: false,
+            })
+        );
+
+        // By document id in the direction of the last order.
+        let query = structured_query(
+            Query::collection(CITIES)
+                .order_by("population", Direction::Descending)
+                .end_at_document(&city("LA", 3_900_000))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            query.order_by,
+            vec![
+                order("population", Direction::Descending),
+                order(DOCUMENT_ID, Direction::Descending)
+            ]
+        );
+        assert_eq!(query.end_at.unwrap().values.len(), 2);
+
+        // Already ordered by document id.
+        let query = structured_query(
+            Query::collection(CITIES)
+                .order_by(DOCUMENT_ID, Direction::Descending)
+                .start_at_document(&city("SF", 870_000))
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            query.order_by,
+            vec![order(DOCUMENT_ID, Direction::Descending)]
+        );
+
+        assert_eq!(
+            invalid(
+                Query::collection(CITIES)
+                    .order_by("name", Direction::Ascending)
+                    .start_at_document(&city("SF", 870_000))
+            ),
+            format!(
+                "the cursor document {}/SF has no field name to order by",
+                CITIES
+            )
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            invalid(
+                Query::collection(CITIES)
+                    .filter("population", Operator::GreaterThan, &1)
+                    .filter("area", Operator::LessThan, &2)
+            ),
+            "a query can't have inequality filters on more than one field, found population and area"
+        );
+        assert_eq!(
+            invalid(
+                Query::collection(CITIES)
+                    .filter("population", Operator::GreaterThan, &1)
+                    .order_by("name", Direction::Ascending)
+            ),
+            "the first order of a query with an inequality filter on population must be on population"
+        );
+        assert_eq!(
+            invalid(Query::collection(CITIES).filter("region", Operator::In, &Vec::<i32>::new())),
+            "the value of an in filter on region must be 1 to 10 values"
+        );
+        assert_eq!(
+            invalid(Query::collection(CITIES).filter(
+                "tags",
+                Operator::ArrayContainsAny,
+                &(0..11).collect::<Vec<_>>()
+            )),
+            "the value of an array-contains-any filter on tags must be 1 to 10 values"
+        );
+        assert_eq!(
+            invalid(
+                Query::collection(CITIES)
+                    .filter("tags", Operator::ArrayContains, &"a")
+                    .filter("tags", Operator::ArrayContainsAny, &["b"])
+            ),
+            "a query can't have more than one array-contains or array-contains-any filter"
+        );
+        assert_eq!(
+            invalid(
+                Query::collection(CITIES)
+                    .filter("region", Operator::In, &["west"])
+                    .filter("state", Operator::In, &["CA"])
+            ),
+            "a query can't have more than one in or array-contains-any filter"
+        );
+        assert_eq!(
+            invalid(Query::collection(CITIES).order_by("a..b", Direction::Ascending)),
+            "invalid field path a..b"
+        );
+        assert_eq!(
+            invalid(
+                Query::collection(CITIES)
+                    .order_by("population", Direction::Ascending)
+                    .start_at(&(1, 2))
+            ),
+            "a cursor can't have more values than the 1 orders of the query"
+        );
+
+        let error = Query::collection(CITIES)
+            .filter("population", Operator::Equal, &u64::MAX)
+            .build()
+            .unwrap_err();
+        assert!(matches!(error, Error::Value(_)));
+    }
+
+    #[tokio::test]
+    async fn test_run_query() {
+        let firestore = Arc::new(FakeFirestore::default());
+        let read_time = |seconds| prost_types::Timestamp { seconds, nanos: 0 };
+        firestore.respond_to_query(vec![
+            Ok(RunQueryResponse {
+                transaction: b"tx".to_vec(),
+                read_time: Some(read_time(1)),
+                skipped_results: 2,
+                ..Default::default()
+            }),
+            Ok(RunQueryResponse {
+                document: Some(city("SF", 870_000)),
+                read_time: Some(read_time(2)),
+                ..Default::default()
+            }),
+            Ok(RunQueryResponse {
+                document: Some(city("LA", 3_900_000)),
+                read_time: Some(read_time(3)),
+                ..Default::default()
+            }),
+            Err(Status::unavailable("unavailable")),
+        ]);
+
+        let query = Query::collection(CITIES).limit(2);
+        let mut stream = query.clone().run(firestore.client());
+        assert_eq!(
+            stream.try_next().await.unwrap(),
+            Some(QueryDocument {
+                document: city("SF", 870_000),
+                read_time: read_time(2),
+            })
+        );
+        assert_eq!(stream.transaction(), b"tx");
+        assert_eq!(stream.skipped_results(), 2);
+        assert_eq!(
+            stream.try_next().await.unwrap().unwrap().read_time,
+            read_time(3)
+        );
+        assert_eq!(stream.read_time(), Some(&read_time(3)));
+        assert_eq!(
+            stream.try_next().await.unwrap_err().code(),
+            Code::Unavailable
+        );
+        assert_eq!(firestore.queries(), vec![query.build().unwrap()]);
+
+        let mut stream = Query::collection("cities").run(firestore.client());
+        assert_eq!(
+            stream.try_next().await.unwrap_err().code(),
+            Code::InvalidArgument
+        );
+        assert_eq!(firestore.queries().len(), 1);
+    }
+}
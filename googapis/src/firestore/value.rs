@@ -0,0 +1,1354 @@
+//! Conversions of Rust types to and from Firestore values with serde.
+//!
+//! [`to_fields`] and [`from_fields`] convert any type implementing `Serialize` and `Deserialize`
+//! to and from the fields of a [`Document`], and [`to_value`] and [`from_value`] to and from a
+//! single [`Value`]:
+//!
+//! ```ignore
+//! use googapis::firestore::value::{self, Reference, Timestamp};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct City {
+//!     name: String,
+//!     population: i64,
+//!     country: Reference,
+//!     updated: Timestamp,
+//!     tags: Vec<String>,
+//! }
+//!
+//! let document = Document {
+//!     fields: value::to_fields(&city)?,
+//!     ..Default::default()
+//! };
+//! let city: City = value::from_fields(&document.fields)?;
+//! ```
+//!
+//! Types map to values like they do to JSON with `serde_json`: structs and maps to map values,
+//! sequences and tuples to array values, unit and `None` to null, and enums are externally
+//! tagged. Integers are 64-bit signed and arrays can't contain arrays, as in Firestore. The
+//! wrappers [`Timestamp`], [`Reference`], [`GeoPoint`] and [`Bytes`] map to the value types that
+//! have no serde equivalent.
+//!
+//! When deserializing a type that accepts anything, e.g. `serde_json::Value`, timestamps become
+//! RFC 3339 strings, references strings, and geo points maps of `latitude` and `longitude`.
+//!
+//! [`Document`]: crate::google::firestore::v1::Document
+
+use std::{cmp::Ordering, collections::HashMap, error, fmt};
+
+use serde::{
+    de::{self, value::SeqDeserializer, IntoDeserializer, Unexpected},
+    forward_to_deserialize_any, ser, Deserialize, Deserializer as _, Serialize,
+};
+
+use super::quote_field_name;
+use crate::{
+    google::{
+        firestore::v1::{value::ValueType, ArrayValue, MapValue, Value},
+        r#type::LatLng,
+    },
+    rfc3339::format_timestamp,
+};
+
+// The names of the newtype structs of the wrappers, which the serializer and the deserializer
+// map to the value types without a serde equivalent.
+const TIMESTAMP: &str = "$__googapis_firestore_timestamp";
+const REFERENCE: &str = "$__googapis_firestore_reference";
+const GEO_POINT: &str = "$__googapis_firestore_geo_point";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Field(String),
+    Index(usize),
+}
+
+/// An error converting a value, with the path of the field where it occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    // The segments of the path, from the innermost one.
+    path: Vec<Segment>,
+    message: String,
+}
+
+impl Error {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            path: Vec::new(),
+            message: message.into(),
+        }
+    }
+
+    fn field(mut self, name: &str) -> Self {
+        self.path.push(Segment::Field(name.to_owned()));
+        self
+    }
+
+    fn index(mut self, index: usize) -> Self {
+        self.path.push(Segment::Index(index));
+        self
+    }
+
+    /// Returns the path of the field, e.g. ``address.`zip code` `` or `tags[2]`, empty for the
+    /// converted value itself.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in self.path.iter().rev() {
+            match segment {
+                Segment::Field(name) if path.is_empty() => path.push_str(&quote_field_name(name)),
+                Segment::Field(name) => {
+                    path.push('.');
+                    path.push_str(&quote_field_name(name));
+                }
+                Segment::Index(index) => path.push_str(&format!("[{}]", index)),
+            }
+        }
+        path
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.path(), self.message)
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::new(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::new(msg.to_string())
+    }
+}
+
+/// A timestamp value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timestamp(pub prost_types::Timestamp);
+
+impl From<prost_types::Timestamp> for Timestamp {
+    fn from(ts: prost_types::Timestamp) -> Self {
+        Timestamp(ts)
+    }
+}
+
+impl From<Timestamp> for prost_types::Timestamp {
+    fn from(ts: Timestamp) -> Self {
+        ts.0
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(TIMESTAMP, &(self.0.seconds, self.0.nanos))
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Timestamp;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a timestamp")
+            }
+
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: de::Deserializer<'de>,
+            {
+                let (seconds, nanos) = Deserialize::deserialize(deserializer)?;
+                Ok(Timestamp(prost_types::Timestamp { seconds, nanos }))
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(TIMESTAMP, Visitor)
+    }
+}
+
+/// A reference value, the full resource name of a document, e.g.
+/// `projects/p/databases/(default)/documents/cities/SF`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Reference(pub String);
+
+impl Serialize for Reference {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(REFERENCE, &self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Reference {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Reference;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a reference")
+            }
+
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: de::Deserializer<'de>,
+            {
+                String::deserialize(deserializer).map(Reference)
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(REFERENCE, Visitor)
+    }
+}
+
+/// A geo point value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoPoint(pub LatLng);
+
+impl From<LatLng> for GeoPoint {
+    fn from(point: LatLng) -> Self {
+        GeoPoint(point)
+    }
+}
+
+impl From<GeoPoint> for LatLng {
+    fn from(point: GeoPoint) -> Self {
+        point.0
+    }
+}
+
+impl Serialize for GeoPoint {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(GEO_POINT, &(self.0.latitude, self.0.longitude))
+    }
+}
+
+impl<'de> Deserialize<'de> for GeoPoint {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GeoPoint;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a geo point")
+            }
+
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: de::Deserializer<'de>,
+            {
+                let (latitude, longitude) = Deserialize::deserialize(deserializer)?;
+                Ok(GeoPoint(LatLng {
+                    latitude,
+                    longitude,
+                }))
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(GEO_POINT, Visitor)
+    }
+}
+
+/// A bytes value, where `Vec<u8>` would be an array of integers.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Bytes(pub Vec<u8>);
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Bytes(bytes)
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(bytes: Bytes) -> Self {
+        bytes.0
+    }
+}
+
+impl Serialize for Bytes {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Bytes;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("bytes")
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(Bytes(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(Bytes(v))
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut bytes = Vec::new();
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(Bytes(bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(Visitor)
+    }
+}
+
+/// Converts `value` to a Firestore value.
+pub fn to_value<T: Serialize + ?Sized>(value: &T) -> Result<Value, Error> {
+    value.serialize(Serializer)
+}
+
+/// Converts `value` to the fields of a document, it must be a struct or a map.
+pub fn to_fields<T: Serialize + ?Sized>(value: &T) -> Result<HashMap<String, Value>, Error> {
+    match to_value(value)?.value_type {
+        Some(ValueType::MapValue(map)) => Ok(map.fields),
+        _ => Err(Error::new("expected a struct or a map")),
+    }
+}
+
+/// Converts a Firestore value to a `T`.
+pub fn from_value<'de, T: Deserialize<'de>>(value: &'de Value) -> Result<T, Error> {
+    T::deserialize(Deserializer(value))
+}
+
+/// Converts the fields of a document to a `T`.
+pub fn from_fields<'de, T: Deserialize<'de>>(
+    fields: &'de HashMap<String, Value>,
+) -> Result<T, Error> {
+    T::deserialize(FieldsDeserializer(fields))
+}
+
+/// Compares values in the order of Firestore queries: null, booleans, numbers, timestamps,
+/// strings, bytes, references, geo points, arrays and maps. Integers and doubles are compared
+/// with each other, and NaN is the smallest number.
+pub fn compare(a: &Value, b: &Value) -> Ordering {
+    use ValueType::*;
+    match (&a.value_type, &b.value_type) {
+        (Some(BooleanValue(a)), Some(BooleanValue(b))) => a.cmp(b),
+        (Some(IntegerValue(a)), Some(IntegerValue(b))) => a.cmp(b),
+        (Some(IntegerValue(a)), Some(DoubleValue(b))) => compare_doubles(*a as f64, *b),
+        (Some(DoubleValue(a)), Some(IntegerValue(b))) => compare_doubles(*a, *b as f64),
+        (Some(DoubleValue(a)), Some(DoubleValue(b))) => compare_doubles(*a, *b),
+        (Some(TimestampValue(a)), Some(TimestampValue(b))) => {
+            (a.seconds, a.nanos).cmp(&(b.seconds, b.nanos))
+        }
+        (Some(StringValue(a)), Some(StringValue(b))) => a.cmp(b),
+        (Some(BytesValue(a)), Some(BytesValue(b))) => a.cmp(b),
+        (Some(ReferenceValue(a)), Some(ReferenceValue(b))) => a.split('/').cmp(b.split('/')),
+        (Some(GeoPointValue(a)), Some(GeoPointValue(b))) => compare_doubles(a.latitude, b.latitude)
+            .then_with(|| compare_doubles(a.longitude, b.longitude)),
+        (Some(ArrayValue(a)), Some(ArrayValue(b))) => {
+            let mut ordering = a.values.iter().zip(&b.values).map(|(a, b)| compare(a, b));
+            ordering
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or_else(|| a.values.len().cmp(&b.values.len()))
+        }
+        (Some(MapValue(a)), Some(MapValue(b))) => {
+            let mut a: Vec<_> = a.fields.iter().collect();
+            let mut b: Vec<_> = b.fields.iter().collect();
+            a.sort_by(|a, b| a.0.cmp(b.0));
+            b.sort_by(|a, b| a.0.cmp(b.0));
+            let mut ordering = a
+                .iter()
+                .zip(&b)
+                .map(|(a, b)| a.0.cmp(b.0).then_with(|| compare(a.1, b.1)));
+            ordering
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or_else(|| a.len().cmp(&b.len()))
+        }
+        (a, b) => type_order(a).cmp(&type_order(b)),
+    }
+}
+
+fn compare_doubles(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+fn type_order(value_type: &Option<ValueType>) -> u8 {
+    match value_type {
+        None | Some(ValueType::NullValue(_)) => 0,
+        Some(ValueType::BooleanValue(_)) => 1,
+        Some(ValueType::IntegerValue(_)) | Some(ValueType::DoubleValue(_)) => 2,
+        Some(ValueType::TimestampValue(_)) => 3,
+        Some(ValueType::StringValue(_)) => 4,
+        Some(ValueType::BytesValue(_)) => 5,
+        Some(ValueType::ReferenceValue(_)) => 6,
+        Some(ValueType::GeoPointValue(_)) => 7,
+        Some(ValueType::ArrayValue(_)) => 8,
+        Some(ValueType::MapValue(_)) => 9,
+    }
+}
+
+fn value_of(value_type: ValueType) -> Value {
+    Value {
+        value_type: Some(value_type),
+    }
+}
+
+fn null() -> Value {
+    value_of(ValueType::NullValue(
+        prost_types::NullValue::NullValue as i32,
+    ))
+}
+
+fn map(fields: HashMap<String, Value>) -> Value {
+    value_of(ValueType::MapValue(MapValue { fields }))
+}
+
+// The serializer of values.
+struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = Value;
+    type Error = Error;
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeArray;
+    type SerializeTupleStruct = SerializeArray;
+    type SerializeTupleVariant = SerializeArray;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeMap;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(value_of(ValueType::BooleanValue(v)))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(value_of(ValueType::IntegerValue(v)))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        if v > i64::MAX as u64 {
+            return Err(Error::new(format!("integer {} out of range", v)));
+        }
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(value_of(ValueType::DoubleValue(v)))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        self.serialize_str(&v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(value_of(ValueType::StringValue(v.to_owned())))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        Ok(value_of(ValueType::BytesValue(v.to_vec())))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Error> {
+        Ok(null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        let inner = value.serialize(self)?;
+        let value_type = match (name, inner.value_type) {
+            (TIMESTAMP, Some(ValueType::ArrayValue(array))) => match &array.values[..] {
+                [seconds, nanos] => match (&seconds.value_type, &nanos.value_type) {
+                    (
+                        Some(ValueType::IntegerValue(seconds)),
+                        Some(ValueType::IntegerValue(nanos)),
+                    ) => ValueType::TimestampValue(prost_types::Timestamp {
+                        seconds: *seconds,
+                        nanos: *nanos as i32,
+                    }),
+                    _ => return Err(Error::new("invalid timestamp")),
+                },
+                _ => return Err(Error::new("invalid timestamp")),
+            },
+            (REFERENCE, Some(ValueType::StringValue(name))) => ValueType::ReferenceValue(name),
+            (GEO_POINT, Some(ValueType::ArrayValue(array))) => match &array.values[..] {
+                [latitude, longitude] => match (&latitude.value_type, &longitude.value_type) {
+                    (
+                        Some(ValueType::DoubleValue(latitude)),
+                        Some(ValueType::DoubleValue(longitude)),
+                    ) => ValueType::GeoPointValue(LatLng {
+                        latitude: *latitude,
+                        longitude: *longitude,
+                    }),
+                    _ => return Err(Error::new("invalid geo point")),
+                },
+                _ => return Err(Error::new("invalid geo point")),
+            },
+            (TIMESTAMP, _) => return Err(Error::new("invalid timestamp")),
+            (REFERENCE, _) => return Err(Error::new("invalid reference")),
+            (GEO_POINT, _) => return Err(Error::new("invalid geo point")),
+            (_, value_type) => return Ok(Value { value_type }),
+        };
+        Ok(value_of(value_type))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        let value = value.serialize(self).map_err(|e| e.field(variant))?;
+        let mut fields = HashMap::new();
+        fields.insert(variant.to_owned(), value);
+        Ok(map(fields))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray, Error> {
+        Ok(SerializeArray {
+            values: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray, Error> {
+        Ok(SerializeArray {
+            values: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, Error> {
+        Ok(SerializeMap {
+            fields: HashMap::with_capacity(len.unwrap_or(0)),
+            key: None,
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<SerializeMap, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeMap, Error> {
+        Ok(SerializeMap {
+            fields: HashMap::with_capacity(len),
+            key: None,
+            variant: Some(variant),
+        })
+    }
+}
+
+// Wraps `value` in a map with the variant as its only field, if any.
+fn with_variant(variant: Option<&'static str>, value: Value) -> Value {
+    match variant {
+        Some(variant) => {
+            let mut fields = HashMap::new();
+            fields.insert(variant.to_owned(), value);
+            map(fields)
+        }
+        None => value,
+    }
+}
+
+struct SerializeArray {
+    values: Vec<Value>,
+    variant: Option<&'static str>,
+}
+
+impl SerializeArray {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let index = self.values.len();
+        let wrap = |e: Error| match self.variant {
+            Some(variant) => e.index(index).field(variant),
+            None => e.index(index),
+        };
+        let value = value.serialize(Serializer).map_err(wrap)?;
+        if let Some(ValueType::ArrayValue(_)) = value.value_type {
+            return Err(wrap(Error::new("arrays can't contain arrays")));
+        }
+        self.values.push(value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        let array = value_of(ValueType::ArrayValue(ArrayValue {
+            values: self.values,
+        }));
+        Ok(with_variant(self.variant, array))
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        SerializeArray::end(self)
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        SerializeArray::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        SerializeArray::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeArray {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        SerializeArray::end(self)
+    }
+}
+
+struct SerializeMap {
+    fields: HashMap<String, Value>,
+    // The key of the next value of a map.
+    key: Option<String>,
+    variant: Option<&'static str>,
+}
+
+impl SerializeMap {
+    fn insert<T: Serialize + ?Sized>(&mut self, key: String, value: &T) -> Result<(), Error> {
+        let value = value.serialize(Serializer).map_err(|e| {
+            let e = e.field(&key);
+            match self.variant {
+                Some(variant) => e.field(variant),
+                None => e,
+            }
+        })?;
+        self.fields.insert(key, value);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(with_variant(self.variant, map(self.fields)))
+    }
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    // The keys are strings, or integers converted to strings.
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        let key = match key.serialize(Serializer)?.value_type {
+            Some(ValueType::StringValue(key)) => key,
+            Some(ValueType::IntegerValue(key)) => key.to_string(),
+            _ => return Err(Error::new("map keys must be strings")),
+        };
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| Error::new("map value without a key"))?;
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        SerializeMap::end(self)
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        SerializeMap::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(key.to_owned(), value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        SerializeMap::end(self)
+    }
+}
+
+fn unexpected(value: &Value) -> Unexpected<'_> {
+    match &value.value_type {
+        None | Some(ValueType::NullValue(_)) => Unexpected::Unit,
+        Some(ValueType::BooleanValue(v)) => Unexpected::Bool(*v),
+        Some(ValueType::IntegerValue(v)) => Unexpected::Signed(*v),
+        Some(ValueType::DoubleValue(v)) => Unexpected::Float(*v),
+        Some(ValueType::TimestampValue(_)) => Unexpected::Other("timestamp"),
+        Some(ValueType::StringValue(v)) => Unexpected::Str(v),
+        Some(ValueType::BytesValue(v)) => Unexpected::Bytes(v),
+        Some(ValueType::ReferenceValue(_)) => Unexpected::Other("reference"),
+        Some(ValueType::GeoPointValue(_)) => Unexpected::Other("geo point"),
+        Some(ValueType::ArrayValue(_)) => Unexpected::Seq,
+        Some(ValueType::MapValue(_)) => Unexpected::Map,
+    }
+}
+
+// The deserializer of a value.
+struct Deserializer<'de>(&'de Value);
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match &self.0.value_type {
+            None | Some(ValueType::NullValue(_)) => visitor.visit_unit(),
+            Some(ValueType::BooleanValue(v)) => visitor.visit_bool(*v),
+            Some(ValueType::IntegerValue(v)) => visitor.visit_i64(*v),
+            Some(ValueType::DoubleValue(v)) => visitor.visit_f64(*v),
+            Some(ValueType::TimestampValue(ts)) => visitor.visit_string(format_timestamp(ts)),
+            Some(ValueType::StringValue(v)) => visitor.visit_borrowed_str(v),
+            Some(ValueType::BytesValue(v)) => visitor.visit_borrowed_bytes(v),
+            Some(ValueType::ReferenceValue(v)) => visitor.visit_borrowed_str(v),
+            Some(ValueType::GeoPointValue(point)) => {
+                let fields = vec![("latitude", point.latitude), ("longitude", point.longitude)];
+                visitor.visit_map(de::value::MapDeserializer::new(fields.into_iter()))
+            }
+            Some(ValueType::ArrayValue(array)) => visitor.visit_seq(SeqAccess {
+                values: array.values.iter().enumerate(),
+            }),
+            Some(ValueType::MapValue(map)) => visitor.visit_map(MapAccess::new(&map.fields)),
+        }
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match &self.0.value_type {
+            None | Some(ValueType::NullValue(_)) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match (name, &self.0.value_type) {
+            (TIMESTAMP, Some(ValueType::TimestampValue(ts))) => {
+                let parts = vec![ts.seconds, ts.nanos.into()];
+                visitor.visit_newtype_struct(SeqDeserializer::new(parts.into_iter()))
+            }
+            (REFERENCE, Some(ValueType::ReferenceValue(name))) => {
+                visitor.visit_newtype_struct(de::value::BorrowedStrDeserializer::new(name))
+            }
+            (GEO_POINT, Some(ValueType::GeoPointValue(point))) => {
+                let parts = vec![point.latitude, point.longitude];
+                visitor.visit_newtype_struct(SeqDeserializer::new(parts.into_iter()))
+            }
+            (TIMESTAMP, _) | (REFERENCE, _) | (GEO_POINT, _) => {
+                Err(de::Error::invalid_type(unexpected(self.0), &visitor))
+            }
+            _ => visitor.visit_newtype_struct(self),
+        }
+    }
+
+    fn deserialize_bytes<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match &self.0.value_type {
+            Some(ValueType::BytesValue(v)) => visitor.visit_borrowed_bytes(v),
+            _ => Err(de::Error::invalid_type(unexpected(self.0), &visitor)),
+        }
+    }
+
+    fn deserialize_byte_buf<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        self.deserialize_bytes(visitor)
+    }
+
+    // Unit variants are strings, the other variants maps with the variant as their only field.
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match &self.0.value_type {
+            Some(ValueType::StringValue(variant)) => {
+                visitor.visit_enum(variant.as_str().into_deserializer())
+            }
+            Some(ValueType::MapValue(map)) if map.fields.len() == 1 => {
+                let (variant, value) = map.fields.iter().next().unwrap();
+                visitor
+                    .visit_enum(EnumAccess { variant, value })
+                    .map_err(|e| e.field(variant))
+            }
+            _ => Err(de::Error::invalid_type(unexpected(self.0), &visitor)),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+// The deserializer of the fields of a document.
+struct FieldsDeserializer<'de>(&'de HashMap<String, Value>);
+
+impl<'de> de::Deserializer<'de> for FieldsDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(MapAccess::new(self.0))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+        unit unit_struct newtype_struct seq tuple tuple_struct map struct enum identifier
+        ignored_any
+    }
+}
+
+struct SeqAccess<'de> {
+    values: std::iter::Enumerate<std::slice::Iter<'de, Value>>,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.values.next() {
+            Some((index, value)) => seed
+                .deserialize(Deserializer(value))
+                .map(Some)
+                .map_err(|e| e.index(index)),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
+
+struct MapAccess<'de> {
+    fields: std::collections::hash_map::Iter<'de, String, Value>,
+    // The field of the next value.
+    field: Option<(&'de str, &'de Value)>,
+}
+
+impl<'de> MapAccess<'de> {
+    fn new(fields: &'de HashMap<String, Value>) -> Self {
+        Self {
+            fields: fields.iter(),
+            field: None,
+        }
+    }
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.fields.next() {
+            Some((key, value)) => {
+                self.field = Some((key, value));
+                seed.deserialize(de::value::BorrowedStrDeserializer::new(key))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let (key, value) = self
+            .field
+            .take()
+            .ok_or_else(|| Error::new("map value without a key"))?;
+        seed.deserialize(Deserializer(value))
+            .map_err(|e| e.field(key))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
+
+struct EnumAccess<'de> {
+    variant: &'de str,
+    value: &'de Value,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess<'de> {
+    type Error = Error;
+    type Variant = Deserializer<'de>;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Deserializer<'de>), Error> {
+        let variant = seed.deserialize(de::value::BorrowedStrDeserializer::new(self.variant))?;
+        Ok((variant, Deserializer(self.value)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: de::Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: de::Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.deserialize_map(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum Status {
+        Active,
+        Suspended { until: Timestamp },
+        Renamed(String),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Address {
+        city: String,
+        zip: u32,
+        location: GeoPoint,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct User {
+        name: String,
+        age: Option<u8>,
+        score: f64,
+        admin: bool,
+        tags: Vec<String>,
+        address: Address,
+        status: Status,
+        previous: Vec<Status>,
+        manager: Option<Reference>,
+        avatar: Bytes,
+        counts: BTreeMap<String, i64>,
+        pair: (i32, String),
+    }
+
+    fn user() -> User {
+        User {
+            name: "Ada".into(),
+            age: None,
+            score: 9.5,
+            admin: true,
+            tags: vec!["a".into(), "b".into()],
+            address: Address {
+                city: "London".into(),
+                zip: 12345,
+                location: GeoPoint(LatLng {
+                    latitude: 51.5,
+                    longitude: -0.1,
+                }),
+            },
+            status: Status::Suspended {
+                until: Timestamp(prost_types::Timestamp {
+                    seconds: 1_600_000_000,
+                    nanos: 5,
+                }),
+            },
+            previous: vec![Status::Active, Status::Renamed("Ada L.".into())],
+            manager: Some(Reference(
+                "projects/p/databases/(default)/documents/users/charles".into(),
+            )),
+            avatar: Bytes(vec![0, 1, 255]),
+            counts: vec![("x".to_owned(), 1)].into_iter().collect(),
+            pair: (7, "seven".into()),
+        }
+    }
+
+    fn field<'a>(fields: &'a HashMap<String, Value>, path: &[&str]) -> &'a ValueType {
+        let mut value = &fields[path[0]];
+        for name in &path[1..] {
+            value = match &value.value_type {
+                Some(ValueType::MapValue(map)) => &map.fields[*name],
+                other => panic!("not a map: {:?}", other),
+            };
+        }
+        value.value_type.as_ref().unwrap()
+    }
+
+    #[test]
+    fn test_to_fields() {
+        let fields = to_fields(&user()).unwrap();
+        assert_eq!(
+            field(&fields, &["name"]),
+            &ValueType::StringValue("Ada".into())
+        );
+        assert_eq!(field(&fields, &["age"]), &ValueType::NullValue(0));
+        assert_eq!(field(&fields, &["score"]), &ValueType::DoubleValue(9.5));
+        assert_eq!(
+            field(&fields, &["address", "zip"]),
+            &ValueType::IntegerValue(12345)
+        );
+        assert_eq!(
+            field(&fields, &["address", "location"]),
+            &ValueType::GeoPointValue(LatLng {
+                latitude: 51.5,
+                longitude: -0.1,
+            })
+        );
+        assert_eq!(
+            field(&fields, &["status", "Suspended", "until"]),
+            &ValueType::TimestampValue(prost_types::Timestamp {
+                seconds: 1_600_000_000,
+                nanos: 5,
+            })
+        );
+        assert_eq!(
+            field(&fields, &["manager"]),
+            &ValueType::ReferenceValue(
+                "projects/p/databases/(default)/documents/users/charles".into()
+            )
+        );
+        assert_eq!(
+            field(&fields, &["avatar"]),
+            &ValueType::BytesValue(vec![0, 1, 255])
+        );
+        match field(&fields, &["previous"]) {
+            ValueType::ArrayValue(array) => {
+                assert_eq!(
+                    array.values[0],
+                    value_of(ValueType::StringValue("Active".into()))
+                );
+            }
+            other => panic!("not an array: {:?}", other),
+        }
+
+        let mut keys = HashMap::new();
+        keys.insert(1, "one");
+        let fields = to_fields(&keys).unwrap();
+        assert_eq!(
+            field(&fields, &["1"]),
+            &ValueType::StringValue("one".into())
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let user = user();
+        let fields = to_fields(&user).unwrap();
+        assert_eq!(from_fields::<User>(&fields).unwrap(), user);
+
+        let value = to_value(&vec![Some(1.5), None]).unwrap();
+        assert_eq!(
+            from_value::<Vec<Option<f64>>>(&value).unwrap(),
+            vec![Some(1.5), None]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_any() {
+        let mut fields = to_fields(&user()).unwrap();
+        // JSON has no bytes.
+        fields.remove("avatar");
+        let json: serde_json::Value = from_fields(&fields).unwrap();
+        assert_eq!(
+            json["status"]["Suspended"]["until"],
+            "2020-09-13T12:26:40.000000005Z"
+        );
+        assert_eq!(json["address"]["location"]["latitude"], 51.5);
+        assert_eq!(
+            json["manager"],
+            "projects/p/databases/(default)/documents/users/charles"
+        );
+        assert_eq!(json["age"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_serialize_errors() {
+        let error = to_value(&vec![vec![1]]).unwrap_err();
+        assert_eq!(error.to_string(), "[0]: arrays can't contain arrays");
+
+        let mut map = HashMap::new();
+        map.insert("zip code", vec![u64::MAX]);
+        let mut fields = HashMap::new();
+        fields.insert("address", map);
+        let error = to_fields(&fields).unwrap_err();
+        assert_eq!(error.path(), "address.`zip code`[0]");
+        assert_eq!(
+            error.message(),
+            format!("integer {} out of range", u64::MAX)
+        );
+
+        let mut keys = HashMap::new();
+        keys.insert((1, 2), 3);
+        assert_eq!(
+            to_value(&keys).unwrap_err().message(),
+            "map keys must be strings"
+        );
+        assert_eq!(
+            to_fields(&1).unwrap_err().message(),
+            "expected a struct or a map"
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let values = vec![
+            null(),
+            to_value(&false).unwrap(),
+            to_value(&true).unwrap(),
+            to_value(&f64::NAN).unwrap(),
+            to_value(&-1.5).unwrap(),
+            to_value(&1).unwrap(),
+            to_value(&1.5).unwrap(),
+            to_value(&Timestamp(prost_types::Timestamp {
+                seconds: 1,
+                nanos: 0,
+            }))
+            .unwrap(),
+            to_value(&"a").unwrap(),
+            to_value(&"b").unwrap(),
+            to_value(&Bytes(vec![0])).unwrap(),
+            to_value(&Reference("projects/p/databases/d/documents/a/b".into())).unwrap(),
+            to_value(&Reference(
+                "projects/p/databases/d/documents/a/b/c/d".into(),
+            ))
+            .unwrap(),
+            to_value(&Reference("projects/p/databases/d/documents/a-/b".into())).unwrap(),
+            to_value(&GeoPoint(LatLng {
+                latitude: 0.0,
+                longitude: 1.0,
+            }))
+            .unwrap(),
+            to_value(&[1]).unwrap(),
+            to_value(&[1, 2]).unwrap(),
+            to_value(&[2]).unwrap(),
+            to_value(&vec![("a", 2)].into_iter().collect::<BTreeMap<_, _>>()).unwrap(),
+            to_value(
+                &vec![("a", 2), ("b", 1)]
+                    .into_iter()
+                    .collect::<BTreeMap<_, _>>(),
+            )
+            .unwrap(),
+            to_value(&vec![("b", 1)].into_iter().collect::<BTreeMap<_, _>>()).unwrap(),
+        ];
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                assert_eq!(compare(a, b), i.cmp(&j), "{:?} {:?}", a, b);
+            }
+        }
+        assert_eq!(
+            compare(&to_value(&1).unwrap(), &to_value(&1.0).unwrap()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        let mut fields = to_fields(&user()).unwrap();
+        let address = match &mut fields.get_mut("address").unwrap().value_type {
+            Some(ValueType::MapValue(map)) => map,
+            _ => unreachable!(),
+        };
+        address
+            .fields
+            .insert("zip".into(), value_of(ValueType::IntegerValue(-1)));
+        let error = from_fields::<User>(&fields).unwrap_err();
+        assert_eq!(error.path(), "address.zip");
+        assert_eq!(error.message(), "invalid value: integer `-1`, expected u32");
+
+        let mut fields = to_fields(&user()).unwrap();
+        fields.insert(
+            "tags".into(),
+            value_of(ValueType::ArrayValue(ArrayValue {
+                values: vec![value_of(ValueType::StringValue("a".into())), null()],
+            })),
+        );
+        let error = from_fields::<User>(&fields).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "tags[1]: invalid type: unit value, expected a string"
+        );
+
+        let mut fields = to_fields(&user()).unwrap();
+        fields.insert(
+            "manager".into(),
+            value_of(ValueType::StringValue("users/charles".into())),
+        );
+        let error = from_fields::<User>(&fields).unwrap_err();
+        assert_eq!(error.path(), "manager");
+        assert_eq!(
+            error.message(),
+            "invalid type: string \"users/charles\", expected a reference"
+        );
+
+        let mut fields = to_fields(&user()).unwrap();
+        fields.remove("name");
+        let error = from_fields::<User>(&fields).unwrap_err();
+        assert_eq!(error.to_string(), "missing field `name`");
+    }
+}
@@ -0,0 +1,175 @@
+//! Flow control of the outstanding messages of a Pub/Sub publisher or subscriber, or of the
+//! outstanding entries of a Bigtable bulk mutator.
+
+use std::{collections::VecDeque, sync::Mutex};
+
+use tokio::sync::oneshot;
+
+/// What happens when the flow control limits are exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitExceededBehavior {
+    /// Waits until enough messages are completed.
+    Block,
+    /// Fails with a flow control error.
+    Error,
+}
+
+/// The limits of the outstanding messages, i.e. the messages published but not yet acknowledged
+/// by the server, or received but not yet acked or nacked.
+#[derive(Debug, Clone)]
+pub struct FlowControlSettings {
+    max_outstanding_messages: Option<usize>,
+    max_outstanding_bytes: Option<usize>,
+    limit_exceeded_behavior: LimitExceededBehavior,
+}
+
+impl Default for FlowControlSettings {
+    fn default() -> Self {
+        Self {
+            max_outstanding_messages: None,
+            max_outstanding_bytes: Some(100 * 1024 * 1024),
+            limit_exceeded_behavior: LimitExceededBehavior::Block,
+        }
+    }
+}
+
+impl FlowControlSettings {
+    pub fn max_outstanding_messages(self, max: impl Into<Option<usize>>) -> Self {
+        Self {
+            max_outstanding_messages: max.into(),
+            ..self
+        }
+    }
+
+    pub fn max_outstanding_bytes(self, max: impl Into<Option<usize>>) -> Self {
+        Self {
+            max_outstanding_bytes: max.into(),
+            ..self
+        }
+    }
+
+    pub fn limit_exceeded_behavior(self, behavior: LimitExceededBehavior) -> Self {
+        Self {
+            limit_exceeded_behavior: behavior,
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    messages: usize,
+    bytes: usize,
+    waiters: VecDeque<(usize, oneshot::Sender<()>)>,
+}
+
+// Admits messages in the order of `acquire` while the outstanding messages are within limits. A
+// message larger than the limits is admitted once nothing else is outstanding.
+#[derive(Debug)]
+pub(crate) struct FlowController {
+    settings: FlowControlSettings,
+    state: Mutex<State>,
+}
+
+impl FlowController {
+    pub(crate) fn new(settings: FlowControlSettings) -> Self {
+        Self {
+            settings,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    fn fits(&self, state: &State, size: usize) -> bool {
+        let within = |max: Option<usize>, value: usize| match max {
+            Some(max) => value <= max,
+            None => true,
+        };
+        state.messages == 0
+            || (within(self.settings.max_outstanding_messages, state.messages + 1)
+                && within(self.settings.max_outstanding_bytes, state.bytes + size))
+    }
+
+    // Admits a message of `size` bytes, returns false if the limits are exceeded with
+    // `LimitExceededBehavior::Error`.
+    pub(crate) async fn acquire(&self, size: usize) -> bool {
+        let rx = {
+            let mut state = self.state.lock().unwrap();
+            if state.waiters.is_empty() && self.fits(&state, size) {
+                state.messages += 1;
+                state.bytes += size;
+                return true;
+            }
+            if self.settings.limit_exceeded_behavior == LimitExceededBehavior::Error {
+                return false;
+            }
+            let (tx, rx) = oneshot::channel();
+            state.waiters.push_back((size, tx));
+            rx
+        };
+        // The sender is only dropped with the controller, which outlives `&self`.
+        rx.await.is_ok()
+    }
+
+    pub(crate) fn release(&self, size: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.messages -= 1;
+        state.bytes -= size;
+        while let Some((size, _)) = state.waiters.front() {
+            if !self.fits(&state, *size) {
+                break;
+            }
+            let (size, tx) = state.waiters.pop_front().unwrap();
+            // The waiter is gone when its future was dropped.
+            if tx.send(()).is_ok() {
+                state.messages += 1;
+                state.bytes += size;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::Arc, time::Duration};
+
+    #[tokio::test]
+    async fn test_limit_exceeded_error() {
+        let flow = FlowController::new(
+            FlowControlSettings::default()
+                .max_outstanding_messages(1)
+                .limit_exceeded_behavior(LimitExceededBehavior::Error),
+        );
+        assert!(flow.acquire(1).await);
+        assert!(!flow.acquire(1).await);
+        flow.release(1);
+        assert!(flow.acquire(1).await);
+    }
+
+    #[tokio::test]
+    async fn test_blocks_in_order() {
+        let flow = Arc::new(FlowController::new(
+            FlowControlSettings::default().max_outstanding_bytes(10),
+        ));
+        assert!(flow.acquire(8).await);
+        // Larger than the limit, admitted once nothing else is outstanding.
+        let large = tokio::spawn({
+            let flow = flow.clone();
+            async move { flow.acquire(20).await }
+        });
+        tokio::time::delay_for(Duration::from_millis(10)).await;
+        let small = tokio::spawn({
+            let flow = flow.clone();
+            async move { flow.acquire(1).await }
+        });
+        tokio::time::delay_for(Duration::from_millis(10)).await;
+        assert_eq!(flow.state.lock().unwrap().waiters.len(), 2);
+
+        flow.release(8);
+        assert!(large.await.unwrap());
+        assert_eq!(flow.state.lock().unwrap().waiters.len(), 1);
+        flow.release(20);
+        assert!(small.await.unwrap());
+        assert_eq!(flow.state.lock().unwrap().bytes, 1);
+    }
+}
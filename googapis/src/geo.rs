@@ -0,0 +1,599 @@
+//! Geometry of `google.type.LatLng` and `google.geo.type.Viewport`.
+//!
+//! The methods added to [`LatLng`] check ranges and compute great-circle distances and bearings
+//! on a spherical earth. Routes are exchanged as [encoded polylines], which
+//! [`encode_polyline`] and [`decode_polyline`] convert to and from points:
+//!
+//! ```ignore
+//! use googapis::geo::{decode_polyline, LatLng};
+//!
+//! let points = decode_polyline("_p~iF~ps|U_ulLnnqC_mqNvxq`@")?;
+//! let start = LatLng::new(38.5, -120.2)?;
+//! assert_eq!(points[0], start);
+//! println!("{:.0} m", start.distance(&points[1]));
+//! ```
+//!
+//! The `Polyline` of the Routes API gets the same conversions when its package is enabled with the
+//! `google-maps-routes-v1` feature, which `geo` does not enable.
+//!
+//! With the `geo-types` feature, points, viewports and polylines convert to and from the types
+//! of the [`geo-types`] crate.
+//!
+//! [encoded polylines]: https://developers.google.com/maps/documentation/utilities/polylinealgorithm
+//! [`geo-types`]: https://docs.rs/geo-types
+
+use std::{error, fmt};
+
+pub use crate::google::geo::r#type::Viewport;
+pub use crate::google::r#type::LatLng;
+
+/// The mean radius of the earth in meters.
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+// Encoded polylines have 5 decimal digits of precision.
+const POLYLINE_FACTOR: f64 = 1e5;
+
+/// An error of a geometry operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A coordinate is out of range or not a number.
+    OutOfRange(&'static str),
+    /// A required point of a viewport is missing.
+    Missing(&'static str),
+    /// The viewport contains no points.
+    EmptyViewport,
+    /// The viewport crosses the 180 degree longitude line.
+    CrossesAntimeridian,
+    /// The encoded polyline is invalid at the byte offset.
+    InvalidPolyline(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::OutOfRange(field) => write!(f, "{} is out of range", field),
+            Error::Missing(field) => write!(f, "missing {}", field),
+            Error::EmptyViewport => f.write_str("empty viewport"),
+            Error::CrossesAntimeridian => f.write_str("viewport crosses the antimeridian"),
+            Error::InvalidPolyline(offset) => write!(f, "invalid polyline at {}", offset),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+impl LatLng {
+    /// Returns a validated point.
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, Error> {
+        let point = LatLng {
+            latitude,
+            longitude,
+        };
+        point.validate()?;
+        Ok(point)
+    }
+
+    /// Checks that the latitude is in `-90.0..=90.0` and the longitude in `-180.0..=180.0`.
+    pub fn validate(&self) -> Result<(), Error> {
+        if !(-90.0..=90.0).contains(&self.latitude) {
+            return Err(Error::OutOfRange("latitude"));
+        }
+        if !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(Error::OutOfRange("longitude"));
+        }
+        Ok(())
+    }
+
+    /// Returns the great-circle distance in meters to `other`, with the haversine formula.
+    pub fn distance(&self, other: &LatLng) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlat = lat2 - lat1;
+        let dlng = (other.longitude - self.longitude).to_radians();
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlng / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().min(1.0).asin()
+    }
+
+    /// Returns the initial bearing in degrees clockwise from north, in `0.0..360.0`, of the
+    /// great-circle path to `other`.
+    pub fn bearing(&self, other: &LatLng) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let dlng = (other.longitude - self.longitude).to_radians();
+        let y = dlng.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlng.cos();
+        y.atan2(x).to_degrees().rem_euclid(360.0)
+    }
+}
+
+fn encode_value(value: i64, out: &mut String) {
+    let mut value = if value < 0 { !(value << 1) } else { value << 1 };
+    while value >= 0x20 {
+        out.push(((0x20 | (value & 0x1f)) + 63) as u8 as char);
+        value >>= 5;
+    }
+    out.push((value + 63) as u8 as char);
+}
+
+/// Returns the encoded polyline of `points`.
+pub fn encode_polyline(points: &[LatLng]) -> String {
+    let mut out = String::new();
+    let (mut prev_lat, mut prev_lng) = (0, 0);
+    for point in points {
+        let lat = (point.latitude * POLYLINE_FACTOR).round() as i64;
+        let lng = (point.longitude * POLYLINE_FACTOR).round() as i64;
+        encode_value(lat - prev_lat, &mut out);
+        encode_value(lng - prev_lng, &mut out);
+        prev_lat = lat;
+        prev_lng = lng;
+    }
+    out
+}
+
+// Returns the next value of `bytes` starting at `*offset`.
+fn decode_value(bytes: &[u8], offset: &mut usize) -> Result<i64, Error> {
+    let (mut value, mut shift) = (0i64, 0);
+    loop {
+        let byte = match bytes.get(*offset) {
+            Some(&byte) if (63..127).contains(&byte) && shift < 64 => i64::from(byte - 63),
+            _ => return Err(Error::InvalidPolyline(*offset)),
+        };
+        *offset += 1;
+        value |= (byte & 0x1f) << shift;
+        shift += 5;
+        if byte < 0x20 {
+            break;
+        }
+    }
+    Ok(if value & 1 == 1 {
+        !(value >> 1)
+    } else {
+        value >> 1
+    })
+}
+
+/// Returns the points of an encoded polyline.
+pub fn decode_polyline(polyline: &str) -> Result<Vec<LatLng>, Error> {
+    let bytes = polyline.as_bytes();
+    let mut points = Vec::new();
+    let (mut offset, mut lat, mut lng) = (0, 0i64, 0i64);
+    while offset < bytes.len() {
+        let start = offset;
+        lat = lat
+            .checked_add(decode_value(bytes, &mut offset)?)
+            .ok_or(Error::InvalidPolyline(start))?;
+        let start = offset;
+        lng = lng
+            .checked_add(decode_value(bytes, &mut offset)?)
+            .ok_or(Error::InvalidPolyline(start))?;
+        points.push(LatLng::new(
+            lat as f64 / POLYLINE_FACTOR,
+            lng as f64 / POLYLINE_FACTOR,
+        )?);
+    }
+    Ok(points)
+}
+
+#[cfg(feature = "google-maps-routes-v1")]
+mod routes {
+    use super::{decode_polyline, encode_polyline, Error, LatLng};
+    use crate::google::maps::routes::v1::{polyline::PolylineType, Polyline};
+
+    impl Polyline {
+        /// Returns the polyline of `points`.
+        pub fn from_points(points: &[LatLng]) -> Self {
+            Polyline {
+                polyline_type: Some(PolylineType::EncodedPolyline(encode_polyline(points))),
+            }
+        }
+
+        /// Returns the points of the polyline, none if it is unset.
+        pub fn points(&self) -> Result<Vec<LatLng>, Error> {
+            match &self.polyline_type {
+                Some(PolylineType::EncodedPolyline(polyline)) => decode_polyline(polyline),
+                None => Ok(Vec::new()),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_polyline() {
+            let points = vec![
+                LatLng::new(38.5, -120.2).unwrap(),
+                LatLng::new(40.7, -120.95).unwrap(),
+            ];
+            let polyline = Polyline::from_points(&points);
+            assert_eq!(polyline.points(), Ok(points));
+            assert_eq!(Polyline::default().points(), Ok(vec![]));
+        }
+    }
+}
+
+mod viewport {
+    use super::{Error, LatLng, Viewport};
+
+    // Returns the width in degrees of the longitude range `lo..=hi`.
+    fn width(lo: f64, hi: f64) -> f64 {
+        if lo <= hi {
+            hi - lo
+        } else {
+            hi - lo + 360.0
+        }
+    }
+
+    fn contains(lo: f64, hi: f64, lng: f64) -> bool {
+        if lo <= hi {
+            lo <= lng && lng <= hi
+        } else {
+            lng >= lo || lng <= hi
+        }
+    }
+
+    // Returns the smallest longitude range containing `a` and `b`.
+    fn union(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+        const FULL: (f64, f64) = (-180.0, 180.0);
+        let (wa, wb) = (width(a.0, a.1), width(b.0, b.1));
+        if wa >= 360.0 || wb >= 360.0 {
+            return FULL;
+        }
+        // Offsets east of `a.0`, `a` covers `0..=wa` and `b` covers `start..=end`.
+        let start = (b.0 - a.0).rem_euclid(360.0);
+        let end = start + wb;
+        if start <= wa {
+            if end <= wa {
+                a
+            } else if end >= 360.0 {
+                FULL
+            } else {
+                (a.0, b.1)
+            }
+        } else if end >= 360.0 + wa {
+            b
+        } else if end >= 360.0 {
+            (b.0, a.1)
+        } else if start - wa <= 360.0 - end {
+            (a.0, b.1)
+        } else {
+            (b.0, a.1)
+        }
+    }
+
+    impl Viewport {
+        /// Returns a validated viewport of the diagonally opposite `low` and `high` points.
+        pub fn new(low: LatLng, high: LatLng) -> Result<Self, Error> {
+            let viewport = Viewport {
+                low: Some(low),
+                high: Some(high),
+            };
+            viewport.validate()?;
+            Ok(viewport)
+        }
+
+        /// Checks that the points are present and valid and that the viewport is not empty.
+        pub fn validate(&self) -> Result<(&LatLng, &LatLng), Error> {
+            let low = self.low.as_ref().ok_or(Error::Missing("low"))?;
+            let high = self.high.as_ref().ok_or(Error::Missing("high"))?;
+            low.validate()?;
+            high.validate()?;
+            if low.latitude > high.latitude || (low.longitude == 180.0 && high.longitude == -180.0)
+            {
+                return Err(Error::EmptyViewport);
+            }
+            Ok((low, high))
+        }
+
+        /// Returns whether `point` is in the viewport, boundary included.
+        pub fn contains(&self, point: &LatLng) -> bool {
+            match self.validate() {
+                Ok((low, high)) => {
+                    low.latitude <= point.latitude
+                        && point.latitude <= high.latitude
+                        && contains(low.longitude, high.longitude, point.longitude)
+                }
+                Err(_) => false,
+            }
+        }
+
+        /// Returns the smallest viewport containing both `self` and `other`.
+        pub fn union(&self, other: &Viewport) -> Result<Viewport, Error> {
+            let (low1, high1) = self.validate()?;
+            let (low2, high2) = other.validate()?;
+            let (lo, hi) = union(
+                (low1.longitude, high1.longitude),
+                (low2.longitude, high2.longitude),
+            );
+            Ok(Viewport {
+                low: Some(LatLng {
+                    latitude: low1.latitude.min(low2.latitude),
+                    longitude: lo,
+                }),
+                high: Some(LatLng {
+                    latitude: high1.latitude.max(high2.latitude),
+                    longitude: hi,
+                }),
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn viewport(low: (f64, f64), high: (f64, f64)) -> Viewport {
+            Viewport::new(
+                LatLng::new(low.0, low.1).unwrap(),
+                LatLng::new(high.0, high.1).unwrap(),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn test_validate() {
+            let point = |latitude, longitude| LatLng {
+                latitude,
+                longitude,
+            };
+            assert_eq!(
+                Viewport::new(point(10.0, 0.0), point(0.0, 0.0)),
+                Err(Error::EmptyViewport)
+            );
+            assert_eq!(
+                Viewport::new(point(0.0, 180.0), point(0.0, -180.0)),
+                Err(Error::EmptyViewport)
+            );
+            assert_eq!(Viewport::default().validate(), Err(Error::Missing("low")));
+        }
+
+        #[test]
+        fn test_contains() {
+            let nyc = viewport((40.477398, -74.259087), (40.91618, -73.70018));
+            assert!(nyc.contains(&LatLng::new(40.7128, -74.006).unwrap()));
+            assert!(nyc.contains(nyc.low.as_ref().unwrap()));
+            assert!(!nyc.contains(&LatLng::new(51.5074, -0.1278).unwrap()));
+
+            let pacific = viewport((-10.0, 170.0), (10.0, -170.0));
+            assert!(pacific.contains(&LatLng::new(0.0, 180.0).unwrap()));
+            assert!(pacific.contains(&LatLng::new(0.0, -175.0).unwrap()));
+            assert!(!pacific.contains(&LatLng::new(0.0, 0.0).unwrap()));
+        }
+
+        #[test]
+        fn test_union() {
+            let cases = vec![
+                // Disjoint, joined over the shorter gap.
+                (
+                    ((0.0, 10.0), (1.0, 20.0)),
+                    ((-5.0, 30.0), (0.0, 40.0)),
+                    ((-5.0, 10.0), (1.0, 40.0)),
+                ),
+                (
+                    ((0.0, 170.0), (1.0, 175.0)),
+                    ((0.0, -175.0), (1.0, -170.0)),
+                    ((0.0, 170.0), (1.0, -170.0)),
+                ),
+                // Overlapping and nested.
+                (
+                    ((0.0, 10.0), (1.0, 30.0)),
+                    ((0.0, 20.0), (1.0, 40.0)),
+                    ((0.0, 10.0), (1.0, 40.0)),
+                ),
+                (
+                    ((0.0, 10.0), (1.0, 40.0)),
+                    ((0.0, 20.0), (1.0, 30.0)),
+                    ((0.0, 10.0), (1.0, 40.0)),
+                ),
+                (
+                    ((0.0, 20.0), (1.0, 30.0)),
+                    ((0.0, 10.0), (1.0, 40.0)),
+                    ((0.0, 10.0), (1.0, 40.0)),
+                ),
+                // Crossing the antimeridian from either side.
+                (
+                    ((0.0, 170.0), (1.0, -170.0)),
+                    ((0.0, -175.0), (1.0, -160.0)),
+                    ((0.0, 170.0), (1.0, -160.0)),
+                ),
+                (
+                    ((0.0, -175.0), (1.0, -160.0)),
+                    ((0.0, 170.0), (1.0, -170.0)),
+                    ((0.0, 170.0), (1.0, -160.0)),
+                ),
+                // Covering all longitudes.
+                (
+                    ((0.0, -100.0), (1.0, 100.0)),
+                    ((0.0, 90.0), (1.0, -90.0)),
+                    ((0.0, -180.0), (1.0, 180.0)),
+                ),
+            ];
+            for (a, b, expected) in cases {
+                let (a, b) = (viewport(a.0, a.1), viewport(b.0, b.1));
+                assert_eq!(
+                    a.union(&b),
+                    Ok(viewport(expected.0, expected.1)),
+                    "{:?} {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+mod convert {
+    use std::convert::TryFrom;
+
+    #[cfg(feature = "google-maps-routes-v1")]
+    use geo_types::LineString;
+    use geo_types::{Coordinate, Point, Rect};
+
+    use super::{Error, LatLng, Viewport};
+    #[cfg(feature = "google-maps-routes-v1")]
+    use crate::google::maps::routes::v1::Polyline;
+
+    impl From<LatLng> for Coordinate<f64> {
+        fn from(point: LatLng) -> Self {
+            Coordinate {
+                x: point.longitude,
+                y: point.latitude,
+            }
+        }
+    }
+
+    impl From<Coordinate<f64>> for LatLng {
+        fn from(c: Coordinate<f64>) -> Self {
+            LatLng {
+                latitude: c.y,
+                longitude: c.x,
+            }
+        }
+    }
+
+    impl From<LatLng> for Point<f64> {
+        fn from(point: LatLng) -> Self {
+            Point(point.into())
+        }
+    }
+
+    impl From<Point<f64>> for LatLng {
+        fn from(point: Point<f64>) -> Self {
+            point.0.into()
+        }
+    }
+
+    /// Fails if the viewport crosses the antimeridian, which a `Rect` cannot represent.
+    impl TryFrom<&Viewport> for Rect<f64> {
+        type Error = Error;
+
+        fn try_from(viewport: &Viewport) -> Result<Self, Self::Error> {
+            let (low, high) = viewport.validate()?;
+            if low.longitude > high.longitude {
+                return Err(Error::CrossesAntimeridian);
+            }
+            Ok(Rect::new(low.clone(), high.clone()))
+        }
+    }
+
+    impl From<Rect<f64>> for Viewport {
+        fn from(rect: Rect<f64>) -> Self {
+            Viewport {
+                low: Some(rect.min().into()),
+                high: Some(rect.max().into()),
+            }
+        }
+    }
+
+    #[cfg(feature = "google-maps-routes-v1")]
+    impl TryFrom<&Polyline> for LineString<f64> {
+        type Error = Error;
+
+        fn try_from(polyline: &Polyline) -> Result<Self, Self::Error> {
+            Ok(polyline.points()?.into_iter().collect())
+        }
+    }
+
+    #[cfg(feature = "google-maps-routes-v1")]
+    impl From<&LineString<f64>> for Polyline {
+        fn from(line: &LineString<f64>) -> Self {
+            let points = line.0.iter().map(|&c| LatLng::from(c)).collect::<Vec<_>>();
+            Polyline::from_points(&points)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_point() {
+            let point = LatLng::new(38.5, -120.2).unwrap();
+            assert_eq!(Point::from(point.clone()), Point::new(-120.2, 38.5));
+            assert_eq!(LatLng::from(Point::new(-120.2, 38.5)), point);
+        }
+
+        #[test]
+        fn test_rect() {
+            let low = LatLng::new(-10.0, 170.0).unwrap();
+            let high = LatLng::new(10.0, 175.0).unwrap();
+            let viewport = Viewport::new(low.clone(), high.clone()).unwrap();
+            let rect = Rect::try_from(&viewport).unwrap();
+            assert_eq!(rect, Rect::new(low, high));
+            assert_eq!(Viewport::from(rect), viewport);
+
+            let viewport = Viewport::new(
+                LatLng::new(-10.0, 170.0).unwrap(),
+                LatLng::new(10.0, -170.0).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(Rect::try_from(&viewport), Err(Error::CrossesAntimeridian));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLYLINE: &str = "_p~iF~ps|U_ulLnnqC_mqNvxq`@";
+
+    fn points() -> Vec<LatLng> {
+        vec![
+            LatLng::new(38.5, -120.2).unwrap(),
+            LatLng::new(40.7, -120.95).unwrap(),
+            LatLng::new(43.252, -126.453).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(LatLng::new(90.0, -180.0).is_ok());
+        assert_eq!(LatLng::new(90.1, 0.0), Err(Error::OutOfRange("latitude")));
+        assert_eq!(
+            LatLng::new(0.0, f64::NAN),
+            Err(Error::OutOfRange("longitude"))
+        );
+    }
+
+    #[test]
+    fn test_distance_and_bearing() {
+        let paris = LatLng::new(48.8566, 2.3522).unwrap();
+        let london = LatLng::new(51.5074, -0.1278).unwrap();
+        assert!((paris.distance(&london) - 343_560.0).abs() < 100.0);
+        assert_eq!(paris.distance(&paris), 0.0);
+        assert!((paris.bearing(&london) - 330.0).abs() < 0.1);
+
+        let origin = LatLng::new(0.0, 0.0).unwrap();
+        assert_eq!(origin.bearing(&LatLng::new(0.0, 1.0).unwrap()), 90.0);
+        assert_eq!(origin.bearing(&LatLng::new(-1.0, 0.0).unwrap()), 180.0);
+    }
+
+    #[test]
+    fn test_encode_polyline() {
+        assert_eq!(encode_polyline(&points()), POLYLINE);
+        assert_eq!(encode_polyline(&[]), "");
+    }
+
+    #[test]
+    fn test_decode_polyline() {
+        assert_eq!(decode_polyline(POLYLINE), Ok(points()));
+        assert_eq!(decode_polyline(""), Ok(vec![]));
+        assert_eq!(decode_polyline("_p~iF"), Err(Error::InvalidPolyline(5)));
+        assert_eq!(decode_polyline("_p~iF~ps|"), Err(Error::InvalidPolyline(9)));
+        assert_eq!(
+            decode_polyline("_p~iF ps|U"),
+            Err(Error::InvalidPolyline(5))
+        );
+        assert_eq!(
+            decode_polyline(&"~".repeat(14)),
+            Err(Error::InvalidPolyline(13))
+        );
+        // The largest values are out of range, instead of overflowing.
+        let value = format!("}}{}F", "~".repeat(11));
+        assert_eq!(
+            decode_polyline(&value.repeat(4)),
+            Err(Error::OutOfRange("latitude"))
+        );
+    }
+}
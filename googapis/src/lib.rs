@@ -84,6 +84,40 @@
 /// ````
 pub const CERTIFICATES: &[u8] = include_bytes!("../data/roots.pem");
 
+#[cfg(feature = "bigtable")]
+pub mod bigtable;
+#[cfg(feature = "channel")]
+pub mod channel;
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub mod datetime;
+#[cfg(feature = "firestore")]
+pub mod firestore;
+#[cfg(any(feature = "bigtable", feature = "pubsub"))]
+mod flow_control;
+#[cfg(feature = "geo")]
+pub mod geo;
+#[cfg(feature = "longrunning")]
+pub mod longrunning;
+#[cfg(feature = "money")]
+pub mod money;
+#[cfg(feature = "operations")]
+pub mod operations;
+#[cfg(feature = "paging")]
+pub mod paging;
+#[cfg(any(
+    feature = "pubsub",
+    feature = "pubsub-emulator",
+    feature = "pubsub-filter",
+    feature = "pubsub-push"
+))]
+pub mod pubsub;
+#[cfg(feature = "retry")]
+pub mod retry;
+#[cfg(any(feature = "firestore", feature = "pubsub-push", feature = "spanner"))]
+mod rfc3339;
+#[cfg(feature = "spanner")]
+pub mod spanner;
+
 #[allow(unused_macros)]
 macro_rules! include_proto {
     ($package: tt) => {
@@ -0,0 +1,504 @@
+//! Typed long-running operations on top of [`google.longrunning`].
+//!
+//! For each method annotated with `google.longrunning.operation_info`, an `*_operation` helper is
+//! generated on the `*_client` types. It returns an [`Operation`] typed with the response and
+//! metadata declared by the annotation, which is then waited on with an [`OperationPoller`]:
+//!
+//! ```ignore
+//! use googapis::{
+//!     google::longrunning::operations_client::OperationsClient,
+//!     longrunning::{OperationPoller, PollSettings},
+//! };
+//!
+//! let op = client.create_database_operation(request).await?;
+//! let mut poller = OperationPoller::new(OperationsClient::new(channel))
+//!     .settings(PollSettings::default().total_timeout(Duration::from_secs(600)));
+//! let database = poller.wait(op).await?;
+//! ```
+//!
+//! [`google.longrunning`]: https://github.com/googleapis/googleapis/tree/master/google/longrunning
+
+use std::{
+    error, fmt,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use prost::{DecodeError, Message};
+use tonic::{Code, Status};
+
+#[cfg(test)]
+mod fake;
+
+use crate::google::{
+    longrunning::{
+        operation, operations_client::OperationsClient, CancelOperationRequest,
+        GetOperationRequest, Operation as RawOperation, WaitOperationRequest,
+    },
+    rpc::Status as RpcStatus,
+};
+
+/// An error of a long-running operation.
+#[derive(Debug)]
+pub enum Error {
+    /// The call to the operations service failed.
+    Status(Status),
+    /// The operation finished with an error.
+    Operation(RpcStatus),
+    /// The response or metadata could not be decoded.
+    Decode(DecodeError),
+    /// The response or metadata is not of the expected type.
+    UnexpectedType { expected: String, actual: String },
+    /// The operation did not finish before the `total_timeout` of the poller.
+    Timeout,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Status(status) => write!(f, "operations service error: {}", status),
+            Error::Operation(status) => write!(
+                f,
+                "operation failed: code: {}, message: {}",
+                status.code, status.message
+            ),
+            Error::Decode(e) => write!(f, "failed to decode operation: {}", e),
+            Error::UnexpectedType { expected, actual } => write!(
+                f,
+                "unexpected type: expected {}, actual {}",
+                expected, actual
+            ),
+            Error::Timeout => f.write_str("operation timed out"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Status(status) => Some(status),
+            Error::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Status> for Error {
+    fn from(status: Status) -> Self {
+        Error::Status(status)
+    }
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Error::Decode(e)
+    }
+}
+
+/// A `google.longrunning.Operation` whose response is of type `R` and metadata of type `M`.
+///
+/// Use `()` for operations returning `google.protobuf.Empty`.
+#[derive(Debug, Clone)]
+pub struct Operation<R, M> {
+    inner: RawOperation,
+    response_type: Option<&'static str>,
+    metadata_type: Option<&'static str>,
+    _marker: PhantomData<fn() -> (R, M)>,
+}
+
+impl<R, M> Operation<R, M>
+where
+    R: Message + Default,
+    M: Message + Default,
+{
+    /// Wraps `inner` without checking the type URLs of the response and metadata.
+    pub fn new(inner: RawOperation) -> Self {
+        Self {
+            inner,
+            response_type: None,
+            metadata_type: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Wraps `inner`, checking the type URLs of the response and metadata when decoding them,
+    /// e.g. `type.googleapis.com/google.spanner.admin.database.v1.Database`.
+    pub fn with_types(
+        inner: RawOperation,
+        response_type: &'static str,
+        metadata_type: &'static str,
+    ) -> Self {
+        Self {
+            response_type: Some(response_type),
+            metadata_type: Some(metadata_type),
+            ..Self::new(inner)
+        }
+    }
+
+    /// Returns the server-assigned name of the operation.
+    pub fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    /// Returns whether the operation is completed.
+    pub fn done(&self) -> bool {
+        self.inner.done
+    }
+
+    /// Decodes the metadata, `None` if the server did not send any.
+    pub fn metadata(&self) -> Option<Result<M, Error>> {
+        let any = self.inner.metadata.as_ref()?;
+        Some(decode(any, self.metadata_type))
+    }
+
+    /// Decodes the result, `None` if the operation is not completed.
+    pub fn result(&self) -> Option<Result<R, Error>> {
+        match self.inner.result.as_ref()? {
+            operation::Result::Error(status) => Some(Err(Error::Operation(status.clone()))),
+            operation::Result::Response(any) => Some(decode(any, self.response_type)),
+        }
+    }
+
+    pub fn into_inner(self) -> RawOperation {
+        self.inner
+    }
+
+    fn update(&mut self, inner: RawOperation) {
+        self.inner = inner;
+    }
+}
+
+fn decode<T>(any: &prost_types::Any, type_url: Option<&str>) -> Result<T, Error>
+where
+    T: Message + Default,
+{
+    if let Some(expected) = type_url {
+        if any.type_url != expected {
+            return Err(Error::UnexpectedType {
+                expected: expected.to_owned(),
+                actual: any.type_url.clone(),
+            });
+        }
+    }
+    Ok(T::decode(&any.value[..])?)
+}
+
+/// Settings controlling how often an operation is polled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PollSettings {
+    /// The delay before the first poll.
+    pub initial_delay: Duration,
+    /// The upper bound of the delay between polls.
+    pub max_delay: Duration,
+    /// The factor the delay is multiplied by after each poll.
+    pub delay_multiplier: f64,
+    /// The overall deadline of the wait, unlimited if `None`.
+    pub total_timeout: Option<Duration>,
+    /// Polls with `WaitOperation` with this timeout instead of `GetOperation`.
+    pub wait_timeout: Option<Duration>,
+}
+
+impl Default for PollSettings {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(45),
+            delay_multiplier: 1.5,
+            total_timeout: Some(Duration::from_secs(3600)),
+            wait_timeout: None,
+        }
+    }
+}
+
+impl PollSettings {
+    pub fn delay(self, initial: Duration, max: Duration, multiplier: f64) -> Self {
+        Self {
+            initial_delay: initial,
+            max_delay: max,
+            delay_multiplier: multiplier,
+            ..self
+        }
+    }
+
+    pub fn total_timeout(self, timeout: impl Into<Option<Duration>>) -> Self {
+        Self {
+            total_timeout: timeout.into(),
+            ..self
+        }
+    }
+
+    pub fn wait_timeout(self, timeout: impl Into<Option<Duration>>) -> Self {
+        Self {
+            wait_timeout: timeout.into(),
+            ..self
+        }
+    }
+}
+
+/// Polls operations with an `OperationsClient` until they are completed.
+#[derive(Debug, Clone)]
+pub struct OperationPoller<T> {
+    client: OperationsClient<T>,
+    settings: PollSettings,
+}
+
+impl<T> OperationPoller<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody>,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    pub fn new(client: OperationsClient<T>) -> Self {
+        Self {
+            client,
+            settings: PollSettings::default(),
+        }
+    }
+
+    pub fn settings(self, settings: PollSettings) -> Self {
+        Self { settings, ..self }
+    }
+
+    /// Fetches the latest state of `op`.
+    pub async fn poll<R, M>(&mut self, op: &mut Operation<R, M>) -> Result<(), Status>
+    where
+        R: Message + Default,
+        M: Message + Default,
+    {
+        let name = op.name().to_owned();
+        let response = match self.settings.wait_timeout {
+            Some(timeout) => {
+                let request = WaitOperationRequest {
+                    name,
+                    timeout: Some(prost_types::Duration {
+                        seconds: timeout.as_secs() as i64,
+                        nanos: timeout.subsec_nanos() as i32,
+                    }),
+                };
+                match self.client.wait_operation(request).await {
+                    // `WaitOperation` is optional, fall back to `GetOperation`.
+                    Err(status) if status.code() == Code::Unimplemented => {
+                        self.settings.wait_timeout = None;
+                        self.client
+                            .get_operation(GetOperationRequest {
+                                name: op.name().to_owned(),
+                            })
+                            .await
+                    }
+                    response => response,
+                }
+            }
+            None => {
+                self.client
+                    .get_operation(GetOperationRequest { name })
+                    .await
+            }
+        }?;
+        op.update(response.into_inner());
+        Ok(())
+    }
+
+    /// Polls `op` until it is completed and returns its decoded response.
+    pub async fn wait<R, M>(&mut self, op: Operation<R, M>) -> Result<R, Error>
+    where
+        R: Message + Default,
+        M: Message + Default,
+    {
+        self.wait_with(op, |_| {}).await
+    }
+
+    /// Polls `op` until it is completed like [`wait`](Self::wait), and calls `f` with the
+    /// operation after each poll, e.g. to report the progress in its metadata.
+    pub async fn wait_with<R, M, F>(
+        &mut self,
+        mut op: Operation<R, M>,
+        mut f: F,
+    ) -> Result<R, Error>
+    where
+        R: Message + Default,
+        M: Message + Default,
+        F: FnMut(&Operation<R, M>),
+    {
+        let deadline = self.settings.total_timeout.map(|t| Instant::now() + t);
+        let mut delay = self.settings.initial_delay;
+
+        while !op.done() {
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if now + delay >= deadline {
+                    return Err(Error::Timeout);
+                }
+            }
+            tokio::time::delay_for(delay).await;
+            delay = delay
+                .mul_f64(self.settings.delay_multiplier)
+                .min(self.settings.max_delay);
+            self.poll(&mut op).await?;
+            f(&op);
+        }
+
+        op.result()
+            .unwrap_or_else(|| Err(Error::Status(Status::internal("operation has no result"))))
+    }
+
+    /// Requests the cancellation of `op`. The operation may still complete afterwards.
+    pub async fn cancel<R, M>(&mut self, op: &Operation<R, M>) -> Result<(), Status>
+    where
+        R: Message + Default,
+        M: Message + Default,
+    {
+        self.client
+            .cancel_operation(CancelOperationRequest {
+                name: op.name().to_owned(),
+            })
+            .await
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::longrunning::fake::{FakeOperations, FakeServer};
+    use std::sync::Arc;
+
+    const RESPONSE_TYPE: &str = "type.googleapis.com/google.rpc.Status";
+    const METADATA_TYPE: &str = "type.googleapis.com/google.protobuf.Duration";
+
+    fn encode(msg: &impl Message) -> Vec<u8> {
+        let mut buf = Vec::new();
+        msg.encode(&mut buf).unwrap();
+        buf
+    }
+
+    fn any(type_url: &str, msg: &impl Message) -> prost_types::Any {
+        prost_types::Any {
+            type_url: type_url.into(),
+            value: encode(msg),
+        }
+    }
+
+    fn raw(done: bool, result: Option<operation::Result>) -> RawOperation {
+        RawOperation {
+            name: "operations/1".into(),
+            metadata: Some(any(
+                METADATA_TYPE,
+                &prost_types::Duration {
+                    seconds: 1,
+                    nanos: 0,
+                },
+            )),
+            done,
+            result,
+        }
+    }
+
+    fn response() -> RpcStatus {
+        RpcStatus {
+            code: 0,
+            message: "ok".into(),
+            details: Vec::new(),
+        }
+    }
+
+    type TestOperation = Operation<RpcStatus, prost_types::Duration>;
+
+    #[test]
+    fn test_operation_result() {
+        let op = TestOperation::with_types(raw(false, None), RESPONSE_TYPE, METADATA_TYPE);
+        assert!(!op.done());
+        assert!(op.result().is_none());
+        assert_eq!(op.metadata().unwrap().unwrap().seconds, 1);
+
+        let result = operation::Result::Response(any(RESPONSE_TYPE, &response()));
+        let op = TestOperation::with_types(raw(true, Some(result)), RESPONSE_TYPE, METADATA_TYPE);
+        assert_eq!(op.result().unwrap().unwrap(), response());
+
+        let error = RpcStatus {
+            code: Code::Aborted as i32,
+            message: "aborted".into(),
+            details: Vec::new(),
+        };
+        let result = operation::Result::Error(error.clone());
+        let op = TestOperation::new(raw(true, Some(result)));
+        match op.result().unwrap() {
+            Err(Error::Operation(status)) => assert_eq!(status, error),
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_operation_unexpected_type() {
+        let result = operation::Result::Response(any(METADATA_TYPE, &response()));
+        let op = TestOperation::with_types(raw(true, Some(result)), RESPONSE_TYPE, METADATA_TYPE);
+        match op.result().unwrap() {
+            Err(Error::UnexpectedType { expected, actual }) => {
+                assert_eq!(expected, RESPONSE_TYPE);
+                assert_eq!(actual, METADATA_TYPE);
+            }
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    // Polls a fake completing the operation on the `done_after`-th poll.
+    fn poller(done_after: usize) -> (OperationPoller<FakeServer>, Arc<FakeOperations>) {
+        let fake = Arc::new(FakeOperations::default());
+        let result = operation::Result::Response(any(RESPONSE_TYPE, &response()));
+        fake.complete_after(raw(true, Some(result)), done_after);
+        let poller = OperationPoller::new(fake.client()).settings(PollSettings::default().delay(
+            Duration::from_millis(1),
+            Duration::from_millis(4),
+            2.0,
+        ));
+        (poller, fake)
+    }
+
+    #[tokio::test]
+    async fn test_poller_wait() {
+        let (mut poller, fake) = poller(3);
+        let op = TestOperation::with_types(raw(false, None), RESPONSE_TYPE, METADATA_TYPE);
+        assert_eq!(poller.wait(op).await.unwrap(), response());
+        assert_eq!(fake.polls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_poller_wait_with() {
+        let (mut poller, _) = poller(3);
+        let op = TestOperation::with_types(raw(false, None), RESPONSE_TYPE, METADATA_TYPE);
+        let mut done = Vec::new();
+        let result = poller.wait_with(op, |op| done.push(op.done())).await;
+        assert_eq!(result.unwrap(), response());
+        assert_eq!(done, vec![false, false, true]);
+    }
+
+    #[tokio::test]
+    async fn test_poller_wait_operation_fallback() {
+        let (mut poller, fake) = poller(2);
+        poller.settings.wait_timeout = Some(Duration::from_secs(1));
+        let op = TestOperation::new(raw(false, None));
+        assert_eq!(poller.wait(op).await.unwrap(), response());
+        assert_eq!(fake.polls(), 2);
+        assert_eq!(poller.settings.wait_timeout, None);
+    }
+
+    #[tokio::test]
+    async fn test_poller_timeout() {
+        let (mut poller, fake) = poller(usize::MAX);
+        fake.support_wait();
+        poller.settings.total_timeout = Some(Duration::from_millis(20));
+        let op = TestOperation::new(raw(false, None));
+        match poller.wait(op).await {
+            Err(Error::Timeout) => {}
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poller_cancel() {
+        let (mut poller, fake) = poller(1);
+        let op = TestOperation::new(raw(false, None));
+        poller.cancel(&op).await.unwrap();
+        assert_eq!(fake.cancels(), 1);
+    }
+}
@@ -0,0 +1,117 @@
+// An in-memory fake of the Operations service for the tests of the `longrunning` module.
+
+use std::sync::{Arc, Mutex};
+
+use tonic::{Request, Response, Status};
+
+use crate::google::longrunning::{
+    operations_client::OperationsClient, operations_server, CancelOperationRequest,
+    DeleteOperationRequest, GetOperationRequest, ListOperationsRequest, ListOperationsResponse,
+    Operation, WaitOperationRequest,
+};
+
+pub(crate) type FakeServer = operations_server::OperationsServer<Arc<FakeOperations>>;
+
+#[derive(Default)]
+struct State {
+    // The operation returned by the polls, which is done from the `done_after`-th one.
+    operation: Operation,
+    done_after: usize,
+    polls: usize,
+    cancels: usize,
+    // Whether `wait_operation` is implemented, and polls like `get_operation`.
+    wait: bool,
+}
+
+#[derive(Default)]
+pub(crate) struct FakeOperations {
+    state: Mutex<State>,
+}
+
+impl FakeOperations {
+    pub(crate) fn client(self: &Arc<Self>) -> OperationsClient<FakeServer> {
+        OperationsClient::new(operations_server::OperationsServer::new(self.clone()))
+    }
+
+    // Returns `operation` from the `done_after`-th poll, and the operation not done yet before.
+    pub(crate) fn complete_after(&self, operation: Operation, done_after: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.operation = operation;
+        state.done_after = done_after;
+    }
+
+    // Implements `wait_operation`, which otherwise fails with `UNIMPLEMENTED`.
+    pub(crate) fn support_wait(&self) {
+        self.state.lock().unwrap().wait = true;
+    }
+
+    // The number of calls of `get_operation` and `wait_operation`.
+    pub(crate) fn polls(&self) -> usize {
+        self.state.lock().unwrap().polls
+    }
+
+    // The number of calls of `cancel_operation`.
+    pub(crate) fn cancels(&self) -> usize {
+        self.state.lock().unwrap().cancels
+    }
+
+    fn poll(&self) -> Operation {
+        let mut state = self.state.lock().unwrap();
+        state.polls += 1;
+        if state.polls < state.done_after {
+            Operation {
+                done: false,
+                result: None,
+                ..state.operation.clone()
+            }
+        } else {
+            state.operation.clone()
+        }
+    }
+}
+
+fn unimplemented<T>() -> Result<Response<T>, Status> {
+    Err(Status::unimplemented("not implemented by the fake"))
+}
+
+#[tonic::async_trait]
+impl operations_server::Operations for Arc<FakeOperations> {
+    async fn list_operations(
+        &self,
+        _: Request<ListOperationsRequest>,
+    ) -> Result<Response<ListOperationsResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn get_operation(
+        &self,
+        _: Request<GetOperationRequest>,
+    ) -> Result<Response<Operation>, Status> {
+        Ok(Response::new(self.poll()))
+    }
+
+    async fn delete_operation(
+        &self,
+        _: Request<DeleteOperationRequest>,
+    ) -> Result<Response<()>, Status> {
+        unimplemented()
+    }
+
+    async fn cancel_operation(
+        &self,
+        _: Request<CancelOperationRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.state.lock().unwrap().cancels += 1;
+        Ok(Response::new(()))
+    }
+
+    async fn wait_operation(
+        &self,
+        _: Request<WaitOperationRequest>,
+    ) -> Result<Response<Operation>, Status> {
+        if !self.state.lock().unwrap().wait {
+            return unimplemented();
+        }
+        Ok(Response::new(self.poll()))
+    }
+}
@@ -0,0 +1,601 @@
+//! Arithmetic and formatting of `google.type.Money` without floating point.
+//!
+//! An amount is `units + nanos / 10^9` where `units` and `nanos` must have the same sign. The
+//! methods added to [`Money`] validate this, and compute on the exact number of nanos:
+//!
+//! ```ignore
+//! use googapis::{google::r#type::Money, money::RoundingMode};
+//! use rust_decimal::Decimal;
+//!
+//! let price: Money = "19.99 USD".parse()?;
+//! let total = price.checked_add(&Money::new("USD", 5, 0)?)?;
+//! let discounted = total
+//!     .scale(Decimal::new(85, 2), RoundingMode::HalfEven)?
+//!     .round_to_minor_units(RoundingMode::HalfEven)?;
+//! assert_eq!(discounted.to_string(), "21.24 USD");
+//! ```
+
+use std::{convert::TryFrom, error, fmt, str::FromStr};
+
+use rust_decimal::Decimal;
+
+pub use crate::google::r#type::Money;
+
+/// The number of nanos in a unit.
+pub const NANOS_PER_UNIT: i64 = 1_000_000_000;
+
+const NANOS_DIGITS: u32 = 9;
+
+/// An error of a money operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// `nanos` is out of range or its sign differs from `units`.
+    InvalidNanos,
+    /// The amounts are in different currencies.
+    CurrencyMismatch(String, String),
+    /// The result does not fit in `units`.
+    Overflow,
+    /// The currency code is not an ISO 4217 code.
+    UnknownCurrency(String),
+    /// The string is not a money amount, e.g. `12.34 USD`.
+    Parse(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidNanos => f.write_str("nanos out of range or sign differs from units"),
+            Error::CurrencyMismatch(a, b) => write!(f, "currency mismatch: {} and {}", a, b),
+            Error::Overflow => f.write_str("overflow"),
+            Error::UnknownCurrency(code) => write!(f, "unknown currency: {}", code),
+            Error::Parse(s) => write!(f, "invalid money: {}", s),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// How a result is rounded when it has more digits than can be represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Towards zero.
+    Down,
+    /// Away from zero.
+    Up,
+    /// Towards negative infinity.
+    Floor,
+    /// Towards positive infinity.
+    Ceiling,
+    /// Towards the nearest neighbor, away from zero if equidistant.
+    HalfUp,
+    /// Towards the nearest neighbor, towards zero if equidistant.
+    HalfDown,
+    /// Towards the nearest neighbor, towards the even neighbor if equidistant.
+    HalfEven,
+}
+
+// Returns `n / d` rounded with `mode`, `d` must be positive.
+fn div_round(n: i128, d: i128, mode: RoundingMode) -> i128 {
+    let (q, r) = (n / d, n % d);
+    if r == 0 {
+        return q;
+    }
+    let away = q + n.signum();
+    let half = (2 * r.abs()).cmp(&d);
+    match mode {
+        RoundingMode::Down => q,
+        RoundingMode::Up => away,
+        RoundingMode::Floor => q.min(away),
+        RoundingMode::Ceiling => q.max(away),
+        _ if half == std::cmp::Ordering::Greater => away,
+        _ if half == std::cmp::Ordering::Less => q,
+        RoundingMode::HalfUp => away,
+        RoundingMode::HalfDown => q,
+        RoundingMode::HalfEven if q % 2 == 0 => q,
+        RoundingMode::HalfEven => away,
+    }
+}
+
+fn pow10(exp: u32) -> i128 {
+    10i128.pow(exp)
+}
+
+/// Returns the number of digits after the decimal separator of an ISO 4217 currency, e.g. 2 for
+/// `USD` and 0 for `JPY`.
+pub fn minor_units(currency_code: &str) -> Option<u32> {
+    CURRENCIES
+        .binary_search_by_key(&currency_code, |(code, _)| code)
+        .ok()
+        .map(|i| CURRENCIES[i].1)
+}
+
+impl Money {
+    /// Returns a validated amount of `units + nanos / 10^9`.
+    pub fn new(currency_code: impl Into<String>, units: i64, nanos: i32) -> Result<Self, Error> {
+        let money = Money {
+            currency_code: currency_code.into(),
+            units,
+            nanos,
+        };
+        money.validate()?;
+        Ok(money)
+    }
+
+    /// Checks that `nanos` is in range and has the same sign as `units`.
+    pub fn validate(&self) -> Result<(), Error> {
+        let nanos = i64::from(self.nanos);
+        if nanos.abs() >= NANOS_PER_UNIT
+            || (self.units > 0 && nanos < 0)
+            || (self.units < 0 && nanos > 0)
+        {
+            return Err(Error::InvalidNanos);
+        }
+        Ok(())
+    }
+
+    /// Returns the amount as a number of nanos.
+    pub fn to_nanos(&self) -> Result<i128, Error> {
+        self.validate()?;
+        Ok(i128::from(self.units) * i128::from(NANOS_PER_UNIT) + i128::from(self.nanos))
+    }
+
+    /// Returns the amount of `nanos`, failing if it does not fit in `units`.
+    pub fn from_nanos(currency_code: impl Into<String>, nanos: i128) -> Result<Self, Error> {
+        let per_unit = i128::from(NANOS_PER_UNIT);
+        Ok(Money {
+            currency_code: currency_code.into(),
+            units: i64::try_from(nanos / per_unit).map_err(|_| Error::Overflow)?,
+            nanos: (nanos % per_unit) as i32,
+        })
+    }
+
+    pub fn to_decimal(&self) -> Result<Decimal, Error> {
+        Ok(Decimal::from_i128_with_scale(
+            self.to_nanos()?,
+            NANOS_DIGITS,
+        ))
+    }
+
+    /// Returns the amount of `value`, rounded with `mode` to nanos.
+    pub fn from_decimal(
+        currency_code: impl Into<String>,
+        value: Decimal,
+        mode: RoundingMode,
+    ) -> Result<Self, Error> {
+        let (mantissa, scale) = (value.mantissa(), value.scale());
+        let nanos = if scale <= NANOS_DIGITS {
+            mantissa
+                .checked_mul(pow10(NANOS_DIGITS - scale))
+                .ok_or(Error::Overflow)?
+        } else {
+            div_round(mantissa, pow10(scale - NANOS_DIGITS), mode)
+        };
+        Self::from_nanos(currency_code, nanos)
+    }
+
+    fn check_currency(&self, other: &Money) -> Result<(), Error> {
+        if self.currency_code != other.currency_code {
+            return Err(Error::CurrencyMismatch(
+                self.currency_code.clone(),
+                other.currency_code.clone(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns `self + other`, failing if the currencies differ.
+    pub fn checked_add(&self, other: &Money) -> Result<Money, Error> {
+        self.check_currency(other)?;
+        let nanos = self.to_nanos()? + other.to_nanos()?;
+        Self::from_nanos(self.currency_code.clone(), nanos)
+    }
+
+    /// Returns `self - other`, failing if the currencies differ.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, Error> {
+        self.check_currency(other)?;
+        let nanos = self.to_nanos()? - other.to_nanos()?;
+        Self::from_nanos(self.currency_code.clone(), nanos)
+    }
+
+    /// Returns `self * factor`, rounded with `mode` to nanos.
+    pub fn scale(&self, factor: Decimal, mode: RoundingMode) -> Result<Money, Error> {
+        let nanos = self
+            .to_nanos()?
+            .checked_mul(factor.mantissa())
+            .ok_or(Error::Overflow)?;
+        let nanos = div_round(nanos, pow10(factor.scale()), mode);
+        Self::from_nanos(self.currency_code.clone(), nanos)
+    }
+
+    /// Returns the amount rounded with `mode` to the minor units of the currency, e.g. cents.
+    pub fn round_to_minor_units(&self, mode: RoundingMode) -> Result<Money, Error> {
+        let digits = minor_units(&self.currency_code)
+            .ok_or_else(|| Error::UnknownCurrency(self.currency_code.clone()))?;
+        let step = pow10(NANOS_DIGITS - digits);
+        let nanos = div_round(self.to_nanos()?, step, mode)
+            .checked_mul(step)
+            .ok_or(Error::Overflow)?;
+        Self::from_nanos(self.currency_code.clone(), nanos)
+    }
+}
+
+/// Formats the amount followed by the currency code, e.g. `12.30 USD` or `1000 JPY`.
+///
+/// The amount has at least the minor units of the currency, and more digits if needed so that
+/// nothing is rounded.
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let negative = self.units < 0 || self.nanos < 0;
+        let units = i128::from(self.units).abs();
+        let mut frac = format!("{:09}", i64::from(self.nanos).abs());
+        let min_digits = minor_units(&self.currency_code).unwrap_or(0) as usize;
+        while frac.len() > min_digits && frac.ends_with('0') {
+            frac.pop();
+        }
+
+        if negative {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", units)?;
+        if !frac.is_empty() {
+            write!(f, ".{}", frac)?;
+        }
+        write!(f, " {}", self.currency_code)
+    }
+}
+
+/// Parses an amount followed by the currency code, e.g. `-12.30 USD`.
+impl FromStr for Money {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || Error::Parse(s.to_owned());
+        let mut iter = s.split_whitespace();
+        let (amount, code) = match (iter.next(), iter.next(), iter.next()) {
+            (Some(amount), Some(code), None) => (amount, code),
+            _ => return Err(err()),
+        };
+        if code.len() != 3 || !code.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(err());
+        }
+
+        let (negative, amount) = match amount.strip_prefix('-') {
+            Some(amount) => (true, amount),
+            None => (false, amount),
+        };
+        let mut parts = amount.splitn(2, '.');
+        let units = parts.next().unwrap_or("");
+        let frac = parts.next().unwrap_or("");
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if units.is_empty() || !is_digits(units) || !is_digits(frac) || frac.len() > 9 {
+            return Err(err());
+        }
+
+        let units = units.parse::<i64>().map_err(|_| Error::Overflow)?;
+        let nanos = format!("{:0<9}", frac).parse::<i32>().map_err(|_| err())?;
+        let (units, nanos) = if negative {
+            (-units, -nanos)
+        } else {
+            (units, nanos)
+        };
+        Money::new(code, units, nanos)
+    }
+}
+
+// https://www.six-group.com/en/products-services/financial-information/data-standards.html
+// The active ISO 4217 currencies and their minor units, sorted by code.
+const CURRENCIES: &[(&str, u32)] = &[
+    ("AED", 2),
+    ("AFN", 2),
+    ("ALL", 2),
+    ("AMD", 2),
+    ("ANG", 2),
+    ("AOA", 2),
+    ("ARS", 2),
+    ("AUD", 2),
+    ("AWG", 2),
+    ("AZN", 2),
+    ("BAM", 2),
+    ("BBD", 2),
+    ("BDT", 2),
+    ("BGN", 2),
+    ("BHD", 3),
+    ("BIF", 0),
+    ("BMD", 2),
+    ("BND", 2),
+    ("BOB", 2),
+    ("BOV", 2),
+    ("BRL", 2),
+    ("BSD", 2),
+    ("BTN", 2),
+    ("BWP", 2),
+    ("BYN", 2),
+    ("BZD", 2),
+    ("CAD", 2),
+    ("CDF", 2),
+    ("CHE", 2),
+    ("CHF", 2),
+    ("CHW", 2),
+    ("CLF", 4),
+    ("CLP", 0),
+    ("CNY", 2),
+    ("COP", 2),
+    ("COU", 2),
+    ("CRC", 2),
+    ("CUC", 2),
+    ("CUP", 2),
+    ("CVE", 2),
+    ("CZK", 2),
+    ("DJF", 0),
+    ("DKK", 2),
+    ("DOP", 2),
+    ("DZD", 2),
+    ("EGP", 2),
+    ("ERN", 2),
+    ("ETB", 2),
+    ("EUR", 2),
+    ("FJD", 2),
+    ("FKP", 2),
+    ("GBP", 2),
+    ("GEL", 2),
+    ("GHS", 2),
+    ("GIP", 2),
+    ("GMD", 2),
+    ("GNF", 0),
+    ("GTQ", 2),
+    ("GYD", 2),
+    ("HKD", 2),
+    ("HNL", 2),
+    ("HRK", 2),
+    ("HTG", 2),
+    ("HUF", 2),
+    ("IDR", 2),
+    ("ILS", 2),
+    ("INR", 2),
+    ("IQD", 3),
+    ("IRR", 2),
+    ("ISK", 0),
+    ("JMD", 2),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KES", 2),
+    ("KGS", 2),
+    ("KHR", 2),
+    ("KMF", 0),
+    ("KPW", 2),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("KYD", 2),
+    ("KZT", 2),
+    ("LAK", 2),
+    ("LBP", 2),
+    ("LKR", 2),
+    ("LRD", 2),
+    ("LSL", 2),
+    ("LYD", 3),
+    ("MAD", 2),
+    ("MDL", 2),
+    ("MGA", 2),
+    ("MKD", 2),
+    ("MMK", 2),
+    ("MNT", 2),
+    ("MOP", 2),
+    ("MRU", 2),
+    ("MUR", 2),
+    ("MVR", 2),
+    ("MWK", 2),
+    ("MXN", 2),
+    ("MXV", 2),
+    ("MYR", 2),
+    ("MZN", 2),
+    ("NAD", 2),
+    ("NGN", 2),
+    ("NIO", 2),
+    ("NOK", 2),
+    ("NPR", 2),
+    ("NZD", 2),
+    ("OMR", 3),
+    ("PAB", 2),
+    ("PEN", 2),
+    ("PGK", 2),
+    ("PHP", 2),
+    ("PKR", 2),
+    ("PLN", 2),
+    ("PYG", 0),
+    ("QAR", 2),
+    ("RON", 2),
+    ("RSD", 2),
+    ("RUB", 2),
+    ("RWF", 0),
+    ("SAR", 2),
+    ("SBD", 2),
+    ("SCR", 2),
+    ("SDG", 2),
+    ("SEK", 2),
+    ("SGD", 2),
+    ("SHP", 2),
+    ("SLL", 2),
+    ("SOS", 2),
+    ("SRD", 2),
+    ("SSP", 2),
+    ("STN", 2),
+    ("SVC", 2),
+    ("SYP", 2),
+    ("SZL", 2),
+    ("THB", 2),
+    ("TJS", 2),
+    ("TMT", 2),
+    ("TND", 3),
+    ("TOP", 2),
+    ("TRY", 2),
+    ("TTD", 2),
+    ("TWD", 2),
+    ("TZS", 2),
+    ("UAH", 2),
+    ("UGX", 0),
+    ("USD", 2),
+    ("USN", 2),
+    ("UYI", 0),
+    ("UYU", 2),
+    ("UYW", 4),
+    ("UZS", 2),
+    ("VES", 2),
+    ("VND", 0),
+    ("VUV", 0),
+    ("WST", 2),
+    ("XAF", 0),
+    ("XCD", 2),
+    ("XOF", 0),
+    ("XPF", 0),
+    ("YER", 2),
+    ("ZAR", 2),
+    ("ZMW", 2),
+    ("ZWL", 2),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn money(s: &str) -> Money {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_currencies_sorted() {
+        assert!(CURRENCIES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(minor_units("USD"), Some(2));
+        assert_eq!(minor_units("JPY"), Some(0));
+        assert_eq!(minor_units("KWD"), Some(3));
+        assert_eq!(minor_units("XXX"), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Money::new("USD", 1, 500_000_000).is_ok());
+        assert!(Money::new("USD", -1, -500_000_000).is_ok());
+        assert!(Money::new("USD", 0, -500_000_000).is_ok());
+        assert_eq!(Money::new("USD", -1, 500_000_000), Err(Error::InvalidNanos));
+        assert_eq!(
+            Money::new("USD", 0, 1_000_000_000),
+            Err(Error::InvalidNanos)
+        );
+    }
+
+    #[test]
+    fn test_div_round() {
+        let round = |n, mode| div_round(n, 10, mode);
+        let cases = vec![
+            (RoundingMode::Down, [2, 2, 2, -2, -2]),
+            (RoundingMode::Up, [3, 3, 3, -3, -3]),
+            (RoundingMode::Floor, [2, 2, 2, -3, -3]),
+            (RoundingMode::Ceiling, [3, 3, 3, -2, -2]),
+            (RoundingMode::HalfUp, [2, 3, 3, -3, -3]),
+            (RoundingMode::HalfDown, [2, 2, 3, -2, -3]),
+            (RoundingMode::HalfEven, [2, 2, 3, -2, -3]),
+        ];
+        for (mode, expected) in cases {
+            let actual = [21, 25, 26, -25, -26]
+                .iter()
+                .map(|&n| round(n, mode))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "{:?}", mode);
+        }
+        assert_eq!(div_round(35, 10, RoundingMode::HalfEven), 4);
+        assert_eq!(div_round(30, 10, RoundingMode::Up), 3);
+    }
+
+    #[test]
+    fn test_decimal() {
+        let m = Money::new("USD", -12, -340_000_000).unwrap();
+        assert_eq!(m.to_decimal(), Ok(Decimal::new(-1234, 2)));
+        assert_eq!(
+            Money::from_decimal("USD", Decimal::new(-1234, 2), RoundingMode::Down),
+            Ok(m)
+        );
+        assert_eq!(
+            Money::from_decimal("USD", Decimal::new(15, 10), RoundingMode::HalfUp),
+            Ok(Money::new("USD", 0, 2).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_add_sub() {
+        assert_eq!(
+            money("1.75 USD").checked_add(&money("0.50 USD")),
+            Ok(money("2.25 USD"))
+        );
+        assert_eq!(
+            money("1.25 USD").checked_sub(&money("2.50 USD")),
+            Ok(money("-1.25 USD"))
+        );
+        assert_eq!(
+            money("1 USD").checked_add(&money("1 EUR")),
+            Err(Error::CurrencyMismatch("USD".into(), "EUR".into()))
+        );
+        let max = Money::new("USD", i64::MAX, 999_999_999).unwrap();
+        assert_eq!(
+            max.checked_add(&money("0.000000001 USD")),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_scale_and_round() {
+        let total = money("24.99 USD")
+            .scale(Decimal::new(85, 2), RoundingMode::HalfEven)
+            .unwrap();
+        assert_eq!(total, money("21.2415 USD"));
+        assert_eq!(
+            total.round_to_minor_units(RoundingMode::HalfEven),
+            Ok(money("21.24 USD"))
+        );
+        assert_eq!(
+            total.round_to_minor_units(RoundingMode::Ceiling),
+            Ok(money("21.25 USD"))
+        );
+        assert_eq!(
+            money("1234.5 JPY").round_to_minor_units(RoundingMode::HalfEven),
+            Ok(money("1234 JPY"))
+        );
+        assert_eq!(
+            money("1 XXX").round_to_minor_units(RoundingMode::HalfEven),
+            Err(Error::UnknownCurrency("XXX".into()))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(money("12.3 USD").to_string(), "12.30 USD");
+        assert_eq!(money("-0.5 USD").to_string(), "-0.50 USD");
+        assert_eq!(money("1000 JPY").to_string(), "1000 JPY");
+        assert_eq!(money("1.005 USD").to_string(), "1.005 USD");
+        assert_eq!(money("1.5 KWD").to_string(), "1.500 KWD");
+        assert_eq!(money("1.50 XXX").to_string(), "1.5 XXX");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            money("-12.34 USD"),
+            Money::new("USD", -12, -340_000_000).unwrap()
+        );
+        assert_eq!(money("0.000000001 USD").nanos, 1);
+        for s in &[
+            "12.34",
+            "USD 12.34",
+            "12.34 usd",
+            "1.0000000001 USD",
+            ".5 USD",
+            "1e3 USD",
+            "-",
+        ] {
+            assert_eq!(
+                s.parse::<Money>(),
+                Err(Error::Parse(s.to_string())),
+                "{}",
+                s
+            );
+        }
+    }
+}
@@ -0,0 +1,600 @@
+//! An implementation of the `google.longrunning.Operations` service backed by an
+//! [`OperationStore`].
+//!
+//! The service owning the operations starts them with [`OperationsService::start`], reports
+//! progress with [`OperationsService::update_metadata`] and finishes them with
+//! [`OperationsService::complete`] or [`OperationsService::fail`], while clients poll them
+//! through the `Operations` service:
+//!
+//! ```ignore
+//! use googapis::{
+//!     google::longrunning::operations_server::OperationsServer, operations::OperationsService,
+//! };
+//!
+//! let operations = OperationsService::default();
+//! let op = operations.start("operations/1", None)?;
+//! // Hand a clone of `operations` to the task doing the work.
+//! Server::builder()
+//!     .add_service(OperationsServer::new(operations.clone()))
+//!     .serve(addr)
+//!     .await?;
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use tokio::sync::watch;
+use tonic::{Code, Request, Response, Status};
+
+use crate::google::{
+    longrunning::{
+        operation, operations_server::Operations, CancelOperationRequest, DeleteOperationRequest,
+        GetOperationRequest, ListOperationsRequest, ListOperationsResponse, Operation,
+        WaitOperationRequest,
+    },
+    rpc::Status as RpcStatus,
+};
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// The timeout of `WaitOperation` when the request does not specify one, and its maximum.
+pub const DEFAULT_WAIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The storage of the operations of an [`OperationsService`].
+pub trait OperationStore: Send + Sync + 'static {
+    /// Inserts `op`, returns `false` if an operation with the same name already exists.
+    fn insert(&self, op: Operation) -> bool;
+
+    fn get(&self, name: &str) -> Option<Operation>;
+
+    /// Applies `f` to the operation named `name` atomically and returns the updated operation.
+    fn update(&self, name: &str, f: &mut dyn FnMut(&mut Operation)) -> Option<Operation>;
+
+    fn remove(&self, name: &str) -> Option<Operation>;
+
+    /// Returns the operations whose name starts with `prefix` and sorts after `start_after`,
+    /// ordered by name.
+    fn list(&self, prefix: &str, start_after: &str) -> Vec<Operation>;
+}
+
+/// An [`OperationStore`] keeping the operations in memory.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    operations: Mutex<BTreeMap<String, Operation>>,
+}
+
+impl OperationStore for MemoryStore {
+    fn insert(&self, op: Operation) -> bool {
+        let mut operations = self.operations.lock().unwrap();
+        if operations.contains_key(&op.name) {
+            return false;
+        }
+        operations.insert(op.name.clone(), op);
+        true
+    }
+
+    fn get(&self, name: &str) -> Option<Operation> {
+        self.operations.lock().unwrap().get(name).cloned()
+    }
+
+    fn update(&self, name: &str, f: &mut dyn FnMut(&mut Operation)) -> Option<Operation> {
+        let mut operations = self.operations.lock().unwrap();
+        let op = operations.get_mut(name)?;
+        f(op);
+        Some(op.clone())
+    }
+
+    fn remove(&self, name: &str) -> Option<Operation> {
+        self.operations.lock().unwrap().remove(name)
+    }
+
+    fn list(&self, prefix: &str, start_after: &str) -> Vec<Operation> {
+        self.operations
+            .lock()
+            .unwrap()
+            .range::<str, _>((
+                std::ops::Bound::Excluded(start_after),
+                std::ops::Bound::Unbounded,
+            ))
+            .filter(|(name, _)| name.starts_with(prefix))
+            .map(|(_, op)| op.clone())
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct Inner<S> {
+    store: S,
+    version: AtomicU64,
+    tx: watch::Sender<u64>,
+    rx: watch::Receiver<u64>,
+}
+
+/// An implementation of the `Operations` service, cheap to clone.
+#[derive(Debug)]
+pub struct OperationsService<S = MemoryStore> {
+    inner: Arc<Inner<S>>,
+}
+
+impl<S> Clone for OperationsService<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl Default for OperationsService<MemoryStore> {
+    fn default() -> Self {
+        Self::new(MemoryStore::default())
+    }
+}
+
+fn not_found(name: &str) -> Status {
+    Status::not_found(format!("operation not found: {}", name))
+}
+
+// https://google.aip.dev/160, only `done` comparisons are supported.
+fn parse_filter(filter: &str) -> Result<Option<bool>, Status> {
+    let filter = filter.trim();
+    if filter.is_empty() {
+        return Ok(None);
+    }
+    let mut iter = filter.splitn(2, '=');
+    match (iter.next().map(str::trim), iter.next().map(str::trim)) {
+        (Some("done"), Some("true")) => Ok(Some(true)),
+        (Some("done"), Some("false")) => Ok(Some(false)),
+        _ => Err(Status::invalid_argument(format!(
+            "unsupported filter: {}",
+            filter
+        ))),
+    }
+}
+
+impl<S: OperationStore> OperationsService<S> {
+    pub fn new(store: S) -> Self {
+        let (tx, rx) = watch::channel(0);
+        Self {
+            inner: Arc::new(Inner {
+                store,
+                version: AtomicU64::new(0),
+                tx,
+                rx,
+            }),
+        }
+    }
+
+    /// Returns the underlying store.
+    pub fn store(&self) -> &S {
+        &self.inner.store
+    }
+
+    // Wakes up the pending `WaitOperation` calls.
+    fn notify(&self) {
+        let version = self.inner.version.fetch_add(1, Ordering::SeqCst) + 1;
+        let _ = self.inner.tx.broadcast(version);
+    }
+
+    // Updates an operation that is not done yet.
+    fn update_pending(
+        &self,
+        name: &str,
+        mut f: impl FnMut(&mut Operation),
+    ) -> Result<Operation, Status> {
+        let mut done = false;
+        let op = self
+            .inner
+            .store
+            .update(name, &mut |op| {
+                done = op.done;
+                if !done {
+                    f(op);
+                }
+            })
+            .ok_or_else(|| not_found(name))?;
+        if done {
+            return Err(Status::failed_precondition(format!(
+                "operation already done: {}",
+                name
+            )));
+        }
+        self.notify();
+        Ok(op)
+    }
+
+    /// Starts a new operation named `name`.
+    pub fn start(
+        &self,
+        name: impl Into<String>,
+        metadata: Option<prost_types::Any>,
+    ) -> Result<Operation, Status> {
+        let op = Operation {
+            name: name.into(),
+            metadata,
+            done: false,
+            result: None,
+        };
+        if !self.inner.store.insert(op.clone()) {
+            return Err(Status::already_exists(format!(
+                "operation already exists: {}",
+                op.name
+            )));
+        }
+        Ok(op)
+    }
+
+    pub fn get(&self, name: &str) -> Option<Operation> {
+        self.inner.store.get(name)
+    }
+
+    /// Replaces the metadata of a pending operation, e.g. to report progress.
+    pub fn update_metadata(
+        &self,
+        name: &str,
+        metadata: prost_types::Any,
+    ) -> Result<Operation, Status> {
+        self.update_pending(name, |op| op.metadata = Some(metadata.clone()))
+    }
+
+    /// Completes a pending operation with `response`.
+    pub fn complete(&self, name: &str, response: prost_types::Any) -> Result<Operation, Status> {
+        self.update_pending(name, |op| {
+            op.done = true;
+            op.result = Some(operation::Result::Response(response.clone()));
+        })
+    }
+
+    /// Completes a pending operation with `error`.
+    pub fn fail(&self, name: &str, error: RpcStatus) -> Result<Operation, Status> {
+        self.update_pending(name, |op| {
+            op.done = true;
+            op.result = Some(operation::Result::Error(error.clone()));
+        })
+    }
+
+    /// Returns whether the operation was cancelled through `CancelOperation`.
+    pub fn is_cancelled(&self, name: &str) -> bool {
+        match self.get(name).and_then(|op| op.result) {
+            Some(operation::Result::Error(status)) => status.code == Code::Cancelled as i32,
+            _ => false,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl<S: OperationStore> Operations for OperationsService<S> {
+    async fn list_operations(
+        &self,
+        request: Request<ListOperationsRequest>,
+    ) -> Result<Response<ListOperationsResponse>, Status> {
+        let req = request.into_inner();
+        let done = parse_filter(&req.filter)?;
+        let page_size = match req.page_size {
+            n if n < 0 => return Err(Status::invalid_argument("negative page_size")),
+            0 => DEFAULT_PAGE_SIZE,
+            n => (n as usize).min(MAX_PAGE_SIZE),
+        };
+
+        // The page token is the name of the last operation of the previous page.
+        let mut operations = self
+            .inner
+            .store
+            .list(&req.name, &req.page_token)
+            .into_iter()
+            .filter(|op| match done {
+                Some(done) => op.done == done,
+                None => true,
+            })
+            .take(page_size + 1)
+            .collect::<Vec<_>>();
+        let next_page_token = if operations.len() > page_size {
+            operations.truncate(page_size);
+            operations
+                .last()
+                .map(|op| op.name.clone())
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        Ok(Response::new(ListOperationsResponse {
+            operations,
+            next_page_token,
+        }))
+    }
+
+    async fn get_operation(
+        &self,
+        request: Request<GetOperationRequest>,
+    ) -> Result<Response<Operation>, Status> {
+        let name = request.into_inner().name;
+        self.get(&name)
+            .map(Response::new)
+            .ok_or_else(|| not_found(&name))
+    }
+
+    async fn delete_operation(
+        &self,
+        request: Request<DeleteOperationRequest>,
+    ) -> Result<Response<()>, Status> {
+        let name = request.into_inner().name;
+        self.inner
+            .store
+            .remove(&name)
+            .ok_or_else(|| not_found(&name))?;
+        self.notify();
+        Ok(Response::new(()))
+    }
+
+    async fn cancel_operation(
+        &self,
+        request: Request<CancelOperationRequest>,
+    ) -> Result<Response<()>, Status> {
+        let name = request.into_inner().name;
+        let error = RpcStatus {
+            code: Code::Cancelled as i32,
+            message: "operation cancelled".into(),
+            details: Vec::new(),
+        };
+        if let Err(status) = self.fail(&name, error) {
+            // Cancelling a finished operation has no effect.
+            if status.code() != Code::FailedPrecondition {
+                return Err(status);
+            }
+        }
+        Ok(Response::new(()))
+    }
+
+    async fn wait_operation(
+        &self,
+        request: Request<WaitOperationRequest>,
+    ) -> Result<Response<Operation>, Status> {
+        let req = request.into_inner();
+        let timeout = match &req.timeout {
+            Some(d) if d.seconds < 0 || d.nanos < 0 => {
+                return Err(Status::invalid_argument("negative timeout"))
+            }
+            Some(d) => Duration::new(d.seconds as u64, d.nanos as u32).min(DEFAULT_WAIT_TIMEOUT),
+            None => DEFAULT_WAIT_TIMEOUT,
+        };
+
+        // Subscribe before reading the operation to not miss an update in between.
+        let mut rx = self.inner.rx.clone();
+        let wait = async {
+            loop {
+                let op = self.get(&req.name).ok_or_else(|| not_found(&req.name))?;
+                if op.done {
+                    return Ok(op);
+                }
+                rx.recv().await;
+            }
+        };
+        match tokio::time::timeout(timeout, wait).await {
+            Ok(op) => op.map(Response::new),
+            // Returns the latest state when the timeout is reached.
+            Err(_) => self
+                .get(&req.name)
+                .map(Response::new)
+                .ok_or_else(|| not_found(&req.name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn any(value: &str) -> prost_types::Any {
+        prost_types::Any {
+            type_url: "type.googleapis.com/google.protobuf.StringValue".into(),
+            value: value.as_bytes().to_vec(),
+        }
+    }
+
+    async fn list(
+        service: &OperationsService,
+        name: &str,
+        filter: &str,
+        page_size: i32,
+        page_token: &str,
+    ) -> Result<ListOperationsResponse, Status> {
+        let request = ListOperationsRequest {
+            name: name.into(),
+            filter: filter.into(),
+            page_size,
+            page_token: page_token.into(),
+        };
+        service
+            .list_operations(Request::new(request))
+            .await
+            .map(Response::into_inner)
+    }
+
+    fn names(res: &ListOperationsResponse) -> Vec<&str> {
+        res.operations.iter().map(|op| op.name.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_list_operations() {
+        let service = OperationsService::default();
+        for i in 0..5 {
+            service.start(format!("a/operations/{}", i), None).unwrap();
+        }
+        service.start("b/operations/0", None).unwrap();
+        service.complete("a/operations/1", any("ok")).unwrap();
+
+        let res = list(&service, "a/", "", 2, "").await.unwrap();
+        assert_eq!(names(&res), vec!["a/operations/0", "a/operations/1"]);
+        let res = list(&service, "a/", "", 2, &res.next_page_token)
+            .await
+            .unwrap();
+        assert_eq!(names(&res), vec!["a/operations/2", "a/operations/3"]);
+        let res = list(&service, "a/", "", 2, &res.next_page_token)
+            .await
+            .unwrap();
+        assert_eq!(names(&res), vec!["a/operations/4"]);
+        assert_eq!(res.next_page_token, "");
+
+        let res = list(&service, "", "done = true", 0, "").await.unwrap();
+        assert_eq!(names(&res), vec!["a/operations/1"]);
+        let res = list(&service, "", "done=false", 0, "").await.unwrap();
+        assert_eq!(res.operations.len(), 5);
+
+        let err = list(&service, "", "name = x", 0, "").await.unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_complete_and_fail() {
+        let service = OperationsService::default();
+        let op = service.start("operations/1", Some(any("0%"))).unwrap();
+        assert!(!op.done);
+        assert_eq!(
+            service.start("operations/1", None).unwrap_err().code(),
+            Code::AlreadyExists
+        );
+
+        let op = service.update_metadata("operations/1", any("50%")).unwrap();
+        assert_eq!(op.metadata, Some(any("50%")));
+        let op = service.complete("operations/1", any("ok")).unwrap();
+        assert!(op.done);
+        assert_eq!(op.result, Some(operation::Result::Response(any("ok"))));
+        assert_eq!(
+            service
+                .fail("operations/1", RpcStatus::default())
+                .unwrap_err()
+                .code(),
+            Code::FailedPrecondition
+        );
+        assert_eq!(
+            service
+                .complete("operations/2", any("ok"))
+                .unwrap_err()
+                .code(),
+            Code::NotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_and_delete() {
+        let service = OperationsService::default();
+        service.start("operations/1", None).unwrap();
+
+        let cancel = |name: &str| {
+            service.cancel_operation(Request::new(CancelOperationRequest { name: name.into() }))
+        };
+        cancel("operations/1").await.unwrap();
+        assert!(service.is_cancelled("operations/1"));
+        assert_eq!(
+            service
+                .complete("operations/1", any("ok"))
+                .unwrap_err()
+                .code(),
+            Code::FailedPrecondition
+        );
+        // Cancelling again has no effect.
+        cancel("operations/1").await.unwrap();
+        assert_eq!(
+            cancel("operations/2").await.unwrap_err().code(),
+            Code::NotFound
+        );
+
+        let delete = |name: &str| {
+            service.delete_operation(Request::new(DeleteOperationRequest { name: name.into() }))
+        };
+        delete("operations/1").await.unwrap();
+        assert_eq!(service.get("operations/1"), None);
+        assert_eq!(
+            delete("operations/1").await.unwrap_err().code(),
+            Code::NotFound
+        );
+    }
+
+    fn wait_request(name: &str, millis: i32) -> Request<WaitOperationRequest> {
+        Request::new(WaitOperationRequest {
+            name: name.into(),
+            timeout: Some(prost_types::Duration {
+                seconds: 0,
+                nanos: millis * 1_000_000,
+            }),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_wait_operation() {
+        let service = OperationsService::default();
+        service.start("operations/1", None).unwrap();
+
+        let owner = service.clone();
+        tokio::spawn(async move {
+            tokio::time::delay_for(Duration::from_millis(10)).await;
+            owner.update_metadata("operations/1", any("50%")).unwrap();
+            tokio::time::delay_for(Duration::from_millis(10)).await;
+            owner.complete("operations/1", any("ok")).unwrap();
+        });
+
+        let op = service
+            .wait_operation(wait_request("operations/1", 900))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(op.done);
+        assert_eq!(op.result, Some(operation::Result::Response(any("ok"))));
+    }
+
+    #[tokio::test]
+    async fn test_wait_operation_timeout() {
+        let service = OperationsService::default();
+        service.start("operations/1", None).unwrap();
+
+        let op = service
+            .wait_operation(wait_request("operations/1", 10))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(!op.done);
+        assert_eq!(
+            service
+                .wait_operation(wait_request("operations/2", 10))
+                .await
+                .unwrap_err()
+                .code(),
+            Code::NotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_operation_timeout_bounds() {
+        let service = OperationsService::default();
+        service.start("operations/1", None).unwrap();
+        service.complete("operations/1", any("ok")).unwrap();
+
+        let request = |seconds, nanos| {
+            Request::new(WaitOperationRequest {
+                name: "operations/1".into(),
+                timeout: Some(prost_types::Duration { seconds, nanos }),
+            })
+        };
+        // Longer timeouts are bounded by `DEFAULT_WAIT_TIMEOUT`.
+        let op = service
+            .wait_operation(request(i64::MAX, 999_999_999))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(op.done);
+        for (seconds, nanos) in [(-1, 0), (0, -1), (i64::MIN, 0)] {
+            let status = service
+                .wait_operation(request(seconds, nanos))
+                .await
+                .unwrap_err();
+            assert_eq!(status.code(), Code::InvalidArgument);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Streams over the pages of `List*` methods following the [AIP-158] pagination pattern.
+//!
+//! For each paginated method of the generated `*_client` types, `*_pages` and `*_stream` helpers
+//! are generated. `*_pages` yields each response, and `*_stream` yields the items of all pages:
+//!
+//! ```ignore
+//! use futures::TryStreamExt;
+//! use googapis::google::pubsub::v1::{publisher_client::PublisherClient, ListTopicsRequest};
+//!
+//! let topics = client
+//!     .list_topics_stream(ListTopicsRequest {
+//!         project: "projects/my-project".into(),
+//!         ..Default::default()
+//!     })
+//!     .try_collect::<Vec<_>>()
+//!     .await?;
+//! ```
+//!
+//! [AIP-158]: https://google.aip.dev/158
+
+use std::future::Future;
+
+use futures::{
+    stream::{self, Stream},
+    TryStreamExt,
+};
+use tonic::Status;
+
+/// A request of a paginated method.
+pub trait PageRequest: Clone {
+    fn set_page_token(&mut self, page_token: String);
+}
+
+/// A response of a paginated method.
+pub trait PageResponse {
+    /// The type of the repeated field holding the items of a page.
+    type Item;
+
+    /// Returns the token of the next page, empty if this is the last page.
+    fn next_page_token(&self) -> &str;
+
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// Returns a stream of the pages returned by `f`, starting from `request`.
+///
+/// The stream ends after the page with an empty `next_page_token`, or after the first error.
+pub fn pages<Req, Resp, F, Fut>(request: Req, f: F) -> impl Stream<Item = Result<Resp, Status>>
+where
+    Req: PageRequest,
+    Resp: PageResponse,
+    F: FnMut(Req) -> Fut,
+    Fut: Future<Output = Result<Resp, Status>>,
+{
+    stream::unfold((Some(request), f), |(request, mut f)| async move {
+        let mut request = request?;
+        match f(request.clone()).await {
+            Ok(page) => {
+                let next = if page.next_page_token().is_empty() {
+                    None
+                } else {
+                    request.set_page_token(page.next_page_token().to_owned());
+                    Some(request)
+                };
+                Some((Ok(page), (next, f)))
+            }
+            Err(status) => Some((Err(status), (None, f))),
+        }
+    })
+}
+
+/// Flattens a stream of pages into a stream of their items.
+pub fn items<S, Resp>(pages: S) -> impl Stream<Item = Result<Resp::Item, Status>>
+where
+    S: Stream<Item = Result<Resp, Status>>,
+    Resp: PageResponse,
+{
+    pages
+        .map_ok(|page| stream::iter(page.into_items().into_iter().map(Ok)))
+        .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[derive(Debug, Clone, Default)]
+    struct Request {
+        page_token: String,
+    }
+
+    impl PageRequest for Request {
+        fn set_page_token(&mut self, page_token: String) {
+            self.page_token = page_token;
+        }
+    }
+
+    #[derive(Debug)]
+    struct Response {
+        items: Vec<u32>,
+        next_page_token: String,
+    }
+
+    impl PageResponse for Response {
+        type Item = u32;
+
+        fn next_page_token(&self) -> &str {
+            &self.next_page_token
+        }
+
+        fn into_items(self) -> Vec<Self::Item> {
+            self.items
+        }
+    }
+
+    // Pages of 3 items over 0..8, the page token is the offset.
+    async fn list(req: Request) -> Result<Response, Status> {
+        let offset = match req.page_token.as_str() {
+            "" => 0,
+            token => token.parse().map_err(|_| Status::invalid_argument(token))?,
+        };
+        let end = (offset + 3).min(8);
+        Ok(Response {
+            items: (offset..end).collect(),
+            next_page_token: if end < 8 {
+                end.to_string()
+            } else {
+                String::new()
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_pages() {
+        let pages = pages(Request::default(), list)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(
+            pages.into_iter().map(|p| p.items).collect::<Vec<_>>(),
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_items() {
+        let items = items(pages(Request::default(), list))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        assert_eq!(items, (0..8).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_pages_stop_after_error() {
+        let req = Request {
+            page_token: "invalid".into(),
+        };
+        let pages = pages(req, list).collect::<Vec<_>>().await;
+        assert_eq!(pages.len(), 1);
+        assert_eq!(
+            pages[0].as_ref().unwrap_err().code(),
+            tonic::Code::InvalidArgument
+        );
+    }
+
+    #[cfg(feature = "pubsub")]
+    mod pubsub {
+        use crate::google::pubsub::v1::{ListTopicsRequest, Topic};
+        use crate::pubsub::fake::FakePublisher;
+        use futures::TryStreamExt;
+        use std::sync::Arc;
+
+        #[tokio::test]
+        async fn test_list_topics_stream() {
+            let fake = Arc::new(FakePublisher::default());
+            let mut client = fake.client();
+            for name in (0..5)
+                .map(|i| format!("projects/p/topics/{}", i))
+                .chain(Some("projects/q/topics/0".into()))
+            {
+                client
+                    .create_topic(Topic {
+                        name,
+                        ..Default::default()
+                    })
+                    .await
+                    .unwrap();
+            }
+            let request = ListTopicsRequest {
+                project: "projects/p".into(),
+                page_size: 2,
+                ..Default::default()
+            };
+
+            let pages = client
+                .list_topics_pages(request.clone())
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+            assert_eq!(
+                pages.iter().map(|p| p.topics.len()).collect::<Vec<_>>(),
+                vec![2, 2, 1]
+            );
+
+            let topics = client
+                .list_topics_stream(request)
+                .map_ok(|t| t.name)
+                .try_collect::<Vec<_>>()
+                .await
+                .unwrap();
+            assert_eq!(
+                topics,
+                (0..5)
+                    .map(|i| format!("projects/p/topics/{}", i))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+}
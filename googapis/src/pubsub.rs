@@ -0,0 +1,32 @@
+//! High-level Pub/Sub clients on top of [`google.pubsub.v1`].
+//!
+//! The `pubsub` feature provides a [`publisher::Publisher`] that batches messages and publishes
+//! them in order per ordering key, and a [`subscriber::Subscriber`] that receives messages over
+//! streaming pulls and manages their leases.
+//!
+//! The `pubsub-push` feature provides a [`push::Receiver`] of the requests of push subscriptions,
+//! which verifies their OIDC tokens.
+//!
+//! The `pubsub-filter` feature provides a parser and an evaluator of the [`filter`] language of
+//! subscriptions.
+//!
+//! The `pubsub-emulator` feature provides an in-memory [`emulator::Emulator`] of the service for
+//! tests.
+//!
+//! [`google.pubsub.v1`]: https://github.com/googleapis/googleapis/tree/master/google/pubsub/v1
+
+#[cfg(feature = "pubsub-emulator")]
+pub mod emulator;
+#[cfg(all(test, feature = "pubsub"))]
+pub(crate) mod fake;
+#[cfg(feature = "pubsub-filter")]
+pub mod filter;
+#[cfg(feature = "pubsub")]
+pub mod publisher;
+#[cfg(feature = "pubsub-push")]
+pub mod push;
+#[cfg(feature = "pubsub")]
+pub mod subscriber;
+
+#[cfg(feature = "pubsub")]
+pub use crate::flow_control::{FlowControlSettings, LimitExceededBehavior};
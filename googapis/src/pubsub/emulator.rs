@@ -0,0 +1,1557 @@
+//! An in-process fake of the Pub/Sub service for tests.
+//!
+//! [`Emulator`] implements the `Publisher` and `Subscriber` services in memory, supporting topics,
+//! subscriptions, pull and streaming pull with ack deadlines, ordering keys, filters, dead letter
+//! policies, snapshots and seek. Push configs, retry policies, retention durations and expiration
+//! policies are stored, but not applied.
+//!
+//! The clients can call the emulator in memory, or over a local port:
+//!
+//! ```ignore
+//! let emulator = Emulator::new();
+//! let mut publisher = emulator.publisher_client();
+//! publisher.create_topic(Topic { name: "projects/p/topics/t".into(), ..Default::default() }).await?;
+//!
+//! let addr = emulator.serve().await?;
+//! let channel = Channel::from_shared(format!("http://{}", addr))?.connect().await?;
+//! let subscriber = Subscriber::new(SubscriberClient::new(channel), "projects/p/subscriptions/s");
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    io,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures::future::{self, Either};
+use prost_types::{FieldMask, Timestamp};
+use tokio::{
+    net::TcpListener,
+    sync::{mpsc, oneshot, watch},
+    time::{self, Instant},
+};
+use tonic::{transport::Server, Request, Response, Status, Streaming};
+
+use super::filter::Filter;
+use crate::google::pubsub::v1::{
+    publisher_client::PublisherClient,
+    publisher_server::{Publisher, PublisherServer},
+    seek_request::Target,
+    subscriber_client::SubscriberClient,
+    subscriber_server::{Subscriber, SubscriberServer},
+    *,
+};
+
+const DEFAULT_ACK_DEADLINE_SECONDS: i32 = 10;
+const MAX_ACK_DEADLINE_SECONDS: i32 = 600;
+const DEFAULT_MAX_DELIVERY_ATTEMPTS: i32 = 5;
+const MAX_DELIVERY_ATTEMPTS: i32 = 100;
+const MAX_STREAMING_PULL_MESSAGES: usize = 1000;
+const SNAPSHOT_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+// The topic of the subscriptions whose topic is deleted.
+const DELETED_TOPIC: &str = "_deleted-topic_";
+// How long a pull waits for messages without `return_immediately`.
+const PULL_TIMEOUT: Duration = Duration::from_secs(1);
+// How often the pulls check for expired ack deadlines.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+fn not_found(name: &str) -> Status {
+    Status::not_found(format!("Resource not found (resource={}).", name))
+}
+
+fn already_exists(name: &str) -> Status {
+    Status::already_exists(format!("Resource already exists (resource={}).", name))
+}
+
+// Checks a name of the form `projects/{project}/{collection}/{id}`.
+fn check_name(name: &str, collection: &str) -> Result<(), Status> {
+    match name.split('/').collect::<Vec<_>>()[..] {
+        ["projects", project, c, id]
+            if c == collection && !project.is_empty() && !id.is_empty() =>
+        {
+            Ok(())
+        }
+        _ => Err(Status::invalid_argument(format!(
+            "invalid resource name: {}",
+            name
+        ))),
+    }
+}
+
+fn now() -> Timestamp {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    Timestamp {
+        seconds: now.as_secs() as i64,
+        nanos: now.subsec_nanos() as i32,
+    }
+}
+
+fn time_key(ts: &Option<Timestamp>) -> (i64, i32) {
+    ts.as_ref()
+        .map(|ts| (ts.seconds, ts.nanos))
+        .unwrap_or_default()
+}
+
+// Returns the page of `items` at `page_token`, which is the offset of the page.
+fn page<T>(items: Vec<T>, page_size: i32, page_token: &str) -> Result<(Vec<T>, String), Status> {
+    let offset = if page_token.is_empty() {
+        0
+    } else {
+        page_token
+            .parse::<usize>()
+            .map_err(|_| Status::invalid_argument("invalid page token"))?
+    };
+    let size = if page_size > 0 {
+        page_size as usize
+    } else {
+        usize::MAX
+    };
+    let mut items: Vec<T> = items.into_iter().skip(offset).collect();
+    let next_page_token = if items.len() > size {
+        items.truncate(size);
+        (offset + size).to_string()
+    } else {
+        String::new()
+    };
+    Ok((items, next_page_token))
+}
+
+fn check_update_mask(mask: &Option<FieldMask>, allowed: &[&str]) -> Result<Vec<String>, Status> {
+    let paths = match mask {
+        Some(mask) if !mask.paths.is_empty() => &mask.paths,
+        _ => return Err(Status::invalid_argument("missing update mask")),
+    };
+    for path in paths {
+        if !allowed.contains(&path.as_str()) {
+            return Err(Status::invalid_argument(format!(
+                "invalid update mask path: {}",
+                path
+            )));
+        }
+    }
+    Ok(paths.clone())
+}
+
+struct TopicState {
+    topic: Topic,
+    // All the messages published, indexed by their sequence number.
+    log: Vec<Arc<PubsubMessage>>,
+}
+
+struct Lease {
+    ack_id: String,
+    deadline: Instant,
+}
+
+struct Entry {
+    message: Arc<PubsubMessage>,
+    delivery_attempt: i32,
+    lease: Option<Lease>,
+}
+
+impl Entry {
+    fn new(message: Arc<PubsubMessage>) -> Self {
+        Self {
+            message,
+            delivery_attempt: 0,
+            lease: None,
+        }
+    }
+}
+
+struct SubscriptionState {
+    subscription: Subscription,
+    filter: Option<Filter>,
+    // The sequence number of the first message of the topic delivered to the subscription.
+    start: usize,
+    // The unacked messages by sequence number.
+    entries: BTreeMap<usize, Entry>,
+    ack_ids: BTreeMap<String, usize>,
+}
+
+impl SubscriptionState {
+    fn accepts(&self, message: &PubsubMessage) -> bool {
+        match &self.filter {
+            Some(filter) => filter.matches(message),
+            None => true,
+        }
+    }
+
+    fn reset_leases(&mut self) {
+        self.ack_ids.clear();
+        for entry in self.entries.values_mut() {
+            entry.lease = None;
+        }
+    }
+}
+
+struct SnapshotState {
+    snapshot: Snapshot,
+    // The messages unacked when the snapshot was created.
+    messages: BTreeMap<usize, Arc<PubsubMessage>>,
+    // The sequence number of the first message of the topic published after the snapshot.
+    end: usize,
+}
+
+#[derive(Default)]
+struct State {
+    topics: BTreeMap<String, TopicState>,
+    subscriptions: BTreeMap<String, SubscriptionState>,
+    snapshots: BTreeMap<String, SnapshotState>,
+    next_message_id: u64,
+    next_ack_id: u64,
+}
+
+impl State {
+    fn topic(&self, name: &str) -> Result<&TopicState, Status> {
+        self.topics.get(name).ok_or_else(|| not_found(name))
+    }
+
+    fn subscription(&mut self, name: &str) -> Result<&mut SubscriptionState, Status> {
+        self.subscriptions
+            .get_mut(name)
+            .ok_or_else(|| not_found(name))
+    }
+
+    fn create_topic(&mut self, topic: Topic) -> Result<Topic, Status> {
+        check_name(&topic.name, "topics")?;
+        if self.topics.contains_key(&topic.name) {
+            return Err(already_exists(&topic.name));
+        }
+        let state = TopicState {
+            topic: topic.clone(),
+            log: Vec::new(),
+        };
+        self.topics.insert(topic.name.clone(), state);
+        Ok(topic)
+    }
+
+    fn update_topic(&mut self, request: UpdateTopicRequest) -> Result<Topic, Status> {
+        let topic = request
+            .topic
+            .ok_or_else(|| Status::invalid_argument("missing topic"))?;
+        let paths = check_update_mask(&request.update_mask, &["labels", "message_storage_policy"])?;
+        let state = self
+            .topics
+            .get_mut(&topic.name)
+            .ok_or_else(|| not_found(&topic.name))?;
+        for path in paths {
+            match path.as_str() {
+                "labels" => state.topic.labels = topic.labels.clone(),
+                _ => state.topic.message_storage_policy = topic.message_storage_policy.clone(),
+            }
+        }
+        Ok(state.topic.clone())
+    }
+
+    fn delete_topic(&mut self, name: &str) -> Result<(), Status> {
+        self.topics.remove(name).ok_or_else(|| not_found(name))?;
+        for state in self.subscriptions.values_mut() {
+            if state.subscription.topic == name {
+                state.subscription.topic = DELETED_TOPIC.into();
+            }
+        }
+        Ok(())
+    }
+
+    fn publish(&mut self, request: PublishRequest) -> Result<Vec<String>, Status> {
+        let PublishRequest { topic, messages } = request;
+        self.topic(&topic)?;
+        if messages.is_empty() {
+            return Err(Status::invalid_argument("no messages"));
+        }
+        if messages
+            .iter()
+            .any(|m| m.data.is_empty() && m.attributes.is_empty())
+        {
+            return Err(Status::invalid_argument(
+                "a message must have data or attributes",
+            ));
+        }
+        Ok(messages
+            .into_iter()
+            .map(|message| self.append(&topic, message))
+            .collect())
+    }
+
+    // Appends a message to the log of an existing topic and to its subscriptions.
+    fn append(&mut self, topic: &str, message: PubsubMessage) -> String {
+        self.next_message_id += 1;
+        let message = Arc::new(PubsubMessage {
+            message_id: self.next_message_id.to_string(),
+            publish_time: Some(now()),
+            ..message
+        });
+        let log = &mut self.topics.get_mut(topic).unwrap().log;
+        let seq = log.len();
+        log.push(message.clone());
+        for state in self.subscriptions.values_mut() {
+            if state.subscription.topic == topic && state.accepts(&message) {
+                state.entries.insert(seq, Entry::new(message.clone()));
+            }
+        }
+        message.message_id.clone()
+    }
+
+    fn create_subscription(
+        &mut self,
+        mut subscription: Subscription,
+    ) -> Result<Subscription, Status> {
+        check_name(&subscription.name, "subscriptions")?;
+        if self.subscriptions.contains_key(&subscription.name) {
+            return Err(already_exists(&subscription.name));
+        }
+        let start = self.topic(&subscription.topic)?.log.len();
+
+        match subscription.ack_deadline_seconds {
+            0 => subscription.ack_deadline_seconds = DEFAULT_ACK_DEADLINE_SECONDS,
+            DEFAULT_ACK_DEADLINE_SECONDS..=MAX_ACK_DEADLINE_SECONDS => {}
+            _ => return Err(Status::invalid_argument("invalid ack deadline")),
+        }
+        if let Some(policy) = &mut subscription.dead_letter_policy {
+            check_name(&policy.dead_letter_topic, "topics")?;
+            match policy.max_delivery_attempts {
+                0 => policy.max_delivery_attempts = DEFAULT_MAX_DELIVERY_ATTEMPTS,
+                DEFAULT_MAX_DELIVERY_ATTEMPTS..=MAX_DELIVERY_ATTEMPTS => {}
+                _ => return Err(Status::invalid_argument("invalid max delivery attempts")),
+            }
+        }
+        let filter = Filter::parse(&subscription.filter)
+            .map_err(|e| Status::invalid_argument(format!("invalid filter: {}", e)))?;
+
+        let state = SubscriptionState {
+            subscription: subscription.clone(),
+            filter,
+            start,
+            entries: BTreeMap::new(),
+            ack_ids: BTreeMap::new(),
+        };
+        self.subscriptions.insert(subscription.name.clone(), state);
+        Ok(subscription)
+    }
+
+    fn update_subscription(
+        &mut self,
+        request: UpdateSubscriptionRequest,
+    ) -> Result<Subscription, Status> {
+        let subscription = request
+            .subscription
+            .ok_or_else(|| Status::invalid_argument("missing subscription"))?;
+        let paths = check_update_mask(
+            &request.update_mask,
+            &[
+                "ack_deadline_seconds",
+                "dead_letter_policy",
+                "expiration_policy",
+                "labels",
+                "message_retention_duration",
+                "push_config",
+                "retain_acked_messages",
+                "retry_policy",
+            ],
+        )?;
+        let state = self.subscription(&subscription.name)?;
+        let mut updated = state.subscription.clone();
+        for path in paths {
+            match path.as_str() {
+                "ack_deadline_seconds" => {
+                    updated.ack_deadline_seconds = subscription.ack_deadline_seconds
+                }
+                "dead_letter_policy" => {
+                    updated.dead_letter_policy = subscription.dead_letter_policy.clone()
+                }
+                "expiration_policy" => {
+                    updated.expiration_policy = subscription.expiration_policy.clone()
+                }
+                "labels" => updated.labels = subscription.labels.clone(),
+                "message_retention_duration" => {
+                    updated.message_retention_duration =
+                        subscription.message_retention_duration.clone()
+                }
+                "push_config" => updated.push_config = subscription.push_config.clone(),
+                "retain_acked_messages" => {
+                    updated.retain_acked_messages = subscription.retain_acked_messages
+                }
+                _ => updated.retry_policy = subscription.retry_policy.clone(),
+            }
+        }
+        if !(DEFAULT_ACK_DEADLINE_SECONDS..=MAX_ACK_DEADLINE_SECONDS)
+            .contains(&updated.ack_deadline_seconds)
+        {
+            return Err(Status::invalid_argument("invalid ack deadline"));
+        }
+        if let Some(policy) = &updated.dead_letter_policy {
+            check_name(&policy.dead_letter_topic, "topics")?;
+            if !(DEFAULT_MAX_DELIVERY_ATTEMPTS..=MAX_DELIVERY_ATTEMPTS)
+                .contains(&policy.max_delivery_attempts)
+            {
+                return Err(Status::invalid_argument("invalid max delivery attempts"));
+            }
+        }
+        state.subscription = updated;
+        Ok(state.subscription.clone())
+    }
+
+    fn delete_subscription(&mut self, name: &str) -> Result<(), Status> {
+        self.subscriptions
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| not_found(name))
+    }
+
+    fn modify_ack_deadline(
+        &mut self,
+        name: &str,
+        ack_ids: &[String],
+        seconds: &[i32],
+    ) -> Result<(), Status> {
+        if seconds
+            .iter()
+            .any(|seconds| !(0..=MAX_ACK_DEADLINE_SECONDS).contains(seconds))
+        {
+            return Err(Status::invalid_argument("invalid ack deadline"));
+        }
+        let state = self.subscription(name)?;
+        let now = Instant::now();
+        for (ack_id, seconds) in ack_ids.iter().zip(seconds) {
+            let entry = match state.ack_ids.get(ack_id) {
+                Some(seq) => state.entries.get_mut(seq),
+                None => None,
+            };
+            if let Some(Entry {
+                lease: Some(lease), ..
+            }) = entry
+            {
+                lease.deadline = now + Duration::from_secs(*seconds as u64);
+            }
+        }
+        Ok(())
+    }
+
+    fn acknowledge(&mut self, name: &str, ack_ids: &[String]) -> Result<(), Status> {
+        let state = self.subscription(name)?;
+        for ack_id in ack_ids {
+            if let Some(seq) = state.ack_ids.remove(ack_id) {
+                state.entries.remove(&seq);
+            }
+        }
+        Ok(())
+    }
+
+    // Leases up to `max_messages` messages for `ack_deadline`. The messages of an ordering key
+    // are leased in order, and not while a previous message of the key is outstanding.
+    fn pull(
+        &mut self,
+        name: &str,
+        max_messages: usize,
+        ack_deadline: Duration,
+    ) -> Result<Vec<ReceivedMessage>, Status> {
+        let now = Instant::now();
+        let next_ack_id = &mut self.next_ack_id;
+        let state = self
+            .subscriptions
+            .get_mut(name)
+            .ok_or_else(|| not_found(name))?;
+        let ordered = state.subscription.enable_message_ordering;
+        let dead_letter_policy = state.subscription.dead_letter_policy.clone();
+
+        let mut received = Vec::new();
+        let mut dead_letters = Vec::new();
+        let mut blocked = HashSet::new();
+        for (seq, entry) in state.entries.iter_mut() {
+            if received.len() >= max_messages {
+                break;
+            }
+            let outstanding = matches!(&entry.lease, Some(lease) if lease.deadline > now);
+            let key = &entry.message.ordering_key;
+            if ordered && !key.is_empty() {
+                if blocked.contains(key) {
+                    continue;
+                }
+                if outstanding {
+                    blocked.insert(key.clone());
+                    continue;
+                }
+            } else if outstanding {
+                continue;
+            }
+
+            if let Some(lease) = entry.lease.take() {
+                state.ack_ids.remove(&lease.ack_id);
+            }
+            entry.delivery_attempt += 1;
+            let delivery_attempt = match &dead_letter_policy {
+                Some(policy) if entry.delivery_attempt > policy.max_delivery_attempts => {
+                    dead_letters.push(*seq);
+                    if ordered && !key.is_empty() {
+                        blocked.insert(key.clone());
+                    }
+                    continue;
+                }
+                Some(_) => entry.delivery_attempt,
+                None => 0,
+            };
+
+            *next_ack_id += 1;
+            let ack_id = format!("{}-{}", seq, next_ack_id);
+            state.ack_ids.insert(ack_id.clone(), *seq);
+            entry.lease = Some(Lease {
+                ack_id: ack_id.clone(),
+                deadline: now + ack_deadline,
+            });
+            received.push(ReceivedMessage {
+                ack_id,
+                message: Some((*entry.message).clone()),
+                delivery_attempt,
+            });
+        }
+
+        if let Some(policy) = dead_letter_policy {
+            let messages: Vec<_> = dead_letters
+                .iter()
+                .filter_map(|seq| state.entries.remove(seq))
+                .map(|entry| (*entry.message).clone())
+                .collect();
+            // The messages are dropped if the dead letter topic doesn't exist.
+            if self.topics.contains_key(&policy.dead_letter_topic) {
+                for message in messages {
+                    self.append(&policy.dead_letter_topic, message);
+                }
+            }
+        }
+        Ok(received)
+    }
+
+    fn modify_push_config(&mut self, request: ModifyPushConfigRequest) -> Result<(), Status> {
+        self.subscription(&request.subscription)?
+            .subscription
+            .push_config = request.push_config;
+        Ok(())
+    }
+
+    fn create_snapshot(&mut self, request: CreateSnapshotRequest) -> Result<Snapshot, Status> {
+        check_name(&request.name, "snapshots")?;
+        if self.snapshots.contains_key(&request.name) {
+            return Err(already_exists(&request.name));
+        }
+        let subscription = self
+            .subscriptions
+            .get(&request.subscription)
+            .ok_or_else(|| not_found(&request.subscription))?;
+        let topic = self
+            .topics
+            .get(&subscription.subscription.topic)
+            .ok_or_else(|| Status::failed_precondition("the topic is deleted"))?;
+
+        let expire_time = now();
+        let snapshot = Snapshot {
+            name: request.name.clone(),
+            topic: topic.topic.name.clone(),
+            expire_time: Some(Timestamp {
+                seconds: expire_time.seconds + SNAPSHOT_TTL.as_secs() as i64,
+                ..expire_time
+            }),
+            labels: request.labels,
+        };
+        let state = SnapshotState {
+            snapshot: snapshot.clone(),
+            messages: subscription
+                .entries
+                .iter()
+                .map(|(seq, entry)| (*seq, entry.message.clone()))
+                .collect(),
+            end: topic.log.len(),
+        };
+        self.snapshots.insert(request.name, state);
+        Ok(snapshot)
+    }
+
+    fn update_snapshot(&mut self, request: UpdateSnapshotRequest) -> Result<Snapshot, Status> {
+        let snapshot = request
+            .snapshot
+            .ok_or_else(|| Status::invalid_argument("missing snapshot"))?;
+        let paths = check_update_mask(&request.update_mask, &["expire_time", "labels"])?;
+        let state = self
+            .snapshots
+            .get_mut(&snapshot.name)
+            .ok_or_else(|| not_found(&snapshot.name))?;
+        for path in paths {
+            match path.as_str() {
+                "labels" => state.snapshot.labels = snapshot.labels.clone(),
+                _ => state.snapshot.expire_time = snapshot.expire_time.clone(),
+            }
+        }
+        Ok(state.snapshot.clone())
+    }
+
+    fn seek(&mut self, request: SeekRequest) -> Result<(), Status> {
+        let state = self
+            .subscriptions
+            .get_mut(&request.subscription)
+            .ok_or_else(|| not_found(&request.subscription))?;
+        let log = match self.topics.get(&state.subscription.topic) {
+            Some(topic) => &topic.log[..],
+            None => &[],
+        };
+        match request.target {
+            // Marks the messages published before the time as acked, and the retained ones
+            // published after as unacked.
+            Some(Target::Time(time)) => {
+                let time = time_key(&Some(time));
+                state
+                    .entries
+                    .retain(|_, entry| time_key(&entry.message.publish_time) >= time);
+                if state.subscription.retain_acked_messages {
+                    for (seq, message) in log.iter().enumerate().skip(state.start) {
+                        if time_key(&message.publish_time) >= time && state.accepts(message) {
+                            state
+                                .entries
+                                .entry(seq)
+                                .or_insert_with(|| Entry::new(message.clone()));
+                        }
+                    }
+                }
+            }
+            // Restores the unacked messages of the snapshot, and the messages published after.
+            Some(Target::Snapshot(name)) => {
+                let snapshot = self.snapshots.get(&name).ok_or_else(|| not_found(&name))?;
+                if snapshot.snapshot.topic != state.subscription.topic {
+                    return Err(Status::invalid_argument("the snapshot is of another topic"));
+                }
+                let mut entries: BTreeMap<_, _> = snapshot
+                    .messages
+                    .iter()
+                    .map(|(seq, message)| (*seq, Entry::new(message.clone())))
+                    .collect();
+                for (seq, message) in log.iter().enumerate().skip(snapshot.end) {
+                    if state.accepts(message) {
+                        entries.insert(seq, Entry::new(message.clone()));
+                    }
+                }
+                state.entries = entries;
+            }
+            None => return Err(Status::invalid_argument("missing seek target")),
+        }
+        state.reset_leases();
+        Ok(())
+    }
+}
+
+struct Inner {
+    state: Mutex<State>,
+    // Signals a change of the state to the pending pulls.
+    changes: watch::Sender<u64>,
+    watcher: watch::Receiver<u64>,
+}
+
+/// An in-memory Pub/Sub service.
+#[derive(Clone)]
+pub struct Emulator {
+    inner: Arc<Inner>,
+}
+
+impl Default for Emulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Emulator {
+    pub fn new() -> Self {
+        let (changes, watcher) = watch::channel(0);
+        Self {
+            inner: Arc::new(Inner {
+                state: Mutex::new(State::default()),
+                changes,
+                watcher,
+            }),
+        }
+    }
+
+    /// Returns a publisher client calling the emulator in memory.
+    pub fn publisher_client(&self) -> PublisherClient<PublisherServer<Emulator>> {
+        PublisherClient::new(PublisherServer::new(self.clone()))
+    }
+
+    /// Returns a subscriber client calling the emulator in memory.
+    pub fn subscriber_client(&self) -> SubscriberClient<SubscriberServer<Emulator>> {
+        SubscriberClient::new(SubscriberServer::new(self.clone()))
+    }
+
+    /// Serves the emulator on a free local port in the background, and returns its address.
+    pub async fn serve(&self) -> io::Result<SocketAddr> {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(
+            Server::builder()
+                .add_service(PublisherServer::new(self.clone()))
+                .add_service(SubscriberServer::new(self.clone()))
+                .serve_with_incoming(listener),
+        );
+        Ok(addr)
+    }
+
+    fn update<T>(&self, f: impl FnOnce(&mut State) -> Result<T, Status>) -> Result<T, Status> {
+        let result = f(&mut self.inner.state.lock().unwrap());
+        let mut version = *self.inner.watcher.borrow();
+        version += 1;
+        let _ = self.inner.changes.broadcast(version);
+        result
+    }
+
+    // Pulls messages, waiting for a change of the state until `timeout` if there are none.
+    async fn wait_pull(
+        &self,
+        name: &str,
+        max_messages: usize,
+        ack_deadline: Duration,
+        timeout: Duration,
+    ) -> Result<Vec<ReceivedMessage>, Status> {
+        let deadline = Instant::now() + timeout;
+        let mut watcher = self.inner.watcher.clone();
+        loop {
+            // Doesn't signal a change, which would wake up the other pending pulls.
+            let received =
+                self.inner
+                    .state
+                    .lock()
+                    .unwrap()
+                    .pull(name, max_messages, ack_deadline)?;
+            let now = Instant::now();
+            if !received.is_empty() || now >= deadline {
+                return Ok(received);
+            }
+            let wait = POLL_INTERVAL.min(deadline - now);
+            let _ = time::timeout(wait, watcher.recv()).await;
+        }
+    }
+
+    // Delivers the messages of a streaming pull until the request stream is closed.
+    async fn deliver(
+        self,
+        name: String,
+        ack_deadline: Duration,
+        tx: mpsc::UnboundedSender<Result<StreamingPullResponse, Status>>,
+        mut closed: oneshot::Receiver<()>,
+    ) {
+        loop {
+            let pull = self.wait_pull(
+                &name,
+                MAX_STREAMING_PULL_MESSAGES,
+                ack_deadline,
+                POLL_INTERVAL,
+            );
+            let result = match future::select(Box::pin(pull), &mut closed).await {
+                Either::Left((result, _)) => result,
+                Either::Right(_) => return,
+            };
+            let response = match result {
+                Ok(received_messages) if received_messages.is_empty() => continue,
+                Ok(received_messages) => Ok(StreamingPullResponse { received_messages }),
+                Err(status) => Err(status),
+            };
+            let end = response.is_err();
+            if tx.send(response).is_err() || end {
+                return;
+            }
+        }
+    }
+
+    fn apply(&self, request: &StreamingPullRequest, name: &str) -> Result<(), Status> {
+        if request.modify_deadline_ack_ids.len() != request.modify_deadline_seconds.len() {
+            return Err(Status::invalid_argument(
+                "modify_deadline_ack_ids and modify_deadline_seconds differ in length",
+            ));
+        }
+        self.update(|state| {
+            state.acknowledge(name, &request.ack_ids)?;
+            state.modify_ack_deadline(
+                name,
+                &request.modify_deadline_ack_ids,
+                &request.modify_deadline_seconds,
+            )
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl Publisher for Emulator {
+    async fn create_topic(&self, request: Request<Topic>) -> Result<Response<Topic>, Status> {
+        self.update(|state| state.create_topic(request.into_inner()))
+            .map(Response::new)
+    }
+
+    async fn update_topic(
+        &self,
+        request: Request<UpdateTopicRequest>,
+    ) -> Result<Response<Topic>, Status> {
+        self.update(|state| state.update_topic(request.into_inner()))
+            .map(Response::new)
+    }
+
+    async fn publish(
+        &self,
+        request: Request<PublishRequest>,
+    ) -> Result<Response<PublishResponse>, Status> {
+        let message_ids = self.update(|state| state.publish(request.into_inner()))?;
+        Ok(Response::new(PublishResponse { message_ids }))
+    }
+
+    async fn get_topic(
+        &self,
+        request: Request<GetTopicRequest>,
+    ) -> Result<Response<Topic>, Status> {
+        let state = self.inner.state.lock().unwrap();
+        let topic = state.topic(&request.get_ref().topic)?;
+        Ok(Response::new(topic.topic.clone()))
+    }
+
+    async fn list_topics(
+        &self,
+        request: Request<ListTopicsRequest>,
+    ) -> Result<Response<ListTopicsResponse>, Status> {
+        let request = request.into_inner();
+        let prefix = format!("{}/topics/", request.project);
+        let state = self.inner.state.lock().unwrap();
+        let topics = state
+            .topics
+            .values()
+            .filter(|state| state.topic.name.starts_with(&prefix))
+            .map(|state| state.topic.clone())
+            .collect();
+        let (topics, next_page_token) = page(topics, request.page_size, &request.page_token)?;
+        Ok(Response::new(ListTopicsResponse {
+            topics,
+            next_page_token,
+        }))
+    }
+
+    async fn list_topic_subscriptions(
+        &self,
+        request: Request<ListTopicSubscriptionsRequest>,
+    ) -> Result<Response<ListTopicSubscriptionsResponse>, Status> {
+        let request = request.into_inner();
+        let state = self.inner.state.lock().unwrap();
+        state.topic(&request.topic)?;
+        let subscriptions = state
+            .subscriptions
+            .values()
+            .filter(|state| state.subscription.topic == request.topic)
+            .map(|state| state.subscription.name.clone())
+            .collect();
+        let (subscriptions, next_page_token) =
+            page(subscriptions, request.page_size, &request.page_token)?;
+        Ok(Response::new(ListTopicSubscriptionsResponse {
+            subscriptions,
+            next_page_token,
+        }))
+    }
+
+    async fn list_topic_snapshots(
+        &self,
+        request: Request<ListTopicSnapshotsRequest>,
+    ) -> Result<Response<ListTopicSnapshotsResponse>, Status> {
+        let request = request.into_inner();
+        let state = self.inner.state.lock().unwrap();
+        state.topic(&request.topic)?;
+        let snapshots = state
+            .snapshots
+            .values()
+            .filter(|state| state.snapshot.topic == request.topic)
+            .map(|state| state.snapshot.name.clone())
+            .collect();
+        let (snapshots, next_page_token) = page(snapshots, request.page_size, &request.page_token)?;
+        Ok(Response::new(ListTopicSnapshotsResponse {
+            snapshots,
+            next_page_token,
+        }))
+    }
+
+    async fn delete_topic(
+        &self,
+        request: Request<DeleteTopicRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.update(|state| state.delete_topic(&request.get_ref().topic))
+            .map(Response::new)
+    }
+}
+
+#[tonic::async_trait]
+impl Subscriber for Emulator {
+    async fn create_subscription(
+        &self,
+        request: Request<Subscription>,
+    ) -> Result<Response<Subscription>, Status> {
+        self.update(|state| state.create_subscription(request.into_inner()))
+            .map(Response::new)
+    }
+
+    async fn get_subscription(
+        &self,
+        request: Request<GetSubscriptionRequest>,
+    ) -> Result<Response<Subscription>, Status> {
+        let mut state = self.inner.state.lock().unwrap();
+        let state = state.subscription(&request.get_ref().subscription)?;
+        Ok(Response::new(state.subscription.clone()))
+    }
+
+    async fn update_subscription(
+        &self,
+        request: Request<UpdateSubscriptionRequest>,
+    ) -> Result<Response<Subscription>, Status> {
+        self.update(|state| state.update_subscription(request.into_inner()))
+            .map(Response::new)
+    }
+
+    async fn list_subscriptions(
+        &self,
+        request: Request<ListSubscriptionsRequest>,
+    ) -> Result<Response<ListSubscriptionsResponse>, Status> {
+        let request = request.into_inner();
+        let prefix = format!("{}/subscriptions/", request.project);
+        let state = self.inner.state.lock().unwrap();
+        let subscriptions = state
+            .subscriptions
+            .values()
+            .filter(|state| state.subscription.name.starts_with(&prefix))
+            .map(|state| state.subscription.clone())
+            .collect();
+        let (subscriptions, next_page_token) =
+            page(subscriptions, request.page_size, &request.page_token)?;
+        Ok(Response::new(ListSubscriptionsResponse {
+            subscriptions,
+            next_page_token,
+        }))
+    }
+
+    async fn delete_subscription(
+        &self,
+        request: Request<DeleteSubscriptionRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.update(|state| state.delete_subscription(&request.get_ref().subscription))
+            .map(Response::new)
+    }
+
+    async fn modify_ack_deadline(
+        &self,
+        request: Request<ModifyAckDeadlineRequest>,
+    ) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        let seconds = vec![request.ack_deadline_seconds; request.ack_ids.len()];
+        self.update(|state| {
+            state.modify_ack_deadline(&request.subscription, &request.ack_ids, &seconds)
+        })
+        .map(Response::new)
+    }
+
+    async fn acknowledge(
+        &self,
+        request: Request<AcknowledgeRequest>,
+    ) -> Result<Response<()>, Status> {
+        let request = request.get_ref();
+        self.update(|state| state.acknowledge(&request.subscription, &request.ack_ids))
+            .map(Response::new)
+    }
+
+    async fn pull(&self, request: Request<PullRequest>) -> Result<Response<PullResponse>, Status> {
+        let request = request.into_inner();
+        if request.max_messages <= 0 {
+            return Err(Status::invalid_argument("max_messages must be positive"));
+        }
+        let ack_deadline = {
+            let mut state = self.inner.state.lock().unwrap();
+            let state = state.subscription(&request.subscription)?;
+            Duration::from_secs(state.subscription.ack_deadline_seconds as u64)
+        };
+        let timeout = if request.return_immediately {
+            Duration::from_secs(0)
+        } else {
+            PULL_TIMEOUT
+        };
+        let received_messages = self
+            .wait_pull(
+                &request.subscription,
+                request.max_messages as usize,
+                ack_deadline,
+                timeout,
+            )
+            .await?;
+        Ok(Response::new(PullResponse { received_messages }))
+    }
+
+    type StreamingPullStream = mpsc::UnboundedReceiver<Result<StreamingPullResponse, Status>>;
+
+    async fn streaming_pull(
+        &self,
+        request: Request<Streaming<StreamingPullRequest>>,
+    ) -> Result<Response<Self::StreamingPullStream>, Status> {
+        let mut requests = request.into_inner();
+        let first = requests
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("missing initial request"))?;
+        if !(DEFAULT_ACK_DEADLINE_SECONDS..=MAX_ACK_DEADLINE_SECONDS)
+            .contains(&first.stream_ack_deadline_seconds)
+        {
+            return Err(Status::invalid_argument("invalid stream ack deadline"));
+        }
+        let name = first.subscription.clone();
+        self.apply(&first, &name)?;
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (close, closed) = oneshot::channel();
+        let ack_deadline = Duration::from_secs(first.stream_ack_deadline_seconds as u64);
+        tokio::spawn(
+            self.clone()
+                .deliver(name.clone(), ack_deadline, tx.clone(), closed),
+        );
+
+        let emulator = self.clone();
+        tokio::spawn(async move {
+            // The delivery stops when the request stream is closed.
+            let _close = close;
+            while let Ok(Some(request)) = requests.message().await {
+                if let Err(status) = emulator.apply(&request, &name) {
+                    let _ = tx.send(Err(status));
+                    return;
+                }
+            }
+        });
+        Ok(Response::new(rx))
+    }
+
+    async fn modify_push_config(
+        &self,
+        request: Request<ModifyPushConfigRequest>,
+    ) -> Result<Response<()>, Status> {
+        self.update(|state| state.modify_push_config(request.into_inner()))
+            .map(Response::new)
+    }
+
+    async fn get_snapshot(
+        &self,
+        request: Request<GetSnapshotRequest>,
+    ) -> Result<Response<Snapshot>, Status> {
+        let name = &request.get_ref().snapshot;
+        let state = self.inner.state.lock().unwrap();
+        let snapshot = state.snapshots.get(name).ok_or_else(|| not_found(name))?;
+        Ok(Response::new(snapshot.snapshot.clone()))
+    }
+
+    async fn list_snapshots(
+        &self,
+        request: Request<ListSnapshotsRequest>,
+    ) -> Result<Response<ListSnapshotsResponse>, Status> {
+        let request = request.into_inner();
+        let prefix = format!("{}/snapshots/", request.project);
+        let state = self.inner.state.lock().unwrap();
+        let snapshots = state
+            .snapshots
+            .values()
+            .filter(|state| state.snapshot.name.starts_with(&prefix))
+            .map(|state| state.snapshot.clone())
+            .collect();
+        let (snapshots, next_page_token) = page(snapshots, request.page_size, &request.page_token)?;
+        Ok(Response::new(ListSnapshotsResponse {
+            snapshots,
+            next_page_token,
+        }))
+    }
+
+    async fn create_snapshot(
+        &self,
+        request: Request<CreateSnapshotRequest>,
+    ) -> Result<Response<Snapshot>, Status> {
+        self.update(|state| state.create_snapshot(request.into_inner()))
+            .map(Response::new)
+    }
+
+    async fn update_snapshot(
+        &self,
+        request: Request<UpdateSnapshotRequest>,
+    ) -> Result<Response<Snapshot>, Status> {
+        self.update(|state| state.update_snapshot(request.into_inner()))
+            .map(Response::new)
+    }
+
+    async fn delete_snapshot(
+        &self,
+        request: Request<DeleteSnapshotRequest>,
+    ) -> Result<Response<()>, Status> {
+        let name = &request.get_ref().snapshot;
+        self.update(|state| {
+            state
+                .snapshots
+                .remove(name)
+                .map(|_| ())
+                .ok_or_else(|| not_found(name))
+        })
+        .map(Response::new)
+    }
+
+    async fn seek(&self, request: Request<SeekRequest>) -> Result<Response<SeekResponse>, Status> {
+        self.update(|state| state.seek(request.into_inner()))?;
+        Ok(Response::new(SeekResponse {}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+
+    const TOPIC: &str = "projects/p/topics/t";
+    const SUBSCRIPTION: &str = "projects/p/subscriptions/s";
+
+    type Publisher = PublisherClient<PublisherServer<Emulator>>;
+    type Subscriber = SubscriberClient<SubscriberServer<Emulator>>;
+
+    async fn setup(subscription: Subscription) -> (Publisher, Subscriber) {
+        let emulator = Emulator::new();
+        let mut publisher = emulator.publisher_client();
+        let mut subscriber = emulator.subscriber_client();
+        publisher
+            .create_topic(Topic {
+                name: TOPIC.into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        subscriber
+            .create_subscription(Subscription {
+                name: SUBSCRIPTION.into(),
+                topic: TOPIC.into(),
+                ..subscription
+            })
+            .await
+            .unwrap();
+        (publisher, subscriber)
+    }
+
+    fn message(data: &str, key: &str, attributes: &[(&str, &str)]) -> PubsubMessage {
+        PubsubMessage {
+            data: data.into(),
+            ordering_key: key.into(),
+            attributes: attributes
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    async fn publish(publisher: &mut Publisher, topic: &str, messages: Vec<PubsubMessage>) {
+        publisher
+            .publish(PublishRequest {
+                topic: topic.into(),
+                messages,
+            })
+            .await
+            .unwrap();
+    }
+
+    async fn pull(subscriber: &mut Subscriber, name: &str) -> Vec<ReceivedMessage> {
+        subscriber
+            .pull(PullRequest {
+                subscription: name.into(),
+                return_immediately: true,
+                max_messages: 10,
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .received_messages
+    }
+
+    fn data(received: &[ReceivedMessage]) -> Vec<String> {
+        received
+            .iter()
+            .map(|m| String::from_utf8(m.message.as_ref().unwrap().data.clone()).unwrap())
+            .collect()
+    }
+
+    async fn ack(subscriber: &mut Subscriber, received: &[ReceivedMessage]) {
+        subscriber
+            .acknowledge(AcknowledgeRequest {
+                subscription: SUBSCRIPTION.into(),
+                ack_ids: received.iter().map(|m| m.ack_id.clone()).collect(),
+            })
+            .await
+            .unwrap();
+    }
+
+    async fn nack(subscriber: &mut Subscriber, received: &[ReceivedMessage]) {
+        subscriber
+            .modify_ack_deadline(ModifyAckDeadlineRequest {
+                subscription: SUBSCRIPTION.into(),
+                ack_ids: received.iter().map(|m| m.ack_id.clone()).collect(),
+                ack_deadline_seconds: 0,
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_topics_and_subscriptions() {
+        let (mut publisher, mut subscriber) = setup(Subscription::default()).await;
+
+        let status = publisher
+            .create_topic(Topic {
+                name: TOPIC.into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::AlreadyExists);
+        let status = publisher
+            .create_topic(Topic {
+                name: "topics/t".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        for name in &["projects/p/topics/t2", "projects/p/topics/t3"] {
+            publisher
+                .create_topic(Topic {
+                    name: name.to_string(),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        let response = publisher
+            .list_topics(ListTopicsRequest {
+                project: "projects/p".into(),
+                page_size: 2,
+                page_token: "".into(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.topics.len(), 2);
+        let response = publisher
+            .list_topics(ListTopicsRequest {
+                project: "projects/p".into(),
+                page_size: 2,
+                page_token: response.next_page_token,
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.topics[0].name, "projects/p/topics/t3");
+        assert_eq!(response.next_page_token, "");
+
+        let response = publisher
+            .list_topic_subscriptions(ListTopicSubscriptionsRequest {
+                topic: TOPIC.into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(response.subscriptions, [SUBSCRIPTION]);
+
+        publisher
+            .delete_topic(DeleteTopicRequest {
+                topic: TOPIC.into(),
+            })
+            .await
+            .unwrap();
+        let subscription = subscriber
+            .get_subscription(GetSubscriptionRequest {
+                subscription: SUBSCRIPTION.into(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(subscription.topic, DELETED_TOPIC);
+        assert_eq!(subscription.ack_deadline_seconds, 10);
+        let status = publisher
+            .publish(PublishRequest {
+                topic: TOPIC.into(),
+                messages: vec![message("a", "", &[])],
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_pull_ack_nack() {
+        let (mut publisher, mut subscriber) = setup(Subscription::default()).await;
+        publish(
+            &mut publisher,
+            TOPIC,
+            vec![message("a", "", &[]), message("b", "", &[])],
+        )
+        .await;
+
+        let received = pull(&mut subscriber, SUBSCRIPTION).await;
+        assert_eq!(data(&received), ["a", "b"]);
+        assert!(pull(&mut subscriber, SUBSCRIPTION).await.is_empty());
+
+        ack(&mut subscriber, &received[..1]).await;
+        nack(&mut subscriber, &received[1..]).await;
+        let redelivered = pull(&mut subscriber, SUBSCRIPTION).await;
+        assert_eq!(data(&redelivered), ["b"]);
+        assert_ne!(redelivered[0].ack_id, received[1].ack_id);
+        // The delivery attempt is only set with a dead letter policy.
+        assert_eq!(redelivered[0].delivery_attempt, 0);
+
+        // The ack id of a previous delivery is ignored.
+        ack(&mut subscriber, &received[1..]).await;
+        nack(&mut subscriber, &redelivered).await;
+        let redelivered = pull(&mut subscriber, SUBSCRIPTION).await;
+        ack(&mut subscriber, &redelivered).await;
+        assert!(pull(&mut subscriber, SUBSCRIPTION).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pull_waits_for_messages() {
+        let (mut publisher, mut subscriber) = setup(Subscription::default()).await;
+        let pull = tokio::spawn(async move {
+            subscriber
+                .pull(PullRequest {
+                    subscription: SUBSCRIPTION.into(),
+                    return_immediately: false,
+                    max_messages: 10,
+                })
+                .await
+                .unwrap()
+                .into_inner()
+                .received_messages
+        });
+        time::delay_for(Duration::from_millis(50)).await;
+        publish(&mut publisher, TOPIC, vec![message("a", "", &[])]).await;
+        assert_eq!(data(&pull.await.unwrap()), ["a"]);
+    }
+
+    #[tokio::test]
+    async fn test_ordering_keys() {
+        let (mut publisher, mut subscriber) = setup(Subscription {
+            enable_message_ordering: true,
+            ..Default::default()
+        })
+        .await;
+        publish(
+            &mut publisher,
+            TOPIC,
+            vec![
+                message("k1", "k", &[]),
+                message("k2", "k", &[]),
+                message("a", "", &[]),
+            ],
+        )
+        .await;
+
+        let received = pull(&mut subscriber, SUBSCRIPTION).await;
+        assert_eq!(data(&received), ["k1", "k2", "a"]);
+        // The messages after an outstanding one of the same key aren't delivered.
+        nack(&mut subscriber, &received).await;
+        ack(&mut subscriber, &received[..1]).await;
+        publish(&mut publisher, TOPIC, vec![message("k3", "k", &[])]).await;
+        let received = pull(&mut subscriber, SUBSCRIPTION).await;
+        assert_eq!(data(&received), ["k2", "a", "k3"]);
+        nack(&mut subscriber, &received[..1]).await;
+        let received = pull(&mut subscriber, SUBSCRIPTION).await;
+        assert_eq!(data(&received), ["k2"]);
+    }
+
+    #[tokio::test]
+    async fn test_filter() {
+        let (mut publisher, mut subscriber) = setup(Subscription {
+            filter: r#"attributes.type = "a" OR hasPrefix(attributes.type, "b")"#.into(),
+            ..Default::default()
+        })
+        .await;
+        publish(
+            &mut publisher,
+            TOPIC,
+            vec![
+                message("a", "", &[("type", "a")]),
+                message("b", "", &[("type", "bb")]),
+                message("c", "", &[("type", "c")]),
+                message("d", "", &[]),
+            ],
+        )
+        .await;
+        assert_eq!(data(&pull(&mut subscriber, SUBSCRIPTION).await), ["a", "b"]);
+
+        let status = subscriber
+            .create_subscription(Subscription {
+                name: "projects/p/subscriptions/invalid".into(),
+                topic: TOPIC.into(),
+                filter: "attributes.type = a".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_dead_letter_policy() {
+        let (mut publisher, mut subscriber) = setup(Subscription {
+            dead_letter_policy: Some(DeadLetterPolicy {
+                dead_letter_topic: "projects/p/topics/dead".into(),
+                max_delivery_attempts: 0,
+            }),
+            ..Default::default()
+        })
+        .await;
+        publisher
+            .create_topic(Topic {
+                name: "projects/p/topics/dead".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        subscriber
+            .create_subscription(Subscription {
+                name: "projects/p/subscriptions/dead".into(),
+                topic: "projects/p/topics/dead".into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        publish(&mut publisher, TOPIC, vec![message("a", "", &[])]).await;
+
+        for attempt in 1..=DEFAULT_MAX_DELIVERY_ATTEMPTS {
+            let received = pull(&mut subscriber, SUBSCRIPTION).await;
+            assert_eq!(received[0].delivery_attempt, attempt);
+            nack(&mut subscriber, &received).await;
+        }
+        assert!(pull(&mut subscriber, SUBSCRIPTION).await.is_empty());
+        let received = pull(&mut subscriber, "projects/p/subscriptions/dead").await;
+        assert_eq!(data(&received), ["a"]);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_and_seek() {
+        let (mut publisher, mut subscriber) = setup(Subscription {
+            retain_acked_messages: true,
+            ..Default::default()
+        })
+        .await;
+        publish(&mut publisher, TOPIC, vec![message("a", "", &[])]).await;
+        let snapshot = subscriber
+            .create_snapshot(CreateSnapshotRequest {
+                name: "projects/p/snapshots/snap".into(),
+                subscription: SUBSCRIPTION.into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(snapshot.topic, TOPIC);
+
+        let received = pull(&mut subscriber, SUBSCRIPTION).await;
+        ack(&mut subscriber, &received).await;
+        publish(&mut publisher, TOPIC, vec![message("b", "", &[])]).await;
+        let received = pull(&mut subscriber, SUBSCRIPTION).await;
+        ack(&mut subscriber, &received).await;
+
+        subscriber
+            .seek(SeekRequest {
+                subscription: SUBSCRIPTION.into(),
+                target: Some(Target::Snapshot(snapshot.name)),
+            })
+            .await
+            .unwrap();
+        let received = pull(&mut subscriber, SUBSCRIPTION).await;
+        assert_eq!(data(&received), ["a", "b"]);
+
+        // Seeking to a time acks the messages published before.
+        let publish_time = received[1].message.as_ref().unwrap().publish_time.clone();
+        subscriber
+            .seek(SeekRequest {
+                subscription: SUBSCRIPTION.into(),
+                target: Some(Target::Time(publish_time.unwrap())),
+            })
+            .await
+            .unwrap();
+        assert_eq!(data(&pull(&mut subscriber, SUBSCRIPTION).await), ["b"]);
+        subscriber
+            .seek(SeekRequest {
+                subscription: SUBSCRIPTION.into(),
+                target: Some(Target::Time(Timestamp::default())),
+            })
+            .await
+            .unwrap();
+        assert_eq!(data(&pull(&mut subscriber, SUBSCRIPTION).await), ["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_streaming_pull() {
+        let emulator = Emulator::new();
+        let addr = emulator.serve().await.unwrap();
+        let mut publisher = PublisherClient::connect(format!("http://{}", addr))
+            .await
+            .unwrap();
+        let mut subscriber = SubscriberClient::connect(format!("http://{}", addr))
+            .await
+            .unwrap();
+        publisher
+            .create_topic(Topic {
+                name: TOPIC.into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        subscriber
+            .create_subscription(Subscription {
+                name: SUBSCRIPTION.into(),
+                topic: TOPIC.into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        tx.send(StreamingPullRequest {
+            subscription: SUBSCRIPTION.into(),
+            stream_ack_deadline_seconds: 10,
+            ..Default::default()
+        })
+        .unwrap();
+        let mut stream = subscriber.streaming_pull(rx).await.unwrap().into_inner();
+
+        publisher
+            .publish(PublishRequest {
+                topic: TOPIC.into(),
+                messages: vec![message("a", "", &[])],
+            })
+            .await
+            .unwrap();
+        let response = stream.message().await.unwrap().unwrap();
+        assert_eq!(data(&response.received_messages), ["a"]);
+
+        // Nacks the message over the stream.
+        tx.send(StreamingPullRequest {
+            modify_deadline_ack_ids: vec![response.received_messages[0].ack_id.clone()],
+            modify_deadline_seconds: vec![0],
+            ..Default::default()
+        })
+        .unwrap();
+        let response = stream.message().await.unwrap().unwrap();
+        assert_eq!(data(&response.received_messages), ["a"]);
+        tx.send(StreamingPullRequest {
+            ack_ids: vec![response.received_messages[0].ack_id.clone()],
+            ..Default::default()
+        })
+        .unwrap();
+
+        subscriber
+            .delete_subscription(DeleteSubscriptionRequest {
+                subscription: SUBSCRIPTION.into(),
+            })
+            .await
+            .unwrap();
+        let status = stream.message().await.unwrap_err();
+        assert_eq!(status.code(), Code::NotFound);
+    }
+}
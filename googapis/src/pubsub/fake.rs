@@ -0,0 +1,339 @@
+// In-memory fakes of the Pub/Sub services for the tests of the `pubsub` module.
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use futures::{Stream, StreamExt};
+use tonic::{Code, Request, Response, Status, Streaming};
+
+use crate::google::pubsub::v1::{
+    publisher_client::PublisherClient, publisher_server, subscriber_client::SubscriberClient,
+    subscriber_server, AcknowledgeRequest, CreateSnapshotRequest, DeleteSnapshotRequest,
+    DeleteSubscriptionRequest, DeleteTopicRequest, GetSnapshotRequest, GetSubscriptionRequest,
+    GetTopicRequest, ListSnapshotsRequest, ListSnapshotsResponse, ListSubscriptionsRequest,
+    ListSubscriptionsResponse, ListTopicSnapshotsRequest, ListTopicSnapshotsResponse,
+    ListTopicSubscriptionsRequest, ListTopicSubscriptionsResponse, ListTopicsRequest,
+    ListTopicsResponse, ModifyAckDeadlineRequest, ModifyPushConfigRequest, PublishRequest,
+    PublishResponse, PullRequest, PullResponse, ReceivedMessage, SeekRequest, SeekResponse,
+    Snapshot, StreamingPullRequest, StreamingPullResponse, Subscription, Topic,
+    UpdateSnapshotRequest, UpdateSubscriptionRequest, UpdateTopicRequest,
+};
+
+pub(crate) type FakePublisherServer = publisher_server::PublisherServer<Arc<FakePublisher>>;
+pub(crate) type FakeSubscriberServer = subscriber_server::SubscriberServer<Arc<FakeSubscriber>>;
+
+#[derive(Default)]
+struct PublisherState {
+    // The topics created, in the order of the calls.
+    topics: Vec<Topic>,
+    requests: Vec<PublishRequest>,
+    // The publish calls with a message of this ordering key fail.
+    failing_key: Option<String>,
+}
+
+#[derive(Default)]
+pub(crate) struct FakePublisher {
+    state: Mutex<PublisherState>,
+}
+
+impl FakePublisher {
+    pub(crate) fn client(self: &Arc<Self>) -> PublisherClient<FakePublisherServer> {
+        PublisherClient::new(publisher_server::PublisherServer::new(self.clone()))
+    }
+
+    // The requests of the successful calls of `publish`.
+    pub(crate) fn requests(&self) -> Vec<PublishRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    // Fails the calls of `publish` with a message of `key` with `UNAVAILABLE` and the details
+    // `FAILURE_DETAILS`, or none of them.
+    pub(crate) fn set_failing_key(&self, key: Option<&str>) {
+        self.state.lock().unwrap().failing_key = key.map(Into::into);
+    }
+}
+
+pub(crate) const FAILURE_DETAILS: &[u8] = b"failure details";
+
+fn unimplemented<T>() -> Result<Response<T>, Status> {
+    Err(Status::unimplemented("not implemented by the fake"))
+}
+
+#[tonic::async_trait]
+impl publisher_server::Publisher for Arc<FakePublisher> {
+    async fn create_topic(&self, request: Request<Topic>) -> Result<Response<Topic>, Status> {
+        let topic = request.into_inner();
+        self.state.lock().unwrap().topics.push(topic.clone());
+        Ok(Response::new(topic))
+    }
+
+    async fn update_topic(
+        &self,
+        _: Request<UpdateTopicRequest>,
+    ) -> Result<Response<Topic>, Status> {
+        unimplemented()
+    }
+
+    // Returns the IDs of the messages in the order of the calls.
+    async fn publish(
+        &self,
+        request: Request<PublishRequest>,
+    ) -> Result<Response<PublishResponse>, Status> {
+        let request = request.into_inner();
+        let mut state = self.state.lock().unwrap();
+        let failing_key = state.failing_key.as_ref();
+        if request
+            .messages
+            .iter()
+            .any(|m| Some(&m.ordering_key) == failing_key)
+        {
+            return Err(Status::with_details(
+                Code::Unavailable,
+                "failing key",
+                FAILURE_DETAILS.into(),
+            ));
+        }
+        let offset = state
+            .requests
+            .iter()
+            .map(|r| r.messages.len())
+            .sum::<usize>();
+        let message_ids = (offset..offset + request.messages.len())
+            .map(|i| i.to_string())
+            .collect();
+        state.requests.push(request);
+        Ok(Response::new(PublishResponse { message_ids }))
+    }
+
+    async fn get_topic(&self, _: Request<GetTopicRequest>) -> Result<Response<Topic>, Status> {
+        unimplemented()
+    }
+
+    // Pages over the topics of the project, with the offset of the next page as token.
+    async fn list_topics(
+        &self,
+        request: Request<ListTopicsRequest>,
+    ) -> Result<Response<ListTopicsResponse>, Status> {
+        let request = request.into_inner();
+        let offset = match request.page_token.as_str() {
+            "" => 0,
+            token => token
+                .parse::<usize>()
+                .map_err(|_| Status::invalid_argument(token))?,
+        };
+        let prefix = format!("{}/topics/", request.project);
+        let state = self.state.lock().unwrap();
+        let topics = state
+            .topics
+            .iter()
+            .filter(|t| t.name.starts_with(&prefix))
+            .cloned()
+            .collect::<Vec<_>>();
+        let end = match request.page_size {
+            0 => topics.len(),
+            size => (offset + size as usize).min(topics.len()),
+        };
+        Ok(Response::new(ListTopicsResponse {
+            topics: topics[offset.min(end)..end].to_vec(),
+            next_page_token: if end < topics.len() {
+                end.to_string()
+            } else {
+                String::new()
+            },
+        }))
+    }
+
+    async fn list_topic_subscriptions(
+        &self,
+        _: Request<ListTopicSubscriptionsRequest>,
+    ) -> Result<Response<ListTopicSubscriptionsResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn list_topic_snapshots(
+        &self,
+        _: Request<ListTopicSnapshotsRequest>,
+    ) -> Result<Response<ListTopicSnapshotsResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn delete_topic(&self, _: Request<DeleteTopicRequest>) -> Result<Response<()>, Status> {
+        unimplemented()
+    }
+}
+
+#[derive(Default)]
+struct SubscriberState {
+    // The result of each streaming pull call. The streams stay open after their messages, and
+    // the calls without a result have no messages.
+    streams: VecDeque<Result<Vec<ReceivedMessage>, Status>>,
+    pulls: usize,
+    acks: Vec<String>,
+    modacks: Vec<(String, i32)>,
+}
+
+#[derive(Default)]
+pub(crate) struct FakeSubscriber {
+    state: Mutex<SubscriberState>,
+}
+
+impl FakeSubscriber {
+    pub(crate) fn client(self: &Arc<Self>) -> SubscriberClient<FakeSubscriberServer> {
+        SubscriberClient::new(subscriber_server::SubscriberServer::new(self.clone()))
+    }
+
+    // Sets the result of the first call of `streaming_pull` that has none yet.
+    pub(crate) fn respond_to_pull(&self, result: Result<Vec<ReceivedMessage>, Status>) {
+        self.state.lock().unwrap().streams.push_back(result);
+    }
+
+    // The number of calls of `streaming_pull`.
+    pub(crate) fn pulls(&self) -> usize {
+        self.state.lock().unwrap().pulls
+    }
+
+    // The acknowledged IDs, in the order of the calls.
+    pub(crate) fn acks(&self) -> Vec<String> {
+        self.state.lock().unwrap().acks.clone()
+    }
+
+    // The IDs and deadlines of the calls of `modify_ack_deadline`, in their order.
+    pub(crate) fn modacks(&self) -> Vec<(String, i32)> {
+        self.state.lock().unwrap().modacks.clone()
+    }
+}
+
+type PullStream = Pin<Box<dyn Stream<Item = Result<StreamingPullResponse, Status>> + Send + Sync>>;
+
+#[tonic::async_trait]
+impl subscriber_server::Subscriber for Arc<FakeSubscriber> {
+    async fn create_subscription(
+        &self,
+        _: Request<Subscription>,
+    ) -> Result<Response<Subscription>, Status> {
+        unimplemented()
+    }
+
+    async fn get_subscription(
+        &self,
+        _: Request<GetSubscriptionRequest>,
+    ) -> Result<Response<Subscription>, Status> {
+        unimplemented()
+    }
+
+    async fn update_subscription(
+        &self,
+        _: Request<UpdateSubscriptionRequest>,
+    ) -> Result<Response<Subscription>, Status> {
+        unimplemented()
+    }
+
+    async fn list_subscriptions(
+        &self,
+        _: Request<ListSubscriptionsRequest>,
+    ) -> Result<Response<ListSubscriptionsResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn delete_subscription(
+        &self,
+        _: Request<DeleteSubscriptionRequest>,
+    ) -> Result<Response<()>, Status> {
+        unimplemented()
+    }
+
+    async fn modify_ack_deadline(
+        &self,
+        request: Request<ModifyAckDeadlineRequest>,
+    ) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        let deadline = request.ack_deadline_seconds;
+        let mut state = self.state.lock().unwrap();
+        state
+            .modacks
+            .extend(request.ack_ids.into_iter().map(|id| (id, deadline)));
+        Ok(Response::new(()))
+    }
+
+    async fn acknowledge(
+        &self,
+        request: Request<AcknowledgeRequest>,
+    ) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        self.state.lock().unwrap().acks.extend(request.ack_ids);
+        Ok(Response::new(()))
+    }
+
+    async fn pull(&self, _: Request<PullRequest>) -> Result<Response<PullResponse>, Status> {
+        unimplemented()
+    }
+
+    type StreamingPullStream = PullStream;
+
+    async fn streaming_pull(
+        &self,
+        _: Request<Streaming<StreamingPullRequest>>,
+    ) -> Result<Response<Self::StreamingPullStream>, Status> {
+        let result = {
+            let mut state = self.state.lock().unwrap();
+            state.pulls += 1;
+            state.streams.pop_front()
+        };
+        let responses = match result {
+            Some(Err(status)) => return Err(status),
+            Some(Ok(received_messages)) => vec![Ok(StreamingPullResponse { received_messages })],
+            None => vec![],
+        };
+        let stream = futures::stream::iter(responses).chain(futures::stream::pending());
+        Ok(Response::new(Box::pin(stream) as PullStream))
+    }
+
+    async fn modify_push_config(
+        &self,
+        _: Request<ModifyPushConfigRequest>,
+    ) -> Result<Response<()>, Status> {
+        unimplemented()
+    }
+
+    async fn get_snapshot(
+        &self,
+        _: Request<GetSnapshotRequest>,
+    ) -> Result<Response<Snapshot>, Status> {
+        unimplemented()
+    }
+
+    async fn list_snapshots(
+        &self,
+        _: Request<ListSnapshotsRequest>,
+    ) -> Result<Response<ListSnapshotsResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn create_snapshot(
+        &self,
+        _: Request<CreateSnapshotRequest>,
+    ) -> Result<Response<Snapshot>, Status> {
+        unimplemented()
+    }
+
+    async fn update_snapshot(
+        &self,
+        _: Request<UpdateSnapshotRequest>,
+    ) -> Result<Response<Snapshot>, Status> {
+        unimplemented()
+    }
+
+    async fn delete_snapshot(
+        &self,
+        _: Request<DeleteSnapshotRequest>,
+    ) -> Result<Response<()>, Status> {
+        unimplemented()
+    }
+
+    async fn seek(&self, _: Request<SeekRequest>) -> Result<Response<SeekResponse>, Status> {
+        unimplemented()
+    }
+}
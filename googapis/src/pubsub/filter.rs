@@ -0,0 +1,527 @@
+//! The filter language of subscriptions over message attributes.
+//!
+//! A subscription with a `filter` only receives the messages whose attributes match it, e.g.
+//! `attributes.region = "eu" AND NOT attributes:test`. The language consists of:
+//!
+//! - `attributes:key`, true if the message has the attribute
+//! - `attributes.key = "value"` and `attributes.key != "value"`
+//! - `hasPrefix(attributes.key, "prefix")`
+//! - `NOT` (or `-`), `AND` and `OR`, where `AND` and `OR` can't be mixed without parentheses
+//!
+//! Keys are identifiers or quoted strings, and filters are at most 256 bytes long.
+//!
+//! [`validate`] catches invalid filters before creating a subscription, and [`Filter::matches`]
+//! applies a filter locally:
+//!
+//! ```ignore
+//! let filter = Filter::parse(r#"hasPrefix(attributes.type, "order.")"#)?.unwrap();
+//! assert!(filter.matches(&message));
+//! ```
+
+use std::{collections::HashMap, error, fmt};
+
+use crate::google::pubsub::v1::PubsubMessage;
+
+// The maximum length of a filter in bytes.
+const MAX_LENGTH: usize = 256;
+
+/// An error parsing a filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    position: usize,
+    message: String,
+}
+
+impl Error {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the byte offset of the error in the filter.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl error::Error for Error {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Dot,
+    Colon,
+    Equal,
+    NotEqual,
+    Minus,
+    Comma,
+    LParen,
+    RParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Ident(ident) => f.write_str(ident),
+            Token::Str(s) => write!(f, "{:?}", s),
+            Token::Dot => f.write_str("."),
+            Token::Colon => f.write_str(":"),
+            Token::Equal => f.write_str("="),
+            Token::NotEqual => f.write_str("!="),
+            Token::Minus => f.write_str("-"),
+            Token::Comma => f.write_str(","),
+            Token::LParen => f.write_str("("),
+            Token::RParen => f.write_str(")"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, Error> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '.' => Token::Dot,
+            ':' => Token::Colon,
+            '=' => Token::Equal,
+            '-' => Token::Minus,
+            ',' => Token::Comma,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '!' => match chars.next() {
+                Some((_, '=')) => Token::NotEqual,
+                _ => return Err(Error::new(position, "expected `!=`")),
+            },
+            '"' | '\'' => {
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, c)) => s.push(c),
+                            None => return Err(Error::new(position, "unterminated string")),
+                        },
+                        Some((_, end)) if end == c => break,
+                        Some((_, c)) => s.push(c),
+                        None => return Err(Error::new(position, "unterminated string")),
+                    }
+                }
+                Token::Str(s)
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let mut ident = c.to_string();
+                while let Some(&(_, c)) = chars.peek() {
+                    if !c.is_ascii_alphanumeric() && c != '_' {
+                        break;
+                    }
+                    ident.push(c);
+                    chars.next();
+                }
+                Token::Ident(ident)
+            }
+            c => return Err(Error::new(position, format!("unexpected `{}`", c))),
+        };
+        tokens.push((position, token));
+    }
+    Ok(tokens)
+}
+
+/// A parsed subscription filter.
+///
+/// The `Display` implementation formats the filter in the language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Filter {
+    /// `attributes:key`
+    Has(String),
+    /// `attributes.key = "value"`
+    Equal(String, String),
+    /// `hasPrefix(attributes.key, "prefix")`
+    HasPrefix(String, String),
+    /// `NOT filter`, `-filter` or `attributes.key != "value"`.
+    Not(Box<Filter>),
+    /// `filter AND filter ...`
+    And(Vec<Filter>),
+    /// `filter OR filter ...`
+    Or(Vec<Filter>),
+}
+
+impl Filter {
+    /// Parses a filter, which is `None` if empty.
+    pub fn parse(input: &str) -> Result<Option<Self>, Error> {
+        if input.len() > MAX_LENGTH {
+            return Err(Error::new(
+                MAX_LENGTH,
+                format!("filter longer than {} bytes", MAX_LENGTH),
+            ));
+        }
+        let mut parser = Parser {
+            tokens: tokenize(input)?,
+            index: 0,
+            end: input.len(),
+        };
+        if parser.tokens.is_empty() {
+            return Ok(None);
+        }
+        let filter = parser.expression()?;
+        match parser.tokens.get(parser.index) {
+            Some((position, token)) => {
+                Err(Error::new(*position, format!("unexpected `{}`", token)))
+            }
+            None => Ok(Some(filter)),
+        }
+    }
+
+    /// Returns true if the message matches the filter.
+    pub fn matches(&self, message: &PubsubMessage) -> bool {
+        self.matches_attributes(&message.attributes)
+    }
+
+    /// Returns true if a message with `attributes` matches the filter.
+    pub fn matches_attributes(&self, attributes: &HashMap<String, String>) -> bool {
+        match self {
+            Filter::Has(key) => attributes.contains_key(key),
+            Filter::Equal(key, value) => attributes.get(key) == Some(value),
+            Filter::HasPrefix(key, prefix) => match attributes.get(key) {
+                Some(value) => value.starts_with(prefix.as_str()),
+                None => false,
+            },
+            Filter::Not(filter) => !filter.matches_attributes(attributes),
+            Filter::And(filters) => filters.iter().all(|f| f.matches_attributes(attributes)),
+            Filter::Or(filters) => filters.iter().any(|f| f.matches_attributes(attributes)),
+        }
+    }
+}
+
+/// Checks the syntax of the `filter` of a subscription.
+pub fn validate(filter: &str) -> Result<(), Error> {
+    Filter::parse(filter).map(|_| ())
+}
+
+fn write_key(f: &mut fmt::Formatter<'_>, key: &str) -> fmt::Result {
+    let mut chars = key.chars();
+    let ident = match chars.next() {
+        Some(c) => {
+            (c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        None => false,
+    };
+    if ident {
+        f.write_str(key)
+    } else {
+        write_string(f, key)
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// Groups the operands of the operators in parentheses.
+fn write_operand(f: &mut fmt::Formatter<'_>, filter: &Filter) -> fmt::Result {
+    match filter {
+        Filter::And(_) | Filter::Or(_) => write!(f, "({})", filter),
+        _ => write!(f, "{}", filter),
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (filters, operator) = match self {
+            Filter::Has(key) => {
+                f.write_str("attributes:")?;
+                return write_key(f, key);
+            }
+            Filter::HasPrefix(key, prefix) => {
+                f.write_str("hasPrefix(attributes.")?;
+                write_key(f, key)?;
+                f.write_str(", ")?;
+                write_string(f, prefix)?;
+                return f.write_str(")");
+            }
+            Filter::Equal(key, value) => {
+                f.write_str("attributes.")?;
+                write_key(f, key)?;
+                f.write_str(" = ")?;
+                return write_string(f, value);
+            }
+            Filter::Not(filter) => {
+                if let Filter::Equal(key, value) = &**filter {
+                    f.write_str("attributes.")?;
+                    write_key(f, key)?;
+                    f.write_str(" != ")?;
+                    return write_string(f, value);
+                }
+                f.write_str("NOT ")?;
+                return write_operand(f, filter);
+            }
+            Filter::And(filters) => (filters, " AND "),
+            Filter::Or(filters) => (filters, " OR "),
+        };
+        for (i, filter) in filters.iter().enumerate() {
+            if i > 0 {
+                f.write_str(operator)?;
+            }
+            write_operand(f, filter)?;
+        }
+        Ok(())
+    }
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    index: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Result<(usize, Token), Error> {
+        let token = self
+            .tokens
+            .get(self.index)
+            .cloned()
+            .ok_or_else(|| Error::new(self.end, "unexpected end of filter"))?;
+        self.index += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), Error> {
+        let (position, token) = self.next()?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(Error::new(
+                position,
+                format!("expected `{}`, found `{}`", expected, token),
+            ))
+        }
+    }
+
+    fn operator(&self) -> Option<(usize, &str)> {
+        match self.tokens.get(self.index) {
+            Some((position, Token::Ident(ident))) if ident == "AND" || ident == "OR" => {
+                Some((*position, ident))
+            }
+            _ => None,
+        }
+    }
+
+    // AND and OR can't be mixed without parentheses.
+    fn expression(&mut self) -> Result<Filter, Error> {
+        let first = self.term()?;
+        let operator = match self.operator() {
+            Some((_, operator)) => operator.to_owned(),
+            None => return Ok(first),
+        };
+        let mut terms = vec![first];
+        while let Some((position, next)) = self.operator() {
+            if next != operator {
+                return Err(Error::new(
+                    position,
+                    "AND and OR must be grouped with parentheses",
+                ));
+            }
+            self.index += 1;
+            terms.push(self.term()?);
+        }
+        Ok(if operator == "AND" {
+            Filter::And(terms)
+        } else {
+            Filter::Or(terms)
+        })
+    }
+
+    fn term(&mut self) -> Result<Filter, Error> {
+        let (position, token) = self.next()?;
+        match token {
+            Token::Minus => Ok(Filter::Not(Box::new(self.term()?))),
+            Token::Ident(ident) if ident == "NOT" => Ok(Filter::Not(Box::new(self.term()?))),
+            Token::LParen => {
+                let filter = self.expression()?;
+                self.expect(Token::RParen)?;
+                Ok(filter)
+            }
+            Token::Ident(ident) if ident == "attributes" => {
+                let (position, token) = self.next()?;
+                match token {
+                    Token::Colon => Ok(Filter::Has(self.key()?)),
+                    Token::Dot => {
+                        let key = self.key()?;
+                        let negated = match self.next()? {
+                            (_, Token::Equal) => false,
+                            (_, Token::NotEqual) => true,
+                            (position, token) => {
+                                return Err(Error::new(
+                                    position,
+                                    format!("expected `=` or `!=`, found `{}`", token),
+                                ))
+                            }
+                        };
+                        let filter = Filter::Equal(key, self.string()?);
+                        if negated {
+                            Ok(Filter::Not(Box::new(filter)))
+                        } else {
+                            Ok(filter)
+                        }
+                    }
+                    token => Err(Error::new(
+                        position,
+                        format!("expected `.` or `:`, found `{}`", token),
+                    )),
+                }
+            }
+            Token::Ident(ident) if ident == "hasPrefix" => {
+                self.expect(Token::LParen)?;
+                self.expect(Token::Ident("attributes".into()))?;
+                self.expect(Token::Dot)?;
+                let key = self.key()?;
+                self.expect(Token::Comma)?;
+                let prefix = self.string()?;
+                self.expect(Token::RParen)?;
+                Ok(Filter::HasPrefix(key, prefix))
+            }
+            token => Err(Error::new(
+                position,
+                format!("expected a condition, found `{}`", token),
+            )),
+        }
+    }
+
+    fn key(&mut self) -> Result<String, Error> {
+        match self.next()? {
+            (_, Token::Ident(key)) | (_, Token::Str(key)) => Ok(key),
+            (position, token) => Err(Error::new(
+                position,
+                format!("expected an attribute key, found `{}`", token),
+            )),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        match self.next()? {
+            (_, Token::Str(s)) => Ok(s),
+            (position, token) => Err(Error::new(
+                position,
+                format!("expected a string, found `{}`", token),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Filter {
+        Filter::parse(input).unwrap().unwrap()
+    }
+
+    fn attributes(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let key = |s: &str| s.to_string();
+        assert_eq!(Filter::parse("  "), Ok(None));
+        assert_eq!(parse("attributes:a"), Filter::Has(key("a")));
+        assert_eq!(
+            parse(r#"attributes."my key" = "v""#),
+            Filter::Equal(key("my key"), key("v"))
+        );
+        assert_eq!(
+            parse(r#"attributes.a != 'v'"#),
+            Filter::Not(Box::new(Filter::Equal(key("a"), key("v"))))
+        );
+        assert_eq!(
+            parse(r#"hasPrefix(attributes.a, "p\"")"#),
+            Filter::HasPrefix(key("a"), key("p\""))
+        );
+        assert_eq!(
+            parse(r#"-attributes:a AND NOT (attributes:b OR attributes:c)"#),
+            Filter::And(vec![
+                Filter::Not(Box::new(Filter::Has(key("a")))),
+                Filter::Not(Box::new(Filter::Or(vec![
+                    Filter::Has(key("b")),
+                    Filter::Has(key("c")),
+                ]))),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = |input: &str| Filter::parse(input).unwrap_err();
+        assert_eq!(
+            error("attributes:a AND attributes:b OR attributes:c"),
+            Error::new(30, "AND and OR must be grouped with parentheses")
+        );
+        assert_eq!(error("attributes.a = b").position(), 15);
+        assert_eq!(
+            error("attributes.a ="),
+            Error::new(14, "unexpected end of filter")
+        );
+        assert_eq!(error("attributes:a)").message(), "unexpected `)`");
+        assert_eq!(
+            error(r#"attributes.a = "v"#).message(),
+            "unterminated string"
+        );
+        assert_eq!(
+            error("labels:a").message(),
+            "expected a condition, found `labels`"
+        );
+        assert_eq!(error("attributes:a & attributes:b").position(), 13);
+        assert_eq!(error(&"a".repeat(257)).position(), MAX_LENGTH);
+        assert!(validate("(attributes:a AND attributes:b) OR attributes:c").is_ok());
+    }
+
+    #[test]
+    fn test_matches() {
+        let filter = parse(
+            r#"attributes.region = "eu" AND (hasPrefix(attributes.type, "order.") OR attributes:all)"#,
+        );
+        assert!(
+            filter.matches_attributes(&attributes(&[("region", "eu"), ("type", "order.created")]))
+        );
+        assert!(filter.matches_attributes(&attributes(&[("region", "eu"), ("all", "")])));
+        assert!(!filter.matches_attributes(&attributes(&[("region", "eu"), ("type", "user")])));
+        assert!(!filter.matches(&PubsubMessage {
+            attributes: attributes(&[("region", "us"), ("all", "")]),
+            ..Default::default()
+        }));
+
+        // A missing attribute isn't equal to any value.
+        let filter = parse(r#"attributes.a != "v""#);
+        assert!(filter.matches_attributes(&attributes(&[])));
+        assert!(!filter.matches_attributes(&attributes(&[("a", "v")])));
+    }
+
+    #[test]
+    fn test_display() {
+        for input in &[
+            r#"attributes:a"#,
+            r#"attributes."a b" != "v\"\\""#,
+            r#"hasPrefix(attributes.a, "p")"#,
+            r#"NOT attributes:a AND (attributes.b = "1" OR NOT (attributes:c AND attributes:d))"#,
+        ] {
+            assert_eq!(parse(input).to_string(), *input);
+        }
+        assert_eq!(parse("-attributes:a").to_string(), "NOT attributes:a");
+    }
+}
@@ -0,0 +1,664 @@
+//! A publisher that batches messages and publishes them in order per ordering key.
+//!
+//! ```ignore
+//! use googapis::{
+//!     google::pubsub::v1::{publisher_client::PublisherClient, PubsubMessage},
+//!     pubsub::publisher::{BatchSettings, Publisher, PublisherSettings},
+//! };
+//!
+//! let publisher = Publisher::with_settings(
+//!     PublisherClient::new(channel),
+//!     "projects/my-project/topics/my-topic",
+//!     PublisherSettings::default().batch(BatchSettings::default().max_messages(500)),
+//! );
+//! let result = publisher
+//!     .publish(PubsubMessage {
+//!         data: b"hello".to_vec(),
+//!         ordering_key: "user-1".into(),
+//!         ..Default::default()
+//!     })
+//!     .await;
+//! let message_id = result.await?;
+//! publisher.flush().await;
+//! ```
+//!
+//! Messages are buffered per ordering key and sent in a batch when `max_messages` or `max_bytes`
+//! is reached, or `max_delay` after the first message of the batch. Messages without an ordering
+//! key are sent concurrently, while the batches of an ordering key are sent one at a time, in
+//! order. When a batch of an ordering key fails, the key is paused: its pending messages fail,
+//! and so do the next ones until [`Publisher::resume_publish`] is called.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    error, fmt,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use prost::Message;
+use tokio::sync::oneshot;
+use tonic::{Code, Status};
+
+use crate::flow_control::{FlowControlSettings, FlowController};
+use crate::google::pubsub::v1::{
+    publisher_client::PublisherClient, PublishRequest, PublishResponse, PubsubMessage,
+};
+
+/// An error publishing a message.
+#[derive(Debug)]
+pub enum Error {
+    /// The publish call failed.
+    Status(Status),
+    /// The ordering key is paused after a failure, see [`Publisher::resume_publish`].
+    Paused(String),
+    /// The flow control limits are exceeded, with [`LimitExceededBehavior::Error`].
+    ///
+    /// [`LimitExceededBehavior::Error`]: super::LimitExceededBehavior::Error
+    FlowControl,
+    /// The publisher was dropped before the message was published.
+    Cancelled,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Status(status) => write!(f, "publish failed: {}", status),
+            Error::Paused(key) => write!(f, "ordering key paused: {}", key),
+            Error::FlowControl => f.write_str("flow control limits exceeded"),
+            Error::Cancelled => f.write_str("publisher dropped"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Status(status) => Some(status),
+            _ => None,
+        }
+    }
+}
+
+/// When a batch of messages is sent.
+#[derive(Debug, Clone)]
+pub struct BatchSettings {
+    max_messages: usize,
+    max_bytes: usize,
+    max_delay: Duration,
+}
+
+impl Default for BatchSettings {
+    fn default() -> Self {
+        Self {
+            max_messages: 100,
+            max_bytes: 1_000_000,
+            max_delay: Duration::from_millis(10),
+        }
+    }
+}
+
+impl BatchSettings {
+    /// The number of messages of a batch, at most 1000.
+    pub fn max_messages(self, max_messages: usize) -> Self {
+        Self {
+            max_messages: max_messages.clamp(1, 1000),
+            ..self
+        }
+    }
+
+    /// The size of the messages of a batch, at most 10 MB.
+    pub fn max_bytes(self, max_bytes: usize) -> Self {
+        Self {
+            max_bytes: max_bytes.clamp(1, 10_000_000),
+            ..self
+        }
+    }
+
+    /// The delay after the first message of a batch before it is sent.
+    pub fn max_delay(self, max_delay: Duration) -> Self {
+        Self { max_delay, ..self }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PublisherSettings {
+    batch: BatchSettings,
+    flow_control: FlowControlSettings,
+}
+
+impl PublisherSettings {
+    pub fn batch(self, batch: BatchSettings) -> Self {
+        Self { batch, ..self }
+    }
+
+    pub fn flow_control(self, flow_control: FlowControlSettings) -> Self {
+        Self {
+            flow_control,
+            ..self
+        }
+    }
+}
+
+/// The message ID of a published message.
+#[derive(Debug)]
+pub struct PublishResult(oneshot::Receiver<Result<String, Error>>);
+
+impl PublishResult {
+    fn ready(result: Result<String, Error>) -> Self {
+        let (tx, rx) = oneshot::channel();
+        let _ = tx.send(result);
+        PublishResult(rx)
+    }
+}
+
+impl Future for PublishResult {
+    type Output = Result<String, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0)
+            .poll(cx)
+            .map(|result| result.unwrap_or(Err(Error::Cancelled)))
+    }
+}
+
+#[derive(Debug)]
+struct Pending {
+    message: PubsubMessage,
+    size: usize,
+    tx: oneshot::Sender<Result<String, Error>>,
+}
+
+#[derive(Debug, Default)]
+struct Key {
+    pending: Vec<Pending>,
+    pending_bytes: usize,
+    // The stamp of the pending batch, so that a timer only sends its own batch.
+    batch: u64,
+    // The batches waiting for the batch in flight, for an ordering key.
+    queued: VecDeque<Vec<Pending>>,
+    in_flight: bool,
+    paused: bool,
+}
+
+impl Key {
+    fn take(&mut self) -> Vec<Pending> {
+        self.pending_bytes = 0;
+        mem::take(&mut self.pending)
+    }
+
+    fn is_idle(&self) -> bool {
+        self.pending.is_empty() && self.queued.is_empty() && !self.in_flight && !self.paused
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    keys: HashMap<String, Key>,
+    // The last stamp of a batch, which is not reused when an idle key is removed and recreated.
+    last_batch: u64,
+    // The batches sent or queued but not completed.
+    batches: usize,
+    flush_waiters: Vec<oneshot::Sender<()>>,
+}
+
+#[derive(Debug)]
+struct Inner<T> {
+    client: PublisherClient<T>,
+    topic: String,
+    batch: BatchSettings,
+    flow: FlowController,
+    state: Mutex<State>,
+}
+
+/// Publishes messages to a topic in batches.
+///
+/// The publisher is cheap to clone, the clones share the batches.
+#[derive(Debug)]
+pub struct Publisher<T> {
+    inner: Arc<Inner<T>>,
+}
+
+impl<T> Clone for Publisher<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Publisher<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    pub fn new(client: PublisherClient<T>, topic: impl Into<String>) -> Self {
+        Self::with_settings(client, topic, PublisherSettings::default())
+    }
+
+    pub fn with_settings(
+        client: PublisherClient<T>,
+        topic: impl Into<String>,
+        settings: PublisherSettings,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                client,
+                topic: topic.into(),
+                batch: settings.batch,
+                flow: FlowController::new(settings.flow_control),
+                state: Mutex::new(State::default()),
+            }),
+        }
+    }
+
+    /// Adds `message` to a batch, waiting first for the flow control limits if needed.
+    ///
+    /// The returned [`PublishResult`] resolves to the message ID once the batch is published.
+    pub async fn publish(&self, message: PubsubMessage) -> PublishResult {
+        let key = message.ordering_key.clone();
+        if self.is_paused(&key) {
+            return PublishResult::ready(Err(Error::Paused(key)));
+        }
+        let size = message.encoded_len();
+        if !self.inner.flow.acquire(size).await {
+            return PublishResult::ready(Err(Error::FlowControl));
+        }
+
+        let (tx, rx) = oneshot::channel();
+        let mut state = self.inner.state.lock().unwrap();
+        let state = &mut *state;
+        let entry = state.keys.entry(key.clone()).or_default();
+        if entry.paused {
+            self.inner.flow.release(size);
+            let _ = tx.send(Err(Error::Paused(key)));
+            return PublishResult(rx);
+        }
+        if !entry.pending.is_empty() && entry.pending_bytes + size > self.inner.batch.max_bytes {
+            let batch = entry.take();
+            Inner::dispatch(&self.inner, state, &key, batch);
+        }
+
+        let entry = state.keys.get_mut(&key).unwrap();
+        entry.pending.push(Pending { message, size, tx });
+        entry.pending_bytes += size;
+        if entry.pending.len() >= self.inner.batch.max_messages
+            || entry.pending_bytes >= self.inner.batch.max_bytes
+        {
+            let batch = entry.take();
+            Inner::dispatch(&self.inner, state, &key, batch);
+        } else if entry.pending.len() == 1 {
+            state.last_batch += 1;
+            entry.batch = state.last_batch;
+            let (inner, batch) = (self.inner.clone(), entry.batch);
+            tokio::spawn(async move {
+                tokio::time::delay_for(inner.batch.max_delay).await;
+                let mut state = inner.state.lock().unwrap();
+                if let Some(entry) = state.keys.get_mut(&key) {
+                    if entry.batch == batch && !entry.pending.is_empty() {
+                        let batch = entry.take();
+                        Inner::dispatch(&inner, &mut state, &key, batch);
+                    }
+                }
+            });
+        }
+        PublishResult(rx)
+    }
+
+    /// Sends the pending batches and waits until all the batches are completed.
+    pub async fn flush(&self) {
+        let rx = {
+            let mut state = self.inner.state.lock().unwrap();
+            let state = &mut *state;
+            let keys = state
+                .keys
+                .iter()
+                .filter(|(_, entry)| !entry.pending.is_empty())
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>();
+            for key in keys {
+                let batch = state.keys.get_mut(&key).unwrap().take();
+                Inner::dispatch(&self.inner, state, &key, batch);
+            }
+            if state.batches == 0 {
+                return;
+            }
+            let (tx, rx) = oneshot::channel();
+            state.flush_waiters.push(tx);
+            rx
+        };
+        let _ = rx.await;
+    }
+
+    /// Returns whether `ordering_key` is paused after a failure.
+    pub fn is_paused(&self, ordering_key: &str) -> bool {
+        let state = self.inner.state.lock().unwrap();
+        state
+            .keys
+            .get(ordering_key)
+            .map(|entry| entry.paused)
+            .unwrap_or(false)
+    }
+
+    /// Accepts messages for `ordering_key` again after a failure.
+    pub fn resume_publish(&self, ordering_key: &str) {
+        let mut state = self.inner.state.lock().unwrap();
+        if let Some(entry) = state.keys.get_mut(ordering_key) {
+            entry.paused = false;
+            if entry.is_idle() {
+                state.keys.remove(ordering_key);
+            }
+        }
+    }
+}
+
+impl<T> Inner<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    // Sends `batch`, or queues it behind the batch in flight of its ordering key.
+    fn dispatch(this: &Arc<Self>, state: &mut State, key: &str, batch: Vec<Pending>) {
+        state.batches += 1;
+        if !key.is_empty() {
+            let entry = state.keys.get_mut(key).unwrap();
+            if entry.in_flight {
+                entry.queued.push_back(batch);
+                return;
+            }
+            entry.in_flight = true;
+        }
+        tokio::spawn(Self::send(this.clone(), key.to_owned(), batch));
+    }
+
+    // Sends `batch`, then the batches queued behind it for an ordering key.
+    async fn send(this: Arc<Self>, key: String, mut batch: Vec<Pending>) {
+        loop {
+            let (messages, waiters): (Vec<_>, Vec<_>) = batch
+                .into_iter()
+                .map(|p| (p.message, (p.size, p.tx)))
+                .unzip();
+            let request = PublishRequest {
+                topic: this.topic.clone(),
+                messages,
+            };
+            let result = this.client.clone().publish(request).await;
+            match this.complete(&key, waiters, result.map(|r| r.into_inner())) {
+                Some(next) => batch = next,
+                None => return,
+            }
+        }
+    }
+
+    // Completes the messages of a batch and returns the next batch of the ordering key.
+    fn complete(
+        &self,
+        key: &str,
+        waiters: Vec<(usize, oneshot::Sender<Result<String, Error>>)>,
+        result: Result<PublishResponse, Status>,
+    ) -> Option<Vec<Pending>> {
+        let failed = result.is_err();
+        match result {
+            Ok(response) => {
+                let mut ids = response.message_ids.into_iter();
+                for (size, tx) in waiters {
+                    self.flow.release(size);
+                    let _ = tx.send(ids.next().ok_or_else(|| {
+                        Error::Status(Status::new(Code::Internal, "missing message id"))
+                    }));
+                }
+            }
+            Err(status) => {
+                for (size, tx) in waiters {
+                    self.flow.release(size);
+                    let _ = tx.send(Err(Error::Status(status.clone())));
+                }
+            }
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        state.batches -= 1;
+        let mut next = None;
+        if let Some(entry) = state.keys.get_mut(key) {
+            entry.in_flight = false;
+            // Only ordering keys are paused, the messages without one are independent.
+            if failed && !key.is_empty() {
+                entry.paused = true;
+                let batches = mem::take(&mut entry.queued);
+                state.batches -= batches.len();
+                for pending in batches.into_iter().flatten().chain(entry.take()) {
+                    self.flow.release(pending.size);
+                    let _ = pending.tx.send(Err(Error::Paused(key.to_owned())));
+                }
+            } else if let Some(batch) = entry.queued.pop_front() {
+                entry.in_flight = true;
+                next = Some(batch);
+            }
+            if entry.is_idle() {
+                state.keys.remove(key);
+            }
+        }
+        if state.batches == 0 {
+            for tx in state.flush_waiters.drain(..) {
+                let _ = tx.send(());
+            }
+        }
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pubsub::{
+        fake::{FakePublisher, FakePublisherServer, FAILURE_DETAILS},
+        LimitExceededBehavior,
+    };
+
+    fn publisher(
+        fake: &Arc<FakePublisher>,
+        settings: PublisherSettings,
+    ) -> Publisher<FakePublisherServer> {
+        Publisher::with_settings(fake.client(), "projects/p/topics/t", settings)
+    }
+
+    fn message(data: &str, ordering_key: &str) -> PubsubMessage {
+        PubsubMessage {
+            data: data.as_bytes().to_vec(),
+            ordering_key: ordering_key.into(),
+            ..Default::default()
+        }
+    }
+
+    fn batch_sizes(fake: &FakePublisher) -> Vec<usize> {
+        fake.requests().iter().map(|r| r.messages.len()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_batch_max_messages() {
+        let fake = Arc::new(FakePublisher::default());
+        let settings = PublisherSettings::default().batch(
+            BatchSettings::default()
+                .max_messages(3)
+                .max_delay(Duration::from_secs(60)),
+        );
+        let publisher = publisher(&fake, settings);
+
+        let mut results = Vec::new();
+        for i in 0..7 {
+            results.push(publisher.publish(message(&i.to_string(), "")).await);
+        }
+        publisher.flush().await;
+
+        let mut ids = Vec::new();
+        for result in results {
+            ids.push(result.await.unwrap());
+        }
+        ids.sort();
+        assert_eq!(ids, (0..7).map(|i| i.to_string()).collect::<Vec<_>>());
+        let mut sizes = batch_sizes(&fake);
+        sizes.sort();
+        assert_eq!(sizes, vec![1, 3, 3]);
+        assert_eq!(fake.requests()[0].topic, "projects/p/topics/t");
+    }
+
+    #[tokio::test]
+    async fn test_batch_max_bytes_and_delay() {
+        let fake = Arc::new(FakePublisher::default());
+        let size = message("0123456789", "").encoded_len();
+        let settings = PublisherSettings::default().batch(
+            BatchSettings::default()
+                .max_bytes(size * 2 + 1)
+                .max_delay(Duration::from_millis(20)),
+        );
+        let publisher = publisher(&fake, settings);
+
+        let mut results = Vec::new();
+        for _ in 0..3 {
+            results.push(publisher.publish(message("0123456789", "")).await);
+        }
+        for result in results {
+            result.await.unwrap();
+        }
+        assert_eq!(batch_sizes(&fake), vec![2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_delay_of_recreated_key() {
+        let fake = Arc::new(FakePublisher::default());
+        let settings = PublisherSettings::default()
+            .batch(BatchSettings::default().max_delay(Duration::from_millis(200)));
+        let publisher = publisher(&fake, settings);
+
+        // The key is removed once idle, and the timer of its first batch must not send the
+        // batch of the recreated key.
+        let first = publisher.publish(message("a", "k")).await;
+        publisher.flush().await;
+        first.await.unwrap();
+        tokio::time::delay_for(Duration::from_millis(100)).await;
+        let second = publisher.publish(message("b", "k")).await;
+        tokio::time::delay_for(Duration::from_millis(150)).await;
+        assert_eq!(fake.requests().len(), 1);
+
+        second.await.unwrap();
+        assert_eq!(batch_sizes(&fake), vec![1, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_ordering_key_in_order() {
+        let fake = Arc::new(FakePublisher::default());
+        let settings = PublisherSettings::default().batch(BatchSettings::default().max_messages(2));
+        let publisher = publisher(&fake, settings);
+
+        let mut results = Vec::new();
+        for i in 0..9 {
+            results.push(publisher.publish(message(&i.to_string(), "k")).await);
+        }
+        for result in results {
+            result.await.unwrap();
+        }
+
+        let requests = fake.requests();
+        let data = requests
+            .iter()
+            .flat_map(|r| r.messages.iter().map(|m| m.data.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            data,
+            (0..9)
+                .map(|i| i.to_string().into_bytes())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ordering_key_paused_on_failure() {
+        let fake = Arc::new(FakePublisher::default());
+        fake.set_failing_key(Some("bad"));
+        let publisher = publisher(&fake, PublisherSettings::default());
+
+        let failed = publisher.publish(message("a", "bad")).await;
+        let other = publisher.publish(message("b", "good")).await;
+        match failed.await {
+            Err(Error::Status(status)) => assert_eq!(status.code(), Code::Unavailable),
+            r => panic!("unexpected result: {:?}", r),
+        }
+        assert!(other.await.is_ok());
+
+        assert!(publisher.is_paused("bad"));
+        match publisher.publish(message("c", "bad")).await.await {
+            Err(Error::Paused(key)) => assert_eq!(key, "bad"),
+            r => panic!("unexpected result: {:?}", r),
+        }
+
+        fake.set_failing_key(None);
+        publisher.resume_publish("bad");
+        assert!(!publisher.is_paused("bad"));
+        assert!(publisher.publish(message("d", "bad")).await.await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_failure_without_ordering_key() {
+        let fake = Arc::new(FakePublisher::default());
+        fake.set_failing_key(Some(""));
+        let settings = PublisherSettings::default().batch(
+            BatchSettings::default()
+                .max_messages(2)
+                .max_delay(Duration::from_secs(60)),
+        );
+        let publisher = publisher(&fake, settings);
+
+        let failed = vec![
+            publisher.publish(message("a", "")).await,
+            publisher.publish(message("b", "")).await,
+        ];
+        let pending = publisher.publish(message("c", "")).await;
+        for result in failed {
+            match result.await {
+                Err(Error::Status(status)) => {
+                    assert_eq!(status.code(), Code::Unavailable);
+                    assert_eq!(status.details(), FAILURE_DETAILS);
+                }
+                r => panic!("unexpected result: {:?}", r),
+            }
+        }
+        assert!(!publisher.is_paused(""));
+
+        fake.set_failing_key(None);
+        let later = publisher.publish(message("d", "")).await;
+        assert!(pending.await.is_ok());
+        assert!(later.await.is_ok());
+        assert_eq!(batch_sizes(&fake), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn test_flow_control() {
+        let fake = Arc::new(FakePublisher::default());
+        let settings = PublisherSettings::default().flow_control(
+            FlowControlSettings::default()
+                .max_outstanding_messages(1)
+                .limit_exceeded_behavior(LimitExceededBehavior::Error),
+        );
+        let publisher = publisher(&fake, settings);
+
+        let first = publisher.publish(message("a", "")).await;
+        match publisher.publish(message("b", "")).await.await {
+            Err(Error::FlowControl) => {}
+            r => panic!("unexpected result: {:?}", r),
+        }
+        first.await.unwrap();
+        assert!(publisher.publish(message("c", "")).await.await.is_ok());
+    }
+}
@@ -0,0 +1,661 @@
+//! Receiving messages of push subscriptions.
+//!
+//! A push subscription POSTs each message to an endpoint as a JSON envelope, with an OIDC token
+//! of the service account set in its `PushConfig` as the bearer token. [`Receiver`] parses the
+//! envelope, verifies the token with a [`Verifier`], and maps the outcome of the handler to the
+//! HTTP status code to respond with, independently of the HTTP framework:
+//!
+//! ```ignore
+//! let jwks = Jwks::from_json(&fetch("https://www.googleapis.com/oauth2/v3/certs").await?)?;
+//! let receiver = Receiver::new().verifier(
+//!     Verifier::new(jwks, "https://example.com/push")
+//!         .service_account_email("push@project.iam.gserviceaccount.com"),
+//! );
+//!
+//! let status = receiver
+//!     .handle(authorization, &body, |request| async move {
+//!         println!("{:?}", request.message);
+//!         Outcome::Ack
+//!     })
+//!     .await;
+//! ```
+
+use std::{
+    collections::HashMap,
+    error, fmt,
+    future::Future,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use ring::signature::{RsaPublicKeyComponents, RSA_PKCS1_2048_8192_SHA256};
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{google::pubsub::v1::PubsubMessage, rfc3339::parse_timestamp};
+
+// The issuers of the tokens of Google accounts.
+const ISSUERS: [&str; 2] = ["https://accounts.google.com", "accounts.google.com"];
+
+/// An error receiving a push request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The request has no bearer token.
+    MissingToken,
+    /// The token is not a JWT.
+    MalformedToken(String),
+    /// The token is signed with an algorithm other than `RS256`.
+    UnsupportedAlgorithm(String),
+    /// No key of the JWKS has the key id of the token.
+    UnknownKey(String),
+    /// The signature of the token is invalid.
+    InvalidSignature,
+    /// A claim of the token is invalid, e.g. `exp` when the token is expired.
+    InvalidClaim(&'static str),
+    /// The JWKS is malformed.
+    InvalidJwks(String),
+    /// The push envelope is malformed.
+    InvalidEnvelope(String),
+}
+
+impl Error {
+    /// Returns the HTTP status code to respond with.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Error::MissingToken => 401,
+            Error::InvalidEnvelope(_) => 400,
+            Error::InvalidJwks(_) => 500,
+            _ => 403,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingToken => f.write_str("missing bearer token"),
+            Error::MalformedToken(msg) => write!(f, "malformed token: {}", msg),
+            Error::UnsupportedAlgorithm(alg) => write!(f, "unsupported algorithm: {}", alg),
+            Error::UnknownKey(kid) => write!(f, "unknown key id: {}", kid),
+            Error::InvalidSignature => f.write_str("invalid signature"),
+            Error::InvalidClaim(claim) => write!(f, "invalid {} claim", claim),
+            Error::InvalidJwks(msg) => write!(f, "invalid jwks: {}", msg),
+            Error::InvalidEnvelope(msg) => write!(f, "invalid push envelope: {}", msg),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RsaKey {
+    n: Vec<u8>,
+    e: Vec<u8>,
+}
+
+#[derive(Deserialize)]
+struct JwkSet {
+    keys: Vec<Jwk>,
+}
+
+#[derive(Deserialize)]
+struct Jwk {
+    kty: String,
+    #[serde(default)]
+    kid: String,
+    alg: Option<String>,
+    n: Option<String>,
+    e: Option<String>,
+}
+
+/// The RSA public keys of a JSON Web Key Set, e.g. of
+/// `https://www.googleapis.com/oauth2/v3/certs` for the tokens of Google accounts.
+///
+/// The keys are rotated, so the set should be fetched again periodically.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Jwks {
+    keys: HashMap<String, RsaKey>,
+}
+
+impl Jwks {
+    /// Parses a JWKS, ignoring the keys that can't verify `RS256` signatures.
+    pub fn from_json(json: &[u8]) -> Result<Self, Error> {
+        let set: JwkSet =
+            serde_json::from_slice(json).map_err(|e| Error::InvalidJwks(e.to_string()))?;
+        let mut keys = HashMap::new();
+        for jwk in set.keys {
+            if jwk.kty != "RSA" || jwk.alg.as_deref().unwrap_or("RS256") != "RS256" {
+                continue;
+            }
+            let (n, e) = match (&jwk.n, &jwk.e) {
+                (Some(n), Some(e)) => (n, e),
+                _ => return Err(Error::InvalidJwks(format!("incomplete key: {}", jwk.kid))),
+            };
+            let decode = |value: &str| {
+                base64::decode_config(value, base64::URL_SAFE_NO_PAD)
+                    .map_err(|_| Error::InvalidJwks(format!("invalid key: {}", jwk.kid)))
+            };
+            let key = RsaKey {
+                n: decode(n)?,
+                e: decode(e)?,
+            };
+            keys.insert(jwk.kid, key);
+        }
+        Ok(Self { keys })
+    }
+
+    /// Returns true if no key can verify `RS256` signatures.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+#[derive(Deserialize)]
+struct Header {
+    alg: String,
+    #[serde(default)]
+    kid: String,
+}
+
+/// The claims of a verified OIDC token.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Claims {
+    pub iss: String,
+    pub aud: String,
+    pub sub: String,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: bool,
+    pub iat: u64,
+    pub exp: u64,
+}
+
+/// Verifies the OIDC tokens of push requests.
+#[derive(Debug, Clone)]
+pub struct Verifier {
+    jwks: Jwks,
+    audience: String,
+    service_account_email: Option<String>,
+    leeway: Duration,
+}
+
+impl Verifier {
+    /// Creates a verifier of the tokens signed by a key of `jwks` for `audience`, i.e. the
+    /// audience of the `OidcToken` of the subscription, which defaults to the push endpoint.
+    pub fn new(jwks: Jwks, audience: impl Into<String>) -> Self {
+        Self {
+            jwks,
+            audience: audience.into(),
+            service_account_email: None,
+            leeway: Duration::from_secs(60),
+        }
+    }
+
+    /// Requires the token to be of the service account of the `OidcToken` of the subscription.
+    pub fn service_account_email(self, email: impl Into<String>) -> Self {
+        Self {
+            service_account_email: Some(email.into()),
+            ..self
+        }
+    }
+
+    /// Sets the clock skew allowed checking `exp` and `iat`, 60 seconds by default.
+    pub fn leeway(self, leeway: Duration) -> Self {
+        Self { leeway, ..self }
+    }
+
+    /// Verifies the value of an `Authorization` header, i.e. `Bearer <token>`.
+    pub fn verify_authorization(&self, authorization: Option<&str>) -> Result<Claims, Error> {
+        let token = authorization
+            .and_then(|value| {
+                let (scheme, token) = value.split_at(value.find(' ')?);
+                if scheme.eq_ignore_ascii_case("bearer") {
+                    Some(token.trim())
+                } else {
+                    None
+                }
+            })
+            .filter(|token| !token.is_empty())
+            .ok_or(Error::MissingToken)?;
+        self.verify(token)
+    }
+
+    /// Verifies a token at the current time.
+    pub fn verify(&self, token: &str) -> Result<Claims, Error> {
+        self.verify_at(token, SystemTime::now())
+    }
+
+    /// Verifies a token at `now`.
+    pub fn verify_at(&self, token: &str, now: SystemTime) -> Result<Claims, Error> {
+        let segments: Vec<&str> = token.split('.').collect();
+        let (header, payload, signature) = match segments[..] {
+            [header, payload, signature] => (header, payload, signature),
+            _ => return Err(Error::MalformedToken("expected 3 segments".into())),
+        };
+
+        let Header { alg, kid } = decode_segment(header)?;
+        if alg != "RS256" {
+            return Err(Error::UnsupportedAlgorithm(alg));
+        }
+        let key = self.jwks.keys.get(&kid).ok_or(Error::UnknownKey(kid))?;
+        let signature = base64::decode_config(signature, base64::URL_SAFE_NO_PAD)
+            .map_err(|e| Error::MalformedToken(e.to_string()))?;
+        let message = &token[..header.len() + 1 + payload.len()];
+        RsaPublicKeyComponents {
+            n: &key.n,
+            e: &key.e,
+        }
+        .verify(&RSA_PKCS1_2048_8192_SHA256, message.as_bytes(), &signature)
+        .map_err(|_| Error::InvalidSignature)?;
+
+        let claims: Claims = decode_segment(payload)?;
+        self.check(&claims, now)?;
+        Ok(claims)
+    }
+
+    fn check(&self, claims: &Claims, now: SystemTime) -> Result<(), Error> {
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let leeway = self.leeway.as_secs();
+        if !ISSUERS.contains(&claims.iss.as_str()) {
+            return Err(Error::InvalidClaim("iss"));
+        }
+        if claims.aud != self.audience {
+            return Err(Error::InvalidClaim("aud"));
+        }
+        if claims.exp.saturating_add(leeway) <= now {
+            return Err(Error::InvalidClaim("exp"));
+        }
+        if claims.iat > now.saturating_add(leeway) {
+            return Err(Error::InvalidClaim("iat"));
+        }
+        if let Some(email) = &self.service_account_email {
+            if claims.email.as_ref() != Some(email) || !claims.email_verified {
+                return Err(Error::InvalidClaim("email"));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn decode_segment<T: DeserializeOwned>(segment: &str) -> Result<T, Error> {
+    let json = base64::decode_config(segment, base64::URL_SAFE_NO_PAD)
+        .map_err(|e| Error::MalformedToken(e.to_string()))?;
+    serde_json::from_slice(&json).map_err(|e| Error::MalformedToken(e.to_string()))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Envelope {
+    message: EnvelopeMessage,
+    subscription: String,
+    delivery_attempt: Option<i32>,
+}
+
+// The message has both the camel case and the snake case names of some fields, which serde
+// rejects as duplicates when they are aliases.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EnvelopeMessage {
+    data: Option<String>,
+    attributes: Option<HashMap<String, String>>,
+    message_id: Option<String>,
+    #[serde(rename = "message_id")]
+    message_id_snake: Option<String>,
+    publish_time: Option<String>,
+    #[serde(rename = "publish_time")]
+    publish_time_snake: Option<String>,
+    ordering_key: Option<String>,
+}
+
+/// A message pushed to the endpoint of a push subscription.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PushRequest {
+    pub message: PubsubMessage,
+    /// The name of the subscription, e.g. `projects/my-project/subscriptions/my-subscription`.
+    pub subscription: String,
+    /// The delivery attempt, only set if the subscription has a dead letter policy.
+    pub delivery_attempt: Option<i32>,
+}
+
+impl PushRequest {
+    /// Parses the JSON envelope of a push request.
+    pub fn from_json(body: &[u8]) -> Result<Self, Error> {
+        let invalid = |msg: &str| Error::InvalidEnvelope(msg.into());
+        let envelope: Envelope =
+            serde_json::from_slice(body).map_err(|e| Error::InvalidEnvelope(e.to_string()))?;
+        let message = envelope.message;
+
+        let data = match message.data {
+            Some(data) => base64::decode(&data).map_err(|_| invalid("invalid data"))?,
+            None => Vec::new(),
+        };
+        let publish_time = match message.publish_time.or(message.publish_time_snake) {
+            Some(time) => {
+                Some(parse_timestamp(&time).ok_or_else(|| invalid("invalid publish time"))?)
+            }
+            None => None,
+        };
+        Ok(Self {
+            message: PubsubMessage {
+                data,
+                attributes: message.attributes.unwrap_or_default(),
+                message_id: message
+                    .message_id
+                    .or(message.message_id_snake)
+                    .unwrap_or_default(),
+                publish_time,
+                ordering_key: message.ordering_key.unwrap_or_default(),
+            },
+            subscription: envelope.subscription,
+            delivery_attempt: envelope.delivery_attempt,
+        })
+    }
+}
+
+/// The outcome of handling a push request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// Acknowledges the message.
+    Ack,
+    /// Negatively acknowledges the message, which is redelivered with backoff.
+    Nack,
+}
+
+impl Outcome {
+    /// Returns the HTTP status code to respond with.
+    pub fn status_code(self) -> u16 {
+        match self {
+            Outcome::Ack => 204,
+            Outcome::Nack => 503,
+        }
+    }
+}
+
+impl<E> From<Result<(), E>> for Outcome {
+    fn from(result: Result<(), E>) -> Self {
+        match result {
+            Ok(()) => Outcome::Ack,
+            Err(_) => Outcome::Nack,
+        }
+    }
+}
+
+/// Receives the requests of a push subscription.
+#[derive(Debug, Clone, Default)]
+pub struct Receiver {
+    verifier: Option<Verifier>,
+}
+
+impl Receiver {
+    /// Creates a receiver of unauthenticated requests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Verifies the token of the requests.
+    pub fn verifier(self, verifier: Verifier) -> Self {
+        Self {
+            verifier: Some(verifier),
+        }
+    }
+
+    /// Verifies and parses a request, given the value of its `Authorization` header and its body.
+    pub fn parse(&self, authorization: Option<&str>, body: &[u8]) -> Result<PushRequest, Error> {
+        if let Some(verifier) = &self.verifier {
+            verifier.verify_authorization(authorization)?;
+        }
+        PushRequest::from_json(body)
+    }
+
+    /// Handles a request with `handler`, and returns the HTTP status code to respond with.
+    pub async fn handle<F, Fut>(&self, authorization: Option<&str>, body: &[u8], handler: F) -> u16
+    where
+        F: FnOnce(PushRequest) -> Fut,
+        Fut: Future<Output = Outcome>,
+    {
+        match self.parse(authorization, body) {
+            Ok(request) => handler(request).await.status_code(),
+            Err(e) => e.status_code(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost_types::Timestamp;
+    use ring::{rand::SystemRandom, signature::RsaKeyPair};
+
+    // A 2048-bit RSA key in PKCS #8, only used to sign test tokens.
+    const PRIVATE_KEY: &str = "
+MIIEvAIBADANBgkqhkiG9w0BAQEFAASCBKYwggSiAgEAAoIBAQCN83aiQCGl4/Uv7D+2HjKkCBZeyGFX8ddNpUTQXV3q3iyI
+yZupO9VwqOMDsKBinrR6e6r57nnuES0se6dbPEnPUEZD4Zkq9OJqg6G3jt5HDPk6JYZgvM3yWgHhQc0bWU2NruyHhmzNKFxk
+K/4FYZsZKFRTjPARPhX1PrF7scyRv6sbQe4Nj1dOK/97TBqpzaLP4VUTAcBugiv8P7INrz5QXIfv3NveN5l9D70bhR9fnjoQ
+7oT7RfGjVKorjClhuCEYZeYERU0Y7ZKDgwMtgnbZ5P6job1jyVKrk37wggGeMtkVz/IbZp7amCUL5AkKg34oAoBv6eRhdIx1
+evy2gB2PAgMBAAECggEAQZ40zeXlaAELe16zSOWGtUrDxIbOcM/rngny2HsAiiyv23+OxFlTXiPD5RXF3jDM7v2x2J0Doigw
+gKqcf1FMMVhDUue7XR8cdu5oWCjj2WZG4zkreBZvAUPYMJ+xR54tenxM/I0ISIIKy4eGgkhPYP7ClGjHJMFVdyvG8Y4MKacH
+DW1tGLOxhD8zewTyeaZhhRNmA1MJ/uLWpc/yyxcBPDW4bG8M571OmrPHrywprVV9j0KeGBuBKflqpKUpIIcYAI+CsFUvSLI8
+RVDShIVNdBOCMId93rpJYvzm8dxdcSXH4sWrdDY6ZNYizdWrkUfYVoThjhGnV/YZKmXYojQ/MQKBgQDDpREhbz3QF933U3Ox
+Duq1E2shxm+18gZeOVEwnmbUFMVYcic2hYwvY9IN2ilzk3k76V6n8O5K63NA18I0BSCm/g+fTKbMDlNkD/9xHNdjyftgZTm+
+pq7jv3jpoRmjHO9I9sdUzBtpTd/HLSU/TTk30YS3yGf69NL9E8FdEW/SuQKBgQC5vfaBTw6XjASaAUIJTRHEU35Yfa7NiQrE
+0vTsNAGS6RDfPwwJy8+Nn5I3r1BbhBaAZEis9fsPRwogv2eD3Fcu3WgNrfHX9AB/Sj4AS6tagXea8qcMIWXXnlEjeHb8SruO
+W+EOmDsBRHupx2KCDnOBtvDGJ0pMHAdrDvR0M6DuhwKBgDDYQ0Cfr0UG0GnxMc8gtERlBrg1/KnmNOTNVpHW2kFUtjhwt42L
+Jgam20iOEQ9D4F6u0u0G39dglTO5KkkmhpJql6fj0Tjc0sEGpXWuB7BoOxRD8XjB0KQXp65chDQPA0aqJfXrx2QxBw+JG+WD
++CcRbhscM6mZ+hspFXuQ4OQxAoGAZfKJEVJWHQICKJFWMIq3nL2DFIjvX+q/GWb8fYTR9FOuKgqNzfaq1f3moF++SGzIePIS
+HbTpduoz+eN0qqBOze9CRHfbzP8lIriaLUlbJNL41fgnjfXq0x0XRdPB4xoVtb5iWcNeZ4SyJ27Dd8T8yw6hOvGnyvx7adyM
+ehc4VpMCgYASGmPQWatdDAzWc3sdmKmTArnHqzj5jAFTlWjtCf1GM2NVspVQSyrv0BZIST3zozekm134O4MshDkc90KoAg7o
+gjlyGzuik0XP5DdvDDIl9yOlxbKwyxxeZBVH3T8v10CB3761CMJ8FfBM08FzxmsAFy3NunP/gygHzvtZ/5Djyw==
+";
+    const MODULUS: &str = "\
+jfN2okAhpeP1L-w_th4ypAgWXshhV_HXTaVE0F1d6t4siMmbqTvVcKjjA7CgYp60enuq-e557hEtLHunWzxJz1BG\
+Q-GZKvTiaoOht47eRwz5OiWGYLzN8loB4UHNG1lNja7sh4ZszShcZCv-BWGbGShUU4zwET4V9T6xe7HMkb-rG0Hu\
+DY9XTiv_e0waqc2iz-FVEwHAboIr_D-yDa8-UFyH79zb3jeZfQ-9G4UfX546EO6E-0Xxo1SqK4wpYbghGGXmBEVN\
+GO2Sg4MDLYJ22eT-o6G9Y8lSq5N-8IIBnjLZFc_yG2ae2pglC-QJCoN-KAKAb-nkYXSMdXr8toAdjw";
+
+    const AUDIENCE: &str = "https://example.com/push";
+    const EMAIL: &str = "push@project.iam.gserviceaccount.com";
+    const NOW: u64 = 1_589_090_701;
+
+    fn encode(json: &str) -> String {
+        base64::encode_config(json, base64::URL_SAFE_NO_PAD)
+    }
+
+    fn sign(header: &str, claims: &str) -> String {
+        let der = base64::decode(PRIVATE_KEY.replace('\n', "")).unwrap();
+        let key_pair = RsaKeyPair::from_pkcs8(&der).unwrap();
+        let message = format!("{}.{}", encode(header), encode(claims));
+        let mut signature = vec![0; key_pair.public_modulus_len()];
+        key_pair
+            .sign(
+                &ring::signature::RSA_PKCS1_SHA256,
+                &SystemRandom::new(),
+                message.as_bytes(),
+                &mut signature,
+            )
+            .unwrap();
+        format!(
+            "{}.{}",
+            message,
+            base64::encode_config(&signature, base64::URL_SAFE_NO_PAD)
+        )
+    }
+
+    fn payload(aud: &str, email: &str, exp: u64) -> String {
+        format!(
+            r#"{{"iss":"https://accounts.google.com","aud":"{}","sub":"1234","email":"{}","email_verified":true,"iat":{},"exp":{}}}"#,
+            aud,
+            email,
+            exp - 3600,
+            exp
+        )
+    }
+
+    fn verifier() -> Verifier {
+        let jwks = format!(
+            r#"{{"keys":[
+                {{"kty":"EC","kid":"ec","crv":"P-256","x":"","y":""}},
+                {{"kty":"RSA","alg":"RS256","use":"sig","kid":"k1","n":"{}","e":"AQAB"}}
+            ]}}"#,
+            MODULUS
+        );
+        Verifier::new(Jwks::from_json(jwks.as_bytes()).unwrap(), AUDIENCE)
+            .service_account_email(EMAIL)
+    }
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    const HEADER: &str = r#"{"alg":"RS256","kid":"k1","typ":"JWT"}"#;
+
+    #[test]
+    fn test_verify() {
+        let verifier = verifier();
+        let token = sign(HEADER, &payload(AUDIENCE, EMAIL, NOW + 600));
+        let claims = verifier.verify_at(&token, at(NOW)).unwrap();
+        assert_eq!(claims.email.as_deref(), Some(EMAIL));
+        assert_eq!(claims.sub, "1234");
+
+        // Within the leeway.
+        assert!(verifier.verify_at(&token, at(NOW + 650)).is_ok());
+        assert_eq!(
+            verifier.verify_at(&token, at(NOW + 660)),
+            Err(Error::InvalidClaim("exp"))
+        );
+        assert_eq!(
+            verifier.verify_at(&token, at(NOW - 3600 - 61)),
+            Err(Error::InvalidClaim("iat"))
+        );
+
+        let token = sign(
+            HEADER,
+            &payload("https://example.com/other", EMAIL, NOW + 600),
+        );
+        assert_eq!(
+            verifier.verify_at(&token, at(NOW)),
+            Err(Error::InvalidClaim("aud"))
+        );
+        let token = sign(HEADER, &payload(AUDIENCE, "other@example.com", NOW + 600));
+        assert_eq!(
+            verifier.verify_at(&token, at(NOW)),
+            Err(Error::InvalidClaim("email"))
+        );
+    }
+
+    #[test]
+    fn test_verify_invalid_token() {
+        let verifier = verifier();
+        let token = sign(HEADER, &payload(AUDIENCE, EMAIL, NOW + 600));
+
+        let segments: Vec<&str> = token.split('.').collect();
+        let tampered = format!(
+            "{}.{}.{}",
+            segments[0],
+            encode(&payload(AUDIENCE, EMAIL, NOW + 7200)),
+            segments[2]
+        );
+        assert_eq!(
+            verifier.verify_at(&tampered, at(NOW)),
+            Err(Error::InvalidSignature)
+        );
+
+        let token = sign(
+            r#"{"alg":"RS256","kid":"k2"}"#,
+            &payload(AUDIENCE, EMAIL, NOW + 600),
+        );
+        assert_eq!(
+            verifier.verify_at(&token, at(NOW)),
+            Err(Error::UnknownKey("k2".into()))
+        );
+        let token = format!("{}.{}.", encode(r#"{"alg":"none"}"#), encode("{}"));
+        assert_eq!(
+            verifier.verify_at(&token, at(NOW)),
+            Err(Error::UnsupportedAlgorithm("none".into()))
+        );
+        assert!(matches!(
+            verifier.verify_at("abc", at(NOW)),
+            Err(Error::MalformedToken(_))
+        ));
+        assert_eq!(
+            verifier.verify_authorization(Some("Basic abc")),
+            Err(Error::MissingToken)
+        );
+        assert_eq!(
+            verifier.verify_authorization(None),
+            Err(Error::MissingToken)
+        );
+    }
+
+    #[test]
+    fn test_push_request() {
+        let body = br#"{
+            "message": {
+                "attributes": {"key": "value"},
+                "data": "SGVsbG8gQ2xvdWQgUHViL1N1YiE=",
+                "messageId": "2070443601311540",
+                "message_id": "2070443601311540",
+                "publishTime": "2021-02-26T19:13:55.749Z",
+                "publish_time": "2021-02-26T19:13:55.749Z"
+            },
+            "subscription": "projects/myproject/subscriptions/mysubscription",
+            "deliveryAttempt": 2
+        }"#;
+        let request = PushRequest::from_json(body).unwrap();
+        assert_eq!(request.message.data, b"Hello Cloud Pub/Sub!");
+        assert_eq!(request.message.attributes["key"], "value");
+        assert_eq!(request.message.message_id, "2070443601311540");
+        assert_eq!(
+            request.message.publish_time,
+            Some(Timestamp {
+                seconds: 1_614_366_835,
+                nanos: 749_000_000
+            })
+        );
+        assert_eq!(
+            request.subscription,
+            "projects/myproject/subscriptions/mysubscription"
+        );
+        assert_eq!(request.delivery_attempt, Some(2));
+
+        let body = br#"{"message": {"messageId": "1", "data": "!"}, "subscription": "s"}"#;
+        assert_eq!(
+            PushRequest::from_json(body),
+            Err(Error::InvalidEnvelope("invalid data".into()))
+        );
+        assert!(PushRequest::from_json(b"{}").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_handle() {
+        let receiver = Receiver::new().verifier(verifier());
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let token = sign(HEADER, &payload(AUDIENCE, EMAIL, now.as_secs() + 600));
+        let authorization = format!("Bearer {}", token);
+        let body = br#"{"message": {"data": "YQ==", "messageId": "1"}, "subscription": "s"}"#;
+
+        let status = receiver
+            .handle(Some(&authorization), body, |request| async move {
+                assert_eq!(request.message.data, b"a");
+                Outcome::Ack
+            })
+            .await;
+        assert_eq!(status, 204);
+        let status = receiver
+            .handle(Some(&authorization), body, |_| async {
+                Err::<(), _>("failed").into()
+            })
+            .await;
+        assert_eq!(status, 503);
+
+        let status = receiver
+            .handle(None, body, |_| async { Outcome::Ack })
+            .await;
+        assert_eq!(status, 401);
+        let status = receiver
+            .handle(Some("Bearer abc"), body, |_| async { Outcome::Ack })
+            .await;
+        assert_eq!(status, 403);
+        let status = receiver
+            .handle(Some(&authorization), b"{}", |_| async { Outcome::Ack })
+            .await;
+        assert_eq!(status, 400);
+    }
+}
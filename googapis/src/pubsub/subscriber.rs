@@ -0,0 +1,665 @@
+//! A subscriber that receives messages over streaming pulls and manages their leases.
+//!
+//! ```ignore
+//! use googapis::{
+//!     google::pubsub::v1::subscriber_client::SubscriberClient,
+//!     pubsub::subscriber::{Subscriber, SubscriberSettings},
+//! };
+//!
+//! let subscriber = Subscriber::new(
+//!     SubscriberClient::new(channel),
+//!     "projects/my-project/subscriptions/my-subscription",
+//! )
+//! .settings(SubscriberSettings::default().max_outstanding_messages(100));
+//! let status = subscriber
+//!     .receive(|message| async move {
+//!         println!("{:?}", message.data);
+//!         message.ack();
+//!     })
+//!     .await;
+//! ```
+//!
+//! Each message is handled in its own task, and must be acked or nacked. Until then, its ack
+//! deadline is extended to the 99th percentile of the observed processing times, for up to
+//! `max_extension`. New messages are not read from the streams while the outstanding messages
+//! exceed `max_outstanding_messages` or `max_outstanding_bytes`. Streams that fail with a
+//! retryable error are reopened with backoff.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    ops::Deref,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use futures::future::{self, Either};
+use prost::Message as _;
+use tokio::sync::mpsc;
+use tonic::{Code, Status};
+
+use crate::flow_control::{FlowControlSettings, FlowController};
+use crate::google::pubsub::v1::{
+    subscriber_client::SubscriberClient, AcknowledgeRequest, ModifyAckDeadlineRequest,
+    PubsubMessage, StreamingPullRequest,
+};
+
+// The bounds of the ack deadline accepted by the service.
+const MIN_ACK_DEADLINE: Duration = Duration::from_secs(10);
+const MAX_ACK_DEADLINE: Duration = Duration::from_secs(600);
+
+// The interval of the acknowledge and modify ack deadline calls.
+const ACK_INTERVAL: Duration = Duration::from_millis(100);
+
+// The maximum number of ack ids of an acknowledge or modify ack deadline call.
+const MAX_ACK_IDS: usize = 2500;
+
+const MIN_BACKOFF: Duration = Duration::from_millis(100);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub struct SubscriberSettings {
+    streams: usize,
+    max_outstanding_messages: Option<usize>,
+    max_outstanding_bytes: Option<usize>,
+    min_ack_deadline: Duration,
+    max_ack_deadline: Duration,
+    max_extension: Duration,
+}
+
+impl Default for SubscriberSettings {
+    fn default() -> Self {
+        Self {
+            streams: 1,
+            max_outstanding_messages: Some(1000),
+            max_outstanding_bytes: Some(1_000_000_000),
+            min_ack_deadline: MIN_ACK_DEADLINE,
+            max_ack_deadline: MAX_ACK_DEADLINE,
+            max_extension: Duration::from_secs(3600),
+        }
+    }
+}
+
+impl SubscriberSettings {
+    /// The number of streaming pulls opened concurrently.
+    pub fn streams(self, streams: usize) -> Self {
+        Self {
+            streams: streams.max(1),
+            ..self
+        }
+    }
+
+    pub fn max_outstanding_messages(self, max: impl Into<Option<usize>>) -> Self {
+        Self {
+            max_outstanding_messages: max.into(),
+            ..self
+        }
+    }
+
+    pub fn max_outstanding_bytes(self, max: impl Into<Option<usize>>) -> Self {
+        Self {
+            max_outstanding_bytes: max.into(),
+            ..self
+        }
+    }
+
+    /// The lower bound of the ack deadline, at least 10 seconds.
+    pub fn min_ack_deadline(self, deadline: Duration) -> Self {
+        Self {
+            min_ack_deadline: deadline.clamp(MIN_ACK_DEADLINE, MAX_ACK_DEADLINE),
+            ..self
+        }
+    }
+
+    /// The upper bound of the ack deadline, at most 600 seconds.
+    pub fn max_ack_deadline(self, deadline: Duration) -> Self {
+        Self {
+            max_ack_deadline: deadline.clamp(MIN_ACK_DEADLINE, MAX_ACK_DEADLINE),
+            ..self
+        }
+    }
+
+    /// The duration after which the ack deadline of a message is no longer extended.
+    pub fn max_extension(self, max_extension: Duration) -> Self {
+        Self {
+            max_extension,
+            ..self
+        }
+    }
+}
+
+// The distribution of the processing times in seconds.
+#[derive(Debug)]
+struct Histogram {
+    buckets: Vec<u64>,
+    count: u64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: vec![0; MAX_ACK_DEADLINE.as_secs() as usize + 1],
+            count: 0,
+        }
+    }
+}
+
+impl Histogram {
+    fn record(&mut self, d: Duration) {
+        let secs = d.as_secs() + u64::from(d.subsec_nanos() > 0);
+        let i = (secs as usize).min(self.buckets.len() - 1);
+        self.buckets[i] += 1;
+        self.count += 1;
+    }
+
+    fn percentile(&self, p: f64) -> Option<Duration> {
+        if self.count == 0 {
+            return None;
+        }
+        let target = ((self.count as f64 * p).ceil() as u64).max(1);
+        let mut sum = 0;
+        for (secs, n) in self.buckets.iter().enumerate() {
+            sum += n;
+            if sum >= target {
+                return Some(Duration::from_secs(secs as u64));
+            }
+        }
+        None
+    }
+}
+
+#[derive(Debug, Default)]
+struct LeaseState {
+    // The messages not yet acked or nacked by ack id, with their receive time.
+    outstanding: HashMap<String, Instant>,
+    acks: Vec<String>,
+    nacks: Vec<String>,
+    // The messages received since the last modify ack deadline call.
+    receipts: Vec<String>,
+    histogram: Histogram,
+}
+
+// The leases of the messages received by a `Subscriber::receive` call.
+#[derive(Debug)]
+struct Lease {
+    settings: SubscriberSettings,
+    flow: FlowController,
+    state: Mutex<LeaseState>,
+}
+
+impl Lease {
+    fn new(settings: &SubscriberSettings) -> Self {
+        let flow = FlowControlSettings::default()
+            .max_outstanding_messages(settings.max_outstanding_messages)
+            .max_outstanding_bytes(settings.max_outstanding_bytes);
+        Self {
+            settings: settings.clone(),
+            flow: FlowController::new(flow),
+            state: Mutex::new(LeaseState::default()),
+        }
+    }
+
+    // Returns the 99th percentile of the processing times within the ack deadline bounds.
+    fn ack_deadline(&self, state: &LeaseState) -> Duration {
+        state
+            .histogram
+            .percentile(0.99)
+            .unwrap_or(self.settings.min_ack_deadline)
+            .max(self.settings.min_ack_deadline)
+            .min(self.settings.max_ack_deadline)
+    }
+
+    fn received(&self, ack_ids: impl Iterator<Item = String>) {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        for ack_id in ack_ids {
+            state.outstanding.insert(ack_id.clone(), now);
+            state.receipts.push(ack_id);
+        }
+    }
+
+    fn done(&self, ack_id: &str, size: usize, ack: bool) {
+        {
+            let mut state = self.state.lock().unwrap();
+            let received = state.outstanding.remove(ack_id);
+            if ack {
+                if let Some(received) = received {
+                    state.histogram.record(received.elapsed());
+                }
+                state.acks.push(ack_id.to_owned());
+            } else {
+                state.nacks.push(ack_id.to_owned());
+            }
+        }
+        self.flow.release(size);
+    }
+
+    // Returns the pending acks, nacks, receipts and the deadline of the receipts.
+    fn take(&self) -> (Vec<String>, Vec<String>, Vec<String>, Duration) {
+        let mut state = self.state.lock().unwrap();
+        let deadline = self.ack_deadline(&state);
+        (
+            state.acks.split_off(0),
+            state.nacks.split_off(0),
+            state.receipts.split_off(0),
+            deadline,
+        )
+    }
+
+    fn retry_acks(&self, ack_ids: &[String]) {
+        let mut state = self.state.lock().unwrap();
+        state.acks.extend_from_slice(ack_ids);
+    }
+
+    // Returns the messages to extend and their deadline, after releasing the messages received
+    // more than `max_extension` ago.
+    fn extend(&self) -> (Vec<String>, Duration) {
+        let mut state = self.state.lock().unwrap();
+        let max_extension = self.settings.max_extension;
+        state
+            .outstanding
+            .retain(|_, received| received.elapsed() < max_extension);
+        let deadline = self.ack_deadline(&state);
+        (state.outstanding.keys().cloned().collect(), deadline)
+    }
+}
+
+/// A received message, which is acked or nacked once handled.
+///
+/// A message dropped without being acked is nacked.
+#[derive(Debug)]
+pub struct Message {
+    message: PubsubMessage,
+    ack_id: String,
+    delivery_attempt: i32,
+    size: usize,
+    lease: Arc<Lease>,
+    done: bool,
+}
+
+impl Message {
+    pub fn ack_id(&self) -> &str {
+        &self.ack_id
+    }
+
+    /// Returns the approximate number of delivery attempts, if the subscription has a dead
+    /// letter policy.
+    pub fn delivery_attempt(&self) -> Option<i32> {
+        if self.delivery_attempt > 0 {
+            Some(self.delivery_attempt)
+        } else {
+            None
+        }
+    }
+
+    /// Acknowledges the message, which is not redelivered.
+    pub fn ack(mut self) {
+        self.finish(true);
+    }
+
+    /// Releases the message, which is redelivered.
+    pub fn nack(mut self) {
+        self.finish(false);
+    }
+
+    fn finish(&mut self, ack: bool) {
+        if !self.done {
+            self.done = true;
+            self.lease.done(&self.ack_id, self.size, ack);
+        }
+    }
+}
+
+impl Deref for Message {
+    type Target = PubsubMessage;
+
+    fn deref(&self) -> &Self::Target {
+        &self.message
+    }
+}
+
+impl Drop for Message {
+    fn drop(&mut self) {
+        self.finish(false);
+    }
+}
+
+// The generated clients return `Unknown` when the channel is not ready, e.g. on a connection
+// error.
+fn is_retryable(status: &Status) -> bool {
+    matches!(
+        status.code(),
+        Code::DeadlineExceeded
+            | Code::ResourceExhausted
+            | Code::Aborted
+            | Code::Internal
+            | Code::Unavailable
+            | Code::Unknown
+    )
+}
+
+/// Receives the messages of a subscription.
+#[derive(Debug, Clone)]
+pub struct Subscriber<T> {
+    client: SubscriberClient<T>,
+    subscription: String,
+    settings: SubscriberSettings,
+}
+
+impl<T> Subscriber<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    pub fn new(client: SubscriberClient<T>, subscription: impl Into<String>) -> Self {
+        Self {
+            client,
+            subscription: subscription.into(),
+            settings: SubscriberSettings::default(),
+        }
+    }
+
+    pub fn settings(self, settings: SubscriberSettings) -> Self {
+        Self { settings, ..self }
+    }
+
+    /// Receives messages and spawns `handler` for each of them.
+    ///
+    /// Runs until a stream fails with a non-retryable error, which is returned. Dropping the
+    /// future stops receiving, and the messages being handled are then redelivered after their
+    /// ack deadline.
+    pub async fn receive<F, Fut>(&self, handler: F) -> Status
+    where
+        F: Fn(Message) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let lease = Arc::new(Lease::new(&self.settings));
+        let handler = Arc::new(handler);
+        let streams = future::select_all(
+            (0..self.settings.streams).map(|_| Box::pin(self.stream(&lease, &handler))),
+        );
+        let maintain = Box::pin(self.maintain(&lease));
+        let status = match future::select(streams, maintain).await {
+            Either::Left(((status, _, _), _)) => status,
+            Either::Right((_, streams)) => streams.await.0,
+        };
+        self.send_acks(&lease).await;
+        status
+    }
+
+    // Pulls messages, reopening the stream on retryable errors.
+    async fn stream<F, Fut>(&self, lease: &Arc<Lease>, handler: &Arc<F>) -> Status
+    where
+        F: Fn(Message) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let mut backoff = MIN_BACKOFF;
+        loop {
+            let status = self.pull(lease, handler, &mut backoff).await;
+            if !is_retryable(&status) {
+                return status;
+            }
+            tokio::time::delay_for(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+
+    async fn pull<F, Fut>(
+        &self,
+        lease: &Arc<Lease>,
+        handler: &Arc<F>,
+        backoff: &mut Duration,
+    ) -> Status
+    where
+        F: Fn(Message) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let deadline = {
+            let state = lease.state.lock().unwrap();
+            lease.ack_deadline(&state)
+        };
+        // The stream is closed when `tx` is dropped.
+        let (tx, rx) = mpsc::unbounded_channel();
+        let _ = tx.send(StreamingPullRequest {
+            subscription: self.subscription.clone(),
+            stream_ack_deadline_seconds: deadline.as_secs() as i32,
+            ..Default::default()
+        });
+        let mut stream = match self.client.clone().streaming_pull(rx).await {
+            Ok(response) => response.into_inner(),
+            Err(status) => return status,
+        };
+
+        loop {
+            let response = match stream.message().await {
+                Ok(Some(response)) => response,
+                Ok(None) => return Status::new(Code::Unavailable, "stream closed"),
+                Err(status) => return status,
+            };
+            *backoff = MIN_BACKOFF;
+
+            let received = response.received_messages;
+            lease.received(received.iter().map(|m| m.ack_id.clone()));
+            for received in received {
+                let message = received.message.unwrap_or_default();
+                let size = message.encoded_len();
+                // Waits for the outstanding messages to be within limits, which stops reading
+                // from the stream meanwhile.
+                lease.flow.acquire(size).await;
+                tokio::spawn(handler(Message {
+                    message,
+                    ack_id: received.ack_id,
+                    delivery_attempt: received.delivery_attempt,
+                    size,
+                    lease: lease.clone(),
+                    done: false,
+                }));
+            }
+        }
+    }
+
+    // Sends the acks and nacks periodically, and extends the ack deadlines.
+    async fn maintain(&self, lease: &Lease) {
+        let mut next_extension = Instant::now();
+        loop {
+            tokio::time::delay_for(ACK_INTERVAL).await;
+            self.send_acks(lease).await;
+
+            if Instant::now() >= next_extension {
+                let (ack_ids, deadline) = lease.extend();
+                self.modify_ack_deadline(ack_ids, deadline).await;
+                // Extends 5 seconds before the deadline, or halfway for short deadlines.
+                let period = (deadline - Duration::from_secs(5)).max(deadline / 2);
+                next_extension = Instant::now() + period;
+            }
+        }
+    }
+
+    async fn send_acks(&self, lease: &Lease) {
+        let (acks, nacks, receipts, deadline) = lease.take();
+        for ack_ids in acks.chunks(MAX_ACK_IDS) {
+            let request = AcknowledgeRequest {
+                subscription: self.subscription.clone(),
+                ack_ids: ack_ids.to_vec(),
+            };
+            if let Err(status) = self.client.clone().acknowledge(request).await {
+                if is_retryable(&status) {
+                    lease.retry_acks(ack_ids);
+                }
+            }
+        }
+        self.modify_ack_deadline(nacks, Duration::from_secs(0))
+            .await;
+        self.modify_ack_deadline(receipts, deadline).await;
+    }
+
+    // Failures are ignored, the message is then redelivered after its current deadline.
+    async fn modify_ack_deadline(&self, ack_ids: Vec<String>, deadline: Duration) {
+        for ack_ids in ack_ids.chunks(MAX_ACK_IDS) {
+            let request = ModifyAckDeadlineRequest {
+                subscription: self.subscription.clone(),
+                ack_ids: ack_ids.to_vec(),
+                ack_deadline_seconds: deadline.as_secs() as i32,
+            };
+            let _ = self.client.clone().modify_ack_deadline(request).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google::pubsub::v1::ReceivedMessage;
+    use crate::pubsub::fake::{FakeSubscriber, FakeSubscriberServer};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn received(n: usize) -> Vec<ReceivedMessage> {
+        (0..n)
+            .map(|i| ReceivedMessage {
+                ack_id: format!("a{}", i),
+                message: Some(PubsubMessage {
+                    data: vec![i as u8],
+                    ..Default::default()
+                }),
+                delivery_attempt: 0,
+            })
+            .collect()
+    }
+
+    fn subscriber(fake: &Arc<FakeSubscriber>) -> Subscriber<FakeSubscriberServer> {
+        Subscriber::new(fake.client(), "projects/p/subscriptions/s")
+    }
+
+    fn sorted(mut v: Vec<String>) -> Vec<String> {
+        v.sort();
+        v
+    }
+
+    #[tokio::test]
+    async fn test_receive_ack() {
+        let fake = Arc::new(FakeSubscriber::default());
+        fake.respond_to_pull(Ok(received(3)));
+        let subscriber = subscriber(&fake);
+
+        let receive = subscriber.receive(|message| async move { message.ack() });
+        assert!(tokio::time::timeout(Duration::from_millis(500), receive)
+            .await
+            .is_err());
+
+        assert_eq!(sorted(fake.acks()), ["a0", "a1", "a2"]);
+        // The deadline of the messages is modified on receipt.
+        let modacks = fake.modacks();
+        assert!(modacks.contains(&("a0".into(), 10)));
+    }
+
+    #[tokio::test]
+    async fn test_receive_nack() {
+        let fake = Arc::new(FakeSubscriber::default());
+        fake.respond_to_pull(Ok(received(2)));
+        let subscriber = subscriber(&fake);
+
+        // The first message is nacked and the second one dropped.
+        let receive = subscriber.receive(|message| async move {
+            if message.ack_id() == "a0" {
+                message.nack();
+            }
+        });
+        let _ = tokio::time::timeout(Duration::from_millis(500), receive).await;
+
+        assert!(fake.acks().is_empty());
+        let modacks = fake.modacks();
+        assert!(modacks.contains(&("a0".into(), 0)));
+        assert!(modacks.contains(&("a1".into(), 0)));
+    }
+
+    #[tokio::test]
+    async fn test_receive_reconnect() {
+        let fake = Arc::new(FakeSubscriber::default());
+        fake.respond_to_pull(Err(Status::unavailable("try again")));
+        fake.respond_to_pull(Ok(received(1)));
+        let subscriber = subscriber(&fake);
+
+        let receive = subscriber.receive(|message| async move { message.ack() });
+        let _ = tokio::time::timeout(Duration::from_millis(500), receive).await;
+
+        assert_eq!(fake.pulls(), 2);
+        assert_eq!(fake.acks(), ["a0"]);
+    }
+
+    #[tokio::test]
+    async fn test_receive_non_retryable_error() {
+        let fake = Arc::new(FakeSubscriber::default());
+        fake.respond_to_pull(Err(Status::not_found("no subscription")));
+        let subscriber = subscriber(&fake);
+
+        let status = subscriber
+            .receive(|message| async move { message.ack() })
+            .await;
+        assert_eq!(status.code(), Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_receive_flow_control() {
+        let fake = Arc::new(FakeSubscriber::default());
+        fake.respond_to_pull(Ok(received(3)));
+        let subscriber =
+            subscriber(&fake).settings(SubscriberSettings::default().max_outstanding_messages(1));
+
+        let active = Arc::new(AtomicUsize::new(0));
+        let max_active = Arc::new(AtomicUsize::new(0));
+        let receive = subscriber.receive({
+            let (active, max_active) = (active.clone(), max_active.clone());
+            move |message| {
+                let (active, max_active) = (active.clone(), max_active.clone());
+                async move {
+                    let n = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_active.fetch_max(n, Ordering::SeqCst);
+                    tokio::time::delay_for(Duration::from_millis(20)).await;
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    message.ack();
+                }
+            }
+        });
+        let _ = tokio::time::timeout(Duration::from_millis(500), receive).await;
+
+        assert_eq!(max_active.load(Ordering::SeqCst), 1);
+        assert_eq!(sorted(fake.acks()), ["a0", "a1", "a2"]);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let mut histogram = Histogram::default();
+        assert_eq!(histogram.percentile(0.99), None);
+        for _ in 0..99 {
+            histogram.record(Duration::from_millis(1500));
+        }
+        histogram.record(Duration::from_secs(1000));
+        assert_eq!(histogram.percentile(0.99), Some(Duration::from_secs(2)));
+        assert_eq!(histogram.percentile(1.0), Some(Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn test_lease() {
+        let settings = SubscriberSettings::default().max_ack_deadline(Duration::from_secs(30));
+        let lease = Lease::new(&settings);
+        {
+            let mut state = lease.state.lock().unwrap();
+            assert_eq!(lease.ack_deadline(&state), Duration::from_secs(10));
+            state.histogram.record(Duration::from_secs(100));
+            assert_eq!(lease.ack_deadline(&state), Duration::from_secs(30));
+        }
+
+        lease.received(vec!["a".to_string(), "b".to_string()].into_iter());
+        let (mut ack_ids, _) = lease.extend();
+        ack_ids.sort();
+        assert_eq!(ack_ids, ["a", "b"]);
+
+        let lease = Lease::new(&settings.max_extension(Duration::from_secs(0)));
+        lease.received(vec!["a".to_string()].into_iter());
+        assert!(lease.extend().0.is_empty());
+    }
+}
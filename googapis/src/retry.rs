@@ -0,0 +1,473 @@
+//! Retry with exponential backoff for the generated `*_client` methods.
+//!
+//! The default [`RetrySettings`] of each method are taken from the gRPC service config files
+//! (`*_grpc_service_config.json`) shipped with the protos, see [`RetrySettings::for_method`].
+//!
+//! # Example
+//! ```ignore
+//! use googapis::{google::pubsub::v1::PublishRequest, retry::{self, RetrySettings}};
+//!
+//! let settings = RetrySettings::for_method("/google.pubsub.v1.Publisher/Publish")
+//!     .unwrap_or_default();
+//! let response = retry::call(&settings, request, |req: PublishRequest| {
+//!     let mut client = client.clone();
+//!     async move { client.publish(req).await }
+//! })
+//! .await?;
+//! ```
+
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use prost::Message;
+use rand::Rng;
+use tonic::{Code, Response, Status};
+
+use crate::google::rpc::{RetryInfo, Status as RpcStatus};
+
+include!("service_config.rs");
+
+const RETRY_INFO_TYPE_URL: &str = "type.googleapis.com/google.rpc.RetryInfo";
+
+#[derive(Debug, Clone, Copy)]
+struct MethodConfig {
+    timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RetryPolicy {
+    max_attempts: Option<usize>,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    backoff_multiplier: f64,
+    retryable_status_codes: &'static [Code],
+}
+
+/// Settings controlling when and how often a call is retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetrySettings {
+    /// The status codes that are retried.
+    pub retry_codes: Vec<Code>,
+    /// The delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The upper bound of the delay between retries.
+    pub max_backoff: Duration,
+    /// The factor the delay is multiplied by after each retry.
+    pub backoff_multiplier: f64,
+    /// Whether the delay is randomized between zero and the computed backoff.
+    pub jitter: bool,
+    /// The maximum number of attempts including the first one, unlimited if `None`.
+    pub max_attempts: Option<usize>,
+    /// The timeout of each attempt.
+    pub attempt_timeout: Option<Duration>,
+    /// The overall deadline of the call including all retries.
+    pub total_timeout: Option<Duration>,
+}
+
+impl Default for RetrySettings {
+    fn default() -> Self {
+        Self {
+            retry_codes: vec![Code::Unavailable],
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(60),
+            backoff_multiplier: 1.3,
+            jitter: true,
+            max_attempts: None,
+            attempt_timeout: None,
+            total_timeout: Some(Duration::from_secs(600)),
+        }
+    }
+}
+
+impl RetrySettings {
+    /// Returns settings that never retry.
+    pub fn never() -> Self {
+        Self {
+            retry_codes: Vec::new(),
+            max_attempts: Some(1),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the default settings of a method from the gRPC service config, e.g.
+    /// `/google.pubsub.v1.Publisher/Publish`.
+    ///
+    /// Method-level entries take precedence over service-level entries. Methods without a retry
+    /// policy in the service config only get a timeout and are never retried.
+    pub fn for_method(path: &str) -> Option<Self> {
+        let service = path.rfind('/').map(|i| &path[..=i])?;
+        let config = find_method_config(path).or_else(|| find_method_config(service))?;
+        let mut settings = match config.retry_policy {
+            Some(policy) => Self {
+                retry_codes: policy.retryable_status_codes.to_vec(),
+                initial_backoff: policy.initial_backoff,
+                max_backoff: policy.max_backoff,
+                backoff_multiplier: policy.backoff_multiplier,
+                max_attempts: policy.max_attempts,
+                ..Default::default()
+            },
+            None => Self::never(),
+        };
+        settings.total_timeout = config.timeout;
+        Some(settings)
+    }
+
+    pub fn retry_codes(self, retry_codes: impl Into<Vec<Code>>) -> Self {
+        Self {
+            retry_codes: retry_codes.into(),
+            ..self
+        }
+    }
+
+    pub fn backoff(self, initial: Duration, max: Duration, multiplier: f64) -> Self {
+        Self {
+            initial_backoff: initial,
+            max_backoff: max,
+            backoff_multiplier: multiplier,
+            ..self
+        }
+    }
+
+    pub fn jitter(self, jitter: bool) -> Self {
+        Self { jitter, ..self }
+    }
+
+    pub fn max_attempts(self, max_attempts: impl Into<Option<usize>>) -> Self {
+        Self {
+            max_attempts: max_attempts.into(),
+            ..self
+        }
+    }
+
+    pub fn attempt_timeout(self, timeout: impl Into<Option<Duration>>) -> Self {
+        Self {
+            attempt_timeout: timeout.into(),
+            ..self
+        }
+    }
+
+    pub fn total_timeout(self, timeout: impl Into<Option<Duration>>) -> Self {
+        Self {
+            total_timeout: timeout.into(),
+            ..self
+        }
+    }
+
+    /// Returns whether a call failed with `status` may be retried.
+    pub fn is_retryable(&self, status: &Status) -> bool {
+        self.retry_codes.contains(&status.code())
+    }
+
+    /// Returns a new backoff sequence starting from `initial_backoff`.
+    pub fn backoff_iter(&self) -> Backoff {
+        Backoff {
+            current: self.initial_backoff,
+            max: self.max_backoff,
+            multiplier: self.backoff_multiplier,
+            jitter: self.jitter,
+        }
+    }
+}
+
+fn find_method_config(key: &str) -> Option<MethodConfig> {
+    METHOD_CONFIGS
+        .binary_search_by_key(&key, |(k, _)| k)
+        .ok()
+        .map(|i| METHOD_CONFIGS[i].1)
+}
+
+/// An infinite sequence of exponentially growing delays.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    current: Duration,
+    max: Duration,
+    multiplier: f64,
+    jitter: bool,
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = if self.jitter {
+            let millis = self.current.as_millis() as u64;
+            Duration::from_millis(rand::thread_rng().gen_range(0, millis + 1))
+        } else {
+            self.current
+        };
+        self.current = self.current.mul_f64(self.multiplier).min(self.max);
+        Some(delay)
+    }
+}
+
+/// Returns the delay of the `google.rpc.RetryInfo` contained in the details of `status`.
+pub fn retry_delay(status: &Status) -> Option<Duration> {
+    let details = RpcStatus::decode(status.details()).ok()?;
+    details
+        .details
+        .iter()
+        .filter(|any| any.type_url == RETRY_INFO_TYPE_URL)
+        .filter_map(|any| RetryInfo::decode(&any.value[..]).ok())
+        .filter_map(|info| info.retry_delay)
+        .map(|d| Duration::new(d.seconds.max(0) as u64, d.nanos.max(0) as u32))
+        .next()
+}
+
+/// Calls `f` with `request` and retries it according to `settings`.
+///
+/// Each attempt is limited by `attempt_timeout` and the remaining `total_timeout`. Between
+/// attempts it waits for the next backoff, or for the delay of the `google.rpc.RetryInfo` when
+/// the server sends one. The last error is returned when retries are exhausted.
+pub async fn call<Req, Resp, F, Fut>(
+    settings: &RetrySettings,
+    request: Req,
+    mut f: F,
+) -> Result<Response<Resp>, Status>
+where
+    Req: Clone,
+    F: FnMut(Req) -> Fut,
+    Fut: Future<Output = Result<Response<Resp>, Status>>,
+{
+    let deadline = settings.total_timeout.map(|t| Instant::now() + t);
+    let mut backoff = settings.backoff_iter();
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+
+        let timeout = match (settings.attempt_timeout, remaining(deadline)) {
+            (Some(a), Some(r)) => Some(a.min(r)),
+            (a, r) => a.or(r),
+        };
+        let result = match timeout {
+            Some(t) => tokio::time::timeout(t, f(request.clone()))
+                .await
+                .unwrap_or_else(|_| Err(Status::deadline_exceeded("attempt timed out"))),
+            None => f(request.clone()).await,
+        };
+
+        let status = match result {
+            Ok(response) => return Ok(response),
+            Err(status) => status,
+        };
+        if !settings.is_retryable(&status)
+            || matches!(settings.max_attempts, Some(max) if attempts >= max)
+        {
+            return Err(status);
+        }
+
+        let delay = retry_delay(&status).unwrap_or_else(|| backoff.next().unwrap());
+        if let Some(r) = remaining(deadline) {
+            if delay >= r {
+                return Err(status);
+            }
+        }
+        tokio::time::delay_for(delay).await;
+    }
+}
+
+fn remaining(deadline: Option<Instant>) -> Option<Duration> {
+    deadline.map(|d| d.saturating_duration_since(Instant::now()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        convert::Infallible,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+    use tonic::{
+        body::BoxBody,
+        client::Grpc,
+        codec::ProstCodec,
+        codegen::{http, ok, Context, Poll, Ready, Service},
+    };
+
+    fn settings() -> RetrySettings {
+        RetrySettings::default()
+            .retry_codes(vec![Code::Unavailable, Code::Aborted])
+            .backoff(Duration::from_millis(1), Duration::from_millis(4), 2.0)
+            .jitter(false)
+    }
+
+    #[test]
+    fn test_backoff() {
+        let delays = settings().backoff_iter().take(4).collect::<Vec<_>>();
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(1),
+                Duration::from_millis(2),
+                Duration::from_millis(4),
+                Duration::from_millis(4),
+            ]
+        );
+
+        let max = Duration::from_millis(4);
+        let jittered = settings().jitter(true).backoff_iter().take(10);
+        assert!(jittered.into_iter().all(|d| d <= max));
+    }
+
+    // A server failing every call with a status, sent in trailers as by tonic servers.
+    struct FailingServer(http::HeaderMap);
+
+    impl Service<http::Request<BoxBody>> for FailingServer {
+        type Response = http::Response<BoxBody>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _: http::Request<BoxBody>) -> Self::Future {
+            let mut response = http::Response::new(BoxBody::empty());
+            *response.headers_mut() = self.0.clone();
+            ok(response)
+        }
+    }
+
+    // Returns the status received by a client from a server failing with `code` and `details`.
+    async fn received_status(code: Code, details: &[u8]) -> Status {
+        let mut headers = http::HeaderMap::new();
+        headers.insert("grpc-status", (code as i32).into());
+        let details = base64::encode(details);
+        headers.insert("grpc-status-details-bin", details.parse().unwrap());
+
+        let mut client = Grpc::new(FailingServer(headers));
+        client.ready().await.unwrap();
+        let path = http::uri::PathAndQuery::from_static("/mechiru.v1.Service/Method");
+        client
+            .unary::<(), (), _>(tonic::Request::new(()), path, ProstCodec::default())
+            .await
+            .unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn test_retry_delay() {
+        let info = RetryInfo {
+            retry_delay: Some(prost_types::Duration {
+                seconds: 1,
+                nanos: 500_000_000,
+            }),
+        };
+        let details = RpcStatus {
+            code: Code::Aborted as i32,
+            message: "aborted".into(),
+            details: vec![prost_types::Any {
+                type_url: RETRY_INFO_TYPE_URL.into(),
+                value: encode(&info),
+            }],
+        };
+        let status = received_status(Code::Aborted, &encode(&details)).await;
+        assert_eq!(status.code(), Code::Aborted);
+        assert_eq!(retry_delay(&status), Some(Duration::from_millis(1500)));
+        assert_eq!(retry_delay(&Status::aborted("aborted")), None);
+    }
+
+    fn encode(msg: &impl Message) -> Vec<u8> {
+        let mut buf = Vec::new();
+        msg.encode(&mut buf).unwrap();
+        buf
+    }
+
+    async fn flaky(
+        counter: Arc<AtomicUsize>,
+        fail: usize,
+        code: Code,
+    ) -> Result<Response<usize>, Status> {
+        let n = counter.fetch_add(1, Ordering::SeqCst) + 1;
+        if n <= fail {
+            Err(Status::new(code, "flaky"))
+        } else {
+            Ok(Response::new(n))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_retries_retryable_codes() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let res = call(&settings(), (), |_| {
+            flaky(counter.clone(), 3, Code::Unavailable)
+        })
+        .await;
+        assert_eq!(res.unwrap().into_inner(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_call_does_not_retry_other_codes() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let res = call(&settings(), (), |_| {
+            flaky(counter.clone(), 3, Code::InvalidArgument)
+        })
+        .await;
+        assert_eq!(res.unwrap_err().code(), Code::InvalidArgument);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_call_max_attempts() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let settings = settings().max_attempts(2);
+        let res = call(&settings, (), |_| flaky(counter.clone(), 3, Code::Aborted)).await;
+        assert_eq!(res.unwrap_err().code(), Code::Aborted);
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_call_total_timeout() {
+        let settings = settings()
+            .backoff(Duration::from_millis(20), Duration::from_millis(20), 1.0)
+            .total_timeout(Duration::from_millis(50));
+        let counter = Arc::new(AtomicUsize::new(0));
+        let res = call(&settings, (), |_| {
+            flaky(counter.clone(), 100, Code::Unavailable)
+        })
+        .await;
+        assert_eq!(res.unwrap_err().code(), Code::Unavailable);
+        assert!(counter.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[test]
+    fn test_for_method() {
+        let settings = RetrySettings::for_method("/google.pubsub.v1.Publisher/Publish").unwrap();
+        assert_eq!(settings.initial_backoff, Duration::from_millis(100));
+        assert_eq!(settings.max_backoff, Duration::from_secs(60));
+        assert_eq!(settings.backoff_multiplier, 4.0);
+        assert_eq!(settings.total_timeout, Some(Duration::from_secs(60)));
+        assert!(settings.is_retryable(&Status::unavailable("unavailable")));
+        assert!(settings.is_retryable(&Status::deadline_exceeded("deadline exceeded")));
+        assert!(!settings.is_retryable(&Status::invalid_argument("invalid")));
+
+        let settings = RetrySettings::for_method("/google.pubsub.v1.Subscriber/StreamingPull");
+        assert_eq!(
+            settings.unwrap().total_timeout,
+            Some(Duration::from_secs(900))
+        );
+
+        let settings = RetrySettings::for_method("/google.spanner.v1.Spanner/Commit").unwrap();
+        assert_eq!(settings.initial_backoff, Duration::from_millis(250));
+        assert_eq!(settings.max_backoff, Duration::from_secs(32));
+        assert_eq!(settings.total_timeout, Some(Duration::from_secs(3600)));
+        assert!(settings.is_retryable(&Status::unavailable("unavailable")));
+        assert!(!settings.is_retryable(&Status::aborted("aborted")));
+
+        // Streamed reads are resumed by the caller rather than retried.
+        let settings = RetrySettings::for_method("/google.bigtable.v2.Bigtable/ReadRows").unwrap();
+        assert_eq!(settings.total_timeout, Some(Duration::from_secs(43200)));
+        assert!(!settings.is_retryable(&Status::unavailable("unavailable")));
+    }
+
+    #[test]
+    fn test_for_method_unknown() {
+        assert_eq!(RetrySettings::for_method("/unknown.Service/Method"), None);
+        assert_eq!(RetrySettings::for_method("invalid"), None);
+    }
+}
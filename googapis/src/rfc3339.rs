@@ -0,0 +1,197 @@
+//! Parsing and formatting of the RFC 3339 timestamps and dates of the JSON encodings of the APIs.
+
+use prost_types::Timestamp;
+
+// Parses an RFC 3339 timestamp, e.g. `2020-05-10T06:05:01.123Z`.
+pub(crate) fn parse_timestamp(s: &str) -> Option<Timestamp> {
+    let number = |start: usize, len: usize| s.get(start..start + len).and_then(parse_digits);
+    let separators = s.as_bytes();
+    if separators.len() < 20
+        || separators[4] != b'-'
+        || separators[7] != b'-'
+        || !matches!(separators[10], b'T' | b't')
+        || separators[13] != b':'
+        || separators[16] != b':'
+    {
+        return None;
+    }
+    let (year, month, day) = (number(0, 4)?, number(5, 2)?, number(8, 2)?);
+    let (hour, minute, second) = (number(11, 2)?, number(14, 2)?, number(17, 2)?);
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 || len > 9 {
+            return None;
+        }
+        nanos = parse_digits(&fraction[..len])? as i32 * 10i32.pow(9 - len as u32);
+        rest = &fraction[len..];
+    }
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match rest.as_bytes().first()? {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            if rest.len() != 6 || rest.as_bytes()[3] != b':' {
+                return None;
+            }
+            let hours = rest.get(1..3).and_then(parse_digits)?;
+            let minutes = rest.get(4..).and_then(parse_digits)?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    Some(Timestamp {
+        seconds: days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+            - offset,
+        nanos,
+    })
+}
+
+// Parses an RFC 3339 full date, e.g. `2020-05-10`, into its year, month and day.
+pub(crate) fn parse_date(s: &str) -> Option<(i64, i64, i64)> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = s.get(..4).and_then(parse_digits)?;
+    let month = s.get(5..7).and_then(parse_digits)?;
+    let day = s.get(8..).and_then(parse_digits)?;
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+// Formats a timestamp in UTC with the shortest of 0, 3, 6 or 9 fractional digits, e.g.
+// `2020-05-10T06:05:01.123Z`. Years outside of 0000-9999 are not valid RFC 3339.
+pub(crate) fn format_timestamp(ts: &Timestamp) -> String {
+    let seconds = ts.seconds + i64::from(ts.nanos).div_euclid(1_000_000_000);
+    let nanos = i64::from(ts.nanos).rem_euclid(1_000_000_000);
+    let (days, time) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+    let (year, month, day) = civil_from_days(days);
+    let fraction = match nanos {
+        0 => String::new(),
+        n if n % 1_000_000 == 0 => format!(".{:03}", n / 1_000_000),
+        n if n % 1_000 == 0 => format!(".{:06}", n / 1_000),
+        n => format!(".{:09}", n),
+    };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time / 60 % 60,
+        time % 60,
+        fraction
+    )
+}
+
+// Formats a full date, e.g. `2020-05-10`.
+pub(crate) fn format_date(year: i64, month: i64, day: i64) -> String {
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn parse_digits(s: &str) -> Option<i64> {
+    if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// The number of days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// The proleptic Gregorian date of a number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp() {
+        let ts = |seconds, nanos| Some(Timestamp { seconds, nanos });
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), ts(0, 0));
+        assert_eq!(
+            parse_timestamp("2000-02-29T12:00:00.5+09:00"),
+            ts(951_793_200, 500_000_000)
+        );
+        assert_eq!(parse_timestamp("1969-12-31T23:59:59.000000001Z"), ts(-1, 1));
+        assert_eq!(
+            parse_timestamp("0001-01-01T00:00:00-00:30"),
+            ts(-62_135_595_000, 0)
+        );
+        assert_eq!(parse_timestamp("2001-02-29T00:00:00Z"), None);
+        assert_eq!(parse_timestamp("2001-01-01T00:00:00"), None);
+        assert_eq!(parse_timestamp("2001-01-01T00:00:00.Z"), None);
+        assert_eq!(parse_timestamp("2001-01-01T00:00:00+-1:00"), None);
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2020-05-10"), Some((2020, 5, 10)));
+        assert_eq!(parse_date("2000-02-29"), Some((2000, 2, 29)));
+        assert_eq!(parse_date("2001-02-29"), None);
+        assert_eq!(parse_date("2001-1-01"), None);
+        assert_eq!(parse_date("2001-01-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        let format = |seconds, nanos| format_timestamp(&Timestamp { seconds, nanos });
+        assert_eq!(format(0, 0), "1970-01-01T00:00:00Z");
+        assert_eq!(format(951_782_400, 500_000_000), "2000-02-29T00:00:00.500Z");
+        assert_eq!(format(-1, 1), "1969-12-31T23:59:59.000000001Z");
+        assert_eq!(format(0, -1000), "1969-12-31T23:59:59.999999Z");
+        assert_eq!(format(-62_135_596_800, 0), "0001-01-01T00:00:00Z");
+        assert_eq!(format(253_402_300_799, 0), "9999-12-31T23:59:59Z");
+        for &(seconds, nanos) in &[(1_589_090_701, 123_000_000), (-86_401, 7)] {
+            let ts = Timestamp { seconds, nanos };
+            assert_eq!(parse_timestamp(&format_timestamp(&ts)), Some(ts));
+        }
+    }
+}
@@ -0,0 +1,748 @@
+const METHOD_CONFIGS: &[(&str, MethodConfig)] = &[
+    (
+        "/google.bigtable.v2.Bigtable/CheckAndMutateRow",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(20000)),
+            retry_policy: None,
+        },
+    ),
+    (
+        "/google.bigtable.v2.Bigtable/MutateRow",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(10),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 2.0,
+                retryable_status_codes: &[Code::Unavailable, Code::DeadlineExceeded],
+            }),
+        },
+    ),
+    (
+        "/google.bigtable.v2.Bigtable/MutateRows",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(600000)),
+            retry_policy: None,
+        },
+    ),
+    (
+        "/google.bigtable.v2.Bigtable/ReadModifyWriteRow",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(20000)),
+            retry_policy: None,
+        },
+    ),
+    (
+        "/google.bigtable.v2.Bigtable/ReadRows",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(43200000)),
+            retry_policy: None,
+        },
+    ),
+    (
+        "/google.bigtable.v2.Bigtable/SampleRowKeys",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: None,
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/BatchGetDocuments",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[
+                    Code::DeadlineExceeded,
+                    Code::Internal,
+                    Code::Unavailable,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/BeginTransaction",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[
+                    Code::DeadlineExceeded,
+                    Code::Internal,
+                    Code::Unavailable,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/Commit",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::ResourceExhausted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/CreateDocument",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::ResourceExhausted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/DeleteDocument",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[
+                    Code::DeadlineExceeded,
+                    Code::Internal,
+                    Code::Unavailable,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/GetDocument",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[
+                    Code::DeadlineExceeded,
+                    Code::Internal,
+                    Code::Unavailable,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/ListCollectionIds",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[
+                    Code::DeadlineExceeded,
+                    Code::Internal,
+                    Code::Unavailable,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/ListDocuments",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[
+                    Code::DeadlineExceeded,
+                    Code::Internal,
+                    Code::Unavailable,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/Listen",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(86400000)),
+            retry_policy: None,
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/Rollback",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[
+                    Code::DeadlineExceeded,
+                    Code::Internal,
+                    Code::Unavailable,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/RunQuery",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[
+                    Code::DeadlineExceeded,
+                    Code::Internal,
+                    Code::Unavailable,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/UpdateDocument",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::ResourceExhausted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.firestore.v1.Firestore/Write",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(86400000)),
+            retry_policy: None,
+        },
+    ),
+    (
+        "/google.pubsub.v1.Publisher/CreateTopic",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Publisher/DeleteTopic",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Publisher/GetTopic",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unknown, Code::Aborted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Publisher/ListTopicSnapshots",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unknown, Code::Aborted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Publisher/ListTopicSubscriptions",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unknown, Code::Aborted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Publisher/ListTopics",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unknown, Code::Aborted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Publisher/Publish",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 4.0,
+                retryable_status_codes: &[
+                    Code::Aborted,
+                    Code::Cancelled,
+                    Code::Internal,
+                    Code::ResourceExhausted,
+                    Code::Unknown,
+                    Code::Unavailable,
+                    Code::DeadlineExceeded,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Publisher/UpdateTopic",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/Acknowledge",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/CreateSnapshot",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/CreateSubscription",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/DeleteSnapshot",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/DeleteSubscription",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/GetSnapshot",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unknown, Code::Aborted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/GetSubscription",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unknown, Code::Aborted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/ListSnapshots",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unknown, Code::Aborted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/ListSubscriptions",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unknown, Code::Aborted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/ModifyAckDeadline",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/ModifyPushConfig",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/Pull",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unknown, Code::Aborted, Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/Seek",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/StreamingPull",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(900000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 4.0,
+                retryable_status_codes: &[
+                    Code::DeadlineExceeded,
+                    Code::ResourceExhausted,
+                    Code::Aborted,
+                    Code::Internal,
+                    Code::Unavailable,
+                ],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/UpdateSnapshot",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.pubsub.v1.Subscriber/UpdateSubscription",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(60000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/BatchCreateSessions",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(60000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/BeginTransaction",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/Commit",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(3600000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/CreateSession",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/DeleteSession",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/ExecuteBatchDml",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/ExecuteSql",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/ExecuteStreamingSql",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(3600000)),
+            retry_policy: None,
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/GetSession",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/ListSessions",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/PartitionQuery",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/PartitionRead",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/Read",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/Rollback",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(30000)),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: None,
+                initial_backoff: Duration::from_millis(250),
+                max_backoff: Duration::from_millis(32000),
+                backoff_multiplier: 1.3,
+                retryable_status_codes: &[Code::Unavailable],
+            }),
+        },
+    ),
+    (
+        "/google.spanner.v1.Spanner/StreamingRead",
+        MethodConfig {
+            timeout: Some(Duration::from_millis(3600000)),
+            retry_policy: None,
+        },
+    ),
+];
@@ -0,0 +1,127 @@
+//! High-level Spanner clients on top of [`google.spanner.v1`].
+//!
+//! The `spanner` feature provides a [`session::SessionPool`] that keeps sessions alive and
+//! prepares read-write transactions ahead of time, and a [`transaction::TransactionRunner`] that
+//! runs read-write transactions and retries them when they are aborted. Query and read results
+//! are streamed as [`row::Row`]s by a [`result_set::ResultSetStream`], which merges chunked values
+//! and resumes interrupted streams, and are decoded to Rust types with [`row::FromSpannerRow`] and
+//! [`value::FromSpannerValue`]. Mutations are built with the builders of [`mutation`], from
+//! values encoded with [`value::ToSpannerValue`], and queries with the typed parameters of a
+//! [`statement::Statement`].
+//!
+//! The `spanner-migration` feature adds a [`migration::Migrator`] that applies schema migrations
+//! with the database admin API.
+//!
+//! ```ignore
+//! use googapis::{
+//!     google::spanner::v1::spanner_client::SpannerClient,
+//!     spanner::session::{PoolSettings, SessionPool},
+//! };
+//!
+//! let pool = SessionPool::new(
+//!     SpannerClient::new(channel),
+//!     "projects/my-project/instances/my-instance/databases/my-database",
+//!     PoolSettings::default().min_opened(10),
+//! )
+//! .await?;
+//! let (count, _) = pool
+//!     .read_write_transaction(|tx| async move {
+//!         let count = tx.execute_update(ExecuteSqlRequest {
+//!             sql: "UPDATE Singers SET Active = TRUE WHERE Active = FALSE".into(),
+//!             ..Default::default()
+//!         })
+//!         .await?;
+//!         Ok(count)
+//!     })
+//!     .await?;
+//! ```
+//!
+//! [`google.spanner.v1`]: https://github.com/googleapis/googleapis/tree/master/google/spanner/v1
+
+use std::{error, fmt};
+
+use tonic::{Code, Status};
+
+#[cfg(test)]
+mod fake;
+#[cfg(feature = "spanner-migration")]
+pub mod migration;
+pub mod mutation;
+pub mod partition;
+pub mod result_set;
+pub mod row;
+pub mod session;
+pub mod statement;
+pub mod transaction;
+pub mod value;
+
+/// The errors of the session pool and of transactions.
+#[derive(Debug)]
+pub enum Error {
+    /// A call failed.
+    Status(Status),
+    /// No session became available within the acquire timeout.
+    Timeout,
+    /// The session pool is closed.
+    Closed,
+    /// Sessions were still checked out when the pool was closed.
+    Leaked(Vec<String>),
+    /// A value could not be decoded.
+    Decode(value::Error),
+}
+
+impl Error {
+    /// Returns whether the transaction was aborted and should be retried.
+    pub fn is_aborted(&self) -> bool {
+        matches!(self, Error::Status(status) if status.code() == Code::Aborted)
+    }
+
+    /// Returns whether the session the call was made in no longer exists.
+    pub fn is_session_not_found(&self) -> bool {
+        matches!(self, Error::Status(status) if is_session_not_found(status))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Status(status) => write!(f, "spanner call failed: {}", status),
+            Error::Timeout => f.write_str("timed out waiting for a session"),
+            Error::Closed => f.write_str("session pool closed"),
+            Error::Leaked(sessions) => write!(
+                f,
+                "{} sessions still checked out: {}",
+                sessions.len(),
+                sessions.join(", ")
+            ),
+            Error::Decode(e) => write!(f, "cannot decode value: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Status(status) => Some(status),
+            Error::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Status> for Error {
+    fn from(status: Status) -> Self {
+        Error::Status(status)
+    }
+}
+
+impl From<value::Error> for Error {
+    fn from(e: value::Error) -> Self {
+        Error::Decode(e)
+    }
+}
+
+// Spanner reports deleted and expired sessions with `NOT_FOUND` and this message.
+fn is_session_not_found(status: &Status) -> bool {
+    status.code() == Code::NotFound && status.message().contains("Session not found")
+}
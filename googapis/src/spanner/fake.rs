@@ -0,0 +1,706 @@
+// An in-memory fake of the Spanner service for the tests of the `spanner` module.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use tonic::{Request, Response, Status};
+
+use crate::google::spanner::v1::{
+    mutation::Operation, result_set_stats::RowCount, spanner_client::SpannerClient, spanner_server,
+    struct_type::Field, transaction_selector::Selector, BatchCreateSessionsRequest,
+    BatchCreateSessionsResponse, BeginTransactionRequest, CommitRequest, CommitResponse,
+    CreateSessionRequest, DeleteSessionRequest, ExecuteBatchDmlRequest, ExecuteBatchDmlResponse,
+    ExecuteSqlRequest, GetSessionRequest, ListSessionsRequest, ListSessionsResponse, Mutation,
+    PartialResultSet, Partition, PartitionQueryRequest, PartitionReadRequest, PartitionResponse,
+    ReadRequest, ResultSet, ResultSetMetadata, ResultSetStats, RollbackRequest, Session,
+    StructType, Transaction, Type, TypeCode,
+};
+
+type PartialResultSetStream =
+    tokio::stream::Iter<std::vec::IntoIter<Result<PartialResultSet, Status>>>;
+
+pub(crate) type FakeServer = spanner_server::SpannerServer<Arc<FakeSpanner>>;
+
+#[derive(Default)]
+struct State {
+    next_id: u64,
+    // The sessions and the id of their current read-write transaction.
+    sessions: HashMap<String, Option<Vec<u8>>>,
+    calls: HashMap<&'static str, usize>,
+    sql: Vec<String>,
+    commit_errors: VecDeque<Status>,
+    sql_errors: VecDeque<Status>,
+    committed: Vec<Vec<Mutation>>,
+    // The columns of the tables whose rows are returned by reads.
+    tables: HashMap<String, Vec<Field>>,
+}
+
+#[derive(Default)]
+pub(crate) struct FakeSpanner {
+    state: Mutex<State>,
+}
+
+impl FakeSpanner {
+    pub(crate) fn client(self: &Arc<Self>) -> SpannerClient<FakeServer> {
+        SpannerClient::new(spanner_server::SpannerServer::new(self.clone()))
+    }
+
+    // The number of calls of `method`, e.g. `"commit"`.
+    pub(crate) fn calls(&self, method: &str) -> usize {
+        let state = self.state.lock().unwrap();
+        state.calls.get(method).copied().unwrap_or(0)
+    }
+
+    pub(crate) fn sessions(&self) -> usize {
+        self.state.lock().unwrap().sessions.len()
+    }
+
+    pub(crate) fn sql(&self) -> Vec<String> {
+        self.state.lock().unwrap().sql.clone()
+    }
+
+    pub(crate) fn committed(&self) -> Vec<Vec<Mutation>> {
+        self.state.lock().unwrap().committed.clone()
+    }
+
+    // Creates a table whose rows are the ones written by the committed mutations.
+    #[cfg(feature = "spanner-migration")]
+    pub(crate) fn create_table(&self, name: &str, fields: Vec<Field>) {
+        let mut state = self.state.lock().unwrap();
+        state.tables.insert(name.into(), fields);
+    }
+
+    // Deletes a session as if it expired.
+    pub(crate) fn expire(&self, name: &str) {
+        self.state.lock().unwrap().sessions.remove(name);
+    }
+
+    // Fails the next commit with `status`.
+    pub(crate) fn fail_commit(&self, status: Status) {
+        self.state.lock().unwrap().commit_errors.push_back(status);
+    }
+
+    // Fails the next query or DML statement with `status`.
+    pub(crate) fn fail_sql(&self, status: Status) {
+        self.state.lock().unwrap().sql_errors.push_back(status);
+    }
+
+    fn call(&self, method: &'static str) -> std::sync::MutexGuard<'_, State> {
+        let mut state = self.state.lock().unwrap();
+        *state.calls.entry(method).or_default() += 1;
+        state
+    }
+}
+
+impl State {
+    fn session(&mut self, name: &str) -> Result<&mut Option<Vec<u8>>, Status> {
+        self.sessions
+            .get_mut(name)
+            .ok_or_else(|| Status::not_found(format!("Session not found: {}", name)))
+    }
+
+    fn create_session(&mut self, database: &str) -> Session {
+        self.next_id += 1;
+        let name = format!("{}/sessions/{}", database, self.next_id);
+        self.sessions.insert(name.clone(), None);
+        Session {
+            name,
+            ..Default::default()
+        }
+    }
+
+    // Checks that `id` is the current transaction of `session`.
+    fn check_transaction(&mut self, session: &str, id: &[u8]) -> Result<(), Status> {
+        match self.session(session)? {
+            Some(current) if current == id => Ok(()),
+            _ => Err(Status::failed_precondition("transaction not active")),
+        }
+    }
+}
+
+fn unimplemented<T>() -> Result<Response<T>, Status> {
+    Err(Status::unimplemented("not implemented by the fake"))
+}
+
+#[tonic::async_trait]
+impl spanner_server::Spanner for Arc<FakeSpanner> {
+    async fn create_session(
+        &self,
+        request: Request<CreateSessionRequest>,
+    ) -> Result<Response<Session>, Status> {
+        let mut state = self.call("create_session");
+        Ok(Response::new(
+            state.create_session(&request.into_inner().database),
+        ))
+    }
+
+    async fn batch_create_sessions(
+        &self,
+        request: Request<BatchCreateSessionsRequest>,
+    ) -> Result<Response<BatchCreateSessionsResponse>, Status> {
+        let request = request.into_inner();
+        let mut state = self.call("batch_create_sessions");
+        let session = (0..request.session_count)
+            .map(|_| state.create_session(&request.database))
+            .collect();
+        Ok(Response::new(BatchCreateSessionsResponse { session }))
+    }
+
+    async fn get_session(
+        &self,
+        request: Request<GetSessionRequest>,
+    ) -> Result<Response<Session>, Status> {
+        let name = request.into_inner().name;
+        self.call("get_session").session(&name)?;
+        Ok(Response::new(Session {
+            name,
+            ..Default::default()
+        }))
+    }
+
+    async fn list_sessions(
+        &self,
+        _: Request<ListSessionsRequest>,
+    ) -> Result<Response<ListSessionsResponse>, Status> {
+        unimplemented()
+    }
+
+    async fn delete_session(
+        &self,
+        request: Request<DeleteSessionRequest>,
+    ) -> Result<Response<()>, Status> {
+        let name = request.into_inner().name;
+        let mut state = self.call("delete_session");
+        state.session(&name)?;
+        state.sessions.remove(&name);
+        Ok(Response::new(()))
+    }
+
+    async fn execute_sql(
+        &self,
+        request: Request<ExecuteSqlRequest>,
+    ) -> Result<Response<ResultSet>, Status> {
+        let request = request.into_inner();
+        let mut state = self.call("execute_sql");
+        match request.transaction.and_then(|t| t.selector) {
+            Some(Selector::Id(id)) => state.check_transaction(&request.session, &id)?,
+            // Single-use transactions end the current transaction of the session.
+            _ => *state.session(&request.session)? = None,
+        }
+        if let Some(status) = state.sql_errors.pop_front() {
+            return Err(status);
+        }
+        state.sql.push(request.sql);
+        Ok(Response::new(ResultSet {
+            stats: Some(ResultSetStats {
+                row_count: Some(RowCount::RowCountExact(1)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }))
+    }
+
+    type ExecuteStreamingSqlStream = PartialResultSetStream;
+
+    // Returns a single row with the partition token of the request as a `STRING`.
+    async fn execute_streaming_sql(
+        &self,
+        request: Request<ExecuteSqlRequest>,
+    ) -> Result<Response<Self::ExecuteStreamingSqlStream>, Status> {
+        let request = request.into_inner();
+        let mut state = self.call("execute_streaming_sql");
+        if let Some(Selector::Id(id)) = request.transaction.and_then(|t| t.selector) {
+            state.check_transaction(&request.session, &id)?;
+        }
+        state.sql.push(request.sql);
+        let token = String::from_utf8(request.partition_token).unwrap();
+        let partial = PartialResultSet {
+            metadata: Some(ResultSetMetadata {
+                row_type: Some(StructType {
+                    fields: vec![Field {
+                        name: "partition".into(),
+                        r#type: Some(Type {
+                            code: TypeCode::String as i32,
+                            ..Default::default()
+                        }),
+                    }],
+                }),
+                ..Default::default()
+            }),
+            values: vec![prost_types::Value {
+                kind: Some(prost_types::value::Kind::StringValue(token)),
+            }],
+            resume_token: b"1".to_vec(),
+            ..Default::default()
+        };
+        Ok(Response::new(tokio::stream::iter(vec![Ok(partial)])))
+    }
+
+    // Each statement modifies one row, until the first error of `fail_sql`.
+    async fn execute_batch_dml(
+        &self,
+        request: Request<ExecuteBatchDmlRequest>,
+    ) -> Result<Response<ExecuteBatchDmlResponse>, Status> {
+        let request = request.into_inner();
+        let mut state = self.call("execute_batch_dml");
+        match request.transaction.and_then(|t| t.selector) {
+            Some(Selector::Id(id)) => state.check_transaction(&request.session, &id)?,
+            _ => return Err(Status::invalid_argument("transaction id required")),
+        }
+        let mut response = ExecuteBatchDmlResponse::default();
+        for statement in request.statements {
+            if let Some(status) = state.sql_errors.pop_front() {
+                response.status = Some(crate::google::rpc::Status {
+                    code: status.code() as i32,
+                    message: status.message().into(),
+                    details: vec![],
+                });
+                break;
+            }
+            state.sql.push(statement.sql);
+            response.result_sets.push(ResultSet {
+                stats: Some(ResultSetStats {
+                    row_count: Some(RowCount::RowCountExact(1)),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+        Ok(Response::new(response))
+    }
+
+    async fn read(&self, request: Request<ReadRequest>) -> Result<Response<ResultSet>, Status> {
+        let request = request.into_inner();
+        let mut state = self.call("read");
+        let selector = request
+            .transaction
+            .as_ref()
+            .and_then(|t| t.selector.as_ref());
+        if let Some(Selector::Id(id)) = selector {
+            state.check_transaction(&request.session, id)?;
+        }
+        let fields = match state.tables.get(&request.table) {
+            Some(fields) => fields,
+            None => return Ok(Response::new(ResultSet::default())),
+        };
+        // The rows of all the writes of the table, ignoring the key set.
+        let mut rows = Vec::new();
+        for mutation in state.committed.iter().flatten() {
+            if let Some(Operation::Insert(write)) | Some(Operation::InsertOrUpdate(write)) =
+                &mutation.operation
+            {
+                if write.table == request.table {
+                    rows.extend(write.values.iter().map(|row| (&write.columns, row)));
+                }
+            }
+        }
+        let column = |name: &String| fields.iter().find(|f| &f.name == name).cloned();
+        let fields = request
+            .columns
+            .iter()
+            .map(|name| column(name).ok_or_else(|| Status::not_found("Column not found")))
+            .collect::<Result<Vec<_>, _>>()?;
+        let rows = rows
+            .into_iter()
+            .map(|(columns, row)| {
+                let values = request.columns.iter().map(|name| {
+                    let index = columns.iter().position(|c| c == name);
+                    index.map_or_else(Default::default, |i| row.values[i].clone())
+                });
+                prost_types::ListValue {
+                    values: values.collect(),
+                }
+            })
+            .collect();
+        Ok(Response::new(ResultSet {
+            metadata: Some(ResultSetMetadata {
+                row_type: Some(StructType { fields }),
+                ..Default::default()
+            }),
+            rows,
+            stats: None,
+        }))
+    }
+
+    type StreamingReadStream = PartialResultSetStream;
+
+    async fn streaming_read(
+        &self,
+        _: Request<ReadRequest>,
+    ) -> Result<Response<Self::StreamingReadStream>, Status> {
+        unimplemented()
+    }
+
+    async fn begin_transaction(
+        &self,
+        request: Request<BeginTransactionRequest>,
+    ) -> Result<Response<Transaction>, Status> {
+        let request = request.into_inner();
+        let mut state = self.call("begin_transaction");
+        state.session(&request.session)?;
+        state.next_id += 1;
+        let id = state.next_id.to_string().into_bytes();
+        *state.session(&request.session)? = Some(id.clone());
+        Ok(Response::new(Transaction {
+            id,
+            ..Default::default()
+        }))
+    }
+
+    async fn commit(
+        &self,
+        request: Request<CommitRequest>,
+    ) -> Result<Response<CommitResponse>, Status> {
+        use crate::google::spanner::v1::commit_request::Transaction;
+
+        let request = request.into_inner();
+        let mut state = self.call("commit");
+        match request.transaction {
+            Some(Transaction::TransactionId(id)) => {
+                state.check_transaction(&request.session, &id)?
+            }
+            _ => return Err(Status::invalid_argument("transaction id required")),
+        }
+        *state.session(&request.session)? = None;
+        if let Some(status) = state.commit_errors.pop_front() {
+            return Err(status);
+        }
+        state.committed.push(request.mutations);
+        Ok(Response::new(CommitResponse {
+            commit_timestamp: Some(prost_types::Timestamp {
+                seconds: state.committed.len() as i64,
+                nanos: 0,
+            }),
+        }))
+    }
+
+    async fn rollback(&self, request: Request<RollbackRequest>) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+        let mut state = self.call("rollback");
+        *state.session(&request.session)? = None;
+        Ok(Response::new(()))
+    }
+
+    // Returns two partitions, whose tokens are `0` and `1`.
+    async fn partition_query(
+        &self,
+        request: Request<PartitionQueryRequest>,
+    ) -> Result<Response<PartitionResponse>, Status> {
+        let request = request.into_inner();
+        let mut state = self.call("partition_query");
+        match request.transaction.and_then(|t| t.selector) {
+            Some(Selector::Id(id)) => state.check_transaction(&request.session, &id)?,
+            _ => return Err(Status::invalid_argument("transaction id required")),
+        }
+        state.sql.push(request.sql);
+        let partitions = (0..2)
+            .map(|i| Partition {
+                partition_token: i.to_string().into_bytes(),
+            })
+            .collect();
+        Ok(Response::new(PartitionResponse {
+            partitions,
+            transaction: None,
+        }))
+    }
+
+    async fn partition_read(
+        &self,
+        _: Request<PartitionReadRequest>,
+    ) -> Result<Response<PartitionResponse>, Status> {
+        unimplemented()
+    }
+}
+
+#[cfg(feature = "spanner-migration")]
+pub(crate) use self::admin::FakeDatabaseAdmin;
+
+// A fake of the database admin API for the tests of the `migration` module.
+#[cfg(feature = "spanner-migration")]
+mod admin {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use prost::Message;
+    use tonic::{Code, Request, Response, Status};
+
+    use super::unimplemented;
+    use crate::google::{
+        iam::v1::{
+            GetIamPolicyRequest, Policy, SetIamPolicyRequest, TestIamPermissionsRequest,
+            TestIamPermissionsResponse,
+        },
+        longrunning::{
+            operation, operations_server, CancelOperationRequest, DeleteOperationRequest,
+            GetOperationRequest, ListOperationsRequest, ListOperationsResponse,
+            Operation as RawOperation, WaitOperationRequest,
+        },
+        rpc::Status as RpcStatus,
+        spanner::admin::database::v1::{
+            database_admin_server, Backup, CreateBackupRequest, CreateDatabaseRequest, Database,
+            DeleteBackupRequest, DropDatabaseRequest, GetBackupRequest, GetDatabaseDdlRequest,
+            GetDatabaseDdlResponse, GetDatabaseRequest, ListBackupOperationsRequest,
+            ListBackupOperationsResponse, ListBackupsRequest, ListBackupsResponse,
+            ListDatabaseOperationsRequest, ListDatabaseOperationsResponse, ListDatabasesRequest,
+            ListDatabasesResponse, RestoreDatabaseRequest, UpdateBackupRequest,
+            UpdateDatabaseDdlMetadata, UpdateDatabaseDdlRequest,
+        },
+    };
+
+    const METADATA_TYPE: &str =
+        "type.googleapis.com/google.spanner.admin.database.v1.UpdateDatabaseDdlMetadata";
+    const RESPONSE_TYPE: &str = "type.googleapis.com/google.protobuf.Empty";
+
+    #[derive(Default)]
+    struct State {
+        ddl: Vec<String>,
+        operations: HashMap<String, RawOperation>,
+        // The results of the operations, set when they are polled.
+        results: HashMap<String, operation::Result>,
+        updates: usize,
+    }
+
+    // A fake of the database admin and operations services, which applies the statements
+    // except the ones containing `INVALID`.
+    #[derive(Default)]
+    pub(crate) struct FakeDatabaseAdmin {
+        state: Mutex<State>,
+    }
+
+    impl FakeDatabaseAdmin {
+        // The statements applied by the calls of `update_database_ddl`.
+        pub(crate) fn ddl(&self) -> Vec<String> {
+            self.state.lock().unwrap().ddl.clone()
+        }
+
+        // Replaces the schema of the database, as if it was changed outside of the migrations.
+        pub(crate) fn set_ddl(&self, ddl: Vec<String>) {
+            self.state.lock().unwrap().ddl = ddl;
+        }
+
+        // The number of calls of `update_database_ddl` which started an operation.
+        pub(crate) fn updates(&self) -> usize {
+            self.state.lock().unwrap().updates
+        }
+    }
+
+    fn any(type_url: &str, msg: &impl Message) -> prost_types::Any {
+        let mut value = Vec::new();
+        msg.encode(&mut value).unwrap();
+        prost_types::Any {
+            type_url: type_url.into(),
+            value,
+        }
+    }
+
+    #[tonic::async_trait]
+    impl database_admin_server::DatabaseAdmin for Arc<FakeDatabaseAdmin> {
+        async fn list_databases(
+            &self,
+            _: Request<ListDatabasesRequest>,
+        ) -> Result<Response<ListDatabasesResponse>, Status> {
+            unimplemented()
+        }
+
+        async fn create_database(
+            &self,
+            _: Request<CreateDatabaseRequest>,
+        ) -> Result<Response<RawOperation>, Status> {
+            unimplemented()
+        }
+
+        async fn get_database(
+            &self,
+            _: Request<GetDatabaseRequest>,
+        ) -> Result<Response<Database>, Status> {
+            unimplemented()
+        }
+
+        async fn update_database_ddl(
+            &self,
+            request: Request<UpdateDatabaseDdlRequest>,
+        ) -> Result<Response<RawOperation>, Status> {
+            let request = request.into_inner();
+            let mut state = self.state.lock().unwrap();
+            let name = format!("{}/operations/{}", request.database, request.operation_id);
+            if state.operations.contains_key(&name) {
+                return Err(Status::already_exists("operation exists"));
+            }
+            state.updates += 1;
+
+            let mut metadata = UpdateDatabaseDdlMetadata {
+                database: request.database,
+                statements: request.statements.clone(),
+                commit_timestamps: Vec::new(),
+            };
+            let mut result = operation::Result::Response(any(RESPONSE_TYPE, &()));
+            for statement in request.statements {
+                if statement.contains("INVALID") {
+                    let error = RpcStatus {
+                        code: Code::InvalidArgument as i32,
+                        message: format!("invalid statement: {}", statement),
+                        details: Vec::new(),
+                    };
+                    result = operation::Result::Error(error);
+                    break;
+                }
+                state.ddl.push(statement);
+                metadata
+                    .commit_timestamps
+                    .push(prost_types::Timestamp::default());
+            }
+            let op = RawOperation {
+                name: name.clone(),
+                metadata: Some(any(METADATA_TYPE, &metadata)),
+                done: false,
+                result: None,
+            };
+            state.operations.insert(name.clone(), op.clone());
+            state.results.insert(name, result);
+            Ok(Response::new(op))
+        }
+
+        async fn drop_database(
+            &self,
+            _: Request<DropDatabaseRequest>,
+        ) -> Result<Response<()>, Status> {
+            unimplemented()
+        }
+
+        async fn get_database_ddl(
+            &self,
+            _: Request<GetDatabaseDdlRequest>,
+        ) -> Result<Response<GetDatabaseDdlResponse>, Status> {
+            let statements = self.state.lock().unwrap().ddl.clone();
+            Ok(Response::new(GetDatabaseDdlResponse { statements }))
+        }
+
+        async fn set_iam_policy(
+            &self,
+            _: Request<SetIamPolicyRequest>,
+        ) -> Result<Response<Policy>, Status> {
+            unimplemented()
+        }
+
+        async fn get_iam_policy(
+            &self,
+            _: Request<GetIamPolicyRequest>,
+        ) -> Result<Response<Policy>, Status> {
+            unimplemented()
+        }
+
+        async fn test_iam_permissions(
+            &self,
+            _: Request<TestIamPermissionsRequest>,
+        ) -> Result<Response<TestIamPermissionsResponse>, Status> {
+            unimplemented()
+        }
+
+        async fn create_backup(
+            &self,
+            _: Request<CreateBackupRequest>,
+        ) -> Result<Response<RawOperation>, Status> {
+            unimplemented()
+        }
+
+        async fn get_backup(
+            &self,
+            _: Request<GetBackupRequest>,
+        ) -> Result<Response<Backup>, Status> {
+            unimplemented()
+        }
+
+        async fn update_backup(
+            &self,
+            _: Request<UpdateBackupRequest>,
+        ) -> Result<Response<Backup>, Status> {
+            unimplemented()
+        }
+
+        async fn delete_backup(
+            &self,
+            _: Request<DeleteBackupRequest>,
+        ) -> Result<Response<()>, Status> {
+            unimplemented()
+        }
+
+        async fn list_backups(
+            &self,
+            _: Request<ListBackupsRequest>,
+        ) -> Result<Response<ListBackupsResponse>, Status> {
+            unimplemented()
+        }
+
+        async fn restore_database(
+            &self,
+            _: Request<RestoreDatabaseRequest>,
+        ) -> Result<Response<RawOperation>, Status> {
+            unimplemented()
+        }
+
+        async fn list_database_operations(
+            &self,
+            _: Request<ListDatabaseOperationsRequest>,
+        ) -> Result<Response<ListDatabaseOperationsResponse>, Status> {
+            unimplemented()
+        }
+
+        async fn list_backup_operations(
+            &self,
+            _: Request<ListBackupOperationsRequest>,
+        ) -> Result<Response<ListBackupOperationsResponse>, Status> {
+            unimplemented()
+        }
+    }
+
+    #[tonic::async_trait]
+    impl operations_server::Operations for Arc<FakeDatabaseAdmin> {
+        async fn list_operations(
+            &self,
+            _: Request<ListOperationsRequest>,
+        ) -> Result<Response<ListOperationsResponse>, Status> {
+            unimplemented()
+        }
+
+        // Completes the operation.
+        async fn get_operation(
+            &self,
+            request: Request<GetOperationRequest>,
+        ) -> Result<Response<RawOperation>, Status> {
+            let name = request.into_inner().name;
+            let state = &mut *self.state.lock().unwrap();
+            let op = state
+                .operations
+                .get_mut(&name)
+                .ok_or_else(|| Status::not_found("operation not found"))?;
+            op.done = true;
+            op.result = state.results.get(&name).cloned();
+            Ok(Response::new(op.clone()))
+        }
+
+        async fn delete_operation(
+            &self,
+            _: Request<DeleteOperationRequest>,
+        ) -> Result<Response<()>, Status> {
+            unimplemented()
+        }
+
+        async fn cancel_operation(
+            &self,
+            _: Request<CancelOperationRequest>,
+        ) -> Result<Response<()>, Status> {
+            unimplemented()
+        }
+
+        async fn wait_operation(
+            &self,
+            _: Request<WaitOperationRequest>,
+        ) -> Result<Response<RawOperation>, Status> {
+            unimplemented()
+        }
+    }
+}
@@ -0,0 +1,724 @@
+//! Schema migrations of Spanner databases.
+//!
+//! The `spanner-migration` feature provides a [`Migrator`] that applies numbered batches of DDL
+//! statements in order with `UpdateDatabaseDdl`, waits for their long-running operations, and
+//! records the applied versions in a table of the database:
+//!
+//! ```ignore
+//! use googapis::spanner::migration::{Migration, Migrator};
+//!
+//! let mut migrator = Migrator::new(admin_client, operations_client, pool)
+//!     .migration(Migration::new(1, "create singers").statement(
+//!         "CREATE TABLE Singers (SingerId INT64 NOT NULL, Name STRING(MAX)) PRIMARY KEY (SingerId)",
+//!     ))
+//!     .migration(Migration::new(2, "index names").statement(
+//!         "CREATE INDEX SingersByName ON Singers(Name)",
+//!     ));
+//! for migration in migrator.pending().await? {
+//!     println!("pending: {} {}", migration.version(), migration.description());
+//! }
+//! migrator.migrate().await?;
+//! ```
+//!
+//! The table stores the schema returned by `GetDatabaseDdl` after each migration, which
+//! [`Migrator::drift`] compares with the current schema to detect the changes that were not made
+//! by migrations.
+//!
+//! The operations are named after the table, the version and the statements of the migrations,
+//! so that a migrator started while another one is applying a migration waits for the same
+//! operation instead of applying the statements twice.
+
+use std::{collections::BTreeSet, error, fmt, sync::Arc};
+
+use tonic::{Code, Status};
+
+use super::{mutation, row::Row, session::SessionPool};
+use crate::{
+    google::{
+        longrunning::operations_client::OperationsClient,
+        spanner::{
+            admin::database::v1::{
+                database_admin_client::DatabaseAdminClient, GetDatabaseDdlRequest,
+                UpdateDatabaseDdlMetadata, UpdateDatabaseDdlRequest,
+            },
+            v1::ReadRequest,
+        },
+    },
+    longrunning::{self, Operation, OperationPoller, PollSettings},
+};
+
+const METADATA_TYPE: &str =
+    "type.googleapis.com/google.spanner.admin.database.v1.UpdateDatabaseDdlMetadata";
+const RESPONSE_TYPE: &str = "type.googleapis.com/google.protobuf.Empty";
+
+/// The default name of the table of the applied migrations.
+pub const DEFAULT_TABLE: &str = "SchemaMigrations";
+
+/// The errors of a [`Migrator`].
+#[derive(Debug)]
+pub enum Error {
+    /// A call to the database admin service failed.
+    Status(Status),
+    /// The operation applying the statements of a migration failed, some of them may have been
+    /// applied.
+    Operation {
+        version: i64,
+        error: longrunning::Error,
+    },
+    /// Reading or recording the applied migrations failed.
+    Spanner(super::Error),
+    /// Several migrations have the same version.
+    DuplicateVersion(i64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Status(status) => write!(f, "database admin call failed: {}", status),
+            Error::Operation { version, error } => {
+                write!(f, "migration {} failed: {}", version, error)
+            }
+            Error::Spanner(e) => write!(f, "migrations table error: {}", e),
+            Error::DuplicateVersion(version) => write!(f, "duplicate migration {}", version),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Status(status) => Some(status),
+            Error::Operation { error, .. } => Some(error),
+            Error::Spanner(e) => Some(e),
+            Error::DuplicateVersion(_) => None,
+        }
+    }
+}
+
+impl From<Status> for Error {
+    fn from(status: Status) -> Self {
+        Error::Status(status)
+    }
+}
+
+impl From<super::Error> for Error {
+    fn from(e: super::Error) -> Self {
+        Error::Spanner(e)
+    }
+}
+
+/// A batch of DDL statements applied as a single operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    version: i64,
+    description: String,
+    statements: Vec<String>,
+}
+
+impl Migration {
+    /// Creates a migration, which is applied after the ones with lower versions.
+    pub fn new(version: i64, description: impl Into<String>) -> Self {
+        Self {
+            version,
+            description: description.into(),
+            statements: Vec::new(),
+        }
+    }
+
+    /// Adds a DDL statement, without a trailing semicolon.
+    pub fn statement(mut self, statement: impl Into<String>) -> Self {
+        self.statements.push(statement.into());
+        self
+    }
+
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn statements(&self) -> &[String] {
+        &self.statements
+    }
+
+    // The id of the operation applying the migration, unique for its statements.
+    fn operation_id(&self, table: &str) -> String {
+        // FNV-1a, which unlike the hasher of the standard library is stable across releases.
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for statement in &self.statements {
+            for byte in statement.bytes().chain(Some(0)) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x100_0000_01b3);
+            }
+        }
+        let table: String = table
+            .chars()
+            .map(|c| match c.to_ascii_lowercase() {
+                c @ 'a'..='z' | c @ '0'..='9' => c,
+                _ => '_',
+            })
+            .collect();
+        // Operation ids can't contain `-`, so negative versions are prefixed with `n` instead.
+        let sign = if self.version < 0 { "n" } else { "" };
+        let version = self.version.unsigned_abs();
+        format!("m_{}_{}{}_{:016x}", table, sign, version, hash)
+    }
+}
+
+/// A migration recorded in the migrations table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub description: Option<String>,
+    /// The DDL statements of the database after the migration.
+    pub schema: Vec<String>,
+}
+
+/// The changes of the schema since the last migration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Drift {
+    /// The statements of the current schema that were not in the recorded schema.
+    pub added: Vec<String>,
+    /// The statements of the recorded schema that are not in the current schema.
+    pub removed: Vec<String>,
+}
+
+impl Drift {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Applies the pending [`Migration`]s of a database.
+#[derive(Debug)]
+pub struct Migrator<T> {
+    admin: DatabaseAdminClient<T>,
+    poller: OperationPoller<T>,
+    pool: SessionPool<T>,
+    table: String,
+    migrations: Vec<Migration>,
+}
+
+impl<T> Migrator<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    /// Creates a migrator of the database of `pool`.
+    pub fn new(
+        admin: DatabaseAdminClient<T>,
+        operations: OperationsClient<T>,
+        pool: SessionPool<T>,
+    ) -> Self {
+        Self {
+            admin,
+            poller: OperationPoller::new(operations),
+            pool,
+            table: DEFAULT_TABLE.into(),
+            migrations: Vec::new(),
+        }
+    }
+
+    /// The table of the applied migrations, created by the first migration.
+    pub fn table(self, table: impl Into<String>) -> Self {
+        Self {
+            table: table.into(),
+            ..self
+        }
+    }
+
+    /// How the operations applying the migrations are polled.
+    pub fn poll_settings(self, settings: PollSettings) -> Self {
+        Self {
+            poller: self.poller.settings(settings),
+            ..self
+        }
+    }
+
+    /// Adds a migration.
+    pub fn migration(mut self, migration: Migration) -> Self {
+        self.migrations.push(migration);
+        self
+    }
+
+    /// Returns the DDL statements of the database.
+    pub async fn database_ddl(&self) -> Result<Vec<String>, Error> {
+        let request = GetDatabaseDdlRequest {
+            database: self.pool.database().into(),
+        };
+        let mut admin = self.admin.clone();
+        Ok(admin
+            .get_database_ddl(request)
+            .await?
+            .into_inner()
+            .statements)
+    }
+
+    /// Returns the applied migrations, in order.
+    pub async fn applied(&self) -> Result<Vec<AppliedMigration>, Error> {
+        let ddl = self.database_ddl().await?;
+        self.read_applied(&ddl).await
+    }
+
+    async fn read_applied(&self, ddl: &[String]) -> Result<Vec<AppliedMigration>, Error> {
+        if !ddl.iter().any(|s| creates_table(s, &self.table)) {
+            return Ok(Vec::new());
+        }
+        let session = self.pool.get().await?;
+        let request = ReadRequest {
+            session: session.name().into(),
+            table: self.table.clone(),
+            columns: vec!["Version".into(), "Description".into(), "Schema".into()],
+            key_set: Some(mutation::all_keys()),
+            ..Default::default()
+        };
+        let mut client = self.pool.client.clone();
+        let result_set = client
+            .read(request)
+            .await
+            .map_err(super::Error::from)?
+            .into_inner();
+        let fields = Arc::new(
+            result_set
+                .metadata
+                .and_then(|m| m.row_type)
+                .map(|t| t.fields)
+                .unwrap_or_default(),
+        );
+        let mut applied = result_set
+            .rows
+            .into_iter()
+            .map(|row| {
+                let (version, description, schema) =
+                    Row::new(fields.clone(), row.values).decode()?;
+                let schema: Option<Vec<String>> = schema;
+                Ok(AppliedMigration {
+                    version,
+                    description,
+                    schema: schema.unwrap_or_default(),
+                })
+            })
+            .collect::<Result<Vec<_>, super::value::Error>>()
+            .map_err(super::Error::from)?;
+        applied.sort_by_key(|m| m.version);
+        Ok(applied)
+    }
+
+    /// Returns the migrations that are not applied yet, in order, without applying them.
+    pub async fn pending(&self) -> Result<Vec<&Migration>, Error> {
+        let applied = self.applied().await?;
+        self.pending_after(&applied)
+    }
+
+    fn pending_after(&self, applied: &[AppliedMigration]) -> Result<Vec<&Migration>, Error> {
+        let applied: BTreeSet<_> = applied.iter().map(|m| m.version).collect();
+        let mut versions = BTreeSet::new();
+        let mut pending = Vec::new();
+        for migration in &self.migrations {
+            if !versions.insert(migration.version) {
+                return Err(Error::DuplicateVersion(migration.version));
+            }
+            if !applied.contains(&migration.version) {
+                pending.push(migration);
+            }
+        }
+        pending.sort_by_key(|m| m.version);
+        Ok(pending)
+    }
+
+    /// Applies the pending migrations in order, and returns their versions.
+    pub async fn migrate(&mut self) -> Result<Vec<i64>, Error> {
+        self.migrate_with_progress(|_, _| {}).await
+    }
+
+    /// Applies the pending migrations like [`migrate`](Self::migrate), and calls `f` with the
+    /// metadata of the operation of a migration each time it is polled. The metadata has the
+    /// commit timestamps of the statements that were applied.
+    pub async fn migrate_with_progress<F>(&mut self, mut f: F) -> Result<Vec<i64>, Error>
+    where
+        F: FnMut(&Migration, &UpdateDatabaseDdlMetadata),
+    {
+        let mut ddl = self.database_ddl().await?;
+        let applied = self.read_applied(&ddl).await?;
+        let pending: Vec<Migration> = self.pending_after(&applied)?.into_iter().cloned().collect();
+
+        let mut versions = Vec::new();
+        for migration in pending {
+            let mut statements = migration.statements.clone();
+            if !ddl.iter().any(|s| creates_table(s, &self.table)) {
+                statements.insert(0, create_table_statement(&self.table));
+            }
+            let operation_id = migration.operation_id(&self.table);
+            self.update_ddl(statements, operation_id, |metadata| f(&migration, metadata))
+                .await
+                .map_err(|error| match error {
+                    longrunning::Error::Status(status) => Error::Status(status),
+                    error => Error::Operation {
+                        version: migration.version,
+                        error,
+                    },
+                })?;
+
+            ddl = self.database_ddl().await?;
+            self.record(&migration, &ddl).await?;
+            versions.push(migration.version);
+        }
+        Ok(versions)
+    }
+
+    // Applies `statements` and waits for the operation, or for the operation of another migrator
+    // applying the same statements.
+    async fn update_ddl<F>(
+        &mut self,
+        statements: Vec<String>,
+        operation_id: String,
+        mut f: F,
+    ) -> Result<(), longrunning::Error>
+    where
+        F: FnMut(&UpdateDatabaseDdlMetadata),
+    {
+        let database = self.pool.database().to_owned();
+        let request = UpdateDatabaseDdlRequest {
+            database: database.clone(),
+            statements,
+            operation_id: operation_id.clone(),
+        };
+        let raw = match self.admin.update_database_ddl(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::AlreadyExists => {
+                crate::google::longrunning::Operation {
+                    name: format!("{}/operations/{}", database, operation_id),
+                    ..Default::default()
+                }
+            }
+            Err(status) => return Err(status.into()),
+        };
+        let op = Operation::<(), UpdateDatabaseDdlMetadata>::with_types(
+            raw,
+            RESPONSE_TYPE,
+            METADATA_TYPE,
+        );
+        self.poller
+            .wait_with(op, |op| {
+                if let Some(Ok(metadata)) = op.metadata() {
+                    f(&metadata);
+                }
+            })
+            .await
+    }
+
+    // Records `migration` as applied, with the schema after it.
+    async fn record(&self, migration: &Migration, ddl: &[String]) -> Result<(), Error> {
+        let mutation = mutation::insert(self.table.as_str())
+            .set("Version", migration.version)
+            .set("Description", migration.description.as_str())
+            .set("Schema", ddl.to_vec())
+            .set("AppliedAt", "spanner.commit_timestamp()")
+            .build();
+        let result = self
+            .pool
+            .read_write_transaction(|tx| {
+                tx.buffer_write(vec![mutation.clone()]);
+                async { Ok(()) }
+            })
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            // Recorded by another migrator that waited for the same operation.
+            Err(super::Error::Status(status)) if status.code() == Code::AlreadyExists => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Compares the current schema with the one recorded by the last applied migration.
+    pub async fn drift(&self) -> Result<Drift, Error> {
+        let ddl = self.database_ddl().await?;
+        let applied = self.read_applied(&ddl).await?;
+        let recorded = applied.last().map(|m| m.schema.as_slice()).unwrap_or(&[]);
+        let missing_from = |a: &[String], b: &[String]| {
+            a.iter()
+                .filter(|s| !b.contains(s))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        Ok(Drift {
+            added: missing_from(&ddl, recorded),
+            removed: missing_from(recorded, &ddl),
+        })
+    }
+}
+
+fn create_table_statement(table: &str) -> String {
+    format!(
+        "CREATE TABLE {} (\
+         Version INT64 NOT NULL, \
+         Description STRING(MAX), \
+         Schema ARRAY<STRING(MAX)>, \
+         AppliedAt TIMESTAMP NOT NULL OPTIONS (allow_commit_timestamp = true)\
+         ) PRIMARY KEY (Version)",
+        table
+    )
+}
+
+// Returns whether `statement` is the `CREATE TABLE` statement of `table`.
+fn creates_table(statement: &str, table: &str) -> bool {
+    let mut words = statement.split_whitespace();
+    match (words.next(), words.next(), words.next()) {
+        (Some(create), Some(keyword), Some(name)) => {
+            let name = name.split('(').next().unwrap().trim_matches('`');
+            create.eq_ignore_ascii_case("CREATE")
+                && keyword.eq_ignore_ascii_case("TABLE")
+                && name.eq_ignore_ascii_case(table)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        google::{
+            longrunning::operations_server::OperationsServer,
+            spanner::{
+                admin::database::v1::database_admin_server::{DatabaseAdmin, DatabaseAdminServer},
+                v1::{spanner_server::SpannerServer, struct_type::Field, Type, TypeCode},
+            },
+        },
+        spanner::{
+            fake::{FakeDatabaseAdmin, FakeSpanner},
+            session::PoolSettings,
+        },
+    };
+    use std::{sync::Arc, time::Duration};
+    use tokio::net::TcpListener;
+    use tonic::{
+        transport::{Channel, Server},
+        Request,
+    };
+
+    const DATABASE: &str = "projects/p/instances/i/databases/d";
+
+    fn field(name: &str, ty: Type) -> Field {
+        Field {
+            name: name.into(),
+            r#type: Some(ty),
+        }
+    }
+
+    fn ty(code: TypeCode) -> Type {
+        Type {
+            code: code as i32,
+            ..Default::default()
+        }
+    }
+
+    async fn migrator(admin: &Arc<FakeDatabaseAdmin>) -> Migrator<Channel> {
+        let spanner = Arc::new(FakeSpanner::default());
+        let strings = Type {
+            code: TypeCode::Array as i32,
+            array_element_type: Some(Box::new(ty(TypeCode::String))),
+            ..Default::default()
+        };
+        spanner.create_table(
+            DEFAULT_TABLE,
+            vec![
+                field("Version", ty(TypeCode::Int64)),
+                field("Description", ty(TypeCode::String)),
+                field("Schema", strings),
+            ],
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            Server::builder()
+                .add_service(SpannerServer::new(spanner))
+                .add_service(DatabaseAdminServer::new(admin.clone()))
+                .add_service(OperationsServer::new(admin.clone()))
+                .serve_with_incoming(listener),
+        );
+        let channel = Channel::from_shared(format!("http://{}", addr))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        let pool = SessionPool::new(
+            crate::google::spanner::v1::spanner_client::SpannerClient::new(channel.clone()),
+            DATABASE,
+            PoolSettings::default().min_opened(1).max_opened(1),
+        )
+        .await
+        .unwrap();
+        Migrator::new(
+            DatabaseAdminClient::new(channel.clone()),
+            OperationsClient::new(channel),
+            pool,
+        )
+        .poll_settings(PollSettings::default().delay(
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+            1.0,
+        ))
+    }
+
+    fn migrations(migrator: Migrator<Channel>) -> Migrator<Channel> {
+        migrator
+            .migration(Migration::new(2, "index").statement("CREATE INDEX I ON T(A)"))
+            .migration(
+                Migration::new(1, "table")
+                    .statement("CREATE TABLE T (A INT64) PRIMARY KEY (A)")
+                    .statement("CREATE TABLE U (B INT64) PRIMARY KEY (B)"),
+            )
+    }
+
+    #[tokio::test]
+    async fn test_migrate() {
+        let admin = Arc::new(FakeDatabaseAdmin::default());
+        let mut migrator = migrations(migrator(&admin).await);
+
+        let pending: Vec<_> = migrator
+            .pending()
+            .await
+            .unwrap()
+            .iter()
+            .map(|m| m.version())
+            .collect();
+        assert_eq!(pending, vec![1, 2]);
+        assert_eq!(admin.updates(), 0);
+
+        let mut progress = Vec::new();
+        let versions = migrator
+            .migrate_with_progress(|m, metadata| {
+                progress.push((m.version(), metadata.commit_timestamps.len()))
+            })
+            .await
+            .unwrap();
+        assert_eq!(versions, vec![1, 2]);
+        // The migrations table is created with the first migration.
+        assert_eq!(progress, vec![(1, 3), (2, 1)]);
+
+        let ddl = admin.ddl();
+        assert!(creates_table(&ddl[0], DEFAULT_TABLE));
+        assert_eq!(
+            ddl[1..],
+            [
+                "CREATE TABLE T (A INT64) PRIMARY KEY (A)",
+                "CREATE TABLE U (B INT64) PRIMARY KEY (B)",
+                "CREATE INDEX I ON T(A)"
+            ]
+        );
+
+        let applied = migrator.applied().await.unwrap();
+        assert_eq!(applied.len(), 2);
+        assert_eq!(applied[0].version, 1);
+        assert_eq!(applied[0].description.as_deref(), Some("table"));
+        assert_eq!(applied[0].schema, ddl[..3]);
+        assert_eq!(applied[1].schema, ddl);
+
+        assert!(migrator.pending().await.unwrap().is_empty());
+        assert_eq!(migrator.migrate().await.unwrap(), Vec::<i64>::new());
+        assert_eq!(admin.updates(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_drift() {
+        let admin = Arc::new(FakeDatabaseAdmin::default());
+        let mut migrator = migrations(migrator(&admin).await);
+        migrator.migrate().await.unwrap();
+        assert!(migrator.drift().await.unwrap().is_empty());
+
+        let mut ddl = admin.ddl();
+        ddl.retain(|s| !s.starts_with("CREATE INDEX"));
+        ddl.push("CREATE INDEX J ON U(B)".into());
+        admin.set_ddl(ddl);
+        assert_eq!(
+            migrator.drift().await.unwrap(),
+            Drift {
+                added: vec!["CREATE INDEX J ON U(B)".into()],
+                removed: vec!["CREATE INDEX I ON T(A)".into()],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_migration() {
+        let admin = Arc::new(FakeDatabaseAdmin::default());
+        let mut migrator = migrations(migrator(&admin).await)
+            .migration(Migration::new(3, "invalid").statement("INVALID"));
+
+        match migrator.migrate().await {
+            Err(Error::Operation { version: 3, .. }) => {}
+            r => panic!("unexpected result: {:?}", r),
+        }
+        let applied: Vec<_> = migrator
+            .applied()
+            .await
+            .unwrap()
+            .iter()
+            .map(|m| m.version)
+            .collect();
+        assert_eq!(applied, vec![1, 2]);
+
+        let migrator = migrator.migration(Migration::new(1, "duplicate"));
+        assert!(matches!(
+            migrator.pending().await,
+            Err(Error::DuplicateVersion(1))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_operation_already_exists() {
+        let admin = Arc::new(FakeDatabaseAdmin::default());
+        let mut migrator = migrator(&admin).await;
+        let migration =
+            Migration::new(1, "table").statement("CREATE TABLE T (A INT64) PRIMARY KEY (A)");
+
+        // Another migrator started the operation of the migration.
+        let request = UpdateDatabaseDdlRequest {
+            database: DATABASE.into(),
+            statements: vec![
+                create_table_statement(DEFAULT_TABLE),
+                migration.statements()[0].clone(),
+            ],
+            operation_id: migration.operation_id(DEFAULT_TABLE),
+        };
+        admin
+            .update_database_ddl(Request::new(request))
+            .await
+            .unwrap();
+
+        migrator = migrator.migration(migration);
+        assert_eq!(migrator.migrate().await.unwrap(), vec![1]);
+        assert_eq!(admin.updates(), 1);
+        assert_eq!(migrator.applied().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_operation_id() {
+        let migration = Migration::new(12, "").statement("CREATE INDEX I ON T(A)");
+        let id = migration.operation_id("Schema-Migrations");
+        assert!(id.starts_with("m_schema_migrations_12_"));
+        assert!(id
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_'));
+        assert_ne!(
+            id,
+            migration
+                .clone()
+                .statement("x")
+                .operation_id("Schema-Migrations")
+        );
+
+        let id = Migration::new(-12, "").operation_id("T");
+        assert!(id.starts_with("m_t_n12_"));
+        let id = Migration::new(i64::MIN, "").operation_id("T");
+        assert!(id.starts_with("m_t_n9223372036854775808_"));
+    }
+}
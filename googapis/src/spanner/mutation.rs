@@ -0,0 +1,339 @@
+//! Builders of mutations and of the key sets they delete.
+//!
+//! Values are encoded with [`ToSpannerValue`], and rows and keys with [`ToSpannerRow`]:
+//!
+//! ```ignore
+//! use std::ops::Bound;
+//! use googapis::spanner::mutation::{self, KeySetBuilder};
+//!
+//! tx.buffer_write(vec![
+//!     mutation::insert("Singers")
+//!         .set("SingerId", 1)
+//!         .set("FirstName", "Marc")
+//!         .build(),
+//!     mutation::insert_or_update("Albums")
+//!         .columns(&["SingerId", "AlbumId", "Title"])
+//!         .row((1, 1, "Total Junk"))
+//!         .row((1, 2, "Go, Go, Go"))
+//!         .build(),
+//!     mutation::delete(
+//!         "Singers",
+//!         KeySetBuilder::new()
+//!             .key((2,))
+//!             .range(Bound::Included((10,)), Bound::Excluded((20,)))
+//!             .build(),
+//!     ),
+//! ]);
+//! ```
+
+use std::ops::Bound;
+
+use prost_types::ListValue;
+
+use super::{row::ToSpannerRow, value::ToSpannerValue};
+use crate::google::spanner::v1::{
+    key_range::{EndKeyType, StartKeyType},
+    mutation::{self, Operation},
+    KeyRange, KeySet, Mutation,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Insert,
+    Update,
+    InsertOrUpdate,
+    Replace,
+}
+
+/// A builder of a mutation writing rows of a table.
+#[derive(Debug, Clone)]
+pub struct WriteBuilder {
+    kind: Kind,
+    write: mutation::Write,
+}
+
+/// Inserts rows, the mutation fails if a row already exists.
+pub fn insert(table: impl Into<String>) -> WriteBuilder {
+    WriteBuilder::new(Kind::Insert, table.into())
+}
+
+/// Updates existing rows, the mutation fails if a row does not exist.
+pub fn update(table: impl Into<String>) -> WriteBuilder {
+    WriteBuilder::new(Kind::Update, table.into())
+}
+
+/// Inserts rows, or updates the written columns of the rows that already exist.
+pub fn insert_or_update(table: impl Into<String>) -> WriteBuilder {
+    WriteBuilder::new(Kind::InsertOrUpdate, table.into())
+}
+
+/// Inserts rows, or replaces the rows that already exist, the columns that are not written are
+/// set to null.
+pub fn replace(table: impl Into<String>) -> WriteBuilder {
+    WriteBuilder::new(Kind::Replace, table.into())
+}
+
+/// Deletes the rows of a table in `key_set`, see [`KeySetBuilder`].
+pub fn delete(table: impl Into<String>, key_set: KeySet) -> Mutation {
+    Mutation {
+        operation: Some(Operation::Delete(mutation::Delete {
+            table: table.into(),
+            key_set: Some(key_set),
+        })),
+    }
+}
+
+impl WriteBuilder {
+    fn new(kind: Kind, table: String) -> Self {
+        Self {
+            kind,
+            write: mutation::Write {
+                table,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// The columns of the rows added with [`row`](Self::row).
+    pub fn columns<I>(self, columns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let columns = columns.into_iter().map(|c| c.as_ref().to_owned()).collect();
+        Self {
+            write: mutation::Write {
+                columns,
+                ..self.write
+            },
+            ..self
+        }
+    }
+
+    /// Adds a row of the values of the columns, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values is not the number of columns.
+    pub fn row(mut self, row: impl ToSpannerRow) -> Self {
+        let row = row.to_spanner_row();
+        assert_eq!(
+            row.values.len(),
+            self.write.columns.len(),
+            "the number of values of a row must be the number of columns"
+        );
+        self.write.values.push(row);
+        self
+    }
+
+    /// Writes `value` to `column`, for mutations writing a single row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if rows were added with [`row`](Self::row).
+    pub fn set(mut self, column: impl Into<String>, value: impl ToSpannerValue) -> Self {
+        assert!(
+            self.write.values.len() <= 1 && self.write.columns.len() == self.row_len(),
+            "set cannot be combined with row"
+        );
+        if self.write.values.is_empty() {
+            self.write.values.push(ListValue::default());
+        }
+        self.write.columns.push(column.into());
+        self.write.values[0].values.push(value.to_spanner_value());
+        self
+    }
+
+    fn row_len(&self) -> usize {
+        self.write.values.first().map_or(0, |row| row.values.len())
+    }
+
+    pub fn build(self) -> Mutation {
+        let operation = match self.kind {
+            Kind::Insert => Operation::Insert(self.write),
+            Kind::Update => Operation::Update(self.write),
+            Kind::InsertOrUpdate => Operation::InsertOrUpdate(self.write),
+            Kind::Replace => Operation::Replace(self.write),
+        };
+        Mutation {
+            operation: Some(operation),
+        }
+    }
+}
+
+impl From<WriteBuilder> for Mutation {
+    fn from(builder: WriteBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// A builder of a set of keys and key ranges.
+///
+/// Keys are the values of the columns of the primary key, or of the index for reads using an
+/// index, in order. The keys of ranges may be prefixes of the keys, e.g. the range from
+/// `Bound::Included((1,))` to `Bound::Included((1,))` contains all the rows whose first key column
+/// is 1.
+#[derive(Debug, Clone, Default)]
+pub struct KeySetBuilder {
+    key_set: KeySet,
+}
+
+impl KeySetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a key.
+    pub fn key(mut self, key: impl ToSpannerRow) -> Self {
+        self.key_set.keys.push(key.to_spanner_row());
+        self
+    }
+
+    /// Adds a range of keys, which is unbounded on the sides of [`Bound::Unbounded`].
+    pub fn range<S: ToSpannerRow, E: ToSpannerRow>(
+        mut self,
+        start: Bound<S>,
+        end: Bound<E>,
+    ) -> Self {
+        self.key_set.ranges.push(key_range(start, end));
+        self
+    }
+
+    /// Includes all the keys, in addition to the keys and ranges, which are then ignored.
+    pub fn all(self) -> Self {
+        Self {
+            key_set: KeySet {
+                all: true,
+                ..self.key_set
+            },
+        }
+    }
+
+    pub fn build(self) -> KeySet {
+        self.key_set
+    }
+}
+
+impl From<KeySetBuilder> for KeySet {
+    fn from(builder: KeySetBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Returns the range of keys between `start` and `end`.
+///
+/// An unbounded start or end is encoded as an empty closed key, which is a prefix of all keys.
+pub fn key_range<S: ToSpannerRow, E: ToSpannerRow>(start: Bound<S>, end: Bound<E>) -> KeyRange {
+    let start = match start {
+        Bound::Included(key) => StartKeyType::StartClosed(key.to_spanner_row()),
+        Bound::Excluded(key) => StartKeyType::StartOpen(key.to_spanner_row()),
+        Bound::Unbounded => StartKeyType::StartClosed(ListValue::default()),
+    };
+    let end = match end {
+        Bound::Included(key) => EndKeyType::EndClosed(key.to_spanner_row()),
+        Bound::Excluded(key) => EndKeyType::EndOpen(key.to_spanner_row()),
+        Bound::Unbounded => EndKeyType::EndClosed(ListValue::default()),
+    };
+    KeyRange {
+        start_key_type: Some(start),
+        end_key_type: Some(end),
+    }
+}
+
+/// Returns a key set of all the keys.
+pub fn all_keys() -> KeySet {
+    KeySet {
+        all: true,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spanner::value::null;
+    use prost_types::{value::Kind, Value};
+
+    fn string(s: &str) -> Value {
+        Value {
+            kind: Some(Kind::StringValue(s.into())),
+        }
+    }
+
+    fn list(values: Vec<Value>) -> ListValue {
+        ListValue { values }
+    }
+
+    #[test]
+    fn test_write() {
+        let mutation = insert("Singers")
+            .set("SingerId", 1)
+            .set("FirstName", "Marc")
+            .set("LastName", None::<String>)
+            .build();
+        let expected = mutation::Write {
+            table: "Singers".into(),
+            columns: vec!["SingerId".into(), "FirstName".into(), "LastName".into()],
+            values: vec![list(vec![string("1"), string("Marc"), null()])],
+        };
+        assert_eq!(mutation.operation, Some(Operation::Insert(expected)));
+
+        let mutation: Mutation = replace("Albums")
+            .columns(&["SingerId", "AlbumId"])
+            .row((1, 1i64))
+            .row(vec![string("1"), string("2")])
+            .into();
+        match mutation.operation {
+            Some(Operation::Replace(write)) => {
+                assert_eq!(write.columns, vec!["SingerId", "AlbumId"]);
+                assert_eq!(
+                    write.values,
+                    vec![
+                        list(vec![string("1"), string("1")]),
+                        list(vec![string("1"), string("2")]),
+                    ]
+                );
+            }
+            operation => panic!("unexpected operation: {:?}", operation),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "number of columns")]
+    fn test_write_row_length() {
+        update("Singers").columns(&["SingerId"]).row((1, "Marc"));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be combined")]
+    fn test_write_set_after_row() {
+        update("Singers")
+            .columns(&["SingerId"])
+            .row((1,))
+            .row((2,))
+            .set("Name", "Marc");
+    }
+
+    #[test]
+    fn test_key_set() {
+        let key_set = KeySetBuilder::new()
+            .key((1, "a"))
+            .range(Bound::Excluded((1,)), Bound::<()>::Unbounded)
+            .build();
+        assert_eq!(key_set.keys, vec![list(vec![string("1"), string("a")])]);
+        assert_eq!(
+            key_set.ranges,
+            vec![KeyRange {
+                start_key_type: Some(StartKeyType::StartOpen(list(vec![string("1")]))),
+                end_key_type: Some(EndKeyType::EndClosed(list(vec![]))),
+            }]
+        );
+        assert!(!key_set.all);
+
+        let mutation = delete("Singers", all_keys());
+        match mutation.operation {
+            Some(Operation::Delete(delete)) => assert!(delete.key_set.unwrap().all),
+            operation => panic!("unexpected operation: {:?}", operation),
+        }
+    }
+}
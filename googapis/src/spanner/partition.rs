@@ -0,0 +1,171 @@
+//! Queries executed in partitions, e.g. by parallel workers.
+//!
+//! [`SessionPool::partition_query`] begins a read-only transaction and splits a query into
+//! partitions with `PartitionQuery`. The partitions read at the same timestamp and can be
+//! executed concurrently:
+//!
+//! ```ignore
+//! use googapis::{google::spanner::v1::PartitionOptions, spanner::statement::Statement};
+//!
+//! let partitions = pool
+//!     .partition_query(Statement::new("SELECT * FROM Singers"), PartitionOptions::default())
+//!     .await?;
+//! let streams = partitions.partitions().iter().map(|p| partitions.execute(p));
+//! ```
+
+use tonic::Status;
+
+use super::{
+    result_set::ResultSetStream,
+    session::{Session, SessionPool},
+    statement::Statement,
+    transaction::begin_with_mode,
+    Error,
+};
+use crate::google::spanner::v1::{
+    spanner_client::SpannerClient,
+    transaction_options::{self, read_only::TimestampBound},
+    transaction_selector, ExecuteSqlRequest, Partition, PartitionOptions, TransactionSelector,
+};
+
+/// The partitions of a query, which keep their session and read-only transaction until dropped.
+#[derive(Debug)]
+pub struct QueryPartitions<T> {
+    client: SpannerClient<T>,
+    session: Session,
+    transaction_id: Vec<u8>,
+    request: ExecuteSqlRequest,
+    partitions: Vec<Partition>,
+}
+
+impl<T> QueryPartitions<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    pub fn partitions(&self) -> &[Partition] {
+        &self.partitions
+    }
+
+    /// The id of the read-only transaction of the partitions.
+    pub fn transaction_id(&self) -> &[u8] {
+        &self.transaction_id
+    }
+
+    /// Executes the query on `partition` and streams its rows.
+    pub fn execute(&self, partition: &Partition) -> ResultSetStream {
+        let request = ExecuteSqlRequest {
+            session: self.session.name().into(),
+            transaction: Some(selector(&self.transaction_id)),
+            partition_token: partition.partition_token.clone(),
+            ..self.request.clone()
+        };
+        ResultSetStream::execute_sql(self.client.clone(), request)
+    }
+}
+
+fn selector(id: &[u8]) -> TransactionSelector {
+    TransactionSelector {
+        selector: Some(transaction_selector::Selector::Id(id.to_vec())),
+    }
+}
+
+impl<T> SessionPool<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    /// Splits a query into partitions that read at a strong timestamp.
+    ///
+    /// Only queries whose first operator is a distributed union can be partitioned.
+    pub async fn partition_query(
+        &self,
+        statement: Statement,
+        options: PartitionOptions,
+    ) -> Result<QueryPartitions<T>, Error> {
+        let mut session = self.get().await?;
+        // The read-only transaction replaces a prepared read-write transaction.
+        session.take_transaction_id();
+        match self.partition(&session, statement, options).await {
+            Ok((transaction_id, request, partitions)) => Ok(QueryPartitions {
+                client: self.client.clone(),
+                session,
+                transaction_id,
+                request,
+                partitions,
+            }),
+            Err(status) => {
+                if super::is_session_not_found(&status) {
+                    session.not_found();
+                }
+                Err(status.into())
+            }
+        }
+    }
+
+    async fn partition(
+        &self,
+        session: &Session,
+        statement: Statement,
+        options: PartitionOptions,
+    ) -> Result<(Vec<u8>, ExecuteSqlRequest, Vec<Partition>), Status> {
+        let mode = transaction_options::Mode::ReadOnly(transaction_options::ReadOnly {
+            timestamp_bound: Some(TimestampBound::Strong(true)),
+            return_read_timestamp: false,
+        });
+        let id = begin_with_mode(&self.client, session.name(), mode).await?;
+        let request = statement.clone().into();
+        let mut partition_request = statement.into_partition_query_request(options);
+        partition_request.session = session.name().into();
+        partition_request.transaction = Some(selector(&id));
+        let mut client = self.client.clone();
+        let response = client.partition_query(partition_request).await?;
+        Ok((id, request, response.into_inner().partitions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spanner::{fake::FakeSpanner, session::PoolSettings};
+    use futures::TryStreamExt;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_partition_query() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = SessionPool::new(
+            fake.client(),
+            "projects/p/instances/i/databases/d",
+            PoolSettings::default().min_opened(1).max_opened(1),
+        )
+        .await
+        .unwrap();
+
+        let statement = Statement::new("SELECT * FROM Singers WHERE SingerId > @id").param("id", 1);
+        let partitions = pool
+            .partition_query(statement, PartitionOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(partitions.partitions().len(), 2);
+        let mut tokens = Vec::new();
+        for partition in partitions.partitions() {
+            let rows: Vec<_> = partitions.execute(partition).try_collect().await.unwrap();
+            tokens.push(rows[0].get::<String>(0).unwrap());
+        }
+        assert_eq!(tokens, vec!["0", "1"]);
+        assert_eq!(fake.calls("execute_streaming_sql"), 2);
+        assert_eq!(pool.stats().in_use, 1);
+
+        drop(partitions);
+        assert_eq!(pool.stats().in_use, 0);
+    }
+}
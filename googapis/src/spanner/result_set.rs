@@ -0,0 +1,635 @@
+//! Streaming result sets of `ExecuteStreamingSql` and `StreamingRead`.
+//!
+//! The server splits results into `PartialResultSet`s whose last value may be chunked, i.e.
+//! continued in the next one. A [`ResultSetStream`] merges the chunks into complete values, groups
+//! them into [`Row`]s, and reopens the stream from the last resume token after a transient error:
+//!
+//! ```ignore
+//! use futures::TryStreamExt;
+//! use googapis::spanner::result_set::ResultSetStream;
+//!
+//! let mut rows = ResultSetStream::execute_sql(client, request);
+//! while let Some(row) = rows.try_next().await? {
+//!     let (id, name): (i64, Option<String>) = row.decode()?;
+//! }
+//! ```
+//!
+//! Rows are only yielded once the server sent a resume token after them, since the rows after
+//! the last token are sent again when the stream is resumed. When more than 128 MiB are received
+//! without a resume token, the buffered rows are yielded and the stream is not resumed until the
+//! next token.
+
+use std::{
+    collections::{btree_map::Entry, VecDeque},
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use futures::{
+    future::BoxFuture,
+    stream::{BoxStream, Stream},
+    FutureExt, StreamExt,
+};
+use prost::Message;
+use prost_types::{value::Kind, Value};
+use tonic::{Code, Status};
+
+use super::{row::Row, Error};
+use crate::{
+    google::spanner::v1::{
+        spanner_client::SpannerClient, struct_type::Field, ExecuteSqlRequest, PartialResultSet,
+        ReadRequest, ResultSetMetadata, ResultSetStats,
+    },
+    retry::{self, Backoff, RetrySettings},
+};
+
+// The maximum size of the results buffered until a resume token is received.
+const MAX_BUFFERED_BYTES: usize = 128 * 1024 * 1024;
+
+type Open = Box<dyn FnMut(Vec<u8>) -> BoxFuture<'static, Result<PartialStream, Status>> + Send>;
+type PartialStream = BoxStream<'static, Result<PartialResultSet, Status>>;
+
+// Merges chunked values into rows.
+#[derive(Default)]
+struct Merger {
+    fields: Option<Arc<Vec<Field>>>,
+    // The values that do not form a complete row yet, the last one is incomplete if `chunked`.
+    values: Vec<Value>,
+    chunked: bool,
+    // The rows received since the last resume token.
+    pending: Vec<Row>,
+    ready: VecDeque<Row>,
+    // The state at the last resume token.
+    resume_token: Vec<u8>,
+    checkpoint: (Vec<Value>, bool),
+    buffered_bytes: usize,
+    resumable: bool,
+}
+
+impl Merger {
+    fn new() -> Self {
+        Self {
+            resumable: true,
+            ..Default::default()
+        }
+    }
+
+    fn add(&mut self, partial: PartialResultSet) -> Result<(), Status> {
+        let size = partial.encoded_len();
+        if self.fields.is_none() {
+            let row_type = partial.metadata.and_then(|m| m.row_type);
+            let fields = row_type.ok_or_else(|| Status::internal("missing result set metadata"))?;
+            self.fields = Some(Arc::new(fields.fields));
+        }
+
+        let mut values = partial.values.into_iter().peekable();
+        if values.peek().is_some() {
+            if self.chunked {
+                let last = self.values.pop().unwrap();
+                self.values.push(merge(last, values.next().unwrap())?);
+            }
+            self.values.extend(values);
+            self.chunked = partial.chunked_value;
+        }
+
+        let fields = self.fields.clone().unwrap();
+        if !fields.is_empty() {
+            let complete = self.values.len() - self.chunked as usize;
+            let rows = complete / fields.len();
+            let mut values = self.values.drain(..rows * fields.len());
+            for _ in 0..rows {
+                let row = values.by_ref().take(fields.len()).collect();
+                self.pending.push(Row::new(fields.clone(), row));
+            }
+        }
+
+        if !partial.resume_token.is_empty() {
+            self.ready.extend(self.pending.drain(..));
+            self.resume_token = partial.resume_token;
+            self.checkpoint = (self.values.clone(), self.chunked);
+            self.buffered_bytes = 0;
+            self.resumable = true;
+        } else {
+            self.buffered_bytes += size;
+            if self.buffered_bytes > MAX_BUFFERED_BYTES {
+                self.ready.extend(self.pending.drain(..));
+                self.resumable = false;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Status> {
+        if self.chunked || !self.values.is_empty() {
+            return Err(Status::internal("result set ended with an incomplete row"));
+        }
+        self.ready.extend(self.pending.drain(..));
+        Ok(())
+    }
+
+    // Discards what was received after the last resume token.
+    fn reset(&mut self) {
+        self.pending.clear();
+        let (values, chunked) = self.checkpoint.clone();
+        self.values = values;
+        self.chunked = chunked;
+        self.buffered_bytes = 0;
+    }
+}
+
+// Merges a chunked value with its continuation.
+fn merge(first: Value, second: Value) -> Result<Value, Status> {
+    let kind = match (first.kind, second.kind) {
+        (Some(Kind::StringValue(mut a)), Some(Kind::StringValue(b))) => {
+            a.push_str(&b);
+            Kind::StringValue(a)
+        }
+        (Some(Kind::ListValue(mut a)), Some(Kind::ListValue(b))) => {
+            let mut b = b.values.into_iter();
+            if let Some(last) = a.values.pop() {
+                if is_mergeable(&last) {
+                    match b.next() {
+                        Some(next) => a.values.push(merge(last, next)?),
+                        None => a.values.push(last),
+                    }
+                } else {
+                    a.values.push(last);
+                }
+            }
+            a.values.extend(b);
+            Kind::ListValue(a)
+        }
+        (Some(Kind::StructValue(mut a)), Some(Kind::StructValue(b))) => {
+            for (name, value) in b.fields {
+                match a.fields.entry(name) {
+                    Entry::Occupied(mut entry) => {
+                        let merged = merge(mem::take(entry.get_mut()), value)?;
+                        entry.insert(merged);
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(value);
+                    }
+                }
+            }
+            Kind::StructValue(a)
+        }
+        _ => {
+            return Err(Status::internal(
+                "cannot merge chunked values of different kinds",
+            ))
+        }
+    };
+    Ok(Value { kind: Some(kind) })
+}
+
+fn is_mergeable(value: &Value) -> bool {
+    matches!(
+        value.kind,
+        Some(Kind::StringValue(_)) | Some(Kind::ListValue(_)) | Some(Kind::StructValue(_))
+    )
+}
+
+// Returns whether the stream can be resumed after failing with `status`.
+fn is_resumable(settings: &RetrySettings, status: &Status) -> bool {
+    settings.is_retryable(status)
+        || status.code() == Code::Internal
+            && (status.message().contains("RST_STREAM")
+                || status.message().contains("unexpected EOS"))
+}
+
+enum State {
+    Opening(BoxFuture<'static, Result<PartialStream, Status>>),
+    Streaming(PartialStream),
+    Waiting(BoxFuture<'static, ()>),
+    Done,
+}
+
+/// A stream of the rows of a query or read, see the [module documentation](self).
+pub struct ResultSetStream {
+    open: Open,
+    state: State,
+    merger: Merger,
+    settings: RetrySettings,
+    backoff: Backoff,
+    attempts: usize,
+    metadata: Option<ResultSetMetadata>,
+    stats: Option<ResultSetStats>,
+}
+
+impl ResultSetStream {
+    /// Creates a stream that calls `open` with the last resume token, which is empty at first, to
+    /// open or resume the stream of `PartialResultSet`s.
+    pub fn new<F, Fut, S>(mut open: F) -> Self
+    where
+        F: FnMut(Vec<u8>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<S, Status>> + Send + 'static,
+        S: Stream<Item = Result<PartialResultSet, Status>> + Send + 'static,
+    {
+        let mut open: Open = Box::new(move |token| {
+            open(token)
+                .map(|result| result.map(StreamExt::boxed))
+                .boxed()
+        });
+        let settings = RetrySettings::default();
+        Self {
+            state: State::Opening(open(Vec::new())),
+            open,
+            merger: Merger::new(),
+            backoff: settings.backoff_iter(),
+            settings,
+            attempts: 0,
+            metadata: None,
+            stats: None,
+        }
+    }
+
+    /// Executes a query with `ExecuteStreamingSql`.
+    pub fn execute_sql<T>(client: SpannerClient<T>, request: ExecuteSqlRequest) -> Self
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+        T::Future: Send,
+        T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+        T::Error: Into<tonic::codegen::StdError>,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+    {
+        Self::new(move |resume_token| {
+            let mut client = client.clone();
+            let request = ExecuteSqlRequest {
+                resume_token,
+                ..request.clone()
+            };
+            async move {
+                let response = client.execute_streaming_sql(request).await?;
+                Ok(response.into_inner())
+            }
+        })
+    }
+
+    /// Reads rows with `StreamingRead`.
+    pub fn read<T>(client: SpannerClient<T>, request: ReadRequest) -> Self
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+        T::Future: Send,
+        T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+        T::Error: Into<tonic::codegen::StdError>,
+        <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+    {
+        Self::new(move |resume_token| {
+            let mut client = client.clone();
+            let request = ReadRequest {
+                resume_token,
+                ..request.clone()
+            };
+            async move {
+                let response = client.streaming_read(request).await?;
+                Ok(response.into_inner())
+            }
+        })
+    }
+
+    /// The codes on which the stream is resumed, and the backoff between attempts. The attempts
+    /// are counted from the last received result.
+    pub fn retry_settings(self, settings: RetrySettings) -> Self {
+        Self {
+            backoff: settings.backoff_iter(),
+            settings,
+            ..self
+        }
+    }
+
+    /// The metadata of the result set, available once the first result is received.
+    pub fn metadata(&self) -> Option<&ResultSetMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// The statistics of the result set, available at the end of the stream. They include the
+    /// number of modified rows of DML statements, and the query plan when requested.
+    pub fn stats(&self) -> Option<&ResultSetStats> {
+        self.stats.as_ref()
+    }
+
+    fn add(&mut self, mut partial: PartialResultSet) -> Result<(), Status> {
+        if self.metadata.is_none() {
+            self.metadata = partial.metadata.clone();
+        }
+        if let Some(stats) = partial.stats.take() {
+            self.stats = Some(stats);
+        }
+        self.attempts = 0;
+        self.backoff = self.settings.backoff_iter();
+        self.merger.add(partial)
+    }
+
+    // Schedules the resumption of the stream, or returns the error if it cannot be resumed.
+    fn fail(&mut self, status: Status) -> Option<Error> {
+        self.attempts += 1;
+        let exhausted = matches!(self.settings.max_attempts, Some(max) if self.attempts >= max);
+        if !self.merger.resumable || exhausted || !is_resumable(&self.settings, &status) {
+            self.state = State::Done;
+            return Some(Error::Status(status));
+        }
+        self.merger.reset();
+        let delay = retry::retry_delay(&status).unwrap_or_else(|| self.backoff.next().unwrap());
+        self.state = State::Waiting(tokio::time::delay_for(delay).boxed());
+        None
+    }
+}
+
+impl Stream for ResultSetStream {
+    type Item = Result<Row, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(row) = this.merger.ready.pop_front() {
+                return Poll::Ready(Some(Ok(row)));
+            }
+            match &mut this.state {
+                State::Opening(open) => match open.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Ok(stream)) => this.state = State::Streaming(stream),
+                    Poll::Ready(Err(status)) => {
+                        if let Some(e) = this.fail(status) {
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                },
+                State::Streaming(stream) => match stream.as_mut().poll_next(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Some(Ok(partial))) => {
+                        if let Err(status) = this.add(partial) {
+                            this.state = State::Done;
+                            return Poll::Ready(Some(Err(Error::Status(status))));
+                        }
+                    }
+                    Poll::Ready(Some(Err(status))) => {
+                        if let Some(e) = this.fail(status) {
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                    Poll::Ready(None) => {
+                        this.state = State::Done;
+                        if let Err(status) = this.merger.finish() {
+                            return Poll::Ready(Some(Err(Error::Status(status))));
+                        }
+                    }
+                },
+                State::Waiting(delay) => match delay.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(()) => {
+                        let token = this.merger.resume_token.clone();
+                        this.state = State::Opening((this.open)(token));
+                    }
+                },
+                State::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for ResultSetStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResultSetStream")
+            .field("metadata", &self.metadata)
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google::spanner::v1::{StructType, Type, TypeCode};
+    use futures::TryStreamExt;
+    use prost_types::{ListValue, Struct};
+    use std::sync::Mutex;
+
+    fn string(s: &str) -> Value {
+        Value {
+            kind: Some(Kind::StringValue(s.into())),
+        }
+    }
+
+    fn list(values: Vec<Value>) -> Value {
+        Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        }
+    }
+
+    fn object(fields: Vec<(&str, Value)>) -> Value {
+        let fields = fields.into_iter().map(|(k, v)| (k.into(), v)).collect();
+        Value {
+            kind: Some(Kind::StructValue(Struct { fields })),
+        }
+    }
+
+    fn boolean(b: bool) -> Value {
+        Value {
+            kind: Some(Kind::BoolValue(b)),
+        }
+    }
+
+    fn metadata(columns: &[&str]) -> ResultSetMetadata {
+        let fields = columns
+            .iter()
+            .map(|name| Field {
+                name: name.to_string(),
+                r#type: Some(Type {
+                    code: TypeCode::String as i32,
+                    ..Default::default()
+                }),
+            })
+            .collect();
+        ResultSetMetadata {
+            row_type: Some(StructType { fields }),
+            ..Default::default()
+        }
+    }
+
+    fn partial(values: Vec<Value>, chunked_value: bool, resume_token: &str) -> PartialResultSet {
+        PartialResultSet {
+            values,
+            chunked_value,
+            resume_token: resume_token.into(),
+            ..Default::default()
+        }
+    }
+
+    fn with_metadata(mut partial: PartialResultSet, columns: &[&str]) -> PartialResultSet {
+        partial.metadata = Some(metadata(columns));
+        partial
+    }
+
+    // Returns a stream that replays the responses of each call of `open`, and records the resume
+    // tokens it was called with.
+    fn stream(
+        calls: Vec<Vec<Result<PartialResultSet, Status>>>,
+        tokens: Arc<Mutex<Vec<Vec<u8>>>>,
+    ) -> ResultSetStream {
+        let mut calls = calls.into_iter();
+        ResultSetStream::new(move |token| {
+            tokens.lock().unwrap().push(token);
+            let responses = calls.next().expect("unexpected call");
+            async move { Ok(futures::stream::iter(responses)) }
+        })
+        .retry_settings(RetrySettings::default().backoff(
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_millis(1),
+            1.0,
+        ))
+    }
+
+    fn strings(rows: &[Row]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| (0..row.len()).map(|i| row.get(i).unwrap()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_merge() {
+        // The examples of the documentation of `PartialResultSet.chunked_value`.
+        let cases = vec![
+            (string("foo"), string("bar"), string("foobar")),
+            (
+                list(vec![boolean(true), string("a")]),
+                list(vec![string("b"), boolean(false)]),
+                list(vec![boolean(true), string("ab"), boolean(false)]),
+            ),
+            (
+                list(vec![boolean(true), list(vec![string("a")])]),
+                list(vec![list(vec![string("b"), string("c")])]),
+                list(vec![boolean(true), list(vec![string("ab"), string("c")])]),
+            ),
+            (
+                list(vec![boolean(true)]),
+                list(vec![boolean(false)]),
+                list(vec![boolean(true), boolean(false)]),
+            ),
+            (
+                object(vec![("a", string("1")), ("b", string("2"))]),
+                object(vec![("b", string("3")), ("c", string("4"))]),
+                object(vec![
+                    ("a", string("1")),
+                    ("b", string("23")),
+                    ("c", string("4")),
+                ]),
+            ),
+        ];
+        for (a, b, merged) in cases {
+            assert_eq!(merge(a, b).unwrap(), merged);
+        }
+        assert!(merge(boolean(true), boolean(false)).is_err());
+        assert!(merge(string("a"), list(vec![])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_chunked_rows() {
+        let tokens = Arc::default();
+        let rows: Vec<Row> = stream(
+            vec![vec![
+                Ok(with_metadata(
+                    partial(vec![string("Hello"), string("W")], true, ""),
+                    &["a", "b"],
+                )),
+                Ok(partial(vec![string("orl")], true, "")),
+                Ok(partial(vec![string("d"), string("x")], false, "1")),
+                Ok(partial(vec![string("y")], false, "")),
+            ]],
+            tokens,
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(strings(&rows), vec![vec!["Hello", "World"], vec!["x", "y"]]);
+    }
+
+    #[tokio::test]
+    async fn test_resume() {
+        let tokens = Arc::new(Mutex::new(Vec::new()));
+        let mut rows = stream(
+            vec![
+                vec![
+                    Ok(with_metadata(
+                        partial(vec![string("1"), string("2")], false, "t1"),
+                        &["a"],
+                    )),
+                    Ok(partial(vec![string("3"), string("4")], true, "")),
+                    Err(Status::unavailable("connection reset")),
+                ],
+                vec![
+                    Ok(with_metadata(
+                        partial(vec![string("3"), string("4")], false, "t2"),
+                        &["a"],
+                    )),
+                    Ok(partial(vec![string("5")], false, "")),
+                ],
+            ],
+            tokens.clone(),
+        );
+        let mut received = Vec::new();
+        while let Some(row) = rows.try_next().await.unwrap() {
+            received.push(row);
+        }
+        assert_eq!(
+            strings(&received),
+            vec![vec!["1"], vec!["2"], vec!["3"], vec!["4"], vec!["5"]]
+        );
+        assert_eq!(*tokens.lock().unwrap(), vec![b"".to_vec(), b"t1".to_vec()]);
+        assert!(rows.metadata().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_non_resumable_error() {
+        let tokens = Arc::default();
+        let result: Result<Vec<Row>, Error> = stream(
+            vec![vec![
+                Ok(with_metadata(
+                    partial(vec![string("1")], false, "t1"),
+                    &["a"],
+                )),
+                Err(Status::invalid_argument("bad query")),
+            ]],
+            tokens,
+        )
+        .try_collect()
+        .await;
+        match result {
+            Err(Error::Status(status)) => assert_eq!(status.code(), Code::InvalidArgument),
+            _ => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_chunk() {
+        let tokens = Arc::default();
+        let mut rows = stream(
+            vec![vec![
+                Ok(with_metadata(
+                    partial(vec![boolean(true)], true, ""),
+                    &["a"],
+                )),
+                Ok(partial(vec![boolean(false)], false, "t1")),
+            ]],
+            tokens,
+        );
+        assert!(rows.try_next().await.is_err());
+        assert!(rows.try_next().await.unwrap().is_none());
+
+        let tokens = Arc::default();
+        let result: Result<Vec<Row>, Error> = stream(
+            vec![vec![Ok(with_metadata(
+                partial(vec![string("a")], true, ""),
+                &["a"],
+            ))]],
+            tokens,
+        )
+        .try_collect()
+        .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,289 @@
+//! Rows of query and read results, and their conversions from and to Rust types.
+
+use std::sync::Arc;
+
+use prost_types::{ListValue, Value};
+
+use super::value::{Error, FromSpannerValue, ToSpannerValue};
+use crate::google::spanner::v1::struct_type::Field;
+
+/// A row of a result set, or the value of a `STRUCT`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    fields: Arc<Vec<Field>>,
+    values: Vec<Value>,
+}
+
+impl Row {
+    pub(crate) fn new(fields: Arc<Vec<Field>>, values: Vec<Value>) -> Self {
+        Self { fields, values }
+    }
+
+    /// The number of columns.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The names and types of the columns.
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// The raw values of the columns.
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// Returns the index of the first column named `name`.
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|f| f.name == name)
+    }
+
+    /// Decodes the value of the column at `index`.
+    pub fn get<T: FromSpannerValue>(&self, index: usize) -> Result<T, Error> {
+        let (field, value) = match (self.fields.get(index), self.values.get(index)) {
+            (Some(field), Some(value)) => (field, value),
+            _ => return Err(Error::new(format!("no column at index {}", index))),
+        };
+        let column = if field.name.is_empty() {
+            index.to_string()
+        } else {
+            field.name.clone()
+        };
+        let ty = field
+            .r#type
+            .as_ref()
+            .ok_or_else(|| Error::new("missing type").in_column(&column))?;
+        T::from_spanner_value(value, ty).map_err(|e| e.in_column(&column))
+    }
+
+    /// Decodes the value of the first column named `name`.
+    pub fn get_by_name<T: FromSpannerValue>(&self, name: &str) -> Result<T, Error> {
+        let index = self
+            .column_index(name)
+            .ok_or_else(|| Error::new(format!("no column named {}", name)))?;
+        self.get(index)
+    }
+
+    /// Decodes the row.
+    pub fn decode<T: FromSpannerRow>(&self) -> Result<T, Error> {
+        T::from_row(self)
+    }
+}
+
+/// A Rust type that a row can be decoded to.
+///
+/// It is implemented for tuples of up to 8 [`FromSpannerValue`]s, decoded from the columns in
+/// order, and can be implemented for structs by decoding their fields by name:
+///
+/// ```ignore
+/// struct Singer {
+///     id: i64,
+///     name: Option<String>,
+/// }
+///
+/// impl FromSpannerRow for Singer {
+///     fn from_row(row: &Row) -> Result<Self, Error> {
+///         Ok(Singer {
+///             id: row.get_by_name("SingerId")?,
+///             name: row.get_by_name("Name")?,
+///         })
+///     }
+/// }
+/// ```
+///
+/// Values of `STRUCT` columns decode to a [`Row`], which can then be decoded with
+/// [`Row::decode`].
+pub trait FromSpannerRow: Sized {
+    fn from_row(row: &Row) -> Result<Self, Error>;
+}
+
+impl FromSpannerRow for Row {
+    fn from_row(row: &Row) -> Result<Self, Error> {
+        Ok(row.clone())
+    }
+}
+
+macro_rules! tuple_from_row {
+    ($len:expr, $($index:tt $name:ident),+) => {
+        impl<$($name: FromSpannerValue),+> FromSpannerRow for ($($name,)+) {
+            fn from_row(row: &Row) -> Result<Self, Error> {
+                if row.len() != $len {
+                    return Err(Error::new(format!(
+                        "cannot decode a row of {} columns as a tuple of {}",
+                        row.len(),
+                        $len
+                    )));
+                }
+                Ok(($(row.get::<$name>($index)?,)+))
+            }
+        }
+    };
+}
+
+tuple_from_row!(1, 0 A);
+tuple_from_row!(2, 0 A, 1 B);
+tuple_from_row!(3, 0 A, 1 B, 2 C);
+tuple_from_row!(4, 0 A, 1 B, 2 C, 3 D);
+tuple_from_row!(5, 0 A, 1 B, 2 C, 3 D, 4 E);
+tuple_from_row!(6, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+tuple_from_row!(7, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+tuple_from_row!(8, 0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+
+/// A Rust type that can be encoded as the values of a row, or of the columns of a key.
+///
+/// It is implemented for tuples of up to 8 [`ToSpannerValue`]s, e.g. `(1, "Marc")`, and for
+/// already encoded values.
+pub trait ToSpannerRow {
+    fn to_spanner_row(&self) -> ListValue;
+}
+
+impl ToSpannerRow for Row {
+    fn to_spanner_row(&self) -> ListValue {
+        ListValue {
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl ToSpannerRow for ListValue {
+    fn to_spanner_row(&self) -> ListValue {
+        self.clone()
+    }
+}
+
+impl ToSpannerRow for Vec<Value> {
+    fn to_spanner_row(&self) -> ListValue {
+        ListValue {
+            values: self.clone(),
+        }
+    }
+}
+
+impl ToSpannerRow for () {
+    fn to_spanner_row(&self) -> ListValue {
+        ListValue::default()
+    }
+}
+
+macro_rules! tuple_to_row {
+    ($($index:tt $name:ident),+) => {
+        impl<$($name: ToSpannerValue),+> ToSpannerRow for ($($name,)+) {
+            fn to_spanner_row(&self) -> ListValue {
+                ListValue {
+                    values: vec![$(self.$index.to_spanner_value()),+],
+                }
+            }
+        }
+    };
+}
+
+tuple_to_row!(0 A);
+tuple_to_row!(0 A, 1 B);
+tuple_to_row!(0 A, 1 B, 2 C);
+tuple_to_row!(0 A, 1 B, 2 C, 3 D);
+tuple_to_row!(0 A, 1 B, 2 C, 3 D, 4 E);
+tuple_to_row!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+tuple_to_row!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+tuple_to_row!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::google::spanner::v1::{StructType, Type, TypeCode};
+    use prost_types::value::Kind;
+
+    fn field(name: &str, ty: Type) -> Field {
+        Field {
+            name: name.into(),
+            r#type: Some(ty),
+        }
+    }
+
+    fn ty(code: TypeCode) -> Type {
+        Type {
+            code: code as i32,
+            ..Default::default()
+        }
+    }
+
+    fn string(s: &str) -> Value {
+        Value {
+            kind: Some(Kind::StringValue(s.into())),
+        }
+    }
+
+    fn list(values: Vec<Value>) -> Value {
+        Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Album {
+        title: String,
+        year: i64,
+    }
+
+    impl FromSpannerRow for Album {
+        fn from_row(row: &Row) -> Result<Self, Error> {
+            Ok(Album {
+                title: row.get_by_name("Title")?,
+                year: row.get_by_name("Year")?,
+            })
+        }
+    }
+
+    #[test]
+    fn test_decode() {
+        let album = Type {
+            code: TypeCode::Struct as i32,
+            struct_type: Some(StructType {
+                fields: vec![
+                    field("Title", ty(TypeCode::String)),
+                    field("Year", ty(TypeCode::Int64)),
+                ],
+            }),
+            ..Default::default()
+        };
+        let albums = Type {
+            code: TypeCode::Array as i32,
+            array_element_type: Some(Box::new(album)),
+            ..Default::default()
+        };
+        let row = Row::new(
+            Arc::new(vec![
+                field("SingerId", ty(TypeCode::Int64)),
+                field("Albums", albums),
+            ]),
+            vec![
+                string("1"),
+                list(vec![
+                    list(vec![string("Go"), string("2019")]),
+                    list(vec![string("Stop"), string("x")]),
+                ]),
+            ],
+        );
+
+        let (id, albums): (i64, Vec<Row>) = row.decode().unwrap();
+        assert_eq!(id, 1);
+        assert_eq!(
+            albums[0].decode::<Album>(),
+            Ok(Album {
+                title: "Go".into(),
+                year: 2019
+            })
+        );
+        assert_eq!(
+            albums[1].decode::<Album>().unwrap_err().to_string(),
+            "column Year: invalid INT64: \"x\""
+        );
+        assert_eq!(row.get_by_name::<i64>("SingerId"), Ok(1));
+        assert!(row.decode::<(i64,)>().is_err());
+        assert!(row.get_by_name::<i64>("Name").is_err());
+    }
+}
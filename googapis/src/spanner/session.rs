@@ -0,0 +1,875 @@
+//! A pool of Spanner sessions.
+//!
+//! Creating a session is expensive, so sessions are created up front and reused. The pool keeps
+//! at least `min_opened` and at most `max_opened` sessions open. A background task
+//! - pings idle sessions with `SELECT 1` before the server deletes them after an hour of
+//!   inactivity, and replaces the sessions the server reports as deleted,
+//! - deletes the idle sessions above `min_opened` that were not used during the last maintenance
+//!   interval,
+//! - begins read-write transactions on a fraction of the idle sessions so that
+//!   [`SessionPool::get_write`] can skip the `BeginTransaction` round trip.
+//!
+//! A checked out [`Session`] is returned to the pool when it is dropped. Sessions that are
+//! checked out for too long can be found with [`SessionPool::leaked_sessions`].
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    sync::{Arc, Mutex, Weak},
+    time::{Duration, Instant},
+};
+
+use tokio::sync::{mpsc, oneshot};
+use tonic::{Code, Status};
+
+use super::{is_session_not_found, Error};
+use crate::google::spanner::v1::{
+    spanner_client::SpannerClient, BatchCreateSessionsRequest, DeleteSessionRequest,
+    ExecuteSqlRequest, Session as SessionProto,
+};
+
+// The maximum number of sessions of a batch create sessions call.
+const MAX_BATCH_CREATE: usize = 100;
+
+/// Settings of a [`SessionPool`].
+#[derive(Debug, Clone)]
+pub struct PoolSettings {
+    min_opened: usize,
+    max_opened: usize,
+    write_sessions: f64,
+    keep_alive_interval: Duration,
+    maintenance_interval: Duration,
+    acquire_timeout: Option<Duration>,
+    labels: HashMap<String, String>,
+}
+
+impl Default for PoolSettings {
+    fn default() -> Self {
+        Self {
+            min_opened: 100,
+            max_opened: 400,
+            write_sessions: 0.2,
+            keep_alive_interval: Duration::from_secs(50 * 60),
+            maintenance_interval: Duration::from_secs(60),
+            acquire_timeout: Some(Duration::from_secs(60)),
+            labels: HashMap::new(),
+        }
+    }
+}
+
+impl PoolSettings {
+    /// The number of sessions opened when the pool is created and kept open afterwards.
+    pub fn min_opened(self, min_opened: usize) -> Self {
+        Self {
+            min_opened,
+            max_opened: self.max_opened.max(min_opened),
+            ..self
+        }
+    }
+
+    /// The maximum number of open sessions, at least one.
+    pub fn max_opened(self, max_opened: usize) -> Self {
+        let max_opened = max_opened.max(1);
+        Self {
+            min_opened: self.min_opened.min(max_opened),
+            max_opened,
+            ..self
+        }
+    }
+
+    /// The fraction of the open sessions with a prepared read-write transaction, between 0 and 1.
+    pub fn write_sessions(self, fraction: f64) -> Self {
+        Self {
+            write_sessions: fraction.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// How long a session may stay idle before it is pinged.
+    pub fn keep_alive_interval(self, interval: Duration) -> Self {
+        Self {
+            keep_alive_interval: interval,
+            ..self
+        }
+    }
+
+    /// The interval of the background maintenance of the pool.
+    pub fn maintenance_interval(self, interval: Duration) -> Self {
+        Self {
+            maintenance_interval: interval,
+            ..self
+        }
+    }
+
+    /// How long [`SessionPool::get`] waits for a session when `max_opened` are checked out,
+    /// forever if `None`.
+    pub fn acquire_timeout(self, timeout: impl Into<Option<Duration>>) -> Self {
+        Self {
+            acquire_timeout: timeout.into(),
+            ..self
+        }
+    }
+
+    /// The labels of the created sessions.
+    pub fn labels(self, labels: HashMap<String, String>) -> Self {
+        Self { labels, ..self }
+    }
+
+    fn write_target(&self, opened: usize) -> usize {
+        (self.write_sessions * opened as f64) as usize
+    }
+}
+
+/// A snapshot of the sessions of a [`SessionPool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PoolStats {
+    /// The sessions that are open or being created.
+    pub opened: usize,
+    /// The idle sessions, including the write-prepared ones.
+    pub idle: usize,
+    /// The idle sessions with a prepared read-write transaction.
+    pub write_prepared: usize,
+    /// The checked out sessions.
+    pub in_use: usize,
+    /// The callers waiting for a session.
+    pub waiters: usize,
+}
+
+#[derive(Debug)]
+struct Entry {
+    name: String,
+    transaction_id: Option<Vec<u8>>,
+    last_used: Instant,
+}
+
+impl Entry {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            transaction_id: None,
+            last_used: Instant::now(),
+        }
+    }
+}
+
+// The calls made by the background task.
+enum Task {
+    Create(usize),
+    Prepare(Entry),
+    Ping(Entry),
+    Delete(String),
+}
+
+#[derive(Default)]
+struct State {
+    idle: VecDeque<Entry>,
+    idle_write: VecDeque<Entry>,
+    in_use: HashMap<String, Instant>,
+    waiters: VecDeque<oneshot::Sender<Result<Session, Status>>>,
+    // The sessions being created, prepared or pinged.
+    creating: usize,
+    preparing: usize,
+    pinging: usize,
+    closed: bool,
+}
+
+impl State {
+    fn opened(&self) -> usize {
+        self.idle.len()
+            + self.idle_write.len()
+            + self.in_use.len()
+            + self.creating
+            + self.preparing
+            + self.pinging
+    }
+
+    fn waiting(&self) -> usize {
+        self.waiters.iter().filter(|w| !w.is_closed()).count()
+    }
+}
+
+struct Shared {
+    settings: PoolSettings,
+    state: Mutex<State>,
+    tasks: mpsc::UnboundedSender<Task>,
+}
+
+impl fmt::Debug for Shared {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shared")
+            .field("settings", &self.settings)
+            .finish()
+    }
+}
+
+impl Shared {
+    fn send(&self, task: Task) {
+        // The background task only stops once the pool is dropped.
+        let _ = self.tasks.send(task);
+    }
+
+    // Hands `entry` to the next waiter or puts it back into the idle sessions, and begins a
+    // read-write transaction on it if there are fewer write-prepared sessions than wanted.
+    fn release(self: &Arc<Self>, mut entry: Entry, prepare: bool) {
+        let mut state = self.state.lock().unwrap();
+        state.in_use.remove(&entry.name);
+        if state.closed {
+            drop(state);
+            self.send(Task::Delete(entry.name));
+            return;
+        }
+
+        while let Some(waiter) = state.waiters.pop_front() {
+            state.in_use.insert(entry.name.clone(), Instant::now());
+            let session = Session::new(self.clone(), entry);
+            match waiter.send(Ok(session)) {
+                Ok(()) => return,
+                Err(Ok(mut session)) => {
+                    entry = session.entry.take().unwrap();
+                    state.in_use.remove(&entry.name);
+                }
+                Err(Err(_)) => unreachable!(),
+            }
+        }
+
+        if entry.transaction_id.is_some() {
+            state.idle_write.push_back(entry);
+        } else if prepare
+            && state.idle_write.len() + state.preparing < self.settings.write_target(state.opened())
+        {
+            state.preparing += 1;
+            drop(state);
+            self.send(Task::Prepare(entry));
+        } else {
+            state.idle.push_back(entry);
+        }
+    }
+
+    // Creates sessions for the waiters that cannot be served by the sessions being created,
+    // and to keep `min_opened` sessions open.
+    fn replenish(&self, state: &mut State) {
+        if state.closed {
+            return;
+        }
+        let opened = state.opened();
+        let wanted = self
+            .settings
+            .min_opened
+            .max(opened + state.waiting().saturating_sub(state.creating))
+            .min(self.settings.max_opened);
+        if wanted > opened {
+            state.creating += wanted - opened;
+            self.send(Task::Create(wanted - opened));
+        }
+    }
+
+    // Forgets a session that was deleted by the server.
+    fn remove(&self, name: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.in_use.remove(name);
+        self.replenish(&mut state);
+    }
+
+    // Fails the waiters that were waiting for sessions that could not be created.
+    fn create_failed(&self, count: usize, status: &Status) {
+        let mut state = self.state.lock().unwrap();
+        state.creating -= count;
+        let mut failed = 0;
+        while failed < count {
+            match state.waiters.pop_front() {
+                Some(waiter) => {
+                    if waiter.send(Err(status.clone())).is_ok() {
+                        failed += 1;
+                    }
+                }
+                None => break,
+            }
+        }
+    }
+
+    // Returns the calls keeping the pool healthy.
+    fn maintain(&self) -> Vec<Task> {
+        let mut state = self.state.lock().unwrap();
+        let state = &mut *state;
+        if state.closed {
+            return Vec::new();
+        }
+        let now = Instant::now();
+        let mut tasks = Vec::new();
+
+        // Shrink the pool to `min_opened` sessions, starting with the least recently used ones.
+        let unused = |entry: &Entry| entry.last_used + self.settings.maintenance_interval <= now;
+        while state.opened() > self.settings.min_opened {
+            let entry = match state.idle.front() {
+                Some(entry) if unused(entry) => state.idle.pop_front(),
+                _ => match state.idle_write.front() {
+                    Some(entry) if unused(entry) => state.idle_write.pop_front(),
+                    _ => None,
+                },
+            };
+            match entry {
+                Some(entry) => tasks.push(Task::Delete(entry.name)),
+                None => break,
+            }
+        }
+
+        // Ping the sessions that have not been used for a while.
+        let stale = |entry: &Entry| entry.last_used + self.settings.keep_alive_interval <= now;
+        for queue in &mut [&mut state.idle, &mut state.idle_write] {
+            let (stale, fresh): (VecDeque<_>, _) = queue.drain(..).partition(stale);
+            **queue = fresh;
+            tasks.extend(stale.into_iter().map(Task::Ping));
+        }
+        state.pinging += tasks.iter().filter(|t| matches!(t, Task::Ping(_))).count();
+
+        // Begin read-write transactions on idle sessions.
+        let target = self.settings.write_target(state.opened());
+        while state.idle_write.len() + state.preparing < target {
+            match state.idle.pop_front() {
+                Some(entry) => {
+                    state.preparing += 1;
+                    tasks.push(Task::Prepare(entry));
+                }
+                None => break,
+            }
+        }
+
+        self.replenish(state);
+        tasks
+    }
+}
+
+/// A session checked out of a [`SessionPool`], returned to the pool when dropped.
+#[derive(Debug)]
+pub struct Session {
+    shared: Arc<Shared>,
+    entry: Option<Entry>,
+}
+
+impl Session {
+    fn new(shared: Arc<Shared>, entry: Entry) -> Self {
+        Self {
+            shared,
+            entry: Some(entry),
+        }
+    }
+
+    /// The name of the session.
+    pub fn name(&self) -> &str {
+        &self.entry.as_ref().unwrap().name
+    }
+
+    /// Returns the read-write transaction that was begun on the session, if any.
+    pub fn take_transaction_id(&mut self) -> Option<Vec<u8>> {
+        self.entry.as_mut().unwrap().transaction_id.take()
+    }
+
+    /// Deletes the session instead of returning it to the pool, e.g. after an unexpected error.
+    pub fn discard(mut self) {
+        let entry = self.entry.take().unwrap();
+        self.shared.remove(&entry.name);
+        self.shared.send(Task::Delete(entry.name));
+    }
+
+    // Forgets the session after the server reported that it does not exist anymore.
+    pub(crate) fn not_found(mut self) {
+        let entry = self.entry.take().unwrap();
+        self.shared.remove(&entry.name);
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        if let Some(mut entry) = self.entry.take() {
+            entry.last_used = Instant::now();
+            self.shared.release(entry, true);
+        }
+    }
+}
+
+/// A pool of the sessions of a database.
+#[derive(Debug, Clone)]
+pub struct SessionPool<T> {
+    pub(crate) client: SpannerClient<T>,
+    database: String,
+    shared: Arc<Shared>,
+}
+
+impl<T> SessionPool<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    /// Creates a pool of the sessions of `database`, e.g.
+    /// `projects/my-project/instances/my-instance/databases/my-database`.
+    ///
+    /// Returns once `min_opened` sessions are created, and fails if they cannot be.
+    pub async fn new(
+        client: SpannerClient<T>,
+        database: impl Into<String>,
+        settings: PoolSettings,
+    ) -> Result<Self, Error> {
+        let database = database.into();
+        let (tx, rx) = mpsc::unbounded_channel();
+        let worker = Worker {
+            client: client.clone(),
+            database: database.clone(),
+            labels: settings.labels.clone(),
+        };
+
+        let mut sessions = Vec::new();
+        while sessions.len() < settings.min_opened {
+            let names = worker.create(settings.min_opened - sessions.len()).await?;
+            sessions.extend(names);
+        }
+
+        let target = settings.write_target(sessions.len());
+        let interval = settings.maintenance_interval;
+        let shared = Arc::new(Shared {
+            settings,
+            state: Mutex::new(State::default()),
+            tasks: tx,
+        });
+        {
+            let mut state = shared.state.lock().unwrap();
+            for name in sessions {
+                let entry = Entry::new(name);
+                if state.preparing < target {
+                    state.preparing += 1;
+                    shared.send(Task::Prepare(entry));
+                } else {
+                    state.idle.push_back(entry);
+                }
+            }
+        }
+
+        tokio::spawn(worker.clone().run(Arc::downgrade(&shared), rx));
+        tokio::spawn(worker.maintain(Arc::downgrade(&shared), interval));
+
+        Ok(Self {
+            client,
+            database,
+            shared,
+        })
+    }
+
+    /// The database of the sessions.
+    pub fn database(&self) -> &str {
+        &self.database
+    }
+
+    /// Checks out a session, preferably one without a prepared read-write transaction.
+    pub async fn get(&self) -> Result<Session, Error> {
+        self.acquire(false).await
+    }
+
+    /// Checks out a session, preferably one with a prepared read-write transaction, see
+    /// [`Session::take_transaction_id`].
+    pub async fn get_write(&self) -> Result<Session, Error> {
+        self.acquire(true).await
+    }
+
+    async fn acquire(&self, write: bool) -> Result<Session, Error> {
+        let mut rx = {
+            let mut state = self.shared.state.lock().unwrap();
+            if state.closed {
+                return Err(Error::Closed);
+            }
+            let entry = if write {
+                state
+                    .idle_write
+                    .pop_front()
+                    .or_else(|| state.idle.pop_front())
+            } else {
+                state
+                    .idle
+                    .pop_front()
+                    .or_else(|| state.idle_write.pop_front())
+            };
+            if let Some(entry) = entry {
+                state.in_use.insert(entry.name.clone(), Instant::now());
+                return Ok(Session::new(self.shared.clone(), entry));
+            }
+
+            let (tx, rx) = oneshot::channel();
+            state.waiters.retain(|w| !w.is_closed());
+            state.waiters.push_back(tx);
+            self.shared.replenish(&mut state);
+            rx
+        };
+
+        let result = match self.shared.settings.acquire_timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, &mut rx).await {
+                Ok(result) => result,
+                Err(_) => {
+                    // A session may have been handed over just as the timeout elapsed.
+                    rx.close();
+                    return match rx.try_recv() {
+                        Ok(result) => result.map_err(Error::Status),
+                        Err(_) => Err(Error::Timeout),
+                    };
+                }
+            },
+            None => (&mut rx).await,
+        };
+        match result {
+            Ok(result) => result.map_err(Error::Status),
+            Err(_) => Err(Error::Closed),
+        }
+    }
+
+    /// Returns the current number of sessions by state.
+    pub fn stats(&self) -> PoolStats {
+        let state = self.shared.state.lock().unwrap();
+        PoolStats {
+            opened: state.opened(),
+            idle: state.idle.len() + state.idle_write.len(),
+            write_prepared: state.idle_write.len(),
+            in_use: state.in_use.len(),
+            waiters: state.waiting(),
+        }
+    }
+
+    /// Returns the names of the sessions that have been checked out for longer than
+    /// `older_than`, which usually means that they were leaked.
+    pub fn leaked_sessions(&self, older_than: Duration) -> Vec<String> {
+        let state = self.shared.state.lock().unwrap();
+        let now = Instant::now();
+        let mut leaked = state
+            .in_use
+            .iter()
+            .filter(|(_, since)| now.duration_since(**since) >= older_than)
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        leaked.sort();
+        leaked
+    }
+
+    /// Closes the pool and deletes the idle sessions.
+    ///
+    /// The sessions still checked out are deleted when they are returned, and their names are
+    /// returned as [`Error::Leaked`].
+    pub async fn close(&self) -> Result<(), Error> {
+        let (idle, in_use) = {
+            let mut state = self.shared.state.lock().unwrap();
+            let state = &mut *state;
+            state.closed = true;
+            state.waiters.clear();
+            let idle = state
+                .idle
+                .drain(..)
+                .chain(state.idle_write.drain(..))
+                .collect::<Vec<_>>();
+            let mut in_use = state.in_use.keys().cloned().collect::<Vec<_>>();
+            in_use.sort();
+            (idle, in_use)
+        };
+
+        for entry in idle {
+            let mut client = self.client.clone();
+            let request = DeleteSessionRequest { name: entry.name };
+            if let Err(status) = client.delete_session(request).await {
+                if status.code() != Code::NotFound {
+                    return Err(Error::Status(status));
+                }
+            }
+        }
+
+        if in_use.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Leaked(in_use))
+        }
+    }
+}
+
+// Makes the calls of the pool in the background.
+#[derive(Clone)]
+struct Worker<T> {
+    client: SpannerClient<T>,
+    database: String,
+    labels: HashMap<String, String>,
+}
+
+impl<T> Worker<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    async fn run(self, shared: Weak<Shared>, mut rx: mpsc::UnboundedReceiver<Task>) {
+        while let Some(task) = rx.recv().await {
+            let shared = match shared.upgrade() {
+                Some(shared) => shared,
+                None => return,
+            };
+            tokio::spawn(self.clone().handle(shared, task));
+        }
+    }
+
+    async fn maintain(self, shared: Weak<Shared>, interval: Duration) {
+        loop {
+            tokio::time::delay_for(interval).await;
+            let shared = match shared.upgrade() {
+                Some(shared) => shared,
+                None => return,
+            };
+            if shared.state.lock().unwrap().closed {
+                return;
+            }
+            for task in shared.maintain() {
+                shared.send(task);
+            }
+        }
+    }
+
+    async fn handle(self, shared: Arc<Shared>, task: Task) {
+        match task {
+            Task::Create(count) => {
+                let mut remaining = count;
+                while remaining > 0 {
+                    match self.create(remaining).await {
+                        Ok(names) => {
+                            for name in names {
+                                remaining -= 1;
+                                shared.state.lock().unwrap().creating -= 1;
+                                shared.release(Entry::new(name), true);
+                            }
+                        }
+                        Err(status) => {
+                            shared.create_failed(remaining, &status);
+                            return;
+                        }
+                    }
+                }
+            }
+            Task::Prepare(mut entry) => {
+                let result = super::transaction::begin(&self.client, &entry.name).await;
+                shared.state.lock().unwrap().preparing -= 1;
+                match result {
+                    Ok(id) => {
+                        entry.transaction_id = Some(id);
+                        shared.release(entry, false);
+                    }
+                    Err(status) if is_session_not_found(&status) => shared.remove(&entry.name),
+                    Err(_) => shared.release(entry, false),
+                }
+            }
+            Task::Ping(mut entry) => {
+                let result = self.ping(&entry.name).await;
+                shared.state.lock().unwrap().pinging -= 1;
+                match result {
+                    Ok(()) => {
+                        // The query ends the prepared transaction.
+                        entry.transaction_id = None;
+                        entry.last_used = Instant::now();
+                        shared.release(entry, true);
+                    }
+                    Err(status) if is_session_not_found(&status) => shared.remove(&entry.name),
+                    Err(_) => shared.release(entry, false),
+                }
+            }
+            Task::Delete(name) => {
+                let mut client = self.client;
+                let _ = client.delete_session(DeleteSessionRequest { name }).await;
+            }
+        }
+    }
+
+    // Creates up to `count` sessions, the server may return fewer.
+    async fn create(&self, count: usize) -> Result<Vec<String>, Status> {
+        let mut client = self.client.clone();
+        let request = BatchCreateSessionsRequest {
+            database: self.database.clone(),
+            session_template: Some(SessionProto {
+                labels: self.labels.clone(),
+                ..Default::default()
+            }),
+            session_count: count.min(MAX_BATCH_CREATE) as i32,
+        };
+        let response = client.batch_create_sessions(request).await?.into_inner();
+        if response.session.is_empty() {
+            return Err(Status::internal("no sessions created"));
+        }
+        Ok(response.session.into_iter().map(|s| s.name).collect())
+    }
+
+    async fn ping(&self, session: &str) -> Result<(), Status> {
+        let mut client = self.client.clone();
+        let request = ExecuteSqlRequest {
+            session: session.into(),
+            sql: "SELECT 1".into(),
+            ..Default::default()
+        };
+        client.execute_sql(request).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spanner::fake::{FakeServer, FakeSpanner};
+
+    const DATABASE: &str = "projects/p/instances/i/databases/d";
+
+    async fn pool(fake: &Arc<FakeSpanner>, settings: PoolSettings) -> SessionPool<FakeServer> {
+        SessionPool::new(fake.client(), DATABASE, settings)
+            .await
+            .unwrap()
+    }
+
+    fn settings() -> PoolSettings {
+        PoolSettings::default()
+            .min_opened(2)
+            .max_opened(2)
+            .write_sessions(0.0)
+            .acquire_timeout(Duration::from_millis(50))
+    }
+
+    async fn sleep(millis: u64) {
+        tokio::time::delay_for(Duration::from_millis(millis)).await;
+    }
+
+    #[tokio::test]
+    async fn test_min_opened() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(
+            &fake,
+            settings().min_opened(4).max_opened(8).write_sessions(0.5),
+        )
+        .await;
+        sleep(20).await;
+
+        assert_eq!(fake.sessions(), 4);
+        assert_eq!(fake.calls("begin_transaction"), 2);
+        let stats = pool.stats();
+        assert_eq!(stats.opened, 4);
+        assert_eq!(stats.idle, 4);
+        assert_eq!(stats.write_prepared, 2);
+
+        let mut session = pool.get_write().await.unwrap();
+        assert!(session.take_transaction_id().is_some());
+        let mut session = pool.get().await.unwrap();
+        assert!(session.take_transaction_id().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_max_opened() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(&fake, settings().min_opened(1)).await;
+
+        let first = pool.get().await.unwrap();
+        let second = pool.get().await.unwrap();
+        assert_ne!(first.name(), second.name());
+        assert_eq!(fake.sessions(), 2);
+        assert!(matches!(pool.get().await, Err(Error::Timeout)));
+
+        // A returned session is handed to the next waiter.
+        let name = first.name().to_owned();
+        let waiter = {
+            let pool = pool.clone();
+            tokio::spawn(async move { pool.get().await.map(|s| s.name().to_owned()) })
+        };
+        sleep(10).await;
+        assert_eq!(pool.stats().waiters, 1);
+        drop(first);
+        assert_eq!(waiter.await.unwrap().unwrap(), name);
+        assert_eq!(pool.stats().in_use, 1);
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(
+            &fake,
+            settings()
+                .keep_alive_interval(Duration::from_millis(10))
+                .maintenance_interval(Duration::from_millis(20)),
+        )
+        .await;
+        sleep(50).await;
+
+        assert!(fake.sql().iter().all(|sql| sql == "SELECT 1"));
+        assert!(fake.calls("execute_sql") >= 2);
+        assert_eq!(pool.stats().opened, 2);
+    }
+
+    #[tokio::test]
+    async fn test_replace_deleted() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(
+            &fake,
+            settings()
+                .keep_alive_interval(Duration::from_millis(10))
+                .maintenance_interval(Duration::from_millis(20)),
+        )
+        .await;
+        let name = pool.get().await.unwrap().name().to_owned();
+        fake.expire(&name);
+        sleep(50).await;
+
+        assert_eq!(fake.sessions(), 2);
+        assert_eq!(pool.stats().opened, 2);
+        let first = pool.get().await.unwrap();
+        let second = pool.get().await.unwrap();
+        assert!(first.name() != name && second.name() != name);
+    }
+
+    #[tokio::test]
+    async fn test_shrink() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(
+            &fake,
+            settings()
+                .min_opened(1)
+                .max_opened(3)
+                .maintenance_interval(Duration::from_millis(20)),
+        )
+        .await;
+        let sessions = vec![
+            pool.get().await.unwrap(),
+            pool.get().await.unwrap(),
+            pool.get().await.unwrap(),
+        ];
+        assert_eq!(fake.sessions(), 3);
+        drop(sessions);
+        sleep(60).await;
+
+        assert_eq!(fake.sessions(), 1);
+        assert_eq!(pool.stats().opened, 1);
+    }
+
+    #[tokio::test]
+    async fn test_leaked_sessions() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(&fake, settings()).await;
+        let session = pool.get().await.unwrap();
+        let name = session.name().to_owned();
+        sleep(10).await;
+
+        assert_eq!(
+            pool.leaked_sessions(Duration::from_millis(5)),
+            vec![name.clone()]
+        );
+        assert!(pool.leaked_sessions(Duration::from_secs(1)).is_empty());
+        match pool.close().await {
+            Err(Error::Leaked(leaked)) => assert_eq!(leaked, vec![name]),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(fake.sessions(), 1);
+        assert!(matches!(pool.get().await, Err(Error::Closed)));
+
+        // Sessions returned after the pool is closed are deleted.
+        drop(session);
+        sleep(10).await;
+        assert_eq!(fake.sessions(), 0);
+    }
+}
@@ -0,0 +1,279 @@
+//! SQL statements with typed parameters.
+//!
+//! A [`Statement`] encodes named parameters with [`ToSpannerValue`] and infers their types, which
+//! are required for the parameters whose type cannot be inferred from their JSON encoding, e.g.
+//! `BYTES`, `TIMESTAMP` or arrays:
+//!
+//! ```ignore
+//! use googapis::spanner::statement::Statement;
+//!
+//! let statement = Statement::new("SELECT Name FROM Singers WHERE SingerId IN UNNEST(@ids)")
+//!     .param("ids", vec![1i64, 2, 3]);
+//! let rows = tx.execute_streaming_sql(statement);
+//! ```
+//!
+//! Statements convert into `ExecuteSqlRequest`s, the statements of `ExecuteBatchDmlRequest`s and
+//! `PartitionQueryRequest`s.
+
+use std::collections::{BTreeMap, HashMap};
+
+use prost_types::{value::Kind, ListValue, Struct, Value};
+
+use super::value::ToSpannerValue;
+use crate::google::spanner::v1::{
+    execute_batch_dml_request,
+    execute_sql_request::{QueryMode, QueryOptions},
+    struct_type::Field,
+    ExecuteSqlRequest, PartitionOptions, PartitionQueryRequest, StructType, Type, TypeCode,
+};
+
+/// A SQL statement and its parameters.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Statement {
+    sql: String,
+    params: BTreeMap<String, Value>,
+    param_types: HashMap<String, Type>,
+    query_mode: QueryMode,
+    query_options: Option<QueryOptions>,
+}
+
+impl Statement {
+    pub fn new(sql: impl Into<String>) -> Self {
+        Self {
+            sql: sql.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the parameter `@name`.
+    pub fn param<V: ToSpannerValue>(self, name: impl Into<String>, value: V) -> Self {
+        self.param_with_type(name, value.to_spanner_value(), V::spanner_type())
+    }
+
+    /// Sets the parameter `@name` to a `STRUCT`.
+    pub fn struct_param(self, name: impl Into<String>, value: StructParam) -> Self {
+        let ty = value.spanner_type();
+        self.param_with_type(name, value.to_spanner_value(), ty)
+    }
+
+    /// Sets the parameter `@name` to an already encoded value of type `ty`, e.g. an array of
+    /// structs.
+    pub fn param_with_type(mut self, name: impl Into<String>, value: Value, ty: Type) -> Self {
+        let name = name.into();
+        self.params.insert(name.clone(), value);
+        self.param_types.insert(name, ty);
+        self
+    }
+
+    /// Whether the query plan or the execution statistics are returned with the results, in
+    /// `ResultSetStats`.
+    pub fn query_mode(self, query_mode: QueryMode) -> Self {
+        Self { query_mode, ..self }
+    }
+
+    /// The version of the query optimizer, e.g. `"1"` or `"latest"`.
+    pub fn optimizer_version(self, version: impl Into<String>) -> Self {
+        Self {
+            query_options: Some(QueryOptions {
+                optimizer_version: version.into(),
+            }),
+            ..self
+        }
+    }
+
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// The encoded parameters.
+    pub fn params(&self) -> &BTreeMap<String, Value> {
+        &self.params
+    }
+
+    /// The types of the parameters.
+    pub fn param_types(&self) -> &HashMap<String, Type> {
+        &self.param_types
+    }
+
+    // Returns the parameters in the fields of requests, which are empty when there are none.
+    fn into_parts(self) -> (String, Option<Struct>, HashMap<String, Type>) {
+        let params = if self.params.is_empty() {
+            None
+        } else {
+            Some(Struct {
+                fields: self.params,
+            })
+        };
+        (self.sql, params, self.param_types)
+    }
+
+    /// Returns a request partitioning the query, for the session and read-only transaction that
+    /// are set by the caller.
+    pub fn into_partition_query_request(self, options: PartitionOptions) -> PartitionQueryRequest {
+        let (sql, params, param_types) = self.into_parts();
+        PartitionQueryRequest {
+            sql,
+            params,
+            param_types,
+            partition_options: Some(options),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Statement> for ExecuteSqlRequest {
+    fn from(statement: Statement) -> Self {
+        let query_mode = statement.query_mode as i32;
+        let query_options = statement.query_options.clone();
+        let (sql, params, param_types) = statement.into_parts();
+        ExecuteSqlRequest {
+            sql,
+            params,
+            param_types,
+            query_mode,
+            query_options,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Statement> for execute_batch_dml_request::Statement {
+    fn from(statement: Statement) -> Self {
+        let (sql, params, param_types) = statement.into_parts();
+        execute_batch_dml_request::Statement {
+            sql,
+            params,
+            param_types,
+        }
+    }
+}
+
+impl From<&str> for Statement {
+    fn from(sql: &str) -> Self {
+        Statement::new(sql)
+    }
+}
+
+impl From<String> for Statement {
+    fn from(sql: String) -> Self {
+        Statement::new(sql)
+    }
+}
+
+/// The value of a `STRUCT` parameter, e.g. of `@name` in
+/// `WHERE STRUCT<FirstName STRING, LastName STRING>(FirstName, LastName) = @name`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StructParam {
+    fields: Vec<Field>,
+    values: Vec<Value>,
+}
+
+impl StructParam {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a field, whose name may be empty.
+    pub fn field<V: ToSpannerValue>(mut self, name: impl Into<String>, value: V) -> Self {
+        self.fields.push(Field {
+            name: name.into(),
+            r#type: Some(V::spanner_type()),
+        });
+        self.values.push(value.to_spanner_value());
+        self
+    }
+
+    /// Encodes the struct as the list of the values of its fields.
+    pub fn to_spanner_value(&self) -> Value {
+        Value {
+            kind: Some(Kind::ListValue(ListValue {
+                values: self.values.clone(),
+            })),
+        }
+    }
+
+    pub fn spanner_type(&self) -> Type {
+        Type {
+            code: TypeCode::Struct as i32,
+            struct_type: Some(StructType {
+                fields: self.fields.clone(),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(s: &str) -> Value {
+        Value {
+            kind: Some(Kind::StringValue(s.into())),
+        }
+    }
+
+    fn ty(code: TypeCode) -> Type {
+        Type {
+            code: code as i32,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_params() {
+        let name = StructParam::new()
+            .field("FirstName", "Marc")
+            .field("", None::<i64>);
+        let request: ExecuteSqlRequest = Statement::new("SELECT 1")
+            .param("ids", vec![1i64, 2])
+            .param("data", b"hi".to_vec())
+            .struct_param("name", name)
+            .query_mode(QueryMode::Profile)
+            .optimizer_version("2")
+            .into();
+
+        let params = request.params.unwrap().fields;
+        assert_eq!(
+            params["ids"],
+            Value {
+                kind: Some(Kind::ListValue(ListValue {
+                    values: vec![string("1"), string("2")]
+                }))
+            }
+        );
+        assert_eq!(params["data"], string("aGk="));
+        assert_eq!(
+            request.param_types["ids"],
+            Type {
+                code: TypeCode::Array as i32,
+                array_element_type: Some(Box::new(ty(TypeCode::Int64))),
+                ..Default::default()
+            }
+        );
+        assert_eq!(request.param_types["data"], ty(TypeCode::Bytes));
+        let fields = request.param_types["name"]
+            .struct_type
+            .as_ref()
+            .unwrap()
+            .fields
+            .clone();
+        assert_eq!(fields[0].name, "FirstName");
+        assert_eq!(fields[1].r#type, Some(ty(TypeCode::Int64)));
+        assert_eq!(request.query_mode, QueryMode::Profile as i32);
+        assert_eq!(request.query_options.unwrap().optimizer_version, "2");
+    }
+
+    #[test]
+    fn test_no_params() {
+        let request = ExecuteSqlRequest::from(Statement::from("SELECT 1"));
+        assert_eq!(request.sql, "SELECT 1");
+        assert_eq!(request.params, None);
+        assert!(request.param_types.is_empty());
+
+        let statement = execute_batch_dml_request::Statement::from(
+            Statement::new("DELETE FROM Singers WHERE SingerId = @id").param("id", 1),
+        );
+        assert_eq!(statement.params.unwrap().fields["id"], string("1"));
+    }
+}
@@ -0,0 +1,694 @@
+//! Read-write transactions that are retried when aborted.
+//!
+//! Spanner may abort a read-write transaction at any time, most often because of a conflict with
+//! a concurrent transaction, and the whole transaction must then be run again. A
+//! [`TransactionRunner`] calls a closure with a [`ReadWriteTransaction`], commits the mutations
+//! it buffered, and calls the closure again in a new transaction when the closure or the commit
+//! fails with `ABORTED`. It waits for the delay of the `google.rpc.RetryInfo` sent by the server
+//! before retrying, or for the next backoff without one.
+//!
+//! ```ignore
+//! use googapis::spanner::transaction::TransactionRunner;
+//!
+//! let runner = TransactionRunner::new(pool.clone());
+//! let (_, commit_timestamp) = runner
+//!     .run(|tx| async move {
+//!         let rows = tx.read(request.clone()).await?;
+//!         tx.buffer_write(mutations_from(rows));
+//!         Ok(())
+//!     })
+//!     .await?;
+//! ```
+
+use std::{
+    future::Future,
+    mem,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tonic::{Code, Status};
+
+use super::{result_set::ResultSetStream, session::Session, session::SessionPool, Error};
+use crate::{
+    google::spanner::v1::{
+        commit_request, execute_batch_dml_request::Statement, result_set_stats::RowCount,
+        spanner_client::SpannerClient, transaction_options, transaction_selector,
+        BeginTransactionRequest, CommitRequest, CommitResponse, ExecuteBatchDmlRequest,
+        ExecuteSqlRequest, Mutation, ReadRequest, ResultSet, ResultSetStats, RollbackRequest,
+        TransactionOptions, TransactionSelector,
+    },
+    retry::{self, RetrySettings},
+};
+
+#[derive(Debug, Default)]
+struct Buffer {
+    seqno: i64,
+    mutations: Vec<Mutation>,
+}
+
+/// A read-write transaction, passed to the closure run by a [`TransactionRunner`].
+///
+/// Clones share the buffered mutations, so the transaction can be used from several tasks.
+#[derive(Debug, Clone)]
+pub struct ReadWriteTransaction<T> {
+    client: SpannerClient<T>,
+    session: String,
+    id: Vec<u8>,
+    buffer: Arc<Mutex<Buffer>>,
+}
+
+impl<T> ReadWriteTransaction<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    /// The name of the session of the transaction.
+    pub fn session(&self) -> &str {
+        &self.session
+    }
+
+    /// The id of the transaction.
+    pub fn id(&self) -> &[u8] {
+        &self.id
+    }
+
+    /// Returns the selector of the transaction, for the requests made without the helpers below.
+    pub fn selector(&self) -> TransactionSelector {
+        TransactionSelector {
+            selector: Some(transaction_selector::Selector::Id(self.id.clone())),
+        }
+    }
+
+    /// Buffers mutations that are applied when the transaction commits.
+    pub fn buffer_write(&self, mutations: impl IntoIterator<Item = Mutation>) {
+        self.buffer.lock().unwrap().mutations.extend(mutations);
+    }
+
+    /// Executes a query or a DML statement in the transaction.
+    ///
+    /// The session, transaction and sequence number of `request` are set by the transaction.
+    pub async fn execute_sql(
+        &self,
+        request: impl Into<ExecuteSqlRequest>,
+    ) -> Result<ResultSet, Status> {
+        let mut request = request.into();
+        request.session = self.session.clone();
+        request.transaction = Some(self.selector());
+        request.seqno = self.next_seqno();
+        let mut client = self.client.clone();
+        Ok(client.execute_sql(request).await?.into_inner())
+    }
+
+    /// Executes a DML statement in the transaction and returns the number of modified rows.
+    pub async fn execute_update(
+        &self,
+        request: impl Into<ExecuteSqlRequest>,
+    ) -> Result<i64, Status> {
+        let result_set = self.execute_sql(request).await?;
+        Ok(row_count(result_set.stats.as_ref()))
+    }
+
+    /// Executes DML statements in order, and returns their results up to the first failed one.
+    ///
+    /// The statements before a failed one are applied when the transaction commits, unless the
+    /// error is returned from the transaction, e.g. with [`BatchDmlResult::into_row_counts`].
+    pub async fn execute_batch_dml<I>(&self, statements: I) -> Result<BatchDmlResult, Status>
+    where
+        I: IntoIterator,
+        I::Item: Into<Statement>,
+    {
+        let request = ExecuteBatchDmlRequest {
+            session: self.session.clone(),
+            transaction: Some(self.selector()),
+            statements: statements.into_iter().map(Into::into).collect(),
+            seqno: self.next_seqno(),
+        };
+        let mut client = self.client.clone();
+        let response = client.execute_batch_dml(request).await?.into_inner();
+        let status = response
+            .status
+            .filter(|s| s.code != Code::Ok as i32)
+            .map(|s| Status::new(Code::from(s.code), s.message));
+        Ok(BatchDmlResult {
+            result_sets: response.result_sets,
+            status,
+        })
+    }
+
+    /// Reads rows in the transaction.
+    ///
+    /// The session and transaction of `request` are set by the transaction.
+    pub async fn read(&self, mut request: ReadRequest) -> Result<ResultSet, Status> {
+        request.session = self.session.clone();
+        request.transaction = Some(self.selector());
+        let mut client = self.client.clone();
+        Ok(client.read(request).await?.into_inner())
+    }
+
+    /// Executes a query in the transaction and streams its rows.
+    ///
+    /// The session, transaction and sequence number of `request` are set by the transaction.
+    pub fn execute_streaming_sql(&self, request: impl Into<ExecuteSqlRequest>) -> ResultSetStream {
+        let mut request = request.into();
+        request.session = self.session.clone();
+        request.transaction = Some(self.selector());
+        request.seqno = self.next_seqno();
+        ResultSetStream::execute_sql(self.client.clone(), request)
+    }
+
+    /// Reads rows in the transaction and streams them.
+    ///
+    /// The session and transaction of `request` are set by the transaction.
+    pub fn streaming_read(&self, mut request: ReadRequest) -> ResultSetStream {
+        request.session = self.session.clone();
+        request.transaction = Some(self.selector());
+        ResultSetStream::read(self.client.clone(), request)
+    }
+
+    fn next_seqno(&self) -> i64 {
+        let mut buffer = self.buffer.lock().unwrap();
+        buffer.seqno += 1;
+        buffer.seqno
+    }
+
+    async fn commit(&self) -> Result<CommitResponse, Status> {
+        let mutations = mem::take(&mut self.buffer.lock().unwrap().mutations);
+        let request = CommitRequest {
+            session: self.session.clone(),
+            mutations,
+            transaction: Some(commit_request::Transaction::TransactionId(self.id.clone())),
+        };
+        let mut client = self.client.clone();
+        Ok(client.commit(request).await?.into_inner())
+    }
+
+    async fn rollback(&self) -> Result<(), Status> {
+        let request = RollbackRequest {
+            session: self.session.clone(),
+            transaction_id: self.id.clone(),
+        };
+        let mut client = self.client.clone();
+        client.rollback(request).await?;
+        Ok(())
+    }
+}
+
+/// The results of the statements of a batch DML, up to the first failed one.
+#[derive(Debug)]
+pub struct BatchDmlResult {
+    result_sets: Vec<ResultSet>,
+    status: Option<Status>,
+}
+
+impl BatchDmlResult {
+    /// The results of the statements that succeeded, in order.
+    pub fn result_sets(&self) -> &[ResultSet] {
+        &self.result_sets
+    }
+
+    /// The numbers of rows modified by the statements that succeeded, in order.
+    pub fn row_counts(&self) -> Vec<i64> {
+        self.result_sets
+            .iter()
+            .map(|r| row_count(r.stats.as_ref()))
+            .collect()
+    }
+
+    /// The error of the first failed statement, whose index is the number of results.
+    pub fn error(&self) -> Option<&Status> {
+        self.status.as_ref()
+    }
+
+    /// Returns the numbers of modified rows, or the error of the failed statement.
+    pub fn into_row_counts(self) -> Result<Vec<i64>, Status> {
+        match self.status {
+            Some(status) => Err(status),
+            None => Ok(self.row_counts()),
+        }
+    }
+}
+
+pub(crate) fn row_count(stats: Option<&ResultSetStats>) -> i64 {
+    match stats.and_then(|s| s.row_count.as_ref()) {
+        Some(RowCount::RowCountExact(n)) | Some(RowCount::RowCountLowerBound(n)) => *n,
+        None => 0,
+    }
+}
+
+// Begins a read-write transaction in `session`.
+pub(crate) async fn begin<T>(client: &SpannerClient<T>, session: &str) -> Result<Vec<u8>, Status>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    let mode = transaction_options::Mode::ReadWrite(transaction_options::ReadWrite {});
+    begin_with_mode(client, session, mode).await
+}
+
+// Begins a transaction of any mode in `session`.
+pub(crate) async fn begin_with_mode<T>(
+    client: &SpannerClient<T>,
+    session: &str,
+    mode: transaction_options::Mode,
+) -> Result<Vec<u8>, Status>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    let request = BeginTransactionRequest {
+        session: session.into(),
+        options: Some(TransactionOptions { mode: Some(mode) }),
+    };
+    let mut client = client.clone();
+    Ok(client.begin_transaction(request).await?.into_inner().id)
+}
+
+/// Runs read-write transactions with the sessions of a [`SessionPool`].
+#[derive(Debug, Clone)]
+pub struct TransactionRunner<T> {
+    pool: SessionPool<T>,
+    settings: RetrySettings,
+}
+
+impl<T> TransactionRunner<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    pub fn new(pool: SessionPool<T>) -> Self {
+        Self {
+            pool,
+            settings: RetrySettings::default()
+                .retry_codes(vec![Code::Aborted])
+                .backoff(Duration::from_millis(20), Duration::from_secs(32), 1.3),
+        }
+    }
+
+    /// The backoff, attempts and total timeout of the retries of aborted transactions.
+    pub fn retry_settings(self, settings: RetrySettings) -> Self {
+        Self { settings, ..self }
+    }
+
+    /// Runs `f` in a read-write transaction and commits it, retrying when it is aborted.
+    ///
+    /// Returns the result of `f` and the commit timestamp. When `f` fails with another error the
+    /// transaction is rolled back and the error is returned. Sessions deleted by the server are
+    /// replaced with new ones.
+    pub async fn run<F, Fut, R>(
+        &self,
+        mut f: F,
+    ) -> Result<(R, Option<prost_types::Timestamp>), Error>
+    where
+        F: FnMut(ReadWriteTransaction<T>) -> Fut,
+        Fut: Future<Output = Result<R, Error>>,
+    {
+        let deadline = self.settings.total_timeout.map(|t| Instant::now() + t);
+        let mut backoff = self.settings.backoff_iter();
+        let mut attempts = 0;
+        let mut session = None;
+
+        loop {
+            attempts += 1;
+            let mut current = match session.take() {
+                Some(session) => session,
+                None => self.pool.get_write().await?,
+            };
+            let error = match self.attempt(&mut current, &mut f).await {
+                Ok(result) => return Ok(result),
+                Err(error) => error,
+            };
+
+            let delay = if error.is_session_not_found() {
+                current.not_found();
+                Duration::from_secs(0)
+            } else if error.is_aborted() {
+                // Retry in the same session, as recommended for aborted transactions.
+                session = Some(current);
+                match &error {
+                    Error::Status(status) => retry::retry_delay(status),
+                    _ => None,
+                }
+                .unwrap_or_else(|| backoff.next().unwrap())
+            } else {
+                return Err(error);
+            };
+
+            let exhausted = matches!(self.settings.max_attempts, Some(max) if attempts >= max)
+                || matches!(deadline, Some(d) if Instant::now() + delay >= d);
+            if exhausted {
+                return Err(error);
+            }
+            tokio::time::delay_for(delay).await;
+        }
+    }
+
+    async fn attempt<F, Fut, R>(
+        &self,
+        session: &mut Session,
+        f: &mut F,
+    ) -> Result<(R, Option<prost_types::Timestamp>), Error>
+    where
+        F: FnMut(ReadWriteTransaction<T>) -> Fut,
+        Fut: Future<Output = Result<R, Error>>,
+    {
+        let id = match session.take_transaction_id() {
+            Some(id) => id,
+            None => begin(&self.pool.client, session.name()).await?,
+        };
+        let tx = ReadWriteTransaction {
+            client: self.pool.client.clone(),
+            session: session.name().into(),
+            id,
+            buffer: Arc::default(),
+        };
+
+        let value = match f(tx.clone()).await {
+            Ok(value) => value,
+            Err(error) => {
+                if !error.is_aborted() && !error.is_session_not_found() {
+                    // The error of `f` matters more than the one of the rollback.
+                    let _ = tx.rollback().await;
+                }
+                return Err(error);
+            }
+        };
+        let response = tx.commit().await?;
+        Ok((value, response.commit_timestamp))
+    }
+}
+
+impl<T> SessionPool<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone + Send + Sync + 'static,
+    T::Future: Send,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Data: Send,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{
+    /// Runs `f` in a read-write transaction with the default [`TransactionRunner`].
+    pub async fn read_write_transaction<F, Fut, R>(
+        &self,
+        f: F,
+    ) -> Result<(R, Option<prost_types::Timestamp>), Error>
+    where
+        F: FnMut(ReadWriteTransaction<T>) -> Fut,
+        Fut: Future<Output = Result<R, Error>>,
+    {
+        TransactionRunner::new(self.clone()).run(f).await
+    }
+
+    /// Executes a DML statement as partitioned DML, and returns a lower bound of the number of
+    /// modified rows.
+    ///
+    /// The statement is executed in independent transactions on partitions of the table, so it
+    /// must be idempotent and may be partially applied when it fails.
+    pub async fn execute_partitioned_update(
+        &self,
+        statement: impl Into<ExecuteSqlRequest>,
+    ) -> Result<i64, Error> {
+        let mut session = self.get().await?;
+        // The partitioned DML transaction replaces a prepared read-write transaction.
+        session.take_transaction_id();
+        let mode =
+            transaction_options::Mode::PartitionedDml(transaction_options::PartitionedDml {});
+        let result = self
+            .partitioned_update(&session, statement.into(), mode)
+            .await;
+        if matches!(&result, Err(status) if super::is_session_not_found(status)) {
+            session.not_found();
+        }
+        Ok(result?)
+    }
+
+    async fn partitioned_update(
+        &self,
+        session: &Session,
+        mut request: ExecuteSqlRequest,
+        mode: transaction_options::Mode,
+    ) -> Result<i64, Status> {
+        let id = begin_with_mode(&self.client, session.name(), mode).await?;
+        request.session = session.name().into();
+        request.transaction = Some(TransactionSelector {
+            selector: Some(transaction_selector::Selector::Id(id)),
+        });
+        request.seqno = 1;
+        let mut client = self.client.clone();
+        let result_set = client.execute_sql(request).await?.into_inner();
+        Ok(row_count(result_set.stats.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        google::{
+            rpc::{RetryInfo, Status as RpcStatus},
+            spanner::v1::mutation,
+        },
+        spanner::{
+            fake::{FakeServer, FakeSpanner},
+            session::PoolSettings,
+        },
+    };
+    use prost::Message;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    async fn pool(fake: &Arc<FakeSpanner>, settings: PoolSettings) -> SessionPool<FakeServer> {
+        SessionPool::new(
+            fake.client(),
+            "projects/p/instances/i/databases/d",
+            settings,
+        )
+        .await
+        .unwrap()
+    }
+
+    fn settings() -> PoolSettings {
+        PoolSettings::default()
+            .min_opened(1)
+            .max_opened(1)
+            .write_sessions(1.0)
+            .acquire_timeout(Duration::from_secs(1))
+    }
+
+    fn runner(pool: SessionPool<FakeServer>) -> TransactionRunner<FakeServer> {
+        TransactionRunner::new(pool).retry_settings(
+            RetrySettings::default()
+                .backoff(Duration::from_millis(1), Duration::from_millis(1), 1.0)
+                .max_attempts(3),
+        )
+    }
+
+    fn mutation(table: &str) -> Mutation {
+        Mutation {
+            operation: Some(mutation::Operation::Insert(mutation::Write {
+                table: table.into(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    fn aborted(delay: Duration) -> Status {
+        let info = RetryInfo {
+            retry_delay: Some(prost_types::Duration {
+                seconds: delay.as_secs() as i64,
+                nanos: delay.subsec_nanos() as i32,
+            }),
+        };
+        let details = RpcStatus {
+            code: Code::Aborted as i32,
+            message: "aborted".into(),
+            details: vec![prost_types::Any {
+                type_url: "type.googleapis.com/google.rpc.RetryInfo".into(),
+                value: encode(&info),
+            }],
+        };
+        Status::with_details(Code::Aborted, "aborted", encode(&details).into())
+    }
+
+    fn encode(msg: &impl Message) -> Vec<u8> {
+        let mut buf = Vec::new();
+        msg.encode(&mut buf).unwrap();
+        buf
+    }
+
+    #[tokio::test]
+    async fn test_commit() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(&fake, settings()).await;
+        tokio::time::delay_for(Duration::from_millis(10)).await;
+        assert_eq!(pool.stats().write_prepared, 1);
+
+        let (count, timestamp) = pool
+            .read_write_transaction(|tx| async move {
+                let count = tx
+                    .execute_update(ExecuteSqlRequest {
+                        sql: "UPDATE T SET A = 1".into(),
+                        ..Default::default()
+                    })
+                    .await?;
+                tx.buffer_write(vec![mutation("T")]);
+                Ok(count)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(count, 1);
+        assert!(timestamp.is_some());
+        assert_eq!(fake.committed(), vec![vec![mutation("T")]]);
+        // The prepared transaction was used.
+        assert_eq!(fake.calls("begin_transaction"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_aborted() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(&fake, settings()).await;
+        fake.fail_commit(aborted(Duration::from_millis(30)));
+        fake.fail_sql(Status::aborted("aborted"));
+
+        let attempts = AtomicUsize::new(0);
+        let start = Instant::now();
+        let (value, _) = runner(pool)
+            .run(|tx| {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                async move {
+                    if attempt > 1 {
+                        tx.execute_sql(ExecuteSqlRequest::default()).await?;
+                    }
+                    tx.buffer_write(vec![mutation("T")]);
+                    Ok(attempt)
+                }
+            })
+            .await
+            .unwrap();
+
+        // The first commit and the query of the second attempt are aborted.
+        assert_eq!(value, 3);
+        assert!(start.elapsed() >= Duration::from_millis(30));
+        assert_eq!(fake.committed(), vec![vec![mutation("T")]]);
+        assert_eq!(fake.calls("rollback"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_retry_exhausted() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(&fake, settings()).await;
+        for _ in 0..3 {
+            fake.fail_commit(Status::aborted("aborted"));
+        }
+
+        let result = runner(pool).run(|_| async { Ok(()) }).await;
+        assert!(result.unwrap_err().is_aborted());
+        assert_eq!(fake.calls("commit"), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rollback() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(&fake, settings()).await;
+
+        let attempts = AtomicUsize::new(0);
+        let result: Result<((), _), _> = runner(pool.clone())
+            .run(|tx| {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    tx.buffer_write(vec![mutation("T")]);
+                    Err(Status::invalid_argument("bad").into())
+                }
+            })
+            .await;
+
+        match result {
+            Err(Error::Status(status)) => assert_eq!(status.code(), Code::InvalidArgument),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert_eq!(fake.calls("rollback"), 1);
+        assert_eq!(fake.calls("commit"), 0);
+        assert_eq!(pool.stats().in_use, 0);
+    }
+
+    #[tokio::test]
+    async fn test_session_not_found() {
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(&fake, settings()).await;
+        let name = pool.get().await.unwrap().name().to_owned();
+        fake.expire(&name);
+
+        let (session, _) = pool
+            .read_write_transaction(|tx| async move { Ok(tx.session().to_owned()) })
+            .await
+            .unwrap();
+
+        assert_ne!(session, name);
+        assert_eq!(fake.sessions(), 1);
+        assert_eq!(pool.stats().opened, 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_dml() {
+        use crate::spanner::statement::Statement as Sql;
+
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(&fake, settings()).await;
+
+        let (results, _) = pool
+            .read_write_transaction(|tx| {
+                let fake = fake.clone();
+                async move {
+                    let ok = tx
+                        .execute_batch_dml(vec![Sql::new("A"), Sql::new("B")])
+                        .await?;
+                    fake.fail_sql(Status::invalid_argument("bad"));
+                    let failed = tx
+                        .execute_batch_dml(vec![Sql::new("C").param("id", 1), Sql::new("D")])
+                        .await?;
+                    Ok((ok, failed))
+                }
+            })
+            .await
+            .unwrap();
+
+        let (ok, failed) = results;
+        assert_eq!(ok.row_counts(), vec![1, 1]);
+        assert!(ok.error().is_none());
+        assert_eq!(failed.row_counts(), Vec::<i64>::new());
+        assert_eq!(failed.error().unwrap().code(), Code::InvalidArgument);
+        assert!(failed.into_row_counts().is_err());
+        assert_eq!(fake.sql(), vec!["A", "B"]);
+    }
+
+    #[tokio::test]
+    async fn test_partitioned_update() {
+        use crate::spanner::statement::Statement as Sql;
+
+        let fake = Arc::new(FakeSpanner::default());
+        let pool = pool(&fake, settings()).await;
+
+        let statement = Sql::new("UPDATE T SET A = @a WHERE TRUE").param("a", 1);
+        assert_eq!(pool.execute_partitioned_update(statement).await.unwrap(), 1);
+        assert_eq!(fake.sql(), vec!["UPDATE T SET A = @a WHERE TRUE"]);
+        assert_eq!(fake.calls("commit"), 0);
+        assert_eq!(pool.stats().in_use, 0);
+    }
+}
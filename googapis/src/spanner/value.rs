@@ -0,0 +1,664 @@
+//! Conversions between the values of Spanner and Rust types.
+//!
+//! Values are sent as `google.protobuf.Value`s in the JSON encoding of their [`TypeCode`]:
+//!
+//! | Type | Encoding | Rust types |
+//! |---|---|---|
+//! | `BOOL` | bool | `bool` |
+//! | `INT64` | decimal string | `i64` |
+//! | `FLOAT64` | number, or `"NaN"`, `"Infinity"`, `"-Infinity"` | `f64` |
+//! | `NUMERIC` | decimal string | `String`, `rust_decimal::Decimal` |
+//! | `STRING` | string | `String` |
+//! | `BYTES` | base64 string | `Vec<u8>` |
+//! | `DATE` | RFC 3339 date | `String`, `google.type.Date`, `chrono::NaiveDate` |
+//! | `TIMESTAMP` | RFC 3339 timestamp | `String`, `prost_types::Timestamp`, `chrono::DateTime<Utc>` |
+//! | `ARRAY` | list | `Vec<T>` |
+//! | `STRUCT` | list of the field values | [`Row`] |
+//!
+//! Values are decoded with [`FromSpannerValue`] and encoded with [`ToSpannerValue`], which also
+//! encodes `&str`, `&[u8]` and `i32`. Any value may be null, which is `None` as an `Option<T>`.
+//! The `rust_decimal` and `chrono` conversions are available with the dependencies of the `money`
+//! and `chrono` features.
+
+use std::{error, fmt, sync::Arc};
+
+use prost_types::{value::Kind, ListValue, Value};
+
+use super::row::Row;
+use crate::{
+    google::{
+        r#type::Date,
+        spanner::v1::{Type, TypeCode},
+    },
+    rfc3339,
+};
+
+/// The code of the `NUMERIC` type, which is missing from the generated [`TypeCode`].
+pub const NUMERIC: i32 = 10;
+
+/// An error decoding a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    column: Option<String>,
+    message: String,
+}
+
+impl Error {
+    pub(crate) fn new(message: impl Into<String>) -> Self {
+        Self {
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    // Prefixes the column with the enclosing column or array index, e.g. `singers[0].name`.
+    pub(crate) fn in_column(self, column: &str) -> Self {
+        let column = match self.column {
+            Some(inner) if inner.starts_with('[') => format!("{}{}", column, inner),
+            Some(inner) => format!("{}.{}", column, inner),
+            None => column.to_owned(),
+        };
+        Self {
+            column: Some(column),
+            ..self
+        }
+    }
+
+    /// The column of the value that failed to decode, with the path of the struct fields.
+    pub fn column(&self) -> Option<&str> {
+        self.column.as_deref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.column {
+            Some(column) => write!(f, "column {}: {}", column, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// A Rust type that a Spanner value can be decoded to.
+pub trait FromSpannerValue: Sized {
+    /// Decodes `value` of type `ty`.
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error>;
+}
+
+/// A Rust type that can be encoded as a Spanner value, e.g. in a mutation or a query parameter.
+pub trait ToSpannerValue {
+    /// Encodes the value.
+    fn to_spanner_value(&self) -> Value;
+
+    /// The Spanner type of the values.
+    fn spanner_type() -> Type;
+}
+
+/// Returns a null value.
+pub fn null() -> Value {
+    Value {
+        kind: Some(Kind::NullValue(0)),
+    }
+}
+
+fn scalar_type(code: i32) -> Type {
+    Type {
+        code,
+        ..Default::default()
+    }
+}
+
+fn string(s: String) -> Value {
+    Value {
+        kind: Some(Kind::StringValue(s)),
+    }
+}
+
+fn type_name(ty: &Type) -> String {
+    match TypeCode::from_i32(ty.code) {
+        Some(TypeCode::Array) => match &ty.array_element_type {
+            Some(element) => format!("ARRAY<{}>", type_name(element)),
+            None => "ARRAY".into(),
+        },
+        Some(code) => format!("{:?}", code).to_uppercase(),
+        None if ty.code == NUMERIC => "NUMERIC".into(),
+        None => format!("type code {}", ty.code),
+    }
+}
+
+fn check_type(ty: &Type, codes: &[i32], rust: &str) -> Result<(), Error> {
+    if codes.contains(&ty.code) {
+        Ok(())
+    } else {
+        Err(Error::new(format!(
+            "cannot decode {} as {}",
+            type_name(ty),
+            rust
+        )))
+    }
+}
+
+fn unexpected(value: &Value, ty: &Type) -> Error {
+    let kind = match &value.kind {
+        None | Some(Kind::NullValue(_)) => "null",
+        Some(Kind::NumberValue(_)) => "number",
+        Some(Kind::StringValue(_)) => "string",
+        Some(Kind::BoolValue(_)) => "bool",
+        Some(Kind::StructValue(_)) => "object",
+        Some(Kind::ListValue(_)) => "list",
+    };
+    Error::new(format!("unexpected {} value for {}", kind, type_name(ty)))
+}
+
+fn string_value<'a>(value: &'a Value, ty: &Type) -> Result<&'a str, Error> {
+    match &value.kind {
+        Some(Kind::StringValue(s)) => Ok(s),
+        _ => Err(unexpected(value, ty)),
+    }
+}
+
+fn list_value<'a>(value: &'a Value, ty: &Type) -> Result<&'a ListValue, Error> {
+    match &value.kind {
+        Some(Kind::ListValue(list)) => Ok(list),
+        _ => Err(unexpected(value, ty)),
+    }
+}
+
+/// Returns whether `value` is null.
+pub fn is_null(value: &Value) -> bool {
+    matches!(value.kind, None | Some(Kind::NullValue(_)))
+}
+
+impl<T: FromSpannerValue> FromSpannerValue for Option<T> {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        if is_null(value) {
+            Ok(None)
+        } else {
+            T::from_spanner_value(value, ty).map(Some)
+        }
+    }
+}
+
+impl FromSpannerValue for bool {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        check_type(ty, &[TypeCode::Bool as i32], "bool")?;
+        match value.kind {
+            Some(Kind::BoolValue(b)) => Ok(b),
+            _ => Err(unexpected(value, ty)),
+        }
+    }
+}
+
+impl FromSpannerValue for i64 {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        check_type(ty, &[TypeCode::Int64 as i32], "i64")?;
+        let s = string_value(value, ty)?;
+        s.parse()
+            .map_err(|_| Error::new(format!("invalid INT64: {:?}", s)))
+    }
+}
+
+impl FromSpannerValue for f64 {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        check_type(ty, &[TypeCode::Float64 as i32], "f64")?;
+        match &value.kind {
+            Some(Kind::NumberValue(n)) => Ok(*n),
+            Some(Kind::StringValue(s)) => match s.as_str() {
+                "NaN" => Ok(f64::NAN),
+                "Infinity" => Ok(f64::INFINITY),
+                "-Infinity" => Ok(f64::NEG_INFINITY),
+                _ => Err(Error::new(format!("invalid FLOAT64: {:?}", s))),
+            },
+            _ => Err(unexpected(value, ty)),
+        }
+    }
+}
+
+impl FromSpannerValue for String {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        // The values of these types are strings that may be parsed by the caller.
+        let codes = [
+            TypeCode::String as i32,
+            TypeCode::Date as i32,
+            TypeCode::Timestamp as i32,
+            NUMERIC,
+        ];
+        check_type(ty, &codes, "String")?;
+        string_value(value, ty).map(str::to_owned)
+    }
+}
+
+impl FromSpannerValue for Vec<u8> {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        check_type(ty, &[TypeCode::Bytes as i32], "Vec<u8>")?;
+        base64::decode(string_value(value, ty)?).map_err(|_| Error::new("invalid base64 BYTES"))
+    }
+}
+
+impl FromSpannerValue for prost_types::Timestamp {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        check_type(ty, &[TypeCode::Timestamp as i32], "Timestamp")?;
+        let s = string_value(value, ty)?;
+        rfc3339::parse_timestamp(s).ok_or_else(|| Error::new(format!("invalid TIMESTAMP: {:?}", s)))
+    }
+}
+
+impl FromSpannerValue for Date {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        check_type(ty, &[TypeCode::Date as i32], "Date")?;
+        let s = string_value(value, ty)?;
+        let (year, month, day) =
+            rfc3339::parse_date(s).ok_or_else(|| Error::new(format!("invalid DATE: {:?}", s)))?;
+        Ok(Date {
+            year: year as i32,
+            month: month as i32,
+            day: day as i32,
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromSpannerValue for chrono::DateTime<chrono::Utc> {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        let ts = prost_types::Timestamp::from_spanner_value(value, ty)?;
+        crate::datetime::chrono::from_timestamp(&ts).map_err(|e| Error::new(e.to_string()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromSpannerValue for chrono::NaiveDate {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        let date = Date::from_spanner_value(value, ty)?;
+        chrono::NaiveDate::from_ymd_opt(date.year, date.month as u32, date.day as u32)
+            .ok_or_else(|| Error::new("DATE out of range"))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl FromSpannerValue for rust_decimal::Decimal {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        check_type(ty, &[NUMERIC], "Decimal")?;
+        let s = string_value(value, ty)?;
+        s.parse()
+            .map_err(|_| Error::new(format!("invalid NUMERIC: {:?}", s)))
+    }
+}
+
+impl<T: FromSpannerValue> FromSpannerValue for Vec<T> {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        check_type(ty, &[TypeCode::Array as i32], "Vec")?;
+        let element = ty
+            .array_element_type
+            .as_deref()
+            .ok_or_else(|| Error::new("missing array element type"))?;
+        list_value(value, ty)?
+            .values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                T::from_spanner_value(v, element).map_err(|e| e.in_column(&format!("[{}]", i)))
+            })
+            .collect()
+    }
+}
+
+impl FromSpannerValue for Row {
+    fn from_spanner_value(value: &Value, ty: &Type) -> Result<Self, Error> {
+        check_type(ty, &[TypeCode::Struct as i32], "Row")?;
+        let fields = ty
+            .struct_type
+            .as_ref()
+            .map(|s| s.fields.clone())
+            .unwrap_or_default();
+        let values = list_value(value, ty)?.values.clone();
+        if values.len() != fields.len() {
+            return Err(Error::new(format!(
+                "{} values for a STRUCT of {} fields",
+                values.len(),
+                fields.len()
+            )));
+        }
+        Ok(Row::new(Arc::new(fields), values))
+    }
+}
+
+impl<T: ToSpannerValue + ?Sized> ToSpannerValue for &T {
+    fn to_spanner_value(&self) -> Value {
+        (**self).to_spanner_value()
+    }
+
+    fn spanner_type() -> Type {
+        T::spanner_type()
+    }
+}
+
+impl<T: ToSpannerValue> ToSpannerValue for Option<T> {
+    fn to_spanner_value(&self) -> Value {
+        match self {
+            Some(value) => value.to_spanner_value(),
+            None => null(),
+        }
+    }
+
+    fn spanner_type() -> Type {
+        T::spanner_type()
+    }
+}
+
+impl ToSpannerValue for bool {
+    fn to_spanner_value(&self) -> Value {
+        Value {
+            kind: Some(Kind::BoolValue(*self)),
+        }
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Bool as i32)
+    }
+}
+
+// INT64 values are strings since they may not fit in the f64 of JSON numbers.
+impl ToSpannerValue for i64 {
+    fn to_spanner_value(&self) -> Value {
+        string(self.to_string())
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Int64 as i32)
+    }
+}
+
+impl ToSpannerValue for i32 {
+    fn to_spanner_value(&self) -> Value {
+        string(self.to_string())
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Int64 as i32)
+    }
+}
+
+impl ToSpannerValue for f64 {
+    fn to_spanner_value(&self) -> Value {
+        let kind = if self.is_nan() {
+            Kind::StringValue("NaN".into())
+        } else if self.is_infinite() {
+            let s = if *self > 0.0 { "Infinity" } else { "-Infinity" };
+            Kind::StringValue(s.into())
+        } else {
+            Kind::NumberValue(*self)
+        };
+        Value { kind: Some(kind) }
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Float64 as i32)
+    }
+}
+
+impl ToSpannerValue for str {
+    fn to_spanner_value(&self) -> Value {
+        string(self.to_owned())
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::String as i32)
+    }
+}
+
+impl ToSpannerValue for String {
+    fn to_spanner_value(&self) -> Value {
+        string(self.clone())
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::String as i32)
+    }
+}
+
+impl ToSpannerValue for [u8] {
+    fn to_spanner_value(&self) -> Value {
+        string(base64::encode(self))
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Bytes as i32)
+    }
+}
+
+impl ToSpannerValue for Vec<u8> {
+    fn to_spanner_value(&self) -> Value {
+        self.as_slice().to_spanner_value()
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Bytes as i32)
+    }
+}
+
+impl ToSpannerValue for prost_types::Timestamp {
+    fn to_spanner_value(&self) -> Value {
+        string(rfc3339::format_timestamp(self))
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Timestamp as i32)
+    }
+}
+
+impl ToSpannerValue for Date {
+    fn to_spanner_value(&self) -> Value {
+        let (year, month, day) = (self.year.into(), self.month.into(), self.day.into());
+        string(rfc3339::format_date(year, month, day))
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Date as i32)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> ToSpannerValue for chrono::DateTime<Tz> {
+    fn to_spanner_value(&self) -> Value {
+        prost_types::Timestamp {
+            seconds: self.timestamp(),
+            nanos: self.timestamp_subsec_nanos() as i32,
+        }
+        .to_spanner_value()
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Timestamp as i32)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToSpannerValue for chrono::NaiveDate {
+    fn to_spanner_value(&self) -> Value {
+        string(self.format("%Y-%m-%d").to_string())
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(TypeCode::Date as i32)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl ToSpannerValue for rust_decimal::Decimal {
+    fn to_spanner_value(&self) -> Value {
+        string(self.to_string())
+    }
+
+    fn spanner_type() -> Type {
+        scalar_type(NUMERIC)
+    }
+}
+
+impl<T: ToSpannerValue> ToSpannerValue for [T] {
+    fn to_spanner_value(&self) -> Value {
+        let values = self.iter().map(T::to_spanner_value).collect();
+        Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        }
+    }
+
+    fn spanner_type() -> Type {
+        Type {
+            code: TypeCode::Array as i32,
+            array_element_type: Some(Box::new(T::spanner_type())),
+            ..Default::default()
+        }
+    }
+}
+
+impl<T: ToSpannerValue> ToSpannerValue for Vec<T> {
+    fn to_spanner_value(&self) -> Value {
+        self.as_slice().to_spanner_value()
+    }
+
+    fn spanner_type() -> Type {
+        <[T]>::spanner_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ty(code: TypeCode) -> Type {
+        Type {
+            code: code as i32,
+            ..Default::default()
+        }
+    }
+
+    fn string(s: &str) -> Value {
+        Value {
+            kind: Some(Kind::StringValue(s.into())),
+        }
+    }
+
+    #[test]
+    fn test_scalars() {
+        assert_eq!(
+            i64::from_spanner_value(&string("-42"), &ty(TypeCode::Int64)),
+            Ok(-42)
+        );
+        assert_eq!(
+            f64::from_spanner_value(&string("-Infinity"), &ty(TypeCode::Float64)),
+            Ok(f64::NEG_INFINITY)
+        );
+        let number = Value {
+            kind: Some(Kind::NumberValue(1.5)),
+        };
+        assert_eq!(
+            f64::from_spanner_value(&number, &ty(TypeCode::Float64)),
+            Ok(1.5)
+        );
+        assert_eq!(
+            Vec::<u8>::from_spanner_value(&string("aGk="), &ty(TypeCode::Bytes)),
+            Ok(b"hi".to_vec())
+        );
+        assert_eq!(
+            prost_types::Timestamp::from_spanner_value(
+                &string("2020-05-10T00:00:00.5Z"),
+                &ty(TypeCode::Timestamp)
+            ),
+            Ok(prost_types::Timestamp {
+                seconds: 1_589_068_800,
+                nanos: 500_000_000
+            })
+        );
+        assert_eq!(
+            Date::from_spanner_value(&string("2020-05-10"), &ty(TypeCode::Date)),
+            Ok(Date {
+                year: 2020,
+                month: 5,
+                day: 10
+            })
+        );
+        let numeric = Type {
+            code: NUMERIC,
+            ..Default::default()
+        };
+        assert_eq!(
+            String::from_spanner_value(&string("1.25"), &numeric),
+            Ok("1.25".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = i64::from_spanner_value(&string("1"), &ty(TypeCode::String)).unwrap_err();
+        assert_eq!(err.to_string(), "cannot decode STRING as i64");
+        let err = i64::from_spanner_value(&string("x"), &ty(TypeCode::Int64)).unwrap_err();
+        assert_eq!(err.to_string(), "invalid INT64: \"x\"");
+        let null = Value::default();
+        assert!(i64::from_spanner_value(&null, &ty(TypeCode::Int64)).is_err());
+        assert_eq!(
+            Option::<i64>::from_spanner_value(&null, &ty(TypeCode::Int64)),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_array() {
+        let array = Type {
+            code: TypeCode::Array as i32,
+            array_element_type: Some(Box::new(ty(TypeCode::Int64))),
+            ..Default::default()
+        };
+        let list = |values| Value {
+            kind: Some(Kind::ListValue(ListValue { values })),
+        };
+        let value = list(vec![string("1"), Value::default(), string("3")]);
+        assert_eq!(
+            Vec::<Option<i64>>::from_spanner_value(&value, &array),
+            Ok(vec![Some(1), None, Some(3)])
+        );
+        let err = Vec::<i64>::from_spanner_value(&value, &array).unwrap_err();
+        assert_eq!(err.column(), Some("[1]"));
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(42i64.to_spanner_value(), string("42"));
+        assert_eq!(f64::NAN.to_spanner_value(), string("NaN"));
+        assert_eq!(b"hi"[..].to_spanner_value(), string("aGk="));
+        assert_eq!(None::<&str>.to_spanner_value(), null());
+        let ts = prost_types::Timestamp {
+            seconds: 1_589_068_800,
+            nanos: 500_000_000,
+        };
+        assert_eq!(ts.to_spanner_value(), string("2020-05-10T00:00:00.500Z"));
+        let date = Date {
+            year: 2020,
+            month: 5,
+            day: 10,
+        };
+        assert_eq!(date.to_spanner_value(), string("2020-05-10"));
+
+        let array = vec![Some(1i64), None];
+        assert_eq!(
+            Vec::<Option<i64>>::spanner_type(),
+            Type {
+                code: TypeCode::Array as i32,
+                array_element_type: Some(Box::new(ty(TypeCode::Int64))),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Vec::<Option<i64>>::from_spanner_value(
+                &array.to_spanner_value(),
+                &Vec::<Option<i64>>::spanner_type()
+            ),
+            Ok(array)
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use std::{env, fs, path::PathBuf};
 
 mod gen;
+mod operation;
+mod paging;
+mod service_config;
 
 fn main() {
     match env::args().nth(1) {
@@ -19,6 +22,8 @@ fn print_help() {
 fn gen() {
     let proto_root = PathBuf::from("xtask/proto/googleapis");
     let protos = gen::find_proto(proto_root.clone());
+    let configs = service_config::find_service_config(&proto_root);
+    let all_protos = operation::find_proto(&proto_root);
 
     // let gates = gen::feature_gates(&protos);
     // println!("{}", gates);
@@ -27,14 +32,20 @@ fn gen() {
     let _ = fs::remove_dir_all(out_dir.as_path());
     let _ = fs::create_dir(out_dir.as_path());
     tonic_build::configure()
-        .out_dir(out_dir)
+        .out_dir(out_dir.clone())
         .compile(&gen::proto_path(&protos), &[proto_root])
         .unwrap();
+    paging::append(&out_dir);
+    operation::append(&out_dir, &all_protos);
+    tonic_build::fmt(out_dir.to_str().unwrap());
 
     let mut out_path = PathBuf::from("googapis/src/googapis.rs");
     let root = gen::from_protos(protos);
     fs::write(out_path.clone(), root.gen_code()).unwrap();
 
+    out_path.set_file_name("service_config.rs");
+    fs::write(out_path.clone(), service_config::gen_code(&configs)).unwrap();
+
     out_path.pop();
     tonic_build::fmt(out_path.to_str().unwrap());
 }
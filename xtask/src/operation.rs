@@ -0,0 +1,393 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const OPERATION: &str = "google.longrunning.Operation";
+const OPERATION_INFO: &str = "(google.longrunning.operation_info)";
+const EMPTY: &str = "google.protobuf.Empty";
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/";
+
+/// A method annotated with `google.longrunning.operation_info`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LroMethod {
+    service: String,
+    name: String,
+    input: String,
+    response_type: String,
+    metadata_type: String,
+}
+
+pub fn find_proto(dir: impl AsRef<Path>) -> Vec<PathBuf> {
+    let mut ret = Vec::new();
+    for entry in fs::read_dir(dir.as_ref()).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            ret.append(&mut find_proto(path));
+        } else if path.extension().filter(|ex| ex == &"proto").is_some() {
+            ret.push(path);
+        }
+    }
+    ret.sort();
+    ret
+}
+
+fn strip_comments(src: &str) -> String {
+    src.lines()
+        .map(|line| match line.find("//") {
+            Some(i) => &line[..i],
+            None => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let s = &s[s.find(start)? + start.len()..];
+    Some(&s[..s.find(end)?])
+}
+
+// Returns the string literal of `key: "value"` in an option block.
+fn option_value<'a>(block: &'a str, key: &str) -> Option<&'a str> {
+    let s = &block[block.find(key)? + key.len()..];
+    let s = s.trim_start().strip_prefix(':')?.trim_start();
+    between(s, "\"", "\"")
+}
+
+// Returns the index of the brace closing the one opened just before `s`.
+fn closing_brace(s: &str) -> Option<usize> {
+    let mut depth = 1;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parses a proto file and returns its package and methods annotated with `operation_info`.
+pub fn parse_proto(src: &str) -> Option<(String, Vec<LroMethod>)> {
+    let src = strip_comments(src);
+    let package = src
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("package "))?
+        .trim_end_matches(';')
+        .trim()
+        .to_owned();
+
+    let mut methods = Vec::new();
+    // Services are declared at the top level, e.g. not `string service = 1;`.
+    let src = format!("\n{}", src);
+    let mut rest = src.as_str();
+    while let Some(i) = rest.find("\nservice ") {
+        rest = &rest[i + "\nservice ".len()..];
+        let service = match rest.find('{') {
+            Some(i) => rest[..i].trim().to_owned(),
+            None => break,
+        };
+        rest = &rest[rest.find('{').unwrap() + 1..];
+        let end = closing_brace(rest).unwrap_or(rest.len());
+        let mut body = &rest[..end];
+        rest = &rest[end..];
+
+        while let Some(i) = body.find("rpc ") {
+            body = &body[i + "rpc ".len()..];
+            let sig_end = body.find([';', '{']).unwrap_or(body.len());
+            let sig = &body[..sig_end];
+            let name = sig[..sig.find('(').unwrap_or(0)].trim();
+            let input = between(sig, "(", ")").map(str::trim);
+            let output = between(sig, "returns", ")")
+                .and_then(|s| s.split('(').nth(1))
+                .map(str::trim);
+            let block = if body[sig_end..].starts_with('{') {
+                let block = &body[sig_end + 1..];
+                &block[..closing_brace(block).unwrap_or(block.len())]
+            } else {
+                ""
+            };
+            body = &body[sig_end..];
+
+            let (input, output) = match (input, output) {
+                (Some(input), Some(output)) => (input, output),
+                _ => continue,
+            };
+            if output.trim_start_matches('.') != OPERATION || input.starts_with("stream ") {
+                continue;
+            }
+            let info = match block.find(OPERATION_INFO) {
+                Some(i) => &block[i..],
+                None => continue,
+            };
+            if let (Some(response_type), Some(metadata_type)) = (
+                option_value(info, "response_type"),
+                option_value(info, "metadata_type"),
+            ) {
+                methods.push(LroMethod {
+                    service: service.clone(),
+                    name: name.to_owned(),
+                    input: input.to_owned(),
+                    response_type: response_type.to_owned(),
+                    metadata_type: metadata_type.to_owned(),
+                });
+            }
+        }
+    }
+
+    Some((package, methods))
+}
+
+// Same as tonic-build, e.g. `ListSSHKeys` -> `list_ssh_keys`.
+fn snake_case(s: &str) -> String {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut ret = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = matches!(chars.get(i + 1), Some(c) if c.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_lower) {
+                ret.push('_');
+            }
+        }
+        ret.extend(c.to_lowercase());
+    }
+    ret
+}
+
+// Returns the fully-qualified name of a message referenced from `package`.
+fn qualify(package: &str, name: &str) -> String {
+    let name = name.trim_start_matches('.');
+    if name.contains('.') {
+        name.to_owned()
+    } else {
+        format!("{}.{}", package, name)
+    }
+}
+
+// Returns the Rust type of a message, only for the messages of `package` and
+// `google.protobuf.Empty` since other packages may not be enabled by the features.
+fn rust_type(package: &str, name: &str) -> Option<String> {
+    let name = qualify(package, name);
+    if name == EMPTY {
+        return Some("()".to_owned());
+    }
+    let prefix = format!("{}.", package);
+    if name.starts_with(&prefix) && !name[prefix.len()..].contains('.') {
+        Some(name[prefix.len()..].to_owned())
+    } else {
+        None
+    }
+}
+
+impl LroMethod {
+    fn gen_code(&self, package: &str) -> Option<String> {
+        let input = rust_type(package, &self.input)?;
+        let response = rust_type(package, &self.response_type)?;
+        let metadata = rust_type(package, &self.metadata_type)?;
+        Some(format!(
+            r#"#[doc = r" Calls `{name}` and returns its operation typed by `operation_info`."]
+pub async fn {name}_operation(
+    &mut self,
+    request: impl tonic::IntoRequest<{input}>,
+) -> Result<crate::longrunning::Operation<{response}, {metadata}>, tonic::Status> {{
+    let response = self.{name}(request).await?;
+    Ok(crate::longrunning::Operation::with_types(
+        response.into_inner(),
+        "{prefix}{response_type}",
+        "{prefix}{metadata_type}",
+    ))
+}}
+"#,
+            name = snake_case(&self.name),
+            input = input,
+            response = response,
+            metadata = metadata,
+            prefix = TYPE_URL_PREFIX,
+            response_type = qualify(package, &self.response_type),
+            metadata_type = qualify(package, &self.metadata_type),
+        ))
+    }
+}
+
+/// Generates the typed operation helpers of the clients of a package.
+pub fn gen_code(package: &str, methods: &[LroMethod]) -> String {
+    let mut services = BTreeMap::new();
+    for m in methods {
+        if let Some(code) = m.gen_code(package) {
+            services
+                .entry(m.service.as_str())
+                .or_insert_with(String::new)
+                .push_str(&code);
+        }
+    }
+
+    services
+        .into_iter()
+        .map(|(service, methods)| {
+            format!(
+                r#"#[cfg(feature = "longrunning")]
+impl<T> {module}_client::{service}Client<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody>,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{{
+{methods}}}
+"#,
+                module = snake_case(service),
+                service = service,
+                methods = methods,
+            )
+        })
+        .collect()
+}
+
+/// Appends the typed operation helpers to the files in `out_dir` generated from `protos`.
+pub fn append(out_dir: impl AsRef<Path>, protos: &[PathBuf]) {
+    let mut packages = BTreeMap::new();
+    for path in protos {
+        if let Some((package, mut methods)) = parse_proto(&fs::read_to_string(path).unwrap()) {
+            packages
+                .entry(package)
+                .or_insert_with(Vec::new)
+                .append(&mut methods);
+        }
+    }
+
+    for (package, methods) in packages {
+        let code = gen_code(&package, &methods);
+        if code.is_empty() {
+            continue;
+        }
+        let path = out_dir.as_ref().join(format!("{}.rs", package));
+        if let Ok(mut src) = fs::read_to_string(&path) {
+            src.push_str(&code);
+            fs::write(&path, src).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROTO: &str = r#"syntax = "proto3";
+
+package mechiru.library.v1;
+
+import "google/longrunning/operations.proto";
+import "google/protobuf/empty.proto";
+
+service Library {
+  // Creates a book.
+  rpc CreateBook(CreateBookRequest) returns (google.longrunning.Operation) {
+    option (google.api.http) = {
+      post: "/v1/{parent=shelves/*}/books"
+      body: "book"
+    };
+    option (google.longrunning.operation_info) = {
+      response_type: "Book"
+      metadata_type: "mechiru.library.v1.CreateBookMetadata"
+    };
+  }
+
+  rpc DeleteShelf(DeleteShelfRequest) returns (google.longrunning.Operation) {
+    option (google.longrunning.operation_info) = {
+      response_type: "google.protobuf.Empty"
+      metadata_type: "OperationMetadata"
+    };
+  }
+
+  rpc GetBook(GetBookRequest) returns (Book);
+
+  // rpc Commented(CommentedRequest) returns (google.longrunning.Operation) {}
+  rpc ImportISBNs(ImportISBNsRequest) returns (google.longrunning.Operation) {
+    option (google.longrunning.operation_info) = {
+      response_type: "google.cloud.common.ImportResponse"
+      metadata_type: "OperationMetadata"
+    };
+  }
+}
+
+message Book {
+  string name = 1;
+}
+"#;
+
+    fn methods() -> Vec<LroMethod> {
+        vec![
+            LroMethod {
+                service: "Library".into(),
+                name: "CreateBook".into(),
+                input: "CreateBookRequest".into(),
+                response_type: "Book".into(),
+                metadata_type: "mechiru.library.v1.CreateBookMetadata".into(),
+            },
+            LroMethod {
+                service: "Library".into(),
+                name: "DeleteShelf".into(),
+                input: "DeleteShelfRequest".into(),
+                response_type: "google.protobuf.Empty".into(),
+                metadata_type: "OperationMetadata".into(),
+            },
+            LroMethod {
+                service: "Library".into(),
+                name: "ImportISBNs".into(),
+                input: "ImportISBNsRequest".into(),
+                response_type: "google.cloud.common.ImportResponse".into(),
+                metadata_type: "OperationMetadata".into(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_proto() {
+        assert_eq!(
+            parse_proto(PROTO),
+            Some(("mechiru.library.v1".into(), methods()))
+        );
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("CreateBook"), "create_book");
+        assert_eq!(snake_case("ImportISBNs"), "import_isb_ns");
+        assert_eq!(snake_case("ListSSHKeys"), "list_ssh_keys");
+        assert_eq!(snake_case("BatchAnnotateV2"), "batch_annotate_v2");
+    }
+
+    #[test]
+    fn test_rust_type() {
+        let package = "mechiru.library.v1";
+        assert_eq!(rust_type(package, "Book"), Some("Book".into()));
+        assert_eq!(
+            rust_type(package, "mechiru.library.v1.Book"),
+            Some("Book".into())
+        );
+        assert_eq!(
+            rust_type(package, "google.protobuf.Empty"),
+            Some("()".into())
+        );
+        assert_eq!(rust_type(package, "google.cloud.common.Book"), None);
+    }
+
+    #[test]
+    fn test_gen_code() {
+        let code = gen_code("mechiru.library.v1", &methods());
+        assert!(code.contains("impl<T> library_client::LibraryClient<T>"));
+        assert!(code.contains("pub async fn create_book_operation("));
+        assert!(code.contains("crate::longrunning::Operation<Book, CreateBookMetadata>"));
+        assert!(code.contains("\"type.googleapis.com/mechiru.library.v1.Book\""));
+        assert!(code.contains("crate::longrunning::Operation<(), OperationMetadata>"));
+        assert!(code.contains("\"type.googleapis.com/google.protobuf.Empty\""));
+        assert!(!code.contains("import_isb_ns_operation"));
+    }
+}
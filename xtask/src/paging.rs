@@ -0,0 +1,468 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    path::Path,
+};
+
+// https://google.aip.dev/158
+const PAGE_TOKEN: &str = "page_token";
+const PAGE_SIZE: &str = "page_size";
+const NEXT_PAGE_TOKEN: &str = "next_page_token";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    name: String,
+    ty: String,
+    repeated: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Method {
+    name: String,
+    input: String,
+    output: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Client {
+    module: String,
+    name: String,
+    methods: Vec<Method>,
+    // The names of all the methods, including the streaming ones not in `methods`.
+    method_names: Vec<String>,
+}
+
+/// A paginated method: the request has `page_token` and `page_size`, the response has
+/// `next_page_token` and a repeated field holding the items of a page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paginated {
+    method: Method,
+    items: Field,
+}
+
+// Parses the top-level messages of a file generated by prost.
+pub fn parse_messages(src: &str) -> BTreeMap<String, Vec<Field>> {
+    let mut map = BTreeMap::new();
+    let mut lines = src.lines();
+
+    while let Some(line) = lines.next() {
+        const STRUCT: &str = "pub struct ";
+        if !line.starts_with(STRUCT) {
+            continue;
+        }
+        if line.ends_with("{}") {
+            let name = line[STRUCT.len()..].trim_end_matches(" {}").to_owned();
+            map.insert(name, Vec::new());
+            continue;
+        }
+        let name = line[STRUCT.len()..].trim_end_matches(" {").to_owned();
+
+        let mut fields = Vec::new();
+        let mut repeated = false;
+        let mut decl = String::new();
+        for line in lines.by_ref() {
+            if line == "}" {
+                break;
+            }
+            let line = line.trim();
+            if line.starts_with("#[prost(") {
+                repeated = line.contains(" repeated,");
+            } else if line.starts_with("pub ") || !decl.is_empty() {
+                decl.push_str(line);
+                if decl.ends_with(',') {
+                    let mut iter = decl["pub ".len()..decl.len() - 1].splitn(2, ':');
+                    let field = iter.next().unwrap().trim().to_owned();
+                    let ty = iter.next().unwrap_or("").trim().to_owned();
+                    fields.push(Field {
+                        name: field,
+                        ty,
+                        repeated,
+                    });
+                    decl.clear();
+                }
+            }
+        }
+        map.insert(name, fields);
+    }
+
+    map
+}
+
+fn between<'a>(s: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let s = &s[s.find(start)? + start.len()..];
+    Some(&s[..s.find(end)?])
+}
+
+// Parses the unary methods of the clients generated by tonic-build.
+pub fn parse_clients(src: &str) -> Vec<Client> {
+    let mut ret = Vec::new();
+    let mut lines = src.lines();
+
+    while let Some(line) = lines.next() {
+        if !(line.starts_with("pub mod ") && line.ends_with("_client {")) {
+            continue;
+        }
+        let module = line["pub mod ".len()..line.len() - " {".len()].to_owned();
+
+        let mut client = Client {
+            module,
+            name: String::new(),
+            methods: Vec::new(),
+            method_names: Vec::new(),
+        };
+        let mut sig = String::new();
+        for line in lines.by_ref() {
+            if line == "}" {
+                break;
+            }
+            let line = line.trim();
+            if client.name.is_empty() {
+                if let Some(name) = between(line, "pub struct ", "<T>") {
+                    client.name = name.to_owned();
+                }
+            }
+            if line.starts_with("pub async fn ") || !sig.is_empty() {
+                sig.push_str(line);
+                if !sig.ends_with('{') {
+                    continue;
+                }
+                let name = between(&sig, "pub async fn ", "(");
+                if let Some(name) = name {
+                    client.method_names.push(name.to_owned());
+                }
+                let input = between(&sig, "tonic::IntoRequest<super::", ">");
+                let output = between(&sig, "Result<tonic::Response<super::", ">, tonic::Status>");
+                if let (Some(name), Some(input), Some(output)) = (name, input, output) {
+                    client.methods.push(Method {
+                        name: name.to_owned(),
+                        input: input.to_owned(),
+                        output: output.to_owned(),
+                    });
+                }
+                sig.clear();
+            }
+        }
+        ret.push(client);
+    }
+
+    ret
+}
+
+fn paginated(messages: &BTreeMap<String, Vec<Field>>, method: &Method) -> Option<Paginated> {
+    let input = messages.get(&method.input)?;
+    let output = messages.get(&method.output)?;
+
+    let has = |fields: &[Field], name: &str| fields.iter().any(|f| f.name == name && !f.repeated);
+    if !(has(input, PAGE_TOKEN) && has(input, PAGE_SIZE) && has(output, NEXT_PAGE_TOKEN)) {
+        return None;
+    }
+
+    // Same as the client libraries, the first repeated field is the one holding the items when
+    // the response has more than one, e.g. `ListTimeSeriesResponse.execution_errors`.
+    let items = output
+        .iter()
+        .find(|f| f.repeated && f.ty.starts_with("::std::vec::Vec<"))?;
+    Some(Paginated {
+        method: method.clone(),
+        items: items.clone(),
+    })
+}
+
+impl Paginated {
+    fn item_type(&self) -> &str {
+        let ty = &self.items.ty;
+        &ty["::std::vec::Vec<".len()..ty.len() - 1]
+    }
+
+    fn gen_request_impl_code(&self) -> String {
+        format!(
+            r#"#[cfg(feature = "paging")]
+impl crate::paging::PageRequest for {input} {{
+    fn set_page_token(&mut self, page_token: std::string::String) {{
+        self.page_token = page_token;
+    }}
+}}
+"#,
+            input = self.method.input,
+        )
+    }
+
+    fn gen_response_impl_code(&self) -> String {
+        format!(
+            r#"#[cfg(feature = "paging")]
+impl crate::paging::PageResponse for {output} {{
+    type Item = {item};
+    fn next_page_token(&self) -> &str {{
+        &self.next_page_token
+    }}
+    fn into_items(self) -> ::std::vec::Vec<Self::Item> {{
+        self.{items}
+    }}
+}}
+"#,
+            output = self.method.output,
+            item = self.item_type(),
+            items = self.items.name,
+        )
+    }
+
+    fn gen_method_code(&self) -> String {
+        format!(
+            r#"#[doc = r" Returns a stream of the pages of `{name}`, following `next_page_token`."]
+pub fn {name}_pages(
+    &self,
+    request: {input},
+) -> impl futures::Stream<Item = Result<{output}, tonic::Status>> {{
+    let client = self.clone();
+    crate::paging::pages(request, move |req| {{
+        let mut client = client.clone();
+        async move {{ client.{name}(req).await.map(tonic::Response::into_inner) }}
+    }})
+}}
+#[doc = r" Returns a stream of the items of all pages of `{name}`."]
+pub fn {name}_stream(
+    &self,
+    request: {input},
+) -> impl futures::Stream<Item = Result<{item}, tonic::Status>> {{
+    crate::paging::items(self.{name}_pages(request))
+}}
+"#,
+            name = self.method.name,
+            input = self.method.input,
+            output = self.method.output,
+            item = self.item_type(),
+        )
+    }
+}
+
+/// Generates the pagination helpers of a file generated by tonic-build.
+pub fn gen_code(src: &str) -> String {
+    let messages = parse_messages(src);
+
+    let mut requests = HashSet::new();
+    let mut responses = HashSet::new();
+    let mut code = String::new();
+    for client in parse_clients(src) {
+        let names = client
+            .method_names
+            .iter()
+            .map(String::as_str)
+            .collect::<HashSet<_>>();
+        let paginated = client
+            .methods
+            .iter()
+            .filter(|m| {
+                !names.contains(format!("{}_pages", m.name).as_str())
+                    && !names.contains(format!("{}_stream", m.name).as_str())
+            })
+            .filter_map(|m| paginated(&messages, m))
+            .collect::<Vec<_>>();
+        if paginated.is_empty() {
+            continue;
+        }
+
+        // Messages may be shared between methods.
+        for p in paginated.iter() {
+            if requests.insert(p.method.input.clone()) {
+                code.push_str(&p.gen_request_impl_code());
+            }
+            if responses.insert(p.method.output.clone()) {
+                code.push_str(&p.gen_response_impl_code());
+            }
+        }
+        code.push_str(&format!(
+            r#"#[cfg(feature = "paging")]
+impl<T> {module}::{name}<T>
+where
+    T: tonic::client::GrpcService<tonic::body::BoxBody> + Clone,
+    T::ResponseBody: tonic::codegen::Body + tonic::codegen::HttpBody + Send + 'static,
+    T::Error: Into<tonic::codegen::StdError>,
+    <T::ResponseBody as tonic::codegen::HttpBody>::Error: Into<tonic::codegen::StdError> + Send,
+{{
+{methods}}}
+"#,
+            module = client.module,
+            name = client.name,
+            methods = paginated
+                .iter()
+                .map(Paginated::gen_method_code)
+                .collect::<String>(),
+        ));
+    }
+
+    code
+}
+
+/// Appends the pagination helpers to each file in `dir`.
+pub fn append(dir: impl AsRef<Path>) {
+    let mut paths = fs::read_dir(dir.as_ref())
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().filter(|ex| ex == &"rs").is_some())
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    for path in paths {
+        let mut src = fs::read_to_string(&path).unwrap();
+        let code = gen_code(&src);
+        if !code.is_empty() {
+            src.push_str(&code);
+            fs::write(&path, src).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SRC: &str = r#"/// Request for the `ListBooks` method.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBooksRequest {
+    #[prost(string, tag = "1")]
+    pub parent: std::string::String,
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    #[prost(string, tag = "3")]
+    pub page_token: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBooksResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub books: ::std::vec::Vec<Book>,
+    #[prost(string, tag = "2")]
+    pub next_page_token: std::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub unreachable_locations_with_a_very_long_name:
+        ::std::vec::Vec<super::super::rpc::Status>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBookRequest {
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Empty {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Book {
+    #[prost(string, tag = "1")]
+    pub name: std::string::String,
+}
+#[doc = r" Generated client implementations."]
+pub mod library_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    pub struct LibraryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl<T> LibraryClient<T> {
+        pub async fn list_books(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListBooksRequest>,
+        ) -> Result<tonic::Response<super::ListBooksResponse>, tonic::Status> {
+            unimplemented!()
+        }
+        pub async fn get_book(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBookRequest>,
+        ) -> Result<tonic::Response<super::Book>, tonic::Status> {
+            unimplemented!()
+        }
+        pub async fn watch_books(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListBooksRequest>,
+        ) -> Result<tonic::Response<tonic::codec::Streaming<super::Book>>, tonic::Status> {
+            unimplemented!()
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn test_parse_messages() {
+        let messages = parse_messages(SRC);
+        assert_eq!(messages.len(), 5);
+        assert_eq!(messages["Empty"], Vec::new());
+        assert_eq!(
+            messages["ListBooksResponse"],
+            vec![
+                Field {
+                    name: "books".into(),
+                    ty: "::std::vec::Vec<Book>".into(),
+                    repeated: true,
+                },
+                Field {
+                    name: "next_page_token".into(),
+                    ty: "std::string::String".into(),
+                    repeated: false,
+                },
+                Field {
+                    name: "unreachable_locations_with_a_very_long_name".into(),
+                    ty: "::std::vec::Vec<super::super::rpc::Status>".into(),
+                    repeated: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_clients() {
+        assert_eq!(
+            parse_clients(SRC),
+            vec![Client {
+                module: "library_client".into(),
+                name: "LibraryClient".into(),
+                methods: vec![
+                    Method {
+                        name: "list_books".into(),
+                        input: "ListBooksRequest".into(),
+                        output: "ListBooksResponse".into(),
+                    },
+                    Method {
+                        name: "get_book".into(),
+                        input: "GetBookRequest".into(),
+                        output: "Book".into(),
+                    },
+                ],
+                method_names: vec!["list_books".into(), "get_book".into(), "watch_books".into()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_paginated() {
+        let messages = parse_messages(SRC);
+        let methods = parse_clients(SRC).remove(0).methods;
+        assert_eq!(
+            paginated(&messages, &methods[0]),
+            Some(Paginated {
+                method: methods[0].clone(),
+                items: messages["ListBooksResponse"][0].clone(),
+            })
+        );
+        assert_eq!(paginated(&messages, &methods[1]), None);
+    }
+
+    #[test]
+    fn test_gen_code() {
+        let code = gen_code(SRC);
+        assert!(code.contains("impl crate::paging::PageRequest for ListBooksRequest {"));
+        assert!(code.contains("type Item = Book;"));
+        assert!(code.contains("impl<T> library_client::LibraryClient<T>"));
+        assert!(code.contains("pub fn list_books_pages("));
+        assert!(code.contains("pub fn list_books_stream("));
+        assert!(!code.contains("get_book_"));
+        assert!(!code.contains("watch_books_"));
+    }
+
+    #[test]
+    fn test_gen_code_name_clash() {
+        // The helpers of `foo` would clash with the server streaming method `foo_stream`.
+        let src = SRC
+            .replace("list_books", "foo")
+            .replace("watch_books", "foo_stream");
+        let code = gen_code(&src);
+        assert!(!code.contains("pub fn foo_pages("));
+        assert!(!code.contains("pub fn foo_stream("));
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+// https://github.com/grpc/grpc/blob/master/doc/service_config.md
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodConfig {
+    timeout: Option<u64>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: Option<u64>,
+    initial_backoff: u64,
+    max_backoff: u64,
+    backoff_multiplier: f64,
+    retryable_status_codes: Vec<String>,
+}
+
+impl MethodConfig {
+    fn gen_code(&self) -> String {
+        let timeout = match self.timeout {
+            Some(ms) => format!("Some(Duration::from_millis({}))", ms),
+            None => "None".to_owned(),
+        };
+        let retry_policy = match &self.retry_policy {
+            Some(p) => format!(
+                "Some(RetryPolicy {{ max_attempts: {max_attempts}, initial_backoff: Duration::from_millis({initial_backoff}), max_backoff: Duration::from_millis({max_backoff}), backoff_multiplier: {backoff_multiplier:?}, retryable_status_codes: &[{codes}] }})",
+                max_attempts = match p.max_attempts {
+                    Some(n) => format!("Some({})", n),
+                    None => "None".to_owned(),
+                },
+                initial_backoff = p.initial_backoff,
+                max_backoff = p.max_backoff,
+                backoff_multiplier = p.backoff_multiplier,
+                codes = p
+                    .retryable_status_codes
+                    .iter()
+                    .map(|c| format!("Code::{},", c))
+                    .collect::<String>(),
+            ),
+            None => "None".to_owned(),
+        };
+        format!(
+            "MethodConfig {{ timeout: {timeout}, retry_policy: {retry_policy} }}",
+            timeout = timeout,
+            retry_policy = retry_policy,
+        )
+    }
+}
+
+pub fn find_service_config(dir: impl AsRef<Path>) -> Vec<PathBuf> {
+    let mut ret = Vec::new();
+    for entry in fs::read_dir(dir.as_ref()).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            ret.append(&mut find_service_config(path));
+        } else if path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .filter(|n| n.ends_with("_grpc_service_config.json"))
+            .is_some()
+        {
+            ret.push(path);
+        }
+    }
+    ret.sort();
+    ret
+}
+
+// Durations are encoded as the JSON representation of `google.protobuf.Duration`, e.g. "0.100s".
+fn parse_duration(s: &str) -> Option<u64> {
+    if !s.ends_with('s') {
+        return None;
+    }
+    let secs = s[..s.len() - 1].parse::<f64>().ok()?;
+    Some((secs * 1000.0).round() as u64)
+}
+
+// https://github.com/grpc/grpc/blob/master/doc/statuscodes.md
+fn parse_code(s: &str) -> Option<&'static str> {
+    Some(match s {
+        "OK" => "Ok",
+        "CANCELLED" => "Cancelled",
+        "UNKNOWN" => "Unknown",
+        "INVALID_ARGUMENT" => "InvalidArgument",
+        "DEADLINE_EXCEEDED" => "DeadlineExceeded",
+        "NOT_FOUND" => "NotFound",
+        "ALREADY_EXISTS" => "AlreadyExists",
+        "PERMISSION_DENIED" => "PermissionDenied",
+        "RESOURCE_EXHAUSTED" => "ResourceExhausted",
+        "FAILED_PRECONDITION" => "FailedPrecondition",
+        "ABORTED" => "Aborted",
+        "OUT_OF_RANGE" => "OutOfRange",
+        "UNIMPLEMENTED" => "Unimplemented",
+        "INTERNAL" => "Internal",
+        "UNAVAILABLE" => "Unavailable",
+        "DATA_LOSS" => "DataLoss",
+        "UNAUTHENTICATED" => "Unauthenticated",
+        _ => return None,
+    })
+}
+
+fn parse_retry_policy(v: &Value) -> Option<RetryPolicy> {
+    let mut codes = v["retryableStatusCodes"]
+        .as_array()?
+        .iter()
+        .filter_map(|c| c.as_str().and_then(parse_code))
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+    codes.dedup();
+    Some(RetryPolicy {
+        max_attempts: v["maxAttempts"].as_u64(),
+        initial_backoff: v["initialBackoff"].as_str().and_then(parse_duration)?,
+        max_backoff: v["maxBackoff"].as_str().and_then(parse_duration)?,
+        backoff_multiplier: v["backoffMultiplier"].as_f64()?,
+        retryable_status_codes: codes,
+    })
+}
+
+/// Parses a service config and returns the method configs keyed by method path, e.g.
+/// `/google.pubsub.v1.Publisher/Publish`. Service-level entries are keyed by the service path
+/// with a trailing slash, e.g. `/google.pubsub.v1.Publisher/`.
+pub fn parse_service_config(json: &str) -> BTreeMap<String, MethodConfig> {
+    let mut map = BTreeMap::new();
+    let root: Value = serde_json::from_str(json).unwrap();
+
+    for config in root["methodConfig"].as_array().into_iter().flatten() {
+        let method_config = MethodConfig {
+            timeout: config["timeout"].as_str().and_then(parse_duration),
+            retry_policy: parse_retry_policy(&config["retryPolicy"]),
+        };
+        for name in config["name"].as_array().into_iter().flatten() {
+            let service = match name["service"].as_str() {
+                Some(s) => s,
+                None => continue,
+            };
+            let method = name["method"].as_str().unwrap_or("");
+            map.insert(format!("/{}/{}", service, method), method_config.clone());
+        }
+    }
+
+    map
+}
+
+pub fn gen_code(paths: &[PathBuf]) -> String {
+    let mut map = BTreeMap::new();
+    for path in paths {
+        map.extend(parse_service_config(&fs::read_to_string(path).unwrap()));
+    }
+
+    let entries = map
+        .into_iter()
+        .map(|(k, v)| format!("({:?}, {}),\n", k, v.gen_code()))
+        .collect::<String>();
+    format!(
+        "const METHOD_CONFIGS: &[(&str, MethodConfig)] = &[\n{}];\n",
+        entries
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SERVICE_CONFIG: &str = r#"{
+  "methodConfig": [
+    {
+      "name": [{ "service": "mechiru.v1.Storage" }],
+      "timeout": "60s"
+    },
+    {
+      "name": [
+        { "service": "mechiru.v1.Storage", "method": "Get" },
+        { "service": "mechiru.v1.Storage", "method": "List" }
+      ],
+      "timeout": "600s",
+      "retryPolicy": {
+        "initialBackoff": "0.100s",
+        "maxBackoff": "60s",
+        "backoffMultiplier": 1.3,
+        "retryableStatusCodes": ["UNAVAILABLE", "DEADLINE_EXCEEDED"]
+      }
+    }
+  ]
+}"#;
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("0.100s"), Some(100));
+        assert_eq!(parse_duration("60s"), Some(60000));
+        assert_eq!(parse_duration("60"), None);
+    }
+
+    #[test]
+    fn test_parse_service_config() {
+        let retry_policy = RetryPolicy {
+            max_attempts: None,
+            initial_backoff: 100,
+            max_backoff: 60000,
+            backoff_multiplier: 1.3,
+            retryable_status_codes: vec!["Unavailable".into(), "DeadlineExceeded".into()],
+        };
+        assert_eq!(parse_service_config(SERVICE_CONFIG), {
+            let mut map = BTreeMap::new();
+            map.insert(
+                "/mechiru.v1.Storage/".into(),
+                MethodConfig {
+                    timeout: Some(60000),
+                    retry_policy: None,
+                },
+            );
+            map.insert(
+                "/mechiru.v1.Storage/Get".into(),
+                MethodConfig {
+                    timeout: Some(600000),
+                    retry_policy: Some(retry_policy.clone()),
+                },
+            );
+            map.insert(
+                "/mechiru.v1.Storage/List".into(),
+                MethodConfig {
+                    timeout: Some(600000),
+                    retry_policy: Some(retry_policy),
+                },
+            );
+            map
+        });
+    }
+
+    #[test]
+    fn test_method_config_gen_code() {
+        let config = parse_service_config(SERVICE_CONFIG);
+        assert_eq!(
+            config["/mechiru.v1.Storage/"].gen_code(),
+            "MethodConfig { timeout: Some(Duration::from_millis(60000)), retry_policy: None }"
+        );
+        assert_eq!(
+            config["/mechiru.v1.Storage/Get"].gen_code(),
+            "MethodConfig { timeout: Some(Duration::from_millis(600000)), retry_policy: Some(RetryPolicy { max_attempts: None, initial_backoff: Duration::from_millis(100), max_backoff: Duration::from_millis(60000), backoff_multiplier: 1.3, retryable_status_codes: &[Code::Unavailable,Code::DeadlineExceeded,] }) }"
+        );
+    }
+}